@@ -1,7 +1,8 @@
-use axum::extract::{Path, Query};
+use axum::extract::{Path, Query, State};
 use axum::Json;
 use serde_json::Value;
 use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
 use crate::api::dto::info_dto::K8sListQuery;
 use crate::api::util::validation_ext::ValidateRequestExt;
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
@@ -10,24 +11,27 @@ use crate::domain::info::dto::info_setting_upsert_request::InfoSettingUpsertRequ
 use crate::domain::info::service::info_k8s_container_service;
 
 pub async fn get_info_k8s_container(
+    State(state): State<AppState>,
     Path(id): Path<String>,
 ) -> Json<ApiResponse<InfoContainerEntity>> {
-    match info_k8s_container_service::get_info_k8s_container(id).await {
+    match info_k8s_container_service::get_info_k8s_container(&state, id).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
 }
 
 pub async fn list_k8s_containers(
+    State(state): State<AppState>,
     Query(filter): Query<K8sListQuery>,
 ) -> Json<ApiResponse<Vec<InfoContainerEntity>>> {
-    match info_k8s_container_service::list_k8s_containers(filter).await {
+    match info_k8s_container_service::list_k8s_containers(&state, filter).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
 }
 
 pub async fn patch_info_k8s_container(
+    State(state): State<AppState>,
     Path(id): Path<String>,
     Json(payload): Json<InfoK8sContainerPatchRequest>,
 ) -> Json<ApiResponse<Value>> {
@@ -38,7 +42,7 @@ pub async fn patch_info_k8s_container(
     };
 
     // ✅ 2. Call your service
-    match info_k8s_container_service::patch_info_k8s_container(id, payload).await {
+    match info_k8s_container_service::patch_info_k8s_container(&state, id, payload).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
@@ -1,7 +1,8 @@
 use axum::Json;
-use axum::extract::Path;
+use axum::extract::{Path, State};
 use serde_json::Value;
 use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
 use crate::api::util::validation_ext::ValidateRequestExt;
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
 use crate::domain::info::dto::info_k8s_container_patch_request::InfoK8sContainerPatchRequest;
@@ -9,22 +10,24 @@ use crate::domain::info::dto::info_k8s_node_patch_request::InfoK8sNodePatchReque
 use crate::domain::info::service::info_k8s_node_service;
 
 pub async fn get_info_k8s_node(
+    State(state): State<AppState>,
     Path(node_name): Path<String>,
 ) -> Json<ApiResponse<InfoNodeEntity>> {
-    match info_k8s_node_service::get_info_k8s_node(node_name).await {
+    match info_k8s_node_service::get_info_k8s_node(&state, node_name).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
 }
 
-pub async fn list_k8s_nodes() -> Json<ApiResponse<Vec<InfoNodeEntity>>> {
-    match info_k8s_node_service::list_k8s_nodes().await {
+pub async fn list_k8s_nodes(State(state): State<AppState>) -> Json<ApiResponse<Vec<InfoNodeEntity>>> {
+    match info_k8s_node_service::list_k8s_nodes(&state).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
 }
 
 pub async fn patch_info_k8s_node(
+    State(state): State<AppState>,
     Path(id): Path<String>,
     Json(payload): Json<InfoK8sNodePatchRequest>,
 ) -> Json<ApiResponse<Value>> {
@@ -35,7 +38,7 @@ pub async fn patch_info_k8s_node(
     };
 
     // ✅ 2. Call your service
-    match info_k8s_node_service::patch_info_k8s_node(id, payload).await {
+    match info_k8s_node_service::patch_info_k8s_node(&state, id, payload).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
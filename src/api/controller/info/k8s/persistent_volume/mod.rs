@@ -1,9 +1,11 @@
+use axum::extract::State;
 use axum::Json;
 use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
 use crate::domain::info::service::info_k8s_persistent_volume_service;
 
-pub async fn get_k8s_persistent_volumes() -> Json<ApiResponse<serde_json::Value>> {
-    match info_k8s_persistent_volume_service::get_k8s_persistent_volumes().await {
+pub async fn get_k8s_persistent_volumes(State(state): State<AppState>) -> Json<ApiResponse<serde_json::Value>> {
+    match info_k8s_persistent_volume_service::get_k8s_persistent_volumes(&state).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
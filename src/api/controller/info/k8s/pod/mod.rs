@@ -1,7 +1,8 @@
-use axum::extract::{Path, Query};
+use axum::extract::{Path, Query, State};
 use axum::Json;
 use serde_json::Value;
 use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
 use crate::api::dto::info_dto::K8sListQuery;
 use crate::api::util::validation_ext::ValidateRequestExt;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
@@ -10,9 +11,10 @@ use crate::domain::info::dto::info_k8s_pod_patch_request::InfoK8sPodPatchRequest
 use crate::domain::info::service::{info_k8s_container_service, info_k8s_pod_service};
 
 pub async fn get_info_k8s_pod(
+    State(state): State<AppState>,
     Path(pod_uid): Path<String>,
 ) -> Json<ApiResponse<InfoPodEntity>> {
-    match info_k8s_pod_service::get_info_k8s_pod(pod_uid).await {
+    match info_k8s_pod_service::get_info_k8s_pod(&state, pod_uid).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
@@ -20,15 +22,17 @@ pub async fn get_info_k8s_pod(
 
 /// List pods — optionally filter by `namespace`, `labelSelector`, or `nodeName`
 pub async fn list_k8s_pods(
+    State(state): State<AppState>,
     Query(filter): Query<K8sListQuery>,
 ) -> Json<ApiResponse<Vec<InfoPodEntity>>> {
-    match info_k8s_pod_service::list_k8s_pods(filter).await {
+    match info_k8s_pod_service::list_k8s_pods(&state, filter).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
 }
 
 pub async fn patch_info_k8s_pod(
+    State(state): State<AppState>,
     Path(id): Path<String>,
     Json(payload): Json<InfoK8sPodPatchRequest>,
 ) -> Json<ApiResponse<Value>> {
@@ -39,7 +43,7 @@ pub async fn patch_info_k8s_pod(
     };
 
     // ✅ 2. Call your service
-    match info_k8s_pod_service::patch_info_k8s_pod(id, payload).await {
+    match info_k8s_pod_service::patch_info_k8s_pod(&state, id, payload).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
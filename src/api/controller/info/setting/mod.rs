@@ -1,17 +1,20 @@
+use axum::extract::State;
 use axum::Json;
 use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
 use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
 use crate::domain::info::dto::info_setting_upsert_request::InfoSettingUpsertRequest;
 use crate::api::util::validation_ext::ValidateRequestExt;
 use serde_json::Value;
-pub async fn get_info_settings() -> Json<ApiResponse<InfoSettingEntity>> {
-    match crate::domain::info::service::info_settings_service::get_info_settings().await {
+pub async fn get_info_settings(State(state): State<AppState>) -> Json<ApiResponse<InfoSettingEntity>> {
+    match crate::domain::info::service::info_settings_service::get_info_settings(&state).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
 }
 
 pub async fn upsert_info_settings(
+    State(state): State<AppState>,
     Json(payload): Json<InfoSettingUpsertRequest>,
 ) -> Json<ApiResponse<Value>> {
     // ✅ 1. Validate the payload
@@ -21,7 +24,7 @@ pub async fn upsert_info_settings(
     };
 
     // ✅ 2. Call your service
-    match crate::domain::info::service::info_settings_service::upsert_info_settings(payload).await {
+    match crate::domain::info::service::info_settings_service::upsert_info_settings(&state, payload).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
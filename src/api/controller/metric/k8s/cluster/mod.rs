@@ -1,6 +1,7 @@
-use axum::{extract::Query, Json};
+use axum::{extract::{Query, State}, Json};
 use serde_json::Value;
 use crate::api::dto::{ApiResponse, metrics_dto::RangeQuery};
+use crate::app_state::AppState;
 use crate::domain::info::service::{info_k8s_node_service, info_unit_price_service};
 use crate::domain::metric::k8s::cluster::service as metric_k8s_cluster_service;
 
@@ -16,10 +17,10 @@ use crate::domain::metric::k8s::cluster::service as metric_k8s_cluster_service;
 
 
 // Time-series for charts
-pub async fn get_metric_k8s_cluster_raw(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_cluster_raw(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let nodes = info_k8s_node_service::list_k8s_nodes().await?;
-        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_raw(nodes, q).await?;
+        let nodes = info_k8s_node_service::list_cost_nodes(&state).await?;
+        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_raw(&state, nodes, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
         .await
@@ -30,10 +31,10 @@ pub async fn get_metric_k8s_cluster_raw(Query(q): Query<RangeQuery>) -> Json<Api
 }
 
 // Aggregated snapshot (avg/sum for time range)
-pub async fn get_metric_k8s_cluster_raw_summary(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_cluster_raw_summary(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let nodes = info_k8s_node_service::list_k8s_nodes().await?;
-        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_raw_summary(nodes, q).await?;
+        let nodes = info_k8s_node_service::list_cost_nodes(&state).await?;
+        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_raw_summary(&state, nodes, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
         .await
@@ -44,11 +45,11 @@ pub async fn get_metric_k8s_cluster_raw_summary(Query(q): Query<RangeQuery>) ->
 }
 
 // Derived cost over time for charts
-pub async fn get_metric_k8s_cluster_cost(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_cluster_cost(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let nodes = info_k8s_node_service::list_k8s_nodes().await?;
-        let costs = info_unit_price_service::get_info_unit_prices().await?;
-        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_cost(nodes, costs, q).await?;
+        let nodes = info_k8s_node_service::list_cost_nodes(&state).await?;
+        let costs = info_unit_price_service::get_info_unit_prices(&state).await?;
+        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_cost(&state, nodes, costs, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
         .await
@@ -59,11 +60,11 @@ pub async fn get_metric_k8s_cluster_cost(Query(q): Query<RangeQuery>) -> Json<Ap
 }
 
 // Summarized cost (total/avg for time range)
-pub async fn get_metric_k8s_cluster_cost_summary(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_cluster_cost_summary(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let nodes = info_k8s_node_service::list_k8s_nodes().await?;
-        let costs = info_unit_price_service::get_info_unit_prices().await?;
-        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_cost_summary(nodes, costs, q).await?;
+        let nodes = info_k8s_node_service::list_cost_nodes(&state).await?;
+        let costs = info_unit_price_service::get_info_unit_prices(&state).await?;
+        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_cost_summary(&state, nodes, costs, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
         .await
@@ -74,11 +75,11 @@ pub async fn get_metric_k8s_cluster_cost_summary(Query(q): Query<RangeQuery>) ->
 }
 
 // Trendline (growth, regression, prediction)
-pub async fn get_metric_k8s_cluster_cost_trend(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_cluster_cost_trend(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let nodes = info_k8s_node_service::list_k8s_nodes().await?;
-        let costs = info_unit_price_service::get_info_unit_prices().await?;
-        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_cost_trend(nodes, costs, q).await?;
+        let nodes = info_k8s_node_service::list_cost_nodes(&state).await?;
+        let costs = info_unit_price_service::get_info_unit_prices(&state).await?;
+        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_cost_trend(&state, nodes, costs, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
         .await
@@ -90,10 +91,10 @@ pub async fn get_metric_k8s_cluster_cost_trend(Query(q): Query<RangeQuery>) -> J
 
 // Ratios (cost per CPU, cost per pod, etc.)
 
-pub async fn get_metric_k8s_cluster_raw_efficiency(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_cluster_raw_efficiency(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let nodes = info_k8s_node_service::list_k8s_nodes().await?;
-        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_raw_efficiency(nodes, q).await?;
+        let nodes = info_k8s_node_service::list_cost_nodes(&state).await?;
+        let result = metric_k8s_cluster_service::get_metric_k8s_cluster_raw_efficiency(&state, nodes, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
         .await
@@ -1,15 +1,16 @@
 use axum::{
-    extract::{Path, Query},
+    extract::{Path, Query, State},
     Json,
 };
 use serde_json::Value;
 
 use crate::api::dto::{ApiResponse, metrics_dto::RangeQuery};
+use crate::app_state::AppState;
 use crate::domain::metric::k8s::container::service as metric_k8s_container_service;
 
-pub async fn get_metric_k8s_containers_raw(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_containers_raw(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_containers_raw(q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_containers_raw(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -19,9 +20,9 @@ pub async fn get_metric_k8s_containers_raw(Query(q): Query<RangeQuery>) -> Json<
     }
 }
 
-pub async fn get_metric_k8s_containers_raw_summary(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_containers_raw_summary(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_containers_raw_summary(q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_containers_raw_summary(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -31,9 +32,9 @@ pub async fn get_metric_k8s_containers_raw_summary(Query(q): Query<RangeQuery>)
     }
 }
 
-pub async fn get_metric_k8s_containers_raw_efficiency(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_containers_raw_efficiency(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_containers_raw_efficiency(q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_containers_raw_efficiency(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -44,11 +45,12 @@ pub async fn get_metric_k8s_containers_raw_efficiency(Query(q): Query<RangeQuery
 }
 
 pub async fn get_metric_k8s_container_raw(
+    State(state): State<AppState>,
     Path(id): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_container_raw(id, q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_container_raw(&state, id, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -59,11 +61,12 @@ pub async fn get_metric_k8s_container_raw(
 }
 
 pub async fn get_metric_k8s_container_raw_summary(
+    State(state): State<AppState>,
     Path(id): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_container_raw_summary(id, q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_container_raw_summary(&state, id, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -74,11 +77,12 @@ pub async fn get_metric_k8s_container_raw_summary(
 }
 
 pub async fn get_metric_k8s_container_raw_efficiency(
+    State(state): State<AppState>,
     Path(id): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_container_raw_efficiency(id, q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_container_raw_efficiency(&state, id, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -88,9 +92,9 @@ pub async fn get_metric_k8s_container_raw_efficiency(
     }
 }
 
-pub async fn get_metric_k8s_containers_cost(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_containers_cost(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_containers_cost(q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_containers_cost(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -100,9 +104,9 @@ pub async fn get_metric_k8s_containers_cost(Query(q): Query<RangeQuery>) -> Json
     }
 }
 
-pub async fn get_metric_k8s_containers_cost_summary(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_containers_cost_summary(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_containers_cost_summary(q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_containers_cost_summary(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -112,9 +116,9 @@ pub async fn get_metric_k8s_containers_cost_summary(Query(q): Query<RangeQuery>)
     }
 }
 
-pub async fn get_metric_k8s_containers_cost_trend(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_containers_cost_trend(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_containers_cost_trend(q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_containers_cost_trend(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -125,11 +129,12 @@ pub async fn get_metric_k8s_containers_cost_trend(Query(q): Query<RangeQuery>) -
 }
 
 pub async fn get_metric_k8s_container_cost(
+    State(state): State<AppState>,
     Path(id): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_container_cost(id, q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_container_cost(&state, id, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -140,11 +145,12 @@ pub async fn get_metric_k8s_container_cost(
 }
 
 pub async fn get_metric_k8s_container_cost_summary(
+    State(state): State<AppState>,
     Path(id): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_container_cost_summary(id, q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_container_cost_summary(&state, id, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -155,11 +161,12 @@ pub async fn get_metric_k8s_container_cost_summary(
 }
 
 pub async fn get_metric_k8s_container_cost_trend(
+    State(state): State<AppState>,
     Path(id): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_container_service::get_metric_k8s_container_cost_trend(id, q).await?;
+        let result = metric_k8s_container_service::get_metric_k8s_container_cost_trend(&state, id, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
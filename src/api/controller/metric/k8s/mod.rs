@@ -3,4 +3,6 @@ pub mod container;
 pub mod deployment;
 pub mod namespace;
 pub mod node;
-pub mod pod;
\ No newline at end of file
+pub mod node_network;
+pub mod pod;
+pub mod pvc;
\ No newline at end of file
@@ -1,15 +1,16 @@
 use axum::{
-    extract::{Path, Query},
+    extract::{Path, Query, State},
     Json,
 };
 use serde_json::Value;
 
 use crate::api::dto::{ApiResponse, metrics_dto::RangeQuery};
+use crate::app_state::AppState;
 use crate::domain::metric::k8s::namespace::service as metric_k8s_namespace_service;
 
-pub async fn get_metric_k8s_namespaces_raw(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_namespaces_raw(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_namespace_service::get_metric_k8s_namespaces_raw(q).await?;
+        let result = metric_k8s_namespace_service::get_metric_k8s_namespaces_raw(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -19,9 +20,9 @@ pub async fn get_metric_k8s_namespaces_raw(Query(q): Query<RangeQuery>) -> Json<
     }
 }
 
-pub async fn get_metric_k8s_namespaces_raw_summary(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_namespaces_raw_summary(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_namespace_service::get_metric_k8s_namespaces_raw_summary(q).await?;
+        let result = metric_k8s_namespace_service::get_metric_k8s_namespaces_raw_summary(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -44,11 +45,12 @@ pub async fn get_metric_k8s_namespaces_raw_efficiency(Query(q): Query<RangeQuery
 }
 
 pub async fn get_metric_k8s_namespace_raw(
+    State(state): State<AppState>,
     Path(namespace): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_namespace_service::get_metric_k8s_namespace_raw(namespace, q).await?;
+        let result = metric_k8s_namespace_service::get_metric_k8s_namespace_raw(&state, namespace, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -59,12 +61,13 @@ pub async fn get_metric_k8s_namespace_raw(
 }
 
 pub async fn get_metric_k8s_namespace_raw_summary(
+    State(state): State<AppState>,
     Path(namespace): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
         let result =
-            metric_k8s_namespace_service::get_metric_k8s_namespace_raw_summary(namespace, q).await?;
+            metric_k8s_namespace_service::get_metric_k8s_namespace_raw_summary(&state, namespace, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -90,9 +93,9 @@ pub async fn get_metric_k8s_namespace_raw_efficiency(
     }
 }
 
-pub async fn get_metric_k8s_namespaces_cost(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_namespaces_cost(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_namespace_service::get_metric_k8s_namespaces_cost(q).await?;
+        let result = metric_k8s_namespace_service::get_metric_k8s_namespaces_cost(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -102,9 +105,9 @@ pub async fn get_metric_k8s_namespaces_cost(Query(q): Query<RangeQuery>) -> Json
     }
 }
 
-pub async fn get_metric_k8s_namespaces_cost_summary(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_namespaces_cost_summary(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_namespace_service::get_metric_k8s_namespaces_cost_summary(q).await?;
+        let result = metric_k8s_namespace_service::get_metric_k8s_namespaces_cost_summary(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -114,9 +117,9 @@ pub async fn get_metric_k8s_namespaces_cost_summary(Query(q): Query<RangeQuery>)
     }
 }
 
-pub async fn get_metric_k8s_namespaces_cost_trend(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_namespaces_cost_trend(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_namespace_service::get_metric_k8s_namespaces_cost_trend(q).await?;
+        let result = metric_k8s_namespace_service::get_metric_k8s_namespaces_cost_trend(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -127,11 +130,12 @@ pub async fn get_metric_k8s_namespaces_cost_trend(Query(q): Query<RangeQuery>) -
 }
 
 pub async fn get_metric_k8s_namespace_cost(
+    State(state): State<AppState>,
     Path(namespace): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_namespace_service::get_metric_k8s_namespace_cost(namespace, q).await?;
+        let result = metric_k8s_namespace_service::get_metric_k8s_namespace_cost(&state, namespace, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -142,12 +146,13 @@ pub async fn get_metric_k8s_namespace_cost(
 }
 
 pub async fn get_metric_k8s_namespace_cost_summary(
+    State(state): State<AppState>,
     Path(namespace): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
         let result =
-            metric_k8s_namespace_service::get_metric_k8s_namespace_cost_summary(namespace, q).await?;
+            metric_k8s_namespace_service::get_metric_k8s_namespace_cost_summary(&state, namespace, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -158,12 +163,13 @@ pub async fn get_metric_k8s_namespace_cost_summary(
 }
 
 pub async fn get_metric_k8s_namespace_cost_trend(
+    State(state): State<AppState>,
     Path(namespace): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
         let result =
-            metric_k8s_namespace_service::get_metric_k8s_namespace_cost_trend(namespace, q).await?;
+            metric_k8s_namespace_service::get_metric_k8s_namespace_cost_trend(&state, namespace, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
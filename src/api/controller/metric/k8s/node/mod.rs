@@ -1,15 +1,16 @@
 use axum::{
-    extract::{Path, Query},
+    extract::{Path, Query, State},
     Json,
 };
 use serde_json::Value;
 
 use crate::api::dto::{ApiResponse, metrics_dto::RangeQuery};
+use crate::app_state::AppState;
 use crate::domain::metric::k8s::node::service as metric_k8s_node_service;
 
-pub async fn get_metric_k8s_nodes_raw(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_nodes_raw(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_nodes_raw(q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_nodes_raw(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -19,9 +20,9 @@ pub async fn get_metric_k8s_nodes_raw(Query(q): Query<RangeQuery>) -> Json<ApiRe
     }
 }
 
-pub async fn get_metric_k8s_nodes_raw_summary(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_nodes_raw_summary(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_nodes_raw_summary(q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_nodes_raw_summary(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -31,9 +32,9 @@ pub async fn get_metric_k8s_nodes_raw_summary(Query(q): Query<RangeQuery>) -> Js
     }
 }
 
-pub async fn get_metric_k8s_nodes_raw_efficiency(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_nodes_raw_efficiency(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_nodes_raw_efficiency(q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_nodes_raw_efficiency(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -44,11 +45,12 @@ pub async fn get_metric_k8s_nodes_raw_efficiency(Query(q): Query<RangeQuery>) ->
 }
 
 pub async fn get_metric_k8s_node_raw(
+    State(state): State<AppState>,
     Path(node_name): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_node_raw(node_name, q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_node_raw(&state, node_name, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -59,11 +61,12 @@ pub async fn get_metric_k8s_node_raw(
 }
 
 pub async fn get_metric_k8s_node_raw_summary(
+    State(state): State<AppState>,
     Path(node_name): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_node_raw_summary(node_name, q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_node_raw_summary(&state, node_name, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -74,11 +77,12 @@ pub async fn get_metric_k8s_node_raw_summary(
 }
 
 pub async fn get_metric_k8s_node_raw_efficiency(
+    State(state): State<AppState>,
     Path(node_name): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_node_raw_efficiency(node_name, q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_node_raw_efficiency(&state, node_name, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -88,9 +92,9 @@ pub async fn get_metric_k8s_node_raw_efficiency(
     }
 }
 
-pub async fn get_metric_k8s_nodes_cost(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_nodes_cost(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_nodes_cost(q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_nodes_cost(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -100,9 +104,9 @@ pub async fn get_metric_k8s_nodes_cost(Query(q): Query<RangeQuery>) -> Json<ApiR
     }
 }
 
-pub async fn get_metric_k8s_nodes_cost_summary(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_nodes_cost_summary(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_nodes_cost_summary(q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_nodes_cost_summary(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -112,9 +116,9 @@ pub async fn get_metric_k8s_nodes_cost_summary(Query(q): Query<RangeQuery>) -> J
     }
 }
 
-pub async fn get_metric_k8s_nodes_cost_trend(Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+pub async fn get_metric_k8s_nodes_cost_trend(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_nodes_cost_trend(q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_nodes_cost_trend(&state, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -125,11 +129,12 @@ pub async fn get_metric_k8s_nodes_cost_trend(Query(q): Query<RangeQuery>) -> Jso
 }
 
 pub async fn get_metric_k8s_node_cost(
+    State(state): State<AppState>,
     Path(node_name): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_node_cost(node_name, q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_node_cost(&state, node_name, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -140,12 +145,13 @@ pub async fn get_metric_k8s_node_cost(
 }
 
 pub async fn get_metric_k8s_node_cost_summary(
+    State(state): State<AppState>,
     Path(node_name): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
         let result =
-            metric_k8s_node_service::get_metric_k8s_node_cost_summary(node_name, q).await?;
+            metric_k8s_node_service::get_metric_k8s_node_cost_summary(&state, node_name, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -156,11 +162,40 @@ pub async fn get_metric_k8s_node_cost_summary(
 }
 
 pub async fn get_metric_k8s_node_cost_trend(
+    State(state): State<AppState>,
     Path(node_name): Path<String>,
     Query(q): Query<RangeQuery>,
 ) -> Json<ApiResponse<Value>> {
     match async {
-        let result = metric_k8s_node_service::get_metric_k8s_node_cost_trend(node_name, q).await?;
+        let result = metric_k8s_node_service::get_metric_k8s_node_cost_trend(&state, node_name, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_nodes_image_fs(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_node_service::get_metric_k8s_nodes_image_fs(&state, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_node_image_fs(
+    State(state): State<AppState>,
+    Path(node_name): Path<String>,
+    Query(q): Query<RangeQuery>,
+) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_node_service::get_metric_k8s_node_image_fs(&state, node_name, q).await?;
         Ok::<Value, anyhow::Error>(result)
     }
     .await
@@ -173,12 +208,14 @@ pub async fn get_metric_k8s_node_cost_trend(
 pub use get_metric_k8s_node_cost as node_cost;
 pub use get_metric_k8s_node_cost_summary as node_cost_summary;
 pub use get_metric_k8s_node_cost_trend as node_cost_trend;
+pub use get_metric_k8s_node_image_fs as node_image_fs;
 pub use get_metric_k8s_node_raw as node_raw;
 pub use get_metric_k8s_node_raw_efficiency as node_raw_efficiency;
 pub use get_metric_k8s_node_raw_summary as node_raw_summary;
 pub use get_metric_k8s_nodes_cost as nodes_cost;
 pub use get_metric_k8s_nodes_cost_summary as nodes_cost_summary;
 pub use get_metric_k8s_nodes_cost_trend as nodes_cost_trend;
+pub use get_metric_k8s_nodes_image_fs as nodes_image_fs;
 pub use get_metric_k8s_nodes_raw as nodes_raw;
 pub use get_metric_k8s_nodes_raw_efficiency as nodes_raw_efficiency;
 pub use get_metric_k8s_nodes_raw_summary as nodes_raw_summary;
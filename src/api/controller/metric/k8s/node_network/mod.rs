@@ -0,0 +1,100 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use serde_json::Value;
+
+use crate::api::dto::{ApiResponse, metrics_dto::RangeQuery};
+use crate::app_state::AppState;
+use crate::domain::metric::k8s::node_network::service as metric_k8s_node_network_service;
+
+pub async fn get_metric_k8s_nodes_network_raw(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_node_network_service::get_metric_k8s_nodes_network_raw(&state, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_nodes_network_cost(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_node_network_service::get_metric_k8s_nodes_network_cost(&state, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_nodes_network_cost_summary(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_node_network_service::get_metric_k8s_nodes_network_cost_summary(&state, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_node_network_raw(
+    State(state): State<AppState>,
+    Path(node_name): Path<String>,
+    Query(q): Query<RangeQuery>,
+) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_node_network_service::get_metric_k8s_node_network_raw(&state, node_name, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_node_network_cost(
+    State(state): State<AppState>,
+    Path(node_name): Path<String>,
+    Query(q): Query<RangeQuery>,
+) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_node_network_service::get_metric_k8s_node_network_cost(&state, node_name, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_node_network_cost_summary(
+    State(state): State<AppState>,
+    Path(node_name): Path<String>,
+    Query(q): Query<RangeQuery>,
+) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_node_network_service::get_metric_k8s_node_network_cost_summary(&state, node_name, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub use get_metric_k8s_node_network_raw as node_interfaces_raw;
+pub use get_metric_k8s_node_network_cost as node_interfaces_cost;
+pub use get_metric_k8s_node_network_cost_summary as node_interfaces_cost_summary;
+pub use get_metric_k8s_nodes_network_raw as nodes_interfaces_raw;
+pub use get_metric_k8s_nodes_network_cost as nodes_interfaces_cost;
+pub use get_metric_k8s_nodes_network_cost_summary as nodes_interfaces_cost_summary;
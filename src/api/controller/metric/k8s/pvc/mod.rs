@@ -0,0 +1,130 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use serde_json::Value;
+
+use crate::api::dto::{ApiResponse, metrics_dto::RangeQuery};
+use crate::app_state::AppState;
+use crate::domain::metric::k8s::pvc::service as metric_k8s_pvc_service;
+
+pub async fn get_metric_k8s_pvcs_raw(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_pvc_service::get_metric_k8s_pvcs_raw(&state, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_pvcs_cost(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_pvc_service::get_metric_k8s_pvcs_cost(&state, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_pvcs_cost_summary(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_pvc_service::get_metric_k8s_pvcs_cost_summary(&state, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_pvcs_cost_trend(State(state): State<AppState>, Query(q): Query<RangeQuery>) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_pvc_service::get_metric_k8s_pvcs_cost_trend(&state, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_pvc_raw(
+    State(state): State<AppState>,
+    Path((namespace, name)): Path<(String, String)>,
+    Query(q): Query<RangeQuery>,
+) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_pvc_service::get_metric_k8s_pvc_raw(&state, namespace, name, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_pvc_cost(
+    State(state): State<AppState>,
+    Path((namespace, name)): Path<(String, String)>,
+    Query(q): Query<RangeQuery>,
+) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_pvc_service::get_metric_k8s_pvc_cost(&state, namespace, name, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_pvc_cost_summary(
+    State(state): State<AppState>,
+    Path((namespace, name)): Path<(String, String)>,
+    Query(q): Query<RangeQuery>,
+) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_pvc_service::get_metric_k8s_pvc_cost_summary(&state, namespace, name, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_metric_k8s_pvc_cost_trend(
+    State(state): State<AppState>,
+    Path((namespace, name)): Path<(String, String)>,
+    Query(q): Query<RangeQuery>,
+) -> Json<ApiResponse<Value>> {
+    match async {
+        let result = metric_k8s_pvc_service::get_metric_k8s_pvc_cost_trend(&state, namespace, name, q).await?;
+        Ok::<Value, anyhow::Error>(result)
+    }
+    .await
+    {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub use get_metric_k8s_pvc_cost as pvc_cost;
+pub use get_metric_k8s_pvc_cost_summary as pvc_cost_summary;
+pub use get_metric_k8s_pvc_cost_trend as pvc_cost_trend;
+pub use get_metric_k8s_pvc_raw as pvc_raw;
+pub use get_metric_k8s_pvcs_cost as pvcs_cost;
+pub use get_metric_k8s_pvcs_cost_summary as pvcs_cost_summary;
+pub use get_metric_k8s_pvcs_cost_trend as pvcs_cost_trend;
+pub use get_metric_k8s_pvcs_raw as pvcs_raw;
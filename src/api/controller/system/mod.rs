@@ -1,9 +1,19 @@
 //! System controller: connects routes to system usecases
 
-use axum::Json;
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
 use serde_json::Value;
+use tokio_util::io::ReaderStream;
 
 use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
+use crate::api::util::validation_ext::ValidateRequestExt;
+use crate::domain::system::dto::{ArchiveListQuery, ArchiveRestoreRequest, BackupRestoreRequest, ExportRequest, ResyncRequest, StorageMigrateRequest};
 
 pub async fn status() -> Json<ApiResponse<Value>> {
     match crate::domain::system::usecase::status().await {
@@ -12,6 +22,13 @@ pub async fn status() -> Json<ApiResponse<Value>> {
     }
 }
 
+pub async fn scrape_status() -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::scrape_status().await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
 pub async fn health() -> Json<ApiResponse<Value>> {
     match crate::domain::system::usecase::health().await {
         Ok(v) => Json(ApiResponse::ok(v)),
@@ -26,10 +43,156 @@ pub async fn backup() -> Json<ApiResponse<Value>> {
     }
 }
 
-pub async fn resync() -> Json<ApiResponse<Value>> {
-    match crate::domain::system::usecase::resync().await {
+pub async fn list_backups() -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::list_backups().await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+/// Streams a backup archive as `application/x-tar`.
+pub async fn download_backup(Path(name): Path<String>) -> Response {
+    let path = match crate::domain::system::usecase::backup_file_path(&name) {
+        Ok(p) => p,
+        Err(e) => return Json(ApiResponse::<Value>::err(e.to_string())).into_response(),
+    };
+    let file = match tokio::fs::File::open(&path).await {
+        Ok(f) => f,
+        Err(e) => return Json(ApiResponse::<Value>::err(e.to_string())).into_response(),
+    };
+
+    (
+        [
+            (header::CONTENT_TYPE, "application/x-tar".to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", name)),
+        ],
+        Body::from_stream(ReaderStream::new(file)),
+    )
+        .into_response()
+}
+
+pub async fn upload_backup(Path(name): Path<String>, body: Body) -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::upload_backup(name, body.into_data_stream()).await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn restore_backup(Json(payload): Json<BackupRestoreRequest>) -> Json<ApiResponse<Value>> {
+    let payload = match payload.validate_or_err() {
+        Ok(v) => v,
+        Err(err_json) => return err_json,
+    };
+
+    match crate::domain::system::usecase::restore_backup(payload).await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn resync(Json(payload): Json<ResyncRequest>) -> Json<ApiResponse<Value>> {
+    let payload = match payload.validate_or_err() {
+        Ok(v) => v,
+        Err(err_json) => return err_json,
+    };
+
+    match crate::domain::system::usecase::resync(payload).await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn list_resync_jobs() -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::list_resync_jobs().await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_resync_job(Path(id): Path<String>) -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::get_resync_job(id).await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn export(
+    State(state): State<AppState>,
+    Json(payload): Json<ExportRequest>,
+) -> Json<ApiResponse<Value>> {
+    let payload = match payload.validate_or_err() {
+        Ok(v) => v,
+        Err(err_json) => return err_json,
+    };
+
+    match crate::domain::system::usecase::export(&state, payload).await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn list_export_jobs() -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::list_export_jobs().await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn get_export_job(Path(id): Path<String>) -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::get_export_job(id).await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn list_archives(Query(q): Query<ArchiveListQuery>) -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::list_archives(q).await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn restore_archive(Json(payload): Json<ArchiveRestoreRequest>) -> Json<ApiResponse<Value>> {
+    let payload = match payload.validate_or_err() {
+        Ok(v) => v,
+        Err(err_json) => return err_json,
+    };
+
+    match crate::domain::system::usecase::restore_archive(payload).await {
         Ok(v) => Json(ApiResponse::ok(v)),
         Err(e) => Json(ApiResponse::err(e.to_string())),
     }
 }
 
+pub async fn rebuild_indexes() -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::rebuild_indexes().await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn fsck() -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::fsck().await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn storage_backend() -> Json<ApiResponse<Value>> {
+    match crate::domain::system::usecase::storage_backend().await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
+
+pub async fn migrate_storage(Json(payload): Json<StorageMigrateRequest>) -> Json<ApiResponse<Value>> {
+    let payload = match payload.validate_or_err() {
+        Ok(v) => v,
+        Err(err_json) => return err_json,
+    };
+
+    match crate::domain::system::usecase::migrate_storage(payload).await {
+        Ok(v) => Json(ApiResponse::ok(v)),
+        Err(e) => Json(ApiResponse::err(e.to_string())),
+    }
+}
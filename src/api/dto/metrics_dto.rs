@@ -3,6 +3,7 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use crate::domain::metric::k8s::common::dto::MetricGranularity;
+use crate::domain::metric::k8s::node_network::dto::metric_node_network_dto::NetworkPriceClass;
 
 /// Common time range + pagination query parameters
 #[derive(Deserialize, Debug, Clone, Serialize)]
@@ -17,7 +18,12 @@ pub struct RangeQuery {
 
     /// Optional override: "minute", "hour", "day"
     pub granularity: Option<MetricGranularity>,
-    
+
+    /// Node network interface, e.g. "eth1" (node interface endpoints only)
+    pub interface: Option<String>,
+    /// Price tier of interface egress not covered by the unit price
+    /// `network_interface_classes`: "local", "regional" or "external" (default)
+    pub network_class: Option<NetworkPriceClass>,
 
 }
 
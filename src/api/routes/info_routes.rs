@@ -1,6 +1,7 @@
 //! Info routes (e.g., /api/v1/info/*)
 
 use axum::{routing::get, Router};
+use crate::app_state::AppState;
 use axum::routing::patch;
 use crate::api::controller::info::info_controller as ic;
 use crate::api::controller::info::setting::get_info_settings;
@@ -14,7 +15,7 @@ use crate::api::controller::info::k8s::limit_range::get_k8s_limit_ranges;
 use crate::api::controller::info::k8s::hpa::get_k8s_hpas;
 use crate::api::controller::info::k8s::{container, node, pod};
 
-pub fn info_routes() -> Router {
+pub fn info_routes() -> Router<AppState> {
     Router::new()
         .route("/settings", get(get_info_settings).put(upsert_info_settings))
         .route("/unit-prices", get(ic::get_info_unit_prices).put(ic::upsert_info_unit_prices))
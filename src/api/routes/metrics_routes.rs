@@ -1,16 +1,19 @@
 //! Metrics routes (e.g., /api/v1/metrics/*)
 
 use axum::{routing::get, Router};
+use crate::app_state::AppState;
 
 use crate::api::controller::metric::k8s::namespace as ns_ctr;
 use crate::api::controller::metric::k8s::node as node_ctr;
+use crate::api::controller::metric::k8s::node_network as node_net_ctr;
 use crate::api::controller::metric::k8s::container as con_ctr;
 use crate::api::controller::metric::k8s::deployment as deploy_ctr;
 use crate::api::controller::metric::k8s::pod as pod_ctr;
+use crate::api::controller::metric::k8s::pvc as pvc_ctr;
 use crate::api::controller::metric::k8s::cluster as cluster_ctr;
 
 /// Build the router for metrics endpoints under /api/v1/metrics
-pub fn metrics_routes() -> Router {
+pub fn metrics_routes() -> Router<AppState> {
     Router::new()
         // Nodes
         .route("/nodes/raw", get(node_ctr::nodes_raw))
@@ -26,6 +29,18 @@ pub fn metrics_routes() -> Router {
         .route("/nodes/{node_name}/cost/summary", get(node_ctr::node_cost_summary))
         .route("/nodes/{node_name}/cost/trend", get(node_ctr::node_cost_trend))
 
+        // Node image filesystems, correlated with the images each node holds
+        .route("/nodes/image-fs", get(node_ctr::nodes_image_fs))
+        .route("/nodes/{node_name}/image-fs", get(node_ctr::node_image_fs))
+
+        // Node network interfaces (filter with ?interface=)
+        .route("/nodes/interfaces/raw", get(node_net_ctr::nodes_interfaces_raw))
+        .route("/nodes/interfaces/cost", get(node_net_ctr::nodes_interfaces_cost))
+        .route("/nodes/interfaces/cost/summary", get(node_net_ctr::nodes_interfaces_cost_summary))
+        .route("/nodes/{node_name}/interfaces/raw", get(node_net_ctr::node_interfaces_raw))
+        .route("/nodes/{node_name}/interfaces/cost", get(node_net_ctr::node_interfaces_cost))
+        .route("/nodes/{node_name}/interfaces/cost/summary", get(node_net_ctr::node_interfaces_cost_summary))
+
         // Pods
         .route("/pods/raw", get(pod_ctr::pods_raw))
         .route("/pods/raw/summary", get(pod_ctr::pods_raw_summary))
@@ -54,6 +69,16 @@ pub fn metrics_routes() -> Router {
         .route("/containers/{id}/cost/summary", get(con_ctr::container_cost_summary))
         .route("/containers/{id}/cost/trend", get(con_ctr::container_cost_trend))
 
+        // PersistentVolumeClaims
+        .route("/pvcs/raw", get(pvc_ctr::pvcs_raw))
+        .route("/pvcs/{namespace}/{name}/raw", get(pvc_ctr::pvc_raw))
+        .route("/pvcs/cost", get(pvc_ctr::pvcs_cost))
+        .route("/pvcs/cost/summary", get(pvc_ctr::pvcs_cost_summary))
+        .route("/pvcs/cost/trend", get(pvc_ctr::pvcs_cost_trend))
+        .route("/pvcs/{namespace}/{name}/cost", get(pvc_ctr::pvc_cost))
+        .route("/pvcs/{namespace}/{name}/cost/summary", get(pvc_ctr::pvc_cost_summary))
+        .route("/pvcs/{namespace}/{name}/cost/trend", get(pvc_ctr::pvc_cost_trend))
+
         // Namespaces
         .route("/namespaces/raw", get(ns_ctr::namespaces_raw))
         .route("/namespaces/raw/summary", get(ns_ctr::namespaces_raw_summary))
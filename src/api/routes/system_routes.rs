@@ -1,12 +1,34 @@
 //! System routes (e.g., /api/v1/system/*)
 
-use axum::{routing::{get, post}, Router};
+use axum::{extract::DefaultBodyLimit, routing::{get, post}, Router};
+use crate::app_state::AppState;
 use crate::api::controller::system as sc;
+use crate::domain::system::service::backup_service::backup_upload_limit;
 
-pub fn system_routes() -> Router {
+pub fn system_routes() -> Router<AppState> {
     Router::new()
         .route("/status", get(sc::status))
+        .route("/status/scrapes", get(sc::scrape_status))
         .route("/health", get(sc::health))
         .route("/backup", post(sc::backup))
-        .route("/resync", post(sc::resync))
+        .route("/backup/restore", post(sc::restore_backup))
+        .route("/backups", get(sc::list_backups))
+        .route(
+            "/backups/{name}",
+            get(sc::download_backup)
+                .put(sc::upload_backup)
+                .layer(DefaultBodyLimit::max(
+                    usize::try_from(backup_upload_limit()).unwrap_or(usize::MAX),
+                )),
+        )
+        .route("/resync", post(sc::resync).get(sc::list_resync_jobs))
+        .route("/resync/{id}", get(sc::get_resync_job))
+        .route("/export", post(sc::export).get(sc::list_export_jobs))
+        .route("/export/{id}", get(sc::get_export_job))
+        .route("/archives", get(sc::list_archives))
+        .route("/archives/restore", post(sc::restore_archive))
+        .route("/index/rebuild", post(sc::rebuild_indexes))
+        .route("/fsck", get(sc::fsck))
+        .route("/storage", get(sc::storage_backend))
+        .route("/storage/migrate", post(sc::migrate_storage))
 }
@@ -0,0 +1,52 @@
+//! Dependencies shared by the API handlers.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::core::client::k8s::k8s_api_client::K8sApiClient;
+use crate::core::client::k8s::k8s_client_trait::K8sClient;
+#[cfg(test)]
+use crate::core::client::k8s::k8s_memory_client::K8sMemoryClient;
+use crate::core::persistence::info::backend::info_fs_backend::InfoFsBackend;
+#[cfg(test)]
+use crate::core::persistence::info::backend::info_memory_backend::InfoMemoryBackend;
+use crate::core::persistence::info::backend::info_storage_backend_trait::InfoStorageBackend;
+use crate::core::persistence::metrics::backend::metric_backend;
+#[cfg(test)]
+use crate::core::persistence::metrics::backend::metric_memory_backend::MetricMemoryBackend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricStorageBackend;
+
+/// Storage and cluster access for the API, passed to handlers as Axum state.
+///
+/// Services build their repositories from these instead of reaching for the
+/// filesystem or the API server themselves, so the whole API can run against
+/// [`AppState::in_memory`].
+#[derive(Clone)]
+pub struct AppState {
+    pub metrics: Arc<dyn MetricStorageBackend>,
+    pub info: Arc<dyn InfoStorageBackend>,
+    pub k8s: Arc<dyn K8sClient>,
+}
+
+impl AppState {
+    /// The configured metric backend, the info files and the in-cluster API server.
+    /// Fails until the metric backend has been opened.
+    pub fn from_env() -> Result<Self> {
+        Ok(Self {
+            metrics: metric_backend()?,
+            info: Arc::new(InfoFsBackend),
+            k8s: Arc::new(K8sApiClient),
+        })
+    }
+
+    /// Empty in-memory storage and a cluster holding whatever `k8s` is given.
+    #[cfg(test)]
+    pub fn in_memory(k8s: Arc<K8sMemoryClient>) -> Self {
+        Self {
+            metrics: Arc::new(MetricMemoryBackend::default()),
+            info: Arc::new(InfoMemoryBackend::default()),
+            k8s,
+        }
+    }
+}
@@ -147,6 +147,7 @@ pub fn map_container_status_to_info_container_entity(
         team,
         service,
         env,
+        source: None,
 
         last_updated_info_at: Some(Utc::now()),
         deleted: Some(false),
@@ -165,7 +166,7 @@ fn parse_cpu_millicores(s: &str) -> Option<u64> {
 }
 
 /// Convert memory quantity (e.g., "128Mi", "2Gi") → bytes
-fn parse_memory_bytes(s: &str) -> Option<u64> {
+pub fn parse_memory_bytes(s: &str) -> Option<u64> {
     let s = s.to_lowercase();
     let units = [
         ("ki", 1024_u64),
@@ -69,3 +69,16 @@ pub async fn fetch_node_summary(token: &str, client: &Client, node_name: &str) -
     Ok(resp.json().await?)
 }
 
+/// Fetch the cAdvisor metrics of a node, in Prometheus text format
+pub async fn fetch_node_cadvisor_metrics(token: &str, client: &Client, node_name: &str) -> Result<String> {
+    let url = format!("{}/api/v1/nodes/{}/proxy/metrics/cadvisor", k8s_api_server(), node_name);
+    let resp = client
+        .get(&url)
+        .bearer_auth(token)
+        .send()
+        .await?
+        .error_for_status()?;
+
+    debug!("Fetching cAdvisor metrics for node '{}'", node_name);
+    Ok(resp.text().await?)
+}
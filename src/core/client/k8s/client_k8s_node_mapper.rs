@@ -25,6 +25,9 @@ pub fn map_summary_to_metrics(summary: &Summary) -> MetricNodeEntity {
         .and_then(|net| sum_network_interfaces(net))
         .unwrap_or((None, None, None, None));
 
+    let image_fs = n.runtime.as_ref().and_then(|r| r.image_fs.as_ref());
+    let container_fs = n.runtime.as_ref().and_then(|r| r.container_fs.as_ref());
+
     MetricNodeEntity {
         time: Utc::now(),
 
@@ -49,6 +52,29 @@ pub fn map_summary_to_metrics(summary: &Summary) -> MetricNodeEntity {
         fs_capacity_bytes: n.fs.as_ref().and_then(|x| x.capacity_bytes),
         fs_inodes_used: n.fs.as_ref().and_then(|x| x.inodes_used),
         fs_inodes: n.fs.as_ref().and_then(|x| x.inodes),
+
+        // Memory pressure
+        memory_available_bytes: n.memory.available_bytes,
+        memory_major_page_faults: n.memory.major_page_faults,
+
+        // Swap
+        swap_usage_bytes: n.swap.as_ref().and_then(|x| x.swap_usage_bytes),
+        swap_available_bytes: n.swap.as_ref().and_then(|x| x.swap_available_bytes),
+
+        // Processes
+        process_count: n.rlimit.as_ref().map(|x| x.curproc),
+        process_limit: n.rlimit.as_ref().map(|x| x.maxpid),
+
+        // Runtime filesystems
+        image_fs_used_bytes: image_fs.and_then(|x| x.used_bytes),
+        image_fs_capacity_bytes: image_fs.and_then(|x| x.capacity_bytes),
+        image_fs_available_bytes: image_fs.and_then(|x| x.available_bytes),
+        image_fs_inodes_used: image_fs.and_then(|x| x.inodes_used),
+        image_fs_inodes: image_fs.and_then(|x| x.inodes),
+        container_fs_used_bytes: container_fs.and_then(|x| x.used_bytes),
+        container_fs_capacity_bytes: container_fs.and_then(|x| x.capacity_bytes),
+
+        ..Default::default()
     }
 }
 
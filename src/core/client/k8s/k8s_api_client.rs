@@ -0,0 +1,83 @@
+use anyhow::Result;
+use futures_util::future::BoxFuture;
+use futures_util::FutureExt;
+use serde_json::Value;
+
+use crate::core::client::k8s::client_k8s_node::{fetch_node_by_name, fetch_nodes};
+use crate::core::client::k8s::client_k8s_node_dto::{Node, NodeList};
+use crate::core::client::k8s::client_k8s_pod::{
+    fetch_pod_by_name_and_namespace, fetch_pod_by_uid, fetch_pods, fetch_pods_by_label, fetch_pods_by_namespace,
+    fetch_pods_by_node,
+};
+use crate::core::client::k8s::client_k8s_pod_dto::{Pod, PodList};
+use crate::core::client::k8s::k8s_client_trait::{K8sClient, K8sResourceKind};
+use crate::core::client::k8s::util::{build_client, read_token};
+use crate::core::client::k8s::{
+    client_k8s_deployment, client_k8s_hpa, client_k8s_limit_range, client_k8s_namespace,
+    client_k8s_persistent_volume, client_k8s_persistent_volume_claim, client_k8s_resource_quota,
+};
+
+/// Talks to the API server with the mounted service account.
+///
+/// The token and CA are read on every call, as before, so a rotated token is
+/// picked up and a missing one fails the request instead of the startup.
+#[derive(Debug, Default)]
+pub struct K8sApiClient;
+
+impl K8sClient for K8sApiClient {
+    fn pods(&self) -> BoxFuture<'_, Result<PodList>> {
+        async { fetch_pods(&read_token()?, &build_client()?).await }.boxed()
+    }
+
+    fn pods_by_namespace<'a>(&'a self, namespace: &'a str) -> BoxFuture<'a, Result<PodList>> {
+        async move { fetch_pods_by_namespace(&read_token()?, &build_client()?, namespace).await }.boxed()
+    }
+
+    fn pods_by_label<'a>(&'a self, label_selector: &'a str) -> BoxFuture<'a, Result<PodList>> {
+        async move { fetch_pods_by_label(&read_token()?, &build_client()?, label_selector).await }.boxed()
+    }
+
+    fn pods_by_node<'a>(&'a self, node_name: &'a str) -> BoxFuture<'a, Result<PodList>> {
+        async move { fetch_pods_by_node(&read_token()?, &build_client()?, node_name).await }.boxed()
+    }
+
+    fn pod_by_uid<'a>(&'a self, pod_uid: &'a str) -> BoxFuture<'a, Result<Pod>> {
+        async move { fetch_pod_by_uid(&read_token()?, &build_client()?, pod_uid).await }.boxed()
+    }
+
+    fn pod_by_name_and_namespace<'a>(&'a self, namespace: &'a str, pod_name: &'a str) -> BoxFuture<'a, Result<Pod>> {
+        async move { fetch_pod_by_name_and_namespace(&read_token()?, &build_client()?, namespace, pod_name).await }
+            .boxed()
+    }
+
+    fn nodes(&self) -> BoxFuture<'_, Result<NodeList>> {
+        async { fetch_nodes(&read_token()?, &build_client()?).await }.boxed()
+    }
+
+    fn node_by_name<'a>(&'a self, node_name: &'a str) -> BoxFuture<'a, Result<Node>> {
+        async move { fetch_node_by_name(&read_token()?, &build_client()?, node_name).await }.boxed()
+    }
+
+    fn resources(&self, kind: K8sResourceKind) -> BoxFuture<'_, Result<Value>> {
+        async move {
+            let token = read_token()?;
+            let client = build_client()?;
+            match kind {
+                K8sResourceKind::Namespace => client_k8s_namespace::fetch_namespaces(&token, &client).await,
+                K8sResourceKind::Deployment => client_k8s_deployment::fetch_deployments(&token, &client).await,
+                K8sResourceKind::PersistentVolume => {
+                    client_k8s_persistent_volume::fetch_persistent_volumes(&token, &client).await
+                }
+                K8sResourceKind::PersistentVolumeClaim => {
+                    client_k8s_persistent_volume_claim::fetch_persistent_volume_claims(&token, &client).await
+                }
+                K8sResourceKind::ResourceQuota => client_k8s_resource_quota::fetch_resource_quotas(&token, &client).await,
+                K8sResourceKind::LimitRange => client_k8s_limit_range::fetch_limit_ranges(&token, &client).await,
+                K8sResourceKind::HorizontalPodAutoscaler => {
+                    client_k8s_hpa::fetch_horizontal_pod_autoscalers(&token, &client).await
+                }
+            }
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,43 @@
+use anyhow::Result;
+use futures_util::future::BoxFuture;
+use serde_json::Value;
+
+use crate::core::client::k8s::client_k8s_node_dto::{Node, NodeList};
+use crate::core::client::k8s::client_k8s_pod_dto::{Pod, PodList};
+
+/// Resources the API lists as raw JSON, without a typed DTO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum K8sResourceKind {
+    Namespace,
+    Deployment,
+    PersistentVolume,
+    PersistentVolumeClaim,
+    ResourceQuota,
+    LimitRange,
+    HorizontalPodAutoscaler,
+}
+
+/// Read access to the Kubernetes API used by the info services.
+///
+/// Methods return boxed futures so the client can live behind `Arc<dyn _>`
+/// in the application state.
+pub trait K8sClient: Send + Sync {
+    fn pods(&self) -> BoxFuture<'_, Result<PodList>>;
+
+    fn pods_by_namespace<'a>(&'a self, namespace: &'a str) -> BoxFuture<'a, Result<PodList>>;
+
+    /// `label_selector` uses the API syntax, e.g. `app=web,tier=frontend`.
+    fn pods_by_label<'a>(&'a self, label_selector: &'a str) -> BoxFuture<'a, Result<PodList>>;
+
+    fn pods_by_node<'a>(&'a self, node_name: &'a str) -> BoxFuture<'a, Result<PodList>>;
+
+    fn pod_by_uid<'a>(&'a self, pod_uid: &'a str) -> BoxFuture<'a, Result<Pod>>;
+
+    fn pod_by_name_and_namespace<'a>(&'a self, namespace: &'a str, pod_name: &'a str) -> BoxFuture<'a, Result<Pod>>;
+
+    fn nodes(&self) -> BoxFuture<'_, Result<NodeList>>;
+
+    fn node_by_name<'a>(&'a self, node_name: &'a str) -> BoxFuture<'a, Result<Node>>;
+
+    fn resources(&self, kind: K8sResourceKind) -> BoxFuture<'_, Result<Value>>;
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use anyhow::{anyhow, Result};
+use futures_util::future::{self, BoxFuture};
+use futures_util::FutureExt;
+use serde_json::{json, Value};
+
+use crate::core::client::k8s::client_k8s_node_dto::{Node, NodeList};
+use crate::core::client::k8s::client_k8s_pod_dto::{Pod, PodList};
+use crate::core::client::k8s::k8s_client_trait::{K8sClient, K8sResourceKind};
+
+/// Fixed cluster contents served from memory, for hermetic tests.
+///
+/// Answers like the API server would for the objects it was given: filters
+/// are applied here, unknown objects are errors and unset resource lists are
+/// empty.
+#[derive(Default)]
+pub struct K8sMemoryClient {
+    pods: RwLock<Vec<Pod>>,
+    nodes: RwLock<Vec<Node>>,
+    resources: RwLock<HashMap<K8sResourceKind, Value>>,
+}
+
+impl K8sMemoryClient {
+    pub fn set_pods(&self, pods: Vec<Pod>) {
+        if let Ok(mut current) = self.pods.write() {
+            *current = pods;
+        }
+    }
+
+    pub fn set_nodes(&self, nodes: Vec<Node>) {
+        if let Ok(mut current) = self.nodes.write() {
+            *current = nodes;
+        }
+    }
+
+    pub fn set_resources(&self, kind: K8sResourceKind, list: Value) {
+        if let Ok(mut current) = self.resources.write() {
+            current.insert(kind, list);
+        }
+    }
+
+    fn filter_pods(&self, keep: impl Fn(&Pod) -> bool) -> Result<PodList> {
+        let pods = self.pods.read().map_err(|_| anyhow!("K8s memory client poisoned"))?;
+        Ok(PodList {
+            items: pods.iter().filter(|p| keep(p)).cloned().collect(),
+        })
+    }
+
+    fn find_pod(&self, keep: impl Fn(&Pod) -> bool) -> Result<Option<Pod>> {
+        Ok(self.filter_pods(keep)?.items.into_iter().next())
+    }
+}
+
+/// Equality-based selectors only (`a=b,c=d`), which is all the API uses.
+fn matches_selector(pod: &Pod, selector: &str) -> bool {
+    let labels = pod.metadata.labels.as_ref();
+    selector
+        .split(',')
+        .filter(|term| !term.trim().is_empty())
+        .all(|term| match term.split_once('=') {
+            Some((key, value)) => labels
+                .and_then(|l| l.get(key.trim()))
+                .is_some_and(|v| v == value.trim_start_matches('=').trim()),
+            None => labels.is_some_and(|l| l.contains_key(term.trim())),
+        })
+}
+
+impl K8sClient for K8sMemoryClient {
+    fn pods(&self) -> BoxFuture<'_, Result<PodList>> {
+        future::ready(self.filter_pods(|_| true)).boxed()
+    }
+
+    fn pods_by_namespace<'a>(&'a self, namespace: &'a str) -> BoxFuture<'a, Result<PodList>> {
+        future::ready(self.filter_pods(|p| p.metadata.namespace == namespace)).boxed()
+    }
+
+    fn pods_by_label<'a>(&'a self, label_selector: &'a str) -> BoxFuture<'a, Result<PodList>> {
+        future::ready(self.filter_pods(|p| matches_selector(p, label_selector))).boxed()
+    }
+
+    fn pods_by_node<'a>(&'a self, node_name: &'a str) -> BoxFuture<'a, Result<PodList>> {
+        future::ready(self.filter_pods(|p| p.spec.node_name.as_deref() == Some(node_name))).boxed()
+    }
+
+    fn pod_by_uid<'a>(&'a self, pod_uid: &'a str) -> BoxFuture<'a, Result<Pod>> {
+        let pod = self
+            .find_pod(|p| p.metadata.uid == pod_uid)
+            .and_then(|p| p.ok_or_else(|| anyhow!("Pod with UID '{}' not found", pod_uid)));
+        future::ready(pod).boxed()
+    }
+
+    fn pod_by_name_and_namespace<'a>(&'a self, namespace: &'a str, pod_name: &'a str) -> BoxFuture<'a, Result<Pod>> {
+        let pod = self
+            .find_pod(|p| p.metadata.namespace == namespace && p.metadata.name == pod_name)
+            .and_then(|p| p.ok_or_else(|| anyhow!("Pod '{}/{}' not found", namespace, pod_name)));
+        future::ready(pod).boxed()
+    }
+
+    fn nodes(&self) -> BoxFuture<'_, Result<NodeList>> {
+        let nodes = self
+            .nodes
+            .read()
+            .map_err(|_| anyhow!("K8s memory client poisoned"))
+            .map(|nodes| NodeList {
+                api_version: Some("v1".to_string()),
+                kind: Some("NodeList".to_string()),
+                items: nodes.clone(),
+                metadata: None,
+            });
+        future::ready(nodes).boxed()
+    }
+
+    fn node_by_name<'a>(&'a self, node_name: &'a str) -> BoxFuture<'a, Result<Node>> {
+        let node = self
+            .nodes
+            .read()
+            .map_err(|_| anyhow!("K8s memory client poisoned"))
+            .and_then(|nodes| {
+                nodes
+                    .iter()
+                    .find(|n| n.metadata.name == node_name)
+                    .cloned()
+                    .ok_or_else(|| anyhow!("Node '{}' not found", node_name))
+            });
+        future::ready(node).boxed()
+    }
+
+    fn resources(&self, kind: K8sResourceKind) -> BoxFuture<'_, Result<Value>> {
+        let list = self
+            .resources
+            .read()
+            .map_err(|_| anyhow!("K8s memory client poisoned"))
+            .map(|resources| resources.get(&kind).cloned().unwrap_or_else(|| json!({ "items": [] })));
+        future::ready(list).boxed()
+    }
+}
@@ -15,3 +15,8 @@ pub mod client_k8s_persistent_volume_claim;
 pub mod client_k8s_resource_quota;
 pub mod client_k8s_limit_range;
 pub mod client_k8s_hpa;
+pub mod k8s_client_trait;
+pub mod k8s_api_client;
+pub mod scrape_status;
+#[cfg(test)]
+pub mod k8s_memory_client;
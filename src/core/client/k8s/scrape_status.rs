@@ -0,0 +1,48 @@
+//! Outcome of the latest kubelet summary scrape of each node.
+//!
+//! Written by the k8s collector on every scrape and read by the system status API.
+//! Kept in memory only; a restart starts with an empty table.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::{LazyLock, Mutex};
+
+static SCRAPE_STATUS: LazyLock<Mutex<HashMap<String, NodeScrapeStatus>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Latest scrape of one node.
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeScrapeStatus {
+    pub node_name: String,
+    pub scraped_at: DateTime<Utc>,
+    /// Time spent fetching the summary, timeouts included.
+    pub duration_ms: u64,
+    /// Whether the summary was fetched and stored.
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Replaces the recorded status of `status.node_name`.
+pub fn record_scrape(status: NodeScrapeStatus) {
+    if let Ok(mut table) = SCRAPE_STATUS.lock() {
+        table.insert(status.node_name.clone(), status);
+    }
+}
+
+/// Drops nodes that are no longer part of the cluster.
+pub fn retain_nodes(nodes: &HashSet<String>) {
+    if let Ok(mut table) = SCRAPE_STATUS.lock() {
+        table.retain(|name, _| nodes.contains(name));
+    }
+}
+
+/// All recorded statuses, sorted by node name.
+pub fn scrape_statuses() -> Vec<NodeScrapeStatus> {
+    let mut statuses: Vec<_> = SCRAPE_STATUS
+        .lock()
+        .map(|table| table.values().cloned().collect())
+        .unwrap_or_default();
+    statuses.sort_by(|a, b| a.node_name.cmp(&b.node_name));
+    statuses
+}
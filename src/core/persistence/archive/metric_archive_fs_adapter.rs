@@ -0,0 +1,137 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::core::persistence::metrics::segment::compression::{is_compressed_segment, segment_exists, segment_period};
+use crate::core::persistence::metrics::segment::index::remove_segment;
+use crate::core::persistence::metrics::segment::write_buffer::release_path;
+
+/// Extension appended to a metric segment once it has been archived.
+const ARCHIVE_EXT: &str = "rcd.gz";
+
+/// One archived metric segment (a single `.rcd` period file).
+#[derive(Debug, Clone)]
+pub struct ArchivedSegment {
+    /// Period stem of the original file (`YYYY-MM-DD`, `YYYY-MM` or `YYYY`).
+    pub period: String,
+    pub size_bytes: u64,
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+/// Adapter for the compressed archive tree used by the `"archive"` retention policy.
+///
+/// Expired `.rcd` segments are gzipped into `archive/metric/k8s/...` with the same
+/// `{kind}/{key}/{m|h|d}` layout as the live metric tree, one file per period.
+#[derive(Debug, Default)]
+pub struct MetricArchiveFsAdapter;
+
+impl MetricArchiveFsAdapter {
+    fn archive_path_for(archive_dir: &Path, period: &str) -> PathBuf {
+        archive_dir.join(format!("{}.{}", period, ARCHIVE_EXT))
+    }
+
+    /// Compresses `src` into `archive_dir` and removes the live file.
+    ///
+    /// The archive is written to a temporary file and renamed into place before the
+    /// source is deleted, so an interrupted run never loses the segment.
+    pub fn archive_file(&self, src: &Path, archive_dir: &Path) -> Result<PathBuf> {
+        let period = segment_period(src)
+            .ok_or_else(|| anyhow!("Invalid metric file name {:?}", src))?;
+        release_path(src)?;
+
+        fs::create_dir_all(archive_dir)?;
+        let dest = Self::archive_path_for(archive_dir, period);
+        let tmp = dest.with_extension("gz.tmp");
+
+        if is_compressed_segment(src) {
+            // Already gzipped by segment compression; move the bytes as they are.
+            fs::copy(src, &tmp)
+                .with_context(|| format!("Failed to copy compressed metric file {:?}", src))?;
+            File::open(&tmp)?.sync_all()?;
+        } else {
+            let mut reader = BufReader::new(
+                File::open(src).with_context(|| format!("Failed to open metric file {:?}", src))?,
+            );
+            let out = File::create(&tmp)
+                .with_context(|| format!("Failed to create archive file {:?}", tmp))?;
+            let mut encoder = GzEncoder::new(BufWriter::new(out), Compression::default());
+            io::copy(&mut reader, &mut encoder)?;
+            let mut writer = encoder.finish()?;
+            writer.flush()?;
+            writer.get_ref().sync_all()?;
+        }
+
+        fs::rename(&tmp, &dest)
+            .with_context(|| format!("Failed to move archive into place {:?}", dest))?;
+        remove_segment(src)
+            .with_context(|| format!("Failed to delete archived metric file {:?}", src))?;
+
+        Ok(dest)
+    }
+
+    /// Lists archived periods in `archive_dir`, sorted by period.
+    pub fn list_segments(&self, archive_dir: &Path) -> Result<Vec<ArchivedSegment>> {
+        let mut segments = Vec::new();
+        if !archive_dir.exists() {
+            return Ok(segments);
+        }
+
+        let suffix = format!(".{}", ARCHIVE_EXT);
+        for entry in fs::read_dir(archive_dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let Some(name) = name.to_str() else { continue };
+            let Some(period) = name.strip_suffix(&suffix) else { continue };
+
+            let meta = entry.metadata()?;
+            segments.push(ArchivedSegment {
+                period: period.to_string(),
+                size_bytes: meta.len(),
+                archived_at: meta.modified().ok().map(DateTime::<Utc>::from),
+            });
+        }
+
+        segments.sort_by(|a, b| a.period.cmp(&b.period));
+        Ok(segments)
+    }
+
+    /// Decompresses an archived period back into `live_dir`.
+    ///
+    /// Refuses to overwrite a live segment for the same period. The archive copy is
+    /// kept, so a restored period that is still past retention is simply re-archived
+    /// on the next retention run.
+    pub fn restore_segment(&self, archive_dir: &Path, live_dir: &Path, period: &str) -> Result<PathBuf> {
+        let src = Self::archive_path_for(archive_dir, period);
+        if !src.exists() {
+            return Err(anyhow!("Archived period {} not found", period));
+        }
+
+        let dest = live_dir.join(format!("{}.rcd", period));
+        if segment_exists(&dest) {
+            return Err(anyhow!("Live metric file already exists for period {}", period));
+        }
+
+        fs::create_dir_all(live_dir)?;
+        let tmp = dest.with_extension("rcd.tmp");
+        {
+            let mut decoder = MultiGzDecoder::new(BufReader::new(
+                File::open(&src).with_context(|| format!("Failed to open archive {:?}", src))?,
+            ));
+            let mut writer = BufWriter::new(File::create(&tmp)?);
+            io::copy(&mut decoder, &mut writer)
+                .with_context(|| format!("Failed to decompress archive {:?}", src))?;
+            writer.flush()?;
+            writer.get_ref().sync_all()?;
+        }
+        fs::rename(&tmp, &dest)?;
+
+        Ok(dest)
+    }
+}
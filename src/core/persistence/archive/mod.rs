@@ -0,0 +1,2 @@
+pub mod path;
+pub mod metric_archive_fs_adapter;
@@ -0,0 +1,109 @@
+use std::path::PathBuf;
+
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+
+fn archive_k8s_root() -> PathBuf {
+    get_rustcost_base_path().join("archive").join("metric").join("k8s")
+}
+
+// --- Node ---
+pub fn archive_k8s_node_dir_path() -> PathBuf {
+    archive_k8s_root().join("node")
+}
+
+pub fn archive_k8s_node_key_day_dir_path(key: &str) -> PathBuf {
+    archive_k8s_node_dir_path().join(key).join("d")
+}
+
+pub fn archive_k8s_node_key_hour_dir_path(key: &str) -> PathBuf {
+    archive_k8s_node_dir_path().join(key).join("h")
+}
+
+pub fn archive_k8s_node_key_minute_dir_path(key: &str) -> PathBuf {
+    archive_k8s_node_dir_path().join(key).join("m")
+}
+
+// --- Pod ---
+pub fn archive_k8s_pod_dir_path() -> PathBuf {
+    archive_k8s_root().join("pod")
+}
+
+pub fn archive_k8s_pod_key_day_dir_path(key: &str) -> PathBuf {
+    archive_k8s_pod_dir_path().join(key).join("d")
+}
+
+pub fn archive_k8s_pod_key_hour_dir_path(key: &str) -> PathBuf {
+    archive_k8s_pod_dir_path().join(key).join("h")
+}
+
+pub fn archive_k8s_pod_key_minute_dir_path(key: &str) -> PathBuf {
+    archive_k8s_pod_dir_path().join(key).join("m")
+}
+
+// --- Container ---
+pub fn archive_k8s_container_dir_path() -> PathBuf {
+    archive_k8s_root().join("container")
+}
+
+pub fn archive_k8s_container_key_day_dir_path(key: &str) -> PathBuf {
+    archive_k8s_container_dir_path().join(key).join("d")
+}
+
+pub fn archive_k8s_container_key_hour_dir_path(key: &str) -> PathBuf {
+    archive_k8s_container_dir_path().join(key).join("h")
+}
+
+pub fn archive_k8s_container_key_minute_dir_path(key: &str) -> PathBuf {
+    archive_k8s_container_dir_path().join(key).join("m")
+}
+
+// --- PVC ---
+pub fn archive_k8s_pvc_dir_path() -> PathBuf {
+    archive_k8s_root().join("pvc")
+}
+
+pub fn archive_k8s_pvc_key_day_dir_path(key: &str) -> PathBuf {
+    archive_k8s_pvc_dir_path().join(key).join("d")
+}
+
+pub fn archive_k8s_pvc_key_hour_dir_path(key: &str) -> PathBuf {
+    archive_k8s_pvc_dir_path().join(key).join("h")
+}
+
+pub fn archive_k8s_pvc_key_minute_dir_path(key: &str) -> PathBuf {
+    archive_k8s_pvc_dir_path().join(key).join("m")
+}
+
+// --- Node network interfaces ---
+pub fn archive_k8s_node_network_dir_path() -> PathBuf {
+    archive_k8s_root().join("node_network")
+}
+
+pub fn archive_k8s_node_network_key_day_dir_path(key: &str) -> PathBuf {
+    archive_k8s_node_network_dir_path().join(key).join("d")
+}
+
+pub fn archive_k8s_node_network_key_hour_dir_path(key: &str) -> PathBuf {
+    archive_k8s_node_network_dir_path().join(key).join("h")
+}
+
+pub fn archive_k8s_node_network_key_minute_dir_path(key: &str) -> PathBuf {
+    archive_k8s_node_network_dir_path().join(key).join("m")
+}
+
+// --- Node system containers ---
+pub fn archive_k8s_node_system_dir_path() -> PathBuf {
+    archive_k8s_root().join("node_system")
+}
+
+pub fn archive_k8s_node_system_key_day_dir_path(key: &str) -> PathBuf {
+    archive_k8s_node_system_dir_path().join(key).join("d")
+}
+
+pub fn archive_k8s_node_system_key_hour_dir_path(key: &str) -> PathBuf {
+    archive_k8s_node_system_dir_path().join(key).join("h")
+}
+
+pub fn archive_k8s_node_system_key_minute_dir_path(key: &str) -> PathBuf {
+    archive_k8s_node_system_dir_path().join(key).join("m")
+}
@@ -0,0 +1,513 @@
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeSet, HashMap};
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Component, Path, PathBuf},
+};
+use tracing::{debug, warn};
+
+use super::backup_manifest::{BackupCounts, BackupFileEntry, BackupManifest, BACKUP_FORMAT_VERSION};
+
+/// Name of the manifest entry inside the archive.
+pub const MANIFEST_NAME: &str = "manifest.json";
+
+/// Top-level directories of the data directory captured by a backup.
+pub const BACKUP_TREES: [&str; 2] = ["info", "metric"];
+
+const BACKUP_PREFIX: &str = "rustcost-backup-";
+const BACKUP_SUFFIX: &str = ".tar";
+
+/// Scratch directories inside the data directory, so renames stay on one filesystem.
+const STAGING_DIR: &str = ".restore-staging";
+const PREVIOUS_DIR: &str = ".restore-previous";
+
+/// A backup archive present in the backup directory.
+#[derive(Debug, Clone)]
+pub struct BackupFileInfo {
+    pub name: String,
+    pub size_bytes: u64,
+    pub modified_at: Option<DateTime<Utc>>,
+}
+
+/// Adapter for tar snapshots of the `info/` and `metric/` trees.
+///
+/// Callers are expected to hold the exclusive storage lock while creating or
+/// restoring, so the trees do not change underneath the adapter.
+#[derive(Debug, Default)]
+pub struct BackupFsAdapter;
+
+impl BackupFsAdapter {
+    /// `rustcost-backup-20250101T003000Z.tar`
+    pub fn backup_name(created_at: DateTime<Utc>) -> String {
+        format!("{}{}{}", BACKUP_PREFIX, created_at.format("%Y%m%dT%H%M%SZ"), BACKUP_SUFFIX)
+    }
+
+    /// Backup names become file names, so only accept the shape we generate.
+    pub fn is_valid_backup_name(name: &str) -> bool {
+        name.starts_with(BACKUP_PREFIX)
+            && name.ends_with(BACKUP_SUFFIX)
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+            && !name.contains("..")
+    }
+
+    /// Writes a snapshot of `base_dir` into `backup_dir` and returns its path and manifest.
+    ///
+    /// The archive is written under a temporary name and renamed once complete.
+    pub fn create(
+        &self,
+        base_dir: &Path,
+        backup_dir: &Path,
+        mut manifest: BackupManifest,
+    ) -> Result<(PathBuf, BackupManifest)> {
+        fs::create_dir_all(backup_dir)?;
+        let dest = backup_dir.join(Self::backup_name(manifest.created_at));
+        let tmp = dest.with_extension("tar.tmp");
+
+        let files = Self::collect_files(base_dir)?;
+        let mut builder = tar::Builder::new(BufWriter::new(
+            File::create(&tmp).with_context(|| format!("Failed to create backup file {:?}", tmp))?,
+        ));
+
+        let mut entries = Vec::with_capacity(files.len());
+        for rel in &files {
+            let path = base_dir.join(rel);
+            let meta = fs::metadata(&path)?;
+            let mut header = tar::Header::new_gnu();
+            header.set_size(meta.len());
+            header.set_mode(0o644);
+            header.set_mtime(
+                meta.modified()
+                    .ok()
+                    .map(|t| DateTime::<Utc>::from(t).timestamp().max(0) as u64)
+                    .unwrap_or(0),
+            );
+
+            let mut reader = HashingReader::new(File::open(&path)?.take(meta.len()));
+            builder
+                .append_data(&mut header, rel, &mut reader)
+                .with_context(|| format!("Failed to add {:?} to backup", path))?;
+
+            entries.push(BackupFileEntry {
+                path: rel.clone(),
+                size_bytes: meta.len(),
+                sha256: reader.finish(),
+            });
+        }
+
+        manifest.counts = Self::count(&entries);
+        manifest.files = entries;
+
+        let manifest_json = serde_json::to_vec_pretty(&manifest)?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(manifest_json.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(manifest.created_at.timestamp().max(0) as u64);
+        builder.append_data(&mut header, MANIFEST_NAME, manifest_json.as_slice())?;
+
+        let mut writer = builder.into_inner()?;
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+        drop(writer);
+
+        fs::rename(&tmp, &dest).with_context(|| format!("Failed to move backup into place {:?}", dest))?;
+        Ok((dest, manifest))
+    }
+
+    /// Lists backup archives in `backup_dir`, newest first.
+    pub fn list(&self, backup_dir: &Path) -> Result<Vec<BackupFileInfo>> {
+        let mut backups = Vec::new();
+        if !backup_dir.exists() {
+            return Ok(backups);
+        }
+
+        for entry in fs::read_dir(backup_dir)? {
+            let entry = entry?;
+            let Some(name) = entry.file_name().to_str().map(str::to_string) else { continue };
+            if !Self::is_valid_backup_name(&name) {
+                continue;
+            }
+            let meta = entry.metadata()?;
+            backups.push(BackupFileInfo {
+                name,
+                size_bytes: meta.len(),
+                modified_at: meta.modified().ok().map(DateTime::<Utc>::from),
+            });
+        }
+
+        backups.sort_by(|a, b| b.name.cmp(&a.name));
+        Ok(backups)
+    }
+
+    /// Reads the manifest of an archive without extracting it.
+    pub fn read_manifest(&self, archive: &Path) -> Result<BackupManifest> {
+        let mut tar = tar::Archive::new(BufReader::new(File::open(archive)?));
+        for entry in tar.entries()? {
+            let mut entry = entry?;
+            if entry.path()?.as_ref() == Path::new(MANIFEST_NAME) {
+                let mut buf = Vec::new();
+                entry.read_to_end(&mut buf)?;
+                return Ok(serde_json::from_slice(&buf)?);
+            }
+        }
+        Err(anyhow!("Backup {:?} has no {}", archive, MANIFEST_NAME))
+    }
+
+    /// Replaces the `info/` and `metric/` trees of `base_dir` with the archive contents.
+    ///
+    /// The archive is extracted into a staging directory and checked against its
+    /// manifest first; live data is only touched once every file has verified.
+    pub fn restore(&self, archive: &Path, base_dir: &Path) -> Result<BackupManifest> {
+        fs::create_dir_all(base_dir)?;
+        let staging = base_dir.join(STAGING_DIR);
+        if staging.exists() {
+            fs::remove_dir_all(&staging)?;
+        }
+        fs::create_dir_all(&staging)?;
+
+        let result = Self::extract(archive, &staging)
+            .and_then(|_| Self::verify(&staging))
+            .and_then(|manifest| {
+                Self::swap_in(&staging, base_dir, &BACKUP_TREES)?;
+                Ok(manifest)
+            });
+
+        if let Err(e) = fs::remove_dir_all(&staging) {
+            warn!("⚠️ Failed to remove restore staging dir {:?}: {}", staging, e);
+        }
+        result
+    }
+
+    fn extract(archive: &Path, staging: &Path) -> Result<()> {
+        let mut tar = tar::Archive::new(BufReader::new(
+            File::open(archive).with_context(|| format!("Failed to open backup {:?}", archive))?,
+        ));
+
+        for entry in tar.entries()? {
+            let mut entry = entry?;
+            let path = entry.path()?.into_owned();
+            let allowed = path == Path::new(MANIFEST_NAME)
+                || matches!(path.components().next(), Some(Component::Normal(top)) if BACKUP_TREES.iter().any(|t| top == *t));
+            if !allowed {
+                bail!("Unexpected entry {:?} in backup", path);
+            }
+            if !entry.unpack_in(staging)? {
+                bail!("Unsafe entry {:?} in backup", path);
+            }
+        }
+        Ok(())
+    }
+
+    fn verify(staging: &Path) -> Result<BackupManifest> {
+        let manifest_path = staging.join(MANIFEST_NAME);
+        if !manifest_path.exists() {
+            bail!("Backup has no {}", MANIFEST_NAME);
+        }
+        let manifest: BackupManifest = serde_json::from_slice(&fs::read(&manifest_path)?)
+            .context("Backup manifest is not valid JSON")?;
+
+        if manifest.format_version > BACKUP_FORMAT_VERSION {
+            bail!(
+                "Backup format version {} is newer than supported version {}",
+                manifest.format_version,
+                BACKUP_FORMAT_VERSION
+            );
+        }
+        if manifest.app_version != env!("CARGO_PKG_VERSION") {
+            warn!(
+                "⚠️ Restoring backup written by version {} into version {}",
+                manifest.app_version,
+                env!("CARGO_PKG_VERSION")
+            );
+        }
+
+        let expected: HashMap<&str, &BackupFileEntry> =
+            manifest.files.iter().map(|f| (f.path.as_str(), f)).collect();
+        let actual: BTreeSet<String> = Self::collect_files(staging)?.into_iter().collect();
+
+        if let Some(extra) = actual.iter().find(|p| !expected.contains_key(p.as_str())) {
+            bail!("Backup contains {} which is not listed in the manifest", extra);
+        }
+        for entry in &manifest.files {
+            if !actual.contains(&entry.path) {
+                bail!("Backup is missing {}", entry.path);
+            }
+            let path = staging.join(&entry.path);
+            let size = fs::metadata(&path)?.len();
+            if size != entry.size_bytes {
+                bail!("Size mismatch for {}: expected {}, got {}", entry.path, entry.size_bytes, size);
+            }
+            let mut reader = HashingReader::new(File::open(&path)?);
+            io::copy(&mut reader, &mut io::sink())?;
+            if reader.finish() != entry.sha256 {
+                bail!("Checksum mismatch for {}", entry.path);
+            }
+        }
+
+        debug!("✅ Backup manifest verified ({} files)", manifest.files.len());
+        Ok(manifest)
+    }
+
+    /// Moves the verified `trees` into place, rolling back if any rename fails.
+    fn swap_in(staging: &Path, base_dir: &Path, trees: &[&str]) -> Result<()> {
+        let previous = base_dir.join(PREVIOUS_DIR);
+        if previous.exists() {
+            fs::remove_dir_all(&previous)?;
+        }
+        fs::create_dir_all(&previous)?;
+
+        let mut moved: Vec<&str> = Vec::new();
+        for &tree in trees {
+            let live = base_dir.join(tree);
+            let staged = staging.join(tree);
+            if !staged.exists() {
+                fs::create_dir_all(&staged)?;
+            }
+
+            let step = (|| -> Result<()> {
+                if live.exists() {
+                    fs::rename(&live, previous.join(tree))?;
+                }
+                fs::rename(&staged, &live)?;
+                Ok(())
+            })();
+
+            if let Err(e) = step {
+                for done in moved.iter().chain(std::iter::once(&tree)) {
+                    let old = previous.join(done);
+                    if old.exists() {
+                        let live = base_dir.join(done);
+                        if live.exists() {
+                            let _ = fs::remove_dir_all(&live);
+                        }
+                        let _ = fs::rename(&old, &live);
+                    }
+                }
+                return Err(e.context(format!("Failed to swap in restored {}/", tree)));
+            }
+            moved.push(tree);
+        }
+
+        if let Err(e) = fs::remove_dir_all(&previous) {
+            warn!("⚠️ Failed to remove replaced data {:?}: {}", previous, e);
+        }
+        Ok(())
+    }
+
+    /// Relative `/`-separated paths of every regular file under the backup trees.
+    fn collect_files(base_dir: &Path) -> Result<Vec<String>> {
+        let mut files = Vec::new();
+        for tree in BACKUP_TREES {
+            let dir = base_dir.join(tree);
+            if dir.exists() {
+                Self::walk(base_dir, &dir, &mut files)?;
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    fn walk(base_dir: &Path, dir: &Path, out: &mut Vec<String>) -> Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                Self::walk(base_dir, &path, out)?;
+            } else if file_type.is_file() {
+                // In-flight temp files are never part of a consistent snapshot.
+                if path.extension().and_then(|e| e.to_str()) == Some("tmp") {
+                    continue;
+                }
+                let rel = path.strip_prefix(base_dir)?;
+                let rel: Vec<&str> = rel
+                    .components()
+                    .filter_map(|c| match c {
+                        Component::Normal(s) => s.to_str(),
+                        _ => None,
+                    })
+                    .collect();
+                out.push(rel.join("/"));
+            }
+        }
+        Ok(())
+    }
+
+    fn count(entries: &[BackupFileEntry]) -> BackupCounts {
+        let mut keys: [BTreeSet<&str>; 3] = Default::default();
+        let mut counts = BackupCounts::default();
+
+        for entry in entries {
+            counts.files += 1;
+            counts.total_bytes += entry.size_bytes;
+
+            let parts: Vec<&str> = entry.path.split('/').collect();
+            if let ["metric", "k8s", kind, key, _tier, file] = parts.as_slice() {
+                match *kind {
+                    "node" => keys[0].insert(key),
+                    "pod" => keys[1].insert(key),
+                    "container" => keys[2].insert(key),
+                    _ => false,
+                };
+                if file.ends_with(".rcd") || file.ends_with(".rcd.gz") {
+                    counts.segments += 1;
+                }
+            }
+        }
+
+        counts.nodes = keys[0].len();
+        counts.pods = keys[1].len();
+        counts.containers = keys[2].len();
+        counts
+    }
+}
+
+/// Reader that computes the SHA-256 of everything read through it.
+struct HashingReader<R> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> HashingReader<R> {
+    fn new(inner: R) -> Self {
+        Self { inner, hasher: Sha256::new() }
+    }
+
+    fn finish(self) -> String {
+        format!("{:x}", self.hasher.finalize())
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::segment::test_support::scratch_dir;
+
+    const NODE_INFO: &str = "info/k8s/node/node-a/info.rci";
+    const POD_SEGMENT: &str = "metric/k8s/pod/pod-1/m/2025-01-01.rcd";
+
+    fn manifest() -> BackupManifest {
+        BackupManifest {
+            format_version: BACKUP_FORMAT_VERSION,
+            created_at: "2025-01-01T00:30:00Z".parse().unwrap(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            git_version: None,
+            git_commit: None,
+            counts: BackupCounts::default(),
+            files: Vec::new(),
+        }
+    }
+
+    fn write(base: &Path, rel: &str, contents: &str) {
+        let path = base.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn read(base: &Path, rel: &str) -> String {
+        fs::read_to_string(base.join(rel)).unwrap()
+    }
+
+    /// A data directory with one info file and one segment, and a backup of it.
+    fn backed_up() -> (PathBuf, PathBuf, BackupManifest) {
+        let scratch = scratch_dir();
+        let base = scratch.join("data");
+        write(&base, NODE_INFO, "node-a info");
+        write(&base, POD_SEGMENT, "#RCD 1\npod-1 rows\n");
+        let (archive, manifest) = BackupFsAdapter.create(&base, &scratch.join("backup"), manifest()).unwrap();
+        (base, archive, manifest)
+    }
+
+    #[test]
+    fn restore_brings_back_the_backed_up_trees() {
+        let (base, archive, manifest) = backed_up();
+        assert_eq!(manifest.counts.files, 2);
+        assert_eq!(manifest.counts.pods, 1);
+        assert_eq!(manifest.counts.segments, 1);
+
+        write(&base, NODE_INFO, "changed since the backup");
+        write(&base, "metric/k8s/pod/pod-2/m/2025-01-01.rcd", "#RCD 1\n");
+
+        let restored = BackupFsAdapter.restore(&archive, &base).unwrap();
+        assert_eq!(restored.files.len(), 2);
+        assert_eq!(read(&base, NODE_INFO), "node-a info");
+        assert_eq!(read(&base, POD_SEGMENT), "#RCD 1\npod-1 rows\n");
+        assert!(!base.join("metric/k8s/pod/pod-2").exists());
+        assert!(!base.join(STAGING_DIR).exists());
+        assert!(!base.join(PREVIOUS_DIR).exists());
+    }
+
+    #[test]
+    fn manifest_records_size_and_sha256_of_every_file() {
+        let (base, archive, manifest) = backed_up();
+
+        let paths: Vec<&str> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec![NODE_INFO, POD_SEGMENT]);
+        for entry in &manifest.files {
+            let contents = fs::read(base.join(&entry.path)).unwrap();
+            assert_eq!(entry.size_bytes, contents.len() as u64);
+            assert_eq!(entry.sha256, format!("{:x}", Sha256::digest(&contents)));
+        }
+
+        let stored = BackupFsAdapter.read_manifest(&archive).unwrap();
+        assert_eq!(stored.files.len(), manifest.files.len());
+        assert_eq!(stored.files[1].sha256, manifest.files[1].sha256);
+    }
+
+    #[test]
+    fn corrupted_archive_is_rejected_and_live_data_kept() {
+        let (base, archive, _) = backed_up();
+        write(&base, POD_SEGMENT, "live rows");
+
+        // Same length, so the tar still reads but the checksum no longer matches.
+        let mut bytes = fs::read(&archive).unwrap();
+        let at = bytes.windows(10).position(|w| w == b"pod-1 rows").unwrap();
+        bytes[at] = b'P';
+        fs::write(&archive, &bytes).unwrap();
+
+        let err = BackupFsAdapter.restore(&archive, &base).unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"), "{}", err);
+        assert_eq!(read(&base, POD_SEGMENT), "live rows");
+        assert_eq!(read(&base, NODE_INFO), "node-a info");
+        assert!(!base.join(STAGING_DIR).exists());
+    }
+
+    #[test]
+    fn truncated_archive_is_rejected_and_live_data_kept() {
+        let (base, archive, _) = backed_up();
+        write(&base, POD_SEGMENT, "live rows");
+
+        let bytes = fs::read(&archive).unwrap();
+        fs::write(&archive, &bytes[..bytes.len() / 2]).unwrap();
+
+        assert!(BackupFsAdapter.restore(&archive, &base).is_err());
+        assert_eq!(read(&base, POD_SEGMENT), "live rows");
+        assert_eq!(read(&base, NODE_INFO), "node-a info");
+    }
+
+    #[test]
+    fn failed_swap_rolls_back_the_trees_already_moved() {
+        let scratch = scratch_dir();
+        let (base, staging) = (scratch.join("data"), scratch.join("staging"));
+        write(&base, "info/settings.rci", "live settings");
+        write(&staging, "info/settings.rci", "restored settings");
+
+        // The parent of `missing/metric` does not exist in the data directory,
+        // so moving that tree in fails after `info/` was swapped.
+        let err = BackupFsAdapter::swap_in(&staging, &base, &["info", "missing/metric"]).unwrap_err();
+        assert!(err.to_string().contains("missing/metric"), "{}", err);
+        assert_eq!(read(&base, "info/settings.rci"), "live settings");
+    }
+}
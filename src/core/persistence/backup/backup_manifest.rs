@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Layout version of the backup archive; bumped on incompatible changes.
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// `manifest.json` stored inside every backup archive.
+///
+/// Restore refuses an archive whose files do not match the manifest exactly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format_version: u32,
+    pub created_at: DateTime<Utc>,
+
+    /// `CARGO_PKG_VERSION` of the binary that wrote the backup.
+    pub app_version: String,
+    /// Values from `version.rci` at backup time, when available.
+    pub git_version: Option<String>,
+    pub git_commit: Option<String>,
+
+    pub counts: BackupCounts,
+    pub files: Vec<BackupFileEntry>,
+}
+
+/// Object and file counts, for a quick sanity check before restoring.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackupCounts {
+    pub nodes: usize,
+    pub pods: usize,
+    pub containers: usize,
+    /// Metric segment files (`.rcd` / `.rcd.gz`).
+    pub segments: usize,
+    pub files: usize,
+    pub total_bytes: u64,
+}
+
+/// One file in the archive, relative to the data directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupFileEntry {
+    pub path: String,
+    pub size_bytes: u64,
+    /// Hex-encoded SHA-256 of the file contents.
+    pub sha256: String,
+}
@@ -0,0 +1,2 @@
+pub mod backup_manifest;
+pub mod backup_fs_adapter;
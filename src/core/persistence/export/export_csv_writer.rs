@@ -0,0 +1,60 @@
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use super::export_writer::{
+    text_value, timestamp_value, tmp_path, uint_value, ExportColumn, ExportColumnType, ExportRow, ExportWriter,
+};
+
+/// CSV with a header row; empty fields are nulls and timestamps are RFC 3339.
+pub struct ExportCsvWriter {
+    columns: Vec<ExportColumn>,
+    writer: csv::Writer<BufWriter<File>>,
+    tmp: PathBuf,
+    path: PathBuf,
+}
+
+impl ExportCsvWriter {
+    pub fn create(columns: &[ExportColumn], path: &Path) -> Result<Self> {
+        let tmp = tmp_path(path);
+        let file = File::create(&tmp).with_context(|| format!("Failed to create export file {:?}", tmp))?;
+        let mut writer = csv::Writer::from_writer(BufWriter::new(file));
+        writer.write_record(columns.iter().map(|c| c.name.as_str()))?;
+
+        Ok(Self {
+            columns: columns.to_vec(),
+            writer,
+            tmp,
+            path: path.to_path_buf(),
+        })
+    }
+}
+
+impl ExportWriter for ExportCsvWriter {
+    fn write_rows(&mut self, rows: &[ExportRow]) -> Result<()> {
+        for row in rows {
+            let mut record = Vec::with_capacity(self.columns.len());
+            for (i, column) in self.columns.iter().enumerate() {
+                let value = row.get(i).unwrap_or(&serde_json::Value::Null);
+                let field = match column.column_type {
+                    ExportColumnType::Timestamp => timestamp_value(value, &column.name)?.map(|t| t.to_rfc3339()),
+                    ExportColumnType::Text => text_value(value),
+                    ExportColumnType::UInt64 => uint_value(value, &column.name)?.map(|v| v.to_string()),
+                };
+                record.push(field.unwrap_or_default());
+            }
+            self.writer.write_record(&record)?;
+        }
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<PathBuf> {
+        let Self { mut writer, tmp, path, .. } = *self;
+        writer.flush()?;
+        drop(writer);
+        fs::rename(&tmp, &path).with_context(|| format!("Failed to move export file into place {:?}", path))?;
+        Ok(path)
+    }
+}
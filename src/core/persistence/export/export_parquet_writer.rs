@@ -0,0 +1,98 @@
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use arrow_array::{ArrayRef, RecordBatch, StringArray, TimestampMicrosecondArray, UInt64Array};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+use super::export_writer::{
+    text_value, timestamp_value, tmp_path, uint_value, ExportColumn, ExportColumnType, ExportRow, ExportWriter,
+};
+
+pub struct ExportParquetWriter {
+    columns: Vec<ExportColumn>,
+    schema: SchemaRef,
+    writer: ArrowWriter<File>,
+    tmp: PathBuf,
+    path: PathBuf,
+}
+
+impl ExportParquetWriter {
+    pub fn create(columns: &[ExportColumn], path: &Path) -> Result<Self> {
+        let schema: SchemaRef = Arc::new(Schema::new(
+            columns
+                .iter()
+                .map(|c| {
+                    let data_type = match c.column_type {
+                        ExportColumnType::Timestamp => DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+                        ExportColumnType::Text => DataType::Utf8,
+                        ExportColumnType::UInt64 => DataType::UInt64,
+                    };
+                    Field::new(&c.name, data_type, true)
+                })
+                .collect::<Vec<_>>(),
+        ));
+
+        let tmp = tmp_path(path);
+        let file = File::create(&tmp).with_context(|| format!("Failed to create export file {:?}", tmp))?;
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let writer = ArrowWriter::try_new(file, schema.clone(), Some(props))?;
+
+        Ok(Self {
+            columns: columns.to_vec(),
+            schema,
+            writer,
+            tmp,
+            path: path.to_path_buf(),
+        })
+    }
+
+    fn column_array(&self, index: usize, rows: &[ExportRow]) -> Result<ArrayRef> {
+        let column = &self.columns[index];
+        let values = rows.iter().map(|row| row.get(index).unwrap_or(&serde_json::Value::Null));
+
+        Ok(match column.column_type {
+            ExportColumnType::Timestamp => Arc::new(
+                TimestampMicrosecondArray::from(
+                    values
+                        .map(|v| Ok(timestamp_value(v, &column.name)?.map(|t| t.timestamp_micros())))
+                        .collect::<Result<Vec<_>>>()?,
+                )
+                .with_timezone("UTC"),
+            ),
+            ExportColumnType::Text => Arc::new(StringArray::from(values.map(text_value).collect::<Vec<_>>())),
+            ExportColumnType::UInt64 => Arc::new(UInt64Array::from(
+                values
+                    .map(|v| uint_value(v, &column.name))
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+        })
+    }
+}
+
+impl ExportWriter for ExportParquetWriter {
+    fn write_rows(&mut self, rows: &[ExportRow]) -> Result<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+        let arrays = (0..self.columns.len())
+            .map(|i| self.column_array(i, rows))
+            .collect::<Result<Vec<_>>>()?;
+        let batch = RecordBatch::try_new(self.schema.clone(), arrays)?;
+        self.writer.write(&batch)?;
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<PathBuf> {
+        let Self { writer, tmp, path, .. } = *self;
+        writer.close()?;
+        fs::rename(&tmp, &path).with_context(|| format!("Failed to move export file into place {:?}", path))?;
+        Ok(path)
+    }
+}
@@ -0,0 +1,122 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::export_csv_writer::ExportCsvWriter;
+use super::export_parquet_writer::ExportParquetWriter;
+
+/// File format of an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// Snappy-compressed Parquet.
+    #[default]
+    Parquet,
+    /// Comma-separated values with a header row.
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Parquet => "parquet",
+            ExportFormat::Csv => "csv",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportColumnType {
+    /// UTC timestamp; RFC 3339 text in the row, microseconds in Parquet.
+    Timestamp,
+    Text,
+    UInt64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportColumn {
+    pub name: String,
+    pub column_type: ExportColumnType,
+}
+
+impl ExportColumn {
+    pub fn new(name: impl Into<String>, column_type: ExportColumnType) -> Self {
+        Self { name: name.into(), column_type }
+    }
+}
+
+/// One row of an export, values in column order.
+pub type ExportRow = Vec<Value>;
+
+/// Writes rows into one export file.
+///
+/// Rows go to a `.tmp` file next to the target, which only replaces the
+/// target in [`ExportWriter::finish`], so readers never see half a file.
+pub trait ExportWriter: Send {
+    fn write_rows(&mut self, rows: &[ExportRow]) -> Result<()>;
+
+    /// Completes the file and moves it into place; returns its path.
+    fn finish(self: Box<Self>) -> Result<PathBuf>;
+}
+
+pub fn create_export_writer(
+    format: ExportFormat,
+    columns: &[ExportColumn],
+    path: &Path,
+) -> Result<Box<dyn ExportWriter>> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    Ok(match format {
+        ExportFormat::Parquet => Box::new(ExportParquetWriter::create(columns, path)?),
+        ExportFormat::Csv => Box::new(ExportCsvWriter::create(columns, path)?),
+    })
+}
+
+pub(super) fn tmp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+pub(super) fn timestamp_value(value: &Value, column: &str) -> Result<Option<DateTime<Utc>>> {
+    match value {
+        Value::Null => Ok(None),
+        Value::String(s) => Ok(Some(
+            DateTime::parse_from_rfc3339(s)
+                .map_err(|e| anyhow!("Column '{}' has an invalid timestamp '{}': {}", column, s, e))?
+                .with_timezone(&Utc),
+        )),
+        other => Err(anyhow!("Column '{}' has a non-timestamp value {}", column, other)),
+    }
+}
+
+/// Strings as they are, lists comma-joined, anything else as JSON.
+pub(super) fn text_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        Value::Array(items) => Some(
+            items
+                .iter()
+                .map(|item| text_value(item).unwrap_or_default())
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        other => Some(other.to_string()),
+    }
+}
+
+pub(super) fn uint_value(value: &Value, column: &str) -> Result<Option<u64>> {
+    match value {
+        Value::Null => Ok(None),
+        Value::Number(n) => n
+            .as_u64()
+            .map(Some)
+            .ok_or_else(|| anyhow!("Column '{}' has a non-integer value {}", column, n)),
+        other => Err(anyhow!("Column '{}' has a non-integer value {}", column, other)),
+    }
+}
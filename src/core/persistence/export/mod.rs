@@ -0,0 +1,5 @@
+//! Columnar export files (Parquet, CSV) for loading metrics into a warehouse.
+
+pub mod export_csv_writer;
+pub mod export_parquet_writer;
+pub mod export_writer;
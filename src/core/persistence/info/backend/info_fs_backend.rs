@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use crate::core::persistence::info::backend::info_storage_backend_trait::InfoStorageBackend;
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::InfoSettingFsAdapter;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_fs_adapter::InfoUnitPriceFsAdapter;
+use crate::core::persistence::info::fixed::version::info_version_entity::InfoVersionEntity;
+use crate::core::persistence::info::fixed::version::info_version_fs_adapter::InfoVersionFsAdapter;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::container::info_container_fs_adapter::InfoContainerFsAdapter;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::node::info_node_fs_adapter::InfoNodeFsAdapter;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_fs_adapter::InfoPodFsAdapter;
+
+/// `.rci` files under `RUSTCOST_BASE_PATH/info`.
+#[derive(Debug, Default)]
+pub struct InfoFsBackend;
+
+impl InfoStorageBackend for InfoFsBackend {
+    fn node(&self) -> Arc<dyn InfoDynamicFsAdapterTrait<InfoNodeEntity>> {
+        Arc::new(InfoNodeFsAdapter)
+    }
+
+    fn pod(&self) -> Arc<dyn InfoDynamicFsAdapterTrait<InfoPodEntity>> {
+        Arc::new(InfoPodFsAdapter)
+    }
+
+    fn container(&self) -> Arc<dyn InfoDynamicFsAdapterTrait<InfoContainerEntity>> {
+        Arc::new(InfoContainerFsAdapter)
+    }
+
+    fn setting(&self) -> Arc<dyn InfoFixedFsAdapterTrait<InfoSettingEntity>> {
+        Arc::new(InfoSettingFsAdapter)
+    }
+
+    fn unit_price(&self) -> Arc<dyn InfoFixedFsAdapterTrait<InfoUnitPriceEntity>> {
+        Arc::new(InfoUnitPriceFsAdapter)
+    }
+
+    fn version(&self) -> Arc<dyn InfoFixedFsAdapterTrait<InfoVersionEntity>> {
+        Arc::new(InfoVersionFsAdapter)
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use anyhow::{anyhow, Result};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+
+/// In-memory store for one kind of per-object info, keyed like the fs adapter
+/// keys its directories.
+pub struct InfoMemoryDynamicAdapter<T> {
+    label: &'static str,
+    key_of: fn(&T) -> Result<String>,
+    entries: RwLock<BTreeMap<String, T>>,
+}
+
+impl<T> InfoMemoryDynamicAdapter<T> {
+    pub fn new(label: &'static str, key_of: fn(&T) -> Result<String>) -> Self {
+        Self {
+            label,
+            key_of,
+            entries: RwLock::new(BTreeMap::new()),
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync> InfoDynamicFsAdapterTrait<T> for InfoMemoryDynamicAdapter<T> {
+    fn read(&self, name: &str) -> Result<T> {
+        let entries = self.entries.read().map_err(|_| anyhow!("Info memory store poisoned"))?;
+        entries
+            .get(name)
+            .cloned()
+            .ok_or_else(|| anyhow!("Missing {} info '{}'", self.label, name))
+    }
+
+    fn insert(&self, data: &T) -> Result<()> {
+        self.update(data)
+    }
+
+    fn update(&self, data: &T) -> Result<()> {
+        let key = (self.key_of)(data)?;
+        let mut entries = self.entries.write().map_err(|_| anyhow!("Info memory store poisoned"))?;
+        entries.insert(key, data.clone());
+        Ok(())
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        let mut entries = self.entries.write().map_err(|_| anyhow!("Info memory store poisoned"))?;
+        entries.remove(name);
+        Ok(())
+    }
+
+    fn exists(&self, name: &str) -> Result<bool> {
+        let entries = self.entries.read().map_err(|_| anyhow!("Info memory store poisoned"))?;
+        Ok(entries.contains_key(name))
+    }
+
+    fn keys(&self) -> Result<Vec<String>> {
+        let entries = self.entries.read().map_err(|_| anyhow!("Info memory store poisoned"))?;
+        Ok(entries.keys().cloned().collect())
+    }
+}
+
+/// In-memory store for one fixed info file. Reads return defaults until
+/// something is written, like a missing file does.
+pub struct InfoMemoryFixedAdapter<T> {
+    entry: RwLock<Option<T>>,
+}
+
+impl<T> Default for InfoMemoryFixedAdapter<T> {
+    fn default() -> Self {
+        Self { entry: RwLock::new(None) }
+    }
+}
+
+impl<T: Clone + Default + Send + Sync> InfoFixedFsAdapterTrait<T> for InfoMemoryFixedAdapter<T> {
+    fn read(&self) -> Result<T> {
+        let entry = self.entry.read().map_err(|_| anyhow!("Info memory store poisoned"))?;
+        Ok(entry.clone().unwrap_or_default())
+    }
+
+    fn insert(&self, data: &T) -> Result<()> {
+        self.update(data)
+    }
+
+    fn update(&self, data: &T) -> Result<()> {
+        let mut entry = self.entry.write().map_err(|_| anyhow!("Info memory store poisoned"))?;
+        *entry = Some(data.clone());
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<()> {
+        let mut entry = self.entry.write().map_err(|_| anyhow!("Info memory store poisoned"))?;
+        *entry = None;
+        Ok(())
+    }
+}
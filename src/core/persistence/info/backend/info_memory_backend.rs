@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use anyhow::anyhow;
+
+use crate::core::persistence::info::backend::info_memory_adapter::{InfoMemoryDynamicAdapter, InfoMemoryFixedAdapter};
+use crate::core::persistence::info::backend::info_storage_backend_trait::InfoStorageBackend;
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::fixed::version::info_version_entity::InfoVersionEntity;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::container::info_container_fs_adapter::InfoContainerFsAdapter;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+
+/// Info backend that never touches the filesystem.
+pub struct InfoMemoryBackend {
+    nodes: Arc<InfoMemoryDynamicAdapter<InfoNodeEntity>>,
+    pods: Arc<InfoMemoryDynamicAdapter<InfoPodEntity>>,
+    containers: Arc<InfoMemoryDynamicAdapter<InfoContainerEntity>>,
+    setting: Arc<InfoMemoryFixedAdapter<InfoSettingEntity>>,
+    unit_price: Arc<InfoMemoryFixedAdapter<InfoUnitPriceEntity>>,
+    version: Arc<InfoMemoryFixedAdapter<InfoVersionEntity>>,
+}
+
+impl Default for InfoMemoryBackend {
+    fn default() -> Self {
+        Self {
+            nodes: Arc::new(InfoMemoryDynamicAdapter::new("node", |n: &InfoNodeEntity| {
+                n.node_name.clone().ok_or_else(|| anyhow!("Missing node_name in InfoNodeEntity"))
+            })),
+            pods: Arc::new(InfoMemoryDynamicAdapter::new("pod", |p: &InfoPodEntity| {
+                p.pod_uid.clone().ok_or_else(|| anyhow!("Missing pod_uid in InfoPodEntity"))
+            })),
+            containers: Arc::new(InfoMemoryDynamicAdapter::new("container", InfoContainerFsAdapter::container_key)),
+            setting: Arc::default(),
+            unit_price: Arc::default(),
+            version: Arc::default(),
+        }
+    }
+}
+
+impl InfoStorageBackend for InfoMemoryBackend {
+    fn node(&self) -> Arc<dyn InfoDynamicFsAdapterTrait<InfoNodeEntity>> {
+        self.nodes.clone()
+    }
+
+    fn pod(&self) -> Arc<dyn InfoDynamicFsAdapterTrait<InfoPodEntity>> {
+        self.pods.clone()
+    }
+
+    fn container(&self) -> Arc<dyn InfoDynamicFsAdapterTrait<InfoContainerEntity>> {
+        self.containers.clone()
+    }
+
+    fn setting(&self) -> Arc<dyn InfoFixedFsAdapterTrait<InfoSettingEntity>> {
+        self.setting.clone()
+    }
+
+    fn unit_price(&self) -> Arc<dyn InfoFixedFsAdapterTrait<InfoUnitPriceEntity>> {
+        self.unit_price.clone()
+    }
+
+    fn version(&self) -> Arc<dyn InfoFixedFsAdapterTrait<InfoVersionEntity>> {
+        self.version.clone()
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::fixed::version::info_version_entity::InfoVersionEntity;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+
+/// Storage backend for info entities.
+///
+/// Hands out one adapter per entity; API repositories wrap those adapters.
+pub trait InfoStorageBackend: Send + Sync {
+    fn node(&self) -> Arc<dyn InfoDynamicFsAdapterTrait<InfoNodeEntity>>;
+
+    fn pod(&self) -> Arc<dyn InfoDynamicFsAdapterTrait<InfoPodEntity>>;
+
+    fn container(&self) -> Arc<dyn InfoDynamicFsAdapterTrait<InfoContainerEntity>>;
+
+    fn setting(&self) -> Arc<dyn InfoFixedFsAdapterTrait<InfoSettingEntity>>;
+
+    fn unit_price(&self) -> Arc<dyn InfoFixedFsAdapterTrait<InfoUnitPriceEntity>>;
+
+    fn version(&self) -> Arc<dyn InfoFixedFsAdapterTrait<InfoVersionEntity>>;
+}
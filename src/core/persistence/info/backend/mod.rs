@@ -0,0 +1,11 @@
+//! Storage for node/pod/container info and the fixed info files.
+//!
+//! The server uses the `.rci` files under `info/`; the memory backend keeps
+//! everything in the process for hermetic API tests.
+
+pub mod info_fs_backend;
+#[cfg(test)]
+pub mod info_memory_adapter;
+#[cfg(test)]
+pub mod info_memory_backend;
+pub mod info_storage_backend_trait;
@@ -3,6 +3,12 @@ use serde::{Deserialize, Serialize};
 use std::env;
 use crate::domain::info::dto::info_setting_upsert_request::InfoSettingUpsertRequest;
 
+/// Shortest accepted scrape interval; the kubelet refreshes its stats about every 10s.
+pub const MIN_SCRAPE_INTERVAL_SEC: u32 = 10;
+
+/// Longest accepted scrape interval.
+pub const MAX_SCRAPE_INTERVAL_SEC: u32 = 300;
+
 /// Global configuration for RustCost.
 ///
 /// This structure defines all configurable aspects of the system,
@@ -40,14 +46,15 @@ pub struct InfoSettingEntity {
     /// Whether to create `.idx` index sidecar files for faster reads.
     pub enable_index_file: bool,
 
-    /// Maximum local storage capacity in gigabytes before cleanup triggers.
+    /// Maximum local storage capacity in GiB before the oldest minute/hour data is evicted (`0` disables the quota).
     pub max_storage_gb: u32,
 
-    /// Enables on-disk compression (gzip or zstd).
+    /// Enables gzip compression of closed metric segments (`.rcd.gz`).
     pub compression_enabled: bool,
 
     // ===== Metrics Collection =====
-    /// Scrape interval in seconds (e.g. 60 = every minute).
+    /// Scrape interval in seconds (e.g. 60 = every minute, 15 = four samples per minute).
+    /// Values outside 10–300 are clamped.
     pub scrape_interval_sec: u32,
 
     /// Number of metrics batched together when written to disk.
@@ -182,6 +189,12 @@ impl Default for InfoSettingEntity {
     }
 }
 impl InfoSettingEntity {
+    /// `scrape_interval_sec` clamped to the supported range.
+    pub fn scrape_interval_sec(&self) -> u32 {
+        self.scrape_interval_sec
+            .clamp(MIN_SCRAPE_INTERVAL_SEC, MAX_SCRAPE_INTERVAL_SEC)
+    }
+
     pub fn apply_update(&mut self, req: InfoSettingUpsertRequest) {
         // === General & UI ===
         if let Some(v) = req.is_dark_mode {
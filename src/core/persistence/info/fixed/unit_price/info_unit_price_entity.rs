@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
+use crate::domain::metric::k8s::node_network::dto::metric_node_network_dto::NetworkPriceClass;
 
 /// Represents per-unit pricing configuration for system resource usage.
 ///
@@ -42,6 +43,9 @@ pub struct InfoUnitPriceEntity {
     pub network_regional_gb: f64,
     /// Price per GB transferred to external networks (internet egress)
     pub network_external_gb: f64,
+    /// Price tier of node interface egress, first matching rule wins
+    #[serde(default)]
+    pub network_interface_classes: Vec<NetworkInterfaceClass>,
 
     /// Last update timestamp (UTC).
     pub updated_at: DateTime<Utc>,
@@ -59,6 +63,52 @@ impl InfoUnitPriceEntity {
         if let Some(v) = req.network_local_gb { self.network_local_gb = v; }
         if let Some(v) = req.network_regional_gb { self.network_regional_gb = v; }
         if let Some(v) = req.network_external_gb { self.network_external_gb = v; }
+        if let Some(v) = req.network_interface_classes { self.network_interface_classes = v; }
+    }
+
+    /// Price tier of a node interface's egress: the first matching rule, or
+    /// `fallback` when none matches.
+    pub fn network_class_of(&self, interface: &str, fallback: NetworkPriceClass) -> NetworkPriceClass {
+        self.network_interface_classes
+            .iter()
+            .find(|rule| rule.matches(interface))
+            .map_or(fallback, |rule| rule.class)
+    }
+}
+
+/// Price tier of the node interfaces matching `pattern`: an interface name, or
+/// a prefix followed by `*` (e.g. `ens*`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkInterfaceClass {
+    pub pattern: String,
+    pub class: NetworkPriceClass,
+}
+
+impl NetworkInterfaceClass {
+    pub fn matches(&self, interface: &str) -> bool {
+        match self.pattern.strip_suffix('*') {
+            Some(prefix) => interface.starts_with(prefix),
+            None => interface == self.pattern,
+        }
+    }
+
+    /// Parses `pattern=class`, e.g. `eth1=local`.
+    pub fn parse(rule: &str) -> Option<Self> {
+        let (pattern, class) = rule.split_once('=')?;
+        let pattern = pattern.trim();
+        if pattern.is_empty() {
+            return None;
+        }
+        Some(Self {
+            pattern: pattern.to_string(),
+            class: NetworkPriceClass::parse(class.trim())?,
+        })
+    }
+}
+
+impl std::fmt::Display for NetworkInterfaceClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.pattern, self.class.as_str())
     }
 }
 
@@ -76,6 +126,7 @@ impl Default for InfoUnitPriceEntity {
             network_local_gb: 0.01,
             network_regional_gb: 0.01,
             network_external_gb: 0.12,
+            network_interface_classes: Vec::new(),
             updated_at: now,
         }
     }
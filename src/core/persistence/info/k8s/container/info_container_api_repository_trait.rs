@@ -18,4 +18,9 @@ pub trait InfoContainerApiRepository: Send + Sync {
     fn update(&self, data: &InfoContainerEntity) -> Result<()> {
         self.fs_adapter().update(data)
     }
+
+    /// Keys of all stored container info entries.
+    fn keys(&self) -> Result<Vec<String>> {
+        self.fs_adapter().keys()
+    }
 }
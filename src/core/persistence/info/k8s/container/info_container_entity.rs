@@ -74,4 +74,8 @@ pub struct InfoContainerEntity {
     pub team: Option<String>,
     pub service: Option<String>,
     pub env: Option<String>, // "dev", "stage", "prod"
+
+    // --- Origin ---
+    /// Collector that wrote the entry; `None` for pod containers (see `info_source`).
+    pub source: Option<String>,
 }
@@ -1,4 +1,4 @@
-use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::{list_info_keys, InfoDynamicFsAdapterTrait};
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
 use anyhow::{anyhow, Context, Result};
 use std::{
@@ -6,7 +6,7 @@ use std::{
     io::{BufRead, BufReader, Write},
     path::Path,
 };
-use crate::core::persistence::info::path::{info_k8s_container_key_dir_path, info_k8s_container_file_path};
+use crate::core::persistence::info::path::{info_k8s_container_dir_path, info_k8s_container_key_dir_path, info_k8s_container_file_path};
 
 /// File-based FS adapter for `InfoContainerEntity`.
 ///
@@ -77,6 +77,9 @@ impl InfoDynamicFsAdapterTrait<InfoContainerEntity> for InfoContainerFsAdapter {
                     "SERVICE" => v.service = Some(val),
                     "ENV" => v.env = Some(val),
 
+                    // Origin
+                    "SOURCE" => v.source = (!val.is_empty()).then_some(val),
+
                     // Bookkeeping
                     "LAST_UPDATED_INFO_AT" => v.last_updated_info_at = val.parse().ok(),
                     "DELETED" => v.deleted = Some(val == "true"),
@@ -110,6 +113,8 @@ impl InfoDynamicFsAdapterTrait<InfoContainerEntity> for InfoContainerFsAdapter {
         if Path::new(&path).exists() {
             fs::remove_file(&path).context("Failed to delete container info file")?;
         }
+        // Drop the now empty entry directory so it no longer shows up in `keys()`.
+        let _ = fs::remove_dir(info_k8s_container_key_dir_path(key));
         Ok(())
     }
 
@@ -117,11 +122,15 @@ impl InfoDynamicFsAdapterTrait<InfoContainerEntity> for InfoContainerFsAdapter {
         let path = info_k8s_container_file_path(container_key);
         Ok(Path::new(&path).exists())
     }
+
+    fn keys(&self) -> Result<Vec<String>> {
+        list_info_keys(&info_k8s_container_dir_path())
+    }
 }
 
 impl InfoContainerFsAdapter {
     /// Builds the unique key (directory name) for the container.
-    fn container_key(data: &InfoContainerEntity) -> Result<String> {
+    pub fn container_key(data: &InfoContainerEntity) -> Result<String> {
         let pod_uid = data
             .pod_uid
             .as_ref()
@@ -209,6 +218,9 @@ impl InfoContainerFsAdapter {
         write_field!("SERVICE", data.service.clone());
         write_field!("ENV", data.env.clone());
 
+        // ---- Origin ----
+        write_field!("SOURCE", data.source.clone());
+
         // ---- Bookkeeping ----
         write_field!("LAST_UPDATED_INFO_AT", data.last_updated_info_at.map(|t| t.to_string()));
         write_field!("DELETED", data.deleted.map(|v| v.to_string()));
@@ -1,4 +1,6 @@
 use anyhow::Result;
+use std::fs;
+use std::path::Path;
 
 /// A simple generic trait for FS_ADAPTER operations.
 pub trait InfoDynamicFsAdapterTrait<T>: Send + Sync {
@@ -9,4 +11,24 @@ pub trait InfoDynamicFsAdapterTrait<T>: Send + Sync {
 
     fn exists(&self, name: &str) -> Result<bool>;
 
-}
\ No newline at end of file
+    /// Names of all stored entries, sorted.
+    fn keys(&self) -> Result<Vec<String>>;
+
+}
+
+/// Entry directories under an info kind directory, sorted.
+/// A missing directory just means nothing was stored yet.
+pub fn list_info_keys(dir: &Path) -> Result<Vec<String>> {
+    let mut keys = Vec::new();
+    if !dir.exists() {
+        return Ok(keys);
+    }
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.path().is_dir() {
+            keys.push(entry.file_name().to_string_lossy().to_string());
+        }
+    }
+    keys.sort();
+    Ok(keys)
+}
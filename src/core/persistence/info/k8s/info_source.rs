@@ -0,0 +1,20 @@
+//! Origin of a node or container info entry.
+//!
+//! Entries without a source were written by the k8s collector, before sources
+//! were recorded, and are treated as Kubernetes objects.
+
+/// Written by the k8s collector from the API server and kubelet summaries.
+pub const SOURCE_K8S: &str = "k8s";
+
+/// Written by the RustExporter collector for hosts outside the cluster.
+pub const SOURCE_RUSTEXPORTER: &str = "rustexporter";
+
+pub fn is_k8s_source(source: Option<&str>) -> bool {
+    source.is_none_or(|s| s.is_empty() || s == SOURCE_K8S)
+}
+
+/// Storage key of a container reported by an exporter, `<host>-<container_name>`,
+/// the counterpart of `<pod_uid>-<container_name>` for pod containers.
+pub fn exporter_container_key(host: &str, container_name: &str) -> String {
+    format!("{}-{}", host, container_name)
+}
@@ -1,4 +1,5 @@
 pub mod container;
 pub mod node;
 pub mod pod;
-pub mod info_dynamic_fs_adapter_trait;
\ No newline at end of file
+pub mod info_dynamic_fs_adapter_trait;
+pub mod info_source;
@@ -18,4 +18,9 @@ pub trait InfoNodeApiRepository: Send + Sync {
     fn update(&self, data: &InfoNodeEntity) -> Result<()> {
         self.fs_adapter().update(data)
     }
+
+    /// Keys of all stored node info entries.
+    fn keys(&self) -> Result<Vec<String>> {
+        self.fs_adapter().keys()
+    }
 }
@@ -55,5 +55,9 @@ pub struct InfoNodeEntity {
     pub service: Option<String>,
     pub env: Option<String>, // "dev", "stage", "prod"
 
+    // --- Origin ---
+    /// Collector that wrote the entry; `None` for Kubernetes nodes (see `info_source`).
+    pub source: Option<String>,
+
 }
 
@@ -1,8 +1,8 @@
 use super::info_node_entity::InfoNodeEntity;
-use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::{list_info_keys, InfoDynamicFsAdapterTrait};
 use anyhow::{anyhow, Context, Result};
 use std::{fs::{self, File}, io::{BufRead, BufReader, Write}, path::Path};
-use crate::core::persistence::info::path::{info_k8s_node_key_dir_path, info_k8s_node_file_path, info_k8s_container_file_path};
+use crate::core::persistence::info::path::{info_k8s_node_dir_path, info_k8s_node_key_dir_path, info_k8s_node_file_path, info_k8s_container_file_path};
 
 /// File-based FS adapter for the `InfoNodeEntity`.
 ///
@@ -67,6 +67,7 @@ impl InfoDynamicFsAdapterTrait<InfoNodeEntity> for InfoNodeFsAdapter {
                     "TEAM" => v.team = Some(val),
                     "SERVICE" => v.service = Some(val),
                     "ENV" => v.env = Some(val),
+                    "SOURCE" => v.source = (!val.is_empty()).then_some(val),
                     _ => {}
                 }
             }
@@ -108,6 +109,8 @@ impl InfoDynamicFsAdapterTrait<InfoNodeEntity> for InfoNodeFsAdapter {
         if Path::new(&path).exists() {
             fs::remove_file(&path).context("Failed to delete node info file")?;
         }
+        // Drop the now empty entry directory so it no longer shows up in `keys()`.
+        let _ = fs::remove_dir(info_k8s_node_key_dir_path(node_name));
         Ok(())
     }
 
@@ -116,6 +119,10 @@ impl InfoDynamicFsAdapterTrait<InfoNodeEntity> for InfoNodeFsAdapter {
         Ok(Path::new(&path).exists())
     }
 
+    fn keys(&self) -> Result<Vec<String>> {
+        list_info_keys(&info_k8s_node_dir_path())
+    }
+
 }
 
 impl InfoNodeFsAdapter {
@@ -198,6 +205,9 @@ impl InfoNodeFsAdapter {
         write_field!("SERVICE", data.service);
         write_field!("ENV", data.env);
 
+        // ---- Origin ----
+        write_field!("SOURCE", data.source);
+
         // ---- Flush only (no fsync) ----
         f.flush()?;
 
@@ -22,4 +22,9 @@ pub trait InfoPodApiRepository: Send + Sync {
     fn insert(&self, data: &InfoPodEntity) -> Result<()> {
         self.fs_adapter().insert(data)
     }
+
+    /// Keys of all stored pod info entries.
+    fn keys(&self) -> Result<Vec<String>> {
+        self.fs_adapter().keys()
+    }
 }
@@ -1,4 +1,4 @@
-use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::{list_info_keys, InfoDynamicFsAdapterTrait};
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
 use anyhow::{anyhow, Context, Result};
 use std::{
@@ -7,7 +7,7 @@ use std::{
     path::Path,
 };
 use tracing::log::debug;
-use crate::core::persistence::info::path::{info_k8s_pod_key_dir_path, info_k8s_pod_file_path, info_k8s_container_file_path};
+use crate::core::persistence::info::path::{info_k8s_pod_dir_path, info_k8s_pod_key_dir_path, info_k8s_pod_file_path, info_k8s_container_file_path};
 
 /// File-based FS adapter for `InfoPodEntity`.
 ///
@@ -151,6 +151,8 @@ impl InfoDynamicFsAdapterTrait<InfoPodEntity> for InfoPodFsAdapter {
         if Path::new(&path).exists() {
             fs::remove_file(&path).context("Failed to delete pod info file")?;
         }
+        // Drop the now empty entry directory so it no longer shows up in `keys()`.
+        let _ = fs::remove_dir(info_k8s_pod_key_dir_path(pod_uid));
         Ok(())
     }
 
@@ -158,6 +160,10 @@ impl InfoDynamicFsAdapterTrait<InfoPodEntity> for InfoPodFsAdapter {
         let path = info_k8s_pod_file_path(pod_uid);
         Ok(Path::new(&path).exists())
     }
+
+    fn keys(&self) -> Result<Vec<String>> {
+        list_info_keys(&info_k8s_pod_dir_path())
+    }
 }
 
 impl InfoPodFsAdapter {
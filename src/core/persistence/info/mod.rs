@@ -1,3 +1,4 @@
+pub mod backend;
 pub mod k8s;
 pub mod fixed;
 pub mod path;
@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{
+    MetricBackendKind, MetricObjectKind, MetricStorageBackend,
+};
+use crate::core::persistence::metrics::k8s::container::day::metric_container_day_fs_adapter::MetricContainerDayFsAdapter;
+use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_fs_adapter::MetricContainerHourFsAdapter;
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::node::day::metric_node_day_fs_adapter::MetricNodeDayFsAdapter;
+use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_fs_adapter::MetricNodeHourFsAdapter;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_fs_adapter::MetricNodeMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::node_network::day::metric_node_network_day_fs_adapter::MetricNodeNetworkDayFsAdapter;
+use crate::core::persistence::metrics::k8s::node_network::hour::metric_node_network_hour_fs_adapter::MetricNodeNetworkHourFsAdapter;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use crate::core::persistence::metrics::k8s::node_network::minute::metric_node_network_minute_fs_adapter::MetricNodeNetworkMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::node_system::day::metric_node_system_day_fs_adapter::MetricNodeSystemDayFsAdapter;
+use crate::core::persistence::metrics::k8s::node_system::hour::metric_node_system_hour_fs_adapter::MetricNodeSystemHourFsAdapter;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use crate::core::persistence::metrics::k8s::node_system::minute::metric_node_system_minute_fs_adapter::MetricNodeSystemMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_container_dir_path, metric_k8s_node_dir_path, metric_k8s_node_network_dir_path,
+    metric_k8s_node_system_dir_path, metric_k8s_pod_dir_path, metric_k8s_pvc_dir_path,
+};
+use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_fs_adapter::MetricPodDayFsAdapter;
+use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_fs_adapter::MetricPodHourFsAdapter;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_adapter::MetricPodMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::pvc::day::metric_pvc_day_fs_adapter::MetricPvcDayFsAdapter;
+use crate::core::persistence::metrics::k8s::pvc::hour::metric_pvc_hour_fs_adapter::MetricPvcHourFsAdapter;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::core::persistence::metrics::k8s::pvc::minute::metric_pvc_minute_fs_adapter::MetricPvcMinuteFsAdapter;
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::segment::compression::segment_period;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::metrics::segment::watermark::{read_watermark, write_watermark};
+use crate::core::persistence::metrics::segment::write_buffer::release_dir;
+
+/// Default backend: one `.rcd` segment per object, tier and period.
+#[derive(Debug, Default)]
+pub struct MetricFsBackend;
+
+impl MetricFsBackend {
+    fn kind_dir(kind: MetricObjectKind) -> PathBuf {
+        match kind {
+            MetricObjectKind::Node => metric_k8s_node_dir_path(),
+            MetricObjectKind::Pod => metric_k8s_pod_dir_path(),
+            MetricObjectKind::Container => metric_k8s_container_dir_path(),
+            MetricObjectKind::Pvc => metric_k8s_pvc_dir_path(),
+            MetricObjectKind::NodeNetwork => metric_k8s_node_network_dir_path(),
+            MetricObjectKind::NodeSystem => metric_k8s_node_system_dir_path(),
+        }
+    }
+}
+
+impl MetricStorageBackend for MetricFsBackend {
+    fn kind(&self) -> MetricBackendKind {
+        MetricBackendKind::Fs
+    }
+
+    fn node(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeEntity>> {
+        match tier {
+            MetricTier::Minute => Arc::new(MetricNodeMinuteFsAdapter),
+            MetricTier::Hour => Arc::new(MetricNodeHourFsAdapter),
+            MetricTier::Day => Arc::new(MetricNodeDayFsAdapter),
+        }
+    }
+
+    fn pod(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricPodEntity>> {
+        match tier {
+            MetricTier::Minute => Arc::new(MetricPodMinuteFsAdapter),
+            MetricTier::Hour => Arc::new(MetricPodHourFsAdapter),
+            MetricTier::Day => Arc::new(MetricPodDayFsAdapter),
+        }
+    }
+
+    fn container(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricContainerEntity>> {
+        match tier {
+            MetricTier::Minute => Arc::new(MetricContainerMinuteFsAdapter),
+            MetricTier::Hour => Arc::new(MetricContainerHourFsAdapter),
+            MetricTier::Day => Arc::new(MetricContainerDayFsAdapter),
+        }
+    }
+
+    fn pvc(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricPvcEntity>> {
+        match tier {
+            MetricTier::Minute => Arc::new(MetricPvcMinuteFsAdapter),
+            MetricTier::Hour => Arc::new(MetricPvcHourFsAdapter),
+            MetricTier::Day => Arc::new(MetricPvcDayFsAdapter),
+        }
+    }
+
+    fn node_network(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeNetworkEntity>> {
+        match tier {
+            MetricTier::Minute => Arc::new(MetricNodeNetworkMinuteFsAdapter),
+            MetricTier::Hour => Arc::new(MetricNodeNetworkHourFsAdapter),
+            MetricTier::Day => Arc::new(MetricNodeNetworkDayFsAdapter),
+        }
+    }
+
+    fn node_system(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeSystemEntity>> {
+        match tier {
+            MetricTier::Minute => Arc::new(MetricNodeSystemMinuteFsAdapter),
+            MetricTier::Hour => Arc::new(MetricNodeSystemHourFsAdapter),
+            MetricTier::Day => Arc::new(MetricNodeSystemDayFsAdapter),
+        }
+    }
+
+    fn keys(&self, kind: MetricObjectKind) -> Result<Vec<String>> {
+        let base_dir = Self::kind_dir(kind);
+        let mut keys = Vec::new();
+        if !base_dir.exists() {
+            return Ok(keys);
+        }
+        for entry in fs::read_dir(&base_dir)? {
+            let entry = entry?;
+            if entry.path().is_dir() {
+                if let Some(key) = entry.file_name().to_str() {
+                    keys.push(key.to_string());
+                }
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn first_time(&self, kind: MetricObjectKind, tier: MetricTier, key: &str) -> Result<Option<DateTime<Utc>>> {
+        let dir = Self::kind_dir(kind).join(key).join(tier.dir_name());
+        if !dir.exists() {
+            return Ok(None);
+        }
+
+        // Segments are named after their period; the oldest period bounds the rows.
+        let mut first = None;
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let Some(date) = segment_period(&path).and_then(|stem| tier.parse_period(stem)) else {
+                continue;
+            };
+            if first.is_none_or(|f| date < f) {
+                first = Some(date);
+            }
+        }
+        Ok(first.and_then(|d| d.and_hms_opt(0, 0, 0)).map(|t| t.and_utc()))
+    }
+
+    fn watermark(&self, kind: MetricObjectKind, tier: MetricTier, key: &str) -> Option<DateTime<Utc>> {
+        read_watermark(&Self::kind_dir(kind).join(key).join(tier.dir_name()))
+    }
+
+    fn set_watermark(&self, kind: MetricObjectKind, tier: MetricTier, key: &str, end: DateTime<Utc>) -> Result<()> {
+        write_watermark(&Self::kind_dir(kind).join(key).join(tier.dir_name()), end)
+    }
+
+    fn remove_object(&self, kind: MetricObjectKind, key: &str) -> Result<()> {
+        let dir = Self::kind_dir(kind).join(key);
+        release_dir(&dir);
+        if dir.exists() {
+            fs::remove_dir_all(&dir).with_context(|| format!("Failed to remove metric directory {:?}", dir))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
+use std::sync::{Arc, RwLock};
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::core::persistence::metrics::backend::metric_row_fields::{entity_columns, keep_column, row_time};
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Rows of one tier: object key → timestamp → row.
+type MemoryTable<T> = RwLock<HashMap<String, BTreeMap<DateTime<Utc>, T>>>;
+
+/// All tiers of one object kind of the `memory` backend.
+pub struct MetricMemoryTables<T> {
+    minute: MemoryTable<T>,
+    hour: MemoryTable<T>,
+    day: MemoryTable<T>,
+}
+
+impl<T> Default for MetricMemoryTables<T> {
+    fn default() -> Self {
+        Self {
+            minute: RwLock::new(HashMap::new()),
+            hour: RwLock::new(HashMap::new()),
+            day: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T> MetricMemoryTables<T> {
+    pub fn tier(&self, tier: MetricTier) -> &MemoryTable<T> {
+        match tier {
+            MetricTier::Minute => &self.minute,
+            MetricTier::Hour => &self.hour,
+            MetricTier::Day => &self.day,
+        }
+    }
+}
+
+/// Adapter for one object kind and tier of the `memory` backend.
+///
+/// Same semantics as the sqlite adapter: one row per `(key, time)`, and hour
+/// and day rows are rolled up from this backend's own lower tiers.
+pub struct MetricMemoryAdapter<T> {
+    tables: Arc<MetricMemoryTables<T>>,
+    tier: MetricTier,
+    columns: Arc<Vec<String>>,
+}
+
+impl<T> MetricMemoryAdapter<T>
+where
+    T: Serialize + DeserializeOwned + Default + Clone + MetricRollup,
+{
+    pub fn new(tables: Arc<MetricMemoryTables<T>>, tier: MetricTier) -> Self {
+        Self {
+            tables,
+            tier,
+            columns: Arc::new(entity_columns::<T>()),
+        }
+    }
+
+    fn table(&self, tier: MetricTier) -> &MemoryTable<T> {
+        self.tables.tier(tier)
+    }
+
+    fn insert_rows(&self, key: &str, rows: &[T]) -> Result<()> {
+        let stamped = rows
+            .iter()
+            .map(|row| Ok((row_time(row)?, row.clone())))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut table = self
+            .table(self.tier)
+            .write()
+            .map_err(|_| anyhow!("Metric memory table poisoned"))?;
+        table.entry(key.to_string()).or_default().extend(stamped);
+        Ok(())
+    }
+
+    fn select(
+        &self,
+        tier: MetricTier,
+        key: &str,
+        range: (Bound<DateTime<Utc>>, Bound<DateTime<Utc>>),
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        let table = self
+            .table(tier)
+            .read()
+            .map_err(|_| anyhow!("Metric memory table poisoned"))?;
+        let Some(rows) = table.get(key) else {
+            return Ok(Vec::new());
+        };
+        if is_empty_range(&range) {
+            return Ok(Vec::new());
+        }
+        Ok(rows
+            .range(range)
+            .skip(offset.unwrap_or(0))
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|(_, row)| row.clone())
+            .collect())
+    }
+
+    fn remove_before(&self, key: &str, before: DateTime<Utc>) -> Result<()> {
+        let mut table = self
+            .table(self.tier)
+            .write()
+            .map_err(|_| anyhow!("Metric memory table poisoned"))?;
+        if let Some(rows) = table.get_mut(key) {
+            *rows = rows.split_off(&before);
+            if rows.is_empty() {
+                table.remove(key);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `BTreeMap::range` panics on inverted ranges; callers may pass any window.
+fn is_empty_range(range: &(Bound<DateTime<Utc>>, Bound<DateTime<Utc>>)) -> bool {
+    match *range {
+        (Bound::Included(s), Bound::Included(e)) => s > e,
+        (Bound::Included(s) | Bound::Excluded(s), Bound::Included(e) | Bound::Excluded(e)) => s >= e,
+        _ => false,
+    }
+}
+
+impl<T> MetricFsAdapterBase<T> for MetricMemoryAdapter<T>
+where
+    T: Serialize + DeserializeOwned + Default + Clone + MetricRollup + Send + Sync,
+{
+    fn append_row(&self, name: &str, data: &T) -> Result<()> {
+        self.insert_rows(name, std::slice::from_ref(data))
+    }
+
+    fn append_row_aggregated(&self, name: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<()> {
+        let aggregated = self
+            .aggregate_between(name, start, end)?
+            .ok_or_else(|| anyhow!("no source data found for aggregation"))?;
+        self.append_row(name, &aggregated)
+    }
+
+    fn aggregate_between(&self, name: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Option<T>> {
+        if self.tier == MetricTier::Minute {
+            return Err(anyhow!("minute rows are collected, not aggregated"));
+        }
+        let minute_rows = self.select(
+            MetricTier::Minute,
+            name,
+            (Bound::Included(start), Bound::Included(end)),
+            None,
+            None,
+        )?;
+        if self.tier == MetricTier::Hour {
+            return Ok(T::rollup_minutes(&minute_rows, end));
+        }
+
+        // The hour row at `start` closes the previous day.
+        let hour_rows = self.select(
+            MetricTier::Hour,
+            name,
+            (Bound::Excluded(start), Bound::Included(end)),
+            None,
+            None,
+        )?;
+        Ok(T::rollup_hours(&hour_rows, &minute_rows, end))
+    }
+
+    fn upsert_rows(&self, name: &str, rows: &[T]) -> Result<()> {
+        self.insert_rows(name, rows)
+    }
+
+    fn cleanup_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
+        self.remove_before(name, before)
+    }
+
+    /// Nothing outlives the process, so archiving just drops the rows.
+    fn archive_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
+        self.remove_before(name, before)
+    }
+
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        self.get_row_between(start, end, object_name, limit, offset)?
+            .into_iter()
+            .map(|row| keep_column(&self.columns, row, column_name))
+            .collect()
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        self.select(
+            self.tier,
+            object_name,
+            (Bound::Included(start), Bound::Included(end)),
+            limit,
+            offset,
+        )
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, RwLock};
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::metrics::backend::metric_memory_adapter::{MetricMemoryAdapter, MetricMemoryTables};
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{
+    MetricBackendKind, MetricObjectKind, MetricStorageBackend,
+};
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// In-process backend that keeps every row in memory.
+///
+/// Nothing touches `RUSTCOST_BASE_PATH`, so it is what hermetic API tests run
+/// against; rows are lost on restart.
+#[derive(Default)]
+pub struct MetricMemoryBackend {
+    nodes: Arc<MetricMemoryTables<MetricNodeEntity>>,
+    pods: Arc<MetricMemoryTables<MetricPodEntity>>,
+    containers: Arc<MetricMemoryTables<MetricContainerEntity>>,
+    pvcs: Arc<MetricMemoryTables<MetricPvcEntity>>,
+    node_networks: Arc<MetricMemoryTables<MetricNodeNetworkEntity>>,
+    node_systems: Arc<MetricMemoryTables<MetricNodeSystemEntity>>,
+    watermarks: RwLock<HashMap<(MetricObjectKind, MetricTier, String), DateTime<Utc>>>,
+}
+
+impl MetricMemoryBackend {
+    fn collect_keys<T>(tables: &MetricMemoryTables<T>, keys: &mut BTreeSet<String>) -> Result<()> {
+        for tier in MetricTier::ALL {
+            let table = tables
+                .tier(tier)
+                .read()
+                .map_err(|_| anyhow!("Metric memory table poisoned"))?;
+            keys.extend(table.keys().cloned());
+        }
+        Ok(())
+    }
+
+    fn remove_in<T>(tables: &MetricMemoryTables<T>, key: &str) -> Result<()> {
+        for tier in MetricTier::ALL {
+            tables
+                .tier(tier)
+                .write()
+                .map_err(|_| anyhow!("Metric memory table poisoned"))?
+                .remove(key);
+        }
+        Ok(())
+    }
+
+    fn first_in<T>(tables: &MetricMemoryTables<T>, tier: MetricTier, key: &str) -> Result<Option<DateTime<Utc>>> {
+        let table = tables
+            .tier(tier)
+            .read()
+            .map_err(|_| anyhow!("Metric memory table poisoned"))?;
+        Ok(table.get(key).and_then(|rows| rows.keys().next().copied()))
+    }
+}
+
+impl MetricStorageBackend for MetricMemoryBackend {
+    fn kind(&self) -> MetricBackendKind {
+        MetricBackendKind::Memory
+    }
+
+    fn node(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeEntity>> {
+        Arc::new(MetricMemoryAdapter::new(self.nodes.clone(), tier))
+    }
+
+    fn pod(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricPodEntity>> {
+        Arc::new(MetricMemoryAdapter::new(self.pods.clone(), tier))
+    }
+
+    fn container(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricContainerEntity>> {
+        Arc::new(MetricMemoryAdapter::new(self.containers.clone(), tier))
+    }
+
+    fn pvc(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricPvcEntity>> {
+        Arc::new(MetricMemoryAdapter::new(self.pvcs.clone(), tier))
+    }
+
+    fn node_network(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeNetworkEntity>> {
+        Arc::new(MetricMemoryAdapter::new(self.node_networks.clone(), tier))
+    }
+
+    fn node_system(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeSystemEntity>> {
+        Arc::new(MetricMemoryAdapter::new(self.node_systems.clone(), tier))
+    }
+
+    fn keys(&self, kind: MetricObjectKind) -> Result<Vec<String>> {
+        let mut keys = BTreeSet::new();
+        match kind {
+            MetricObjectKind::Node => Self::collect_keys(&self.nodes, &mut keys)?,
+            MetricObjectKind::Pod => Self::collect_keys(&self.pods, &mut keys)?,
+            MetricObjectKind::Container => Self::collect_keys(&self.containers, &mut keys)?,
+            MetricObjectKind::Pvc => Self::collect_keys(&self.pvcs, &mut keys)?,
+            MetricObjectKind::NodeNetwork => Self::collect_keys(&self.node_networks, &mut keys)?,
+            MetricObjectKind::NodeSystem => Self::collect_keys(&self.node_systems, &mut keys)?,
+        }
+        Ok(keys.into_iter().collect())
+    }
+
+    fn first_time(&self, kind: MetricObjectKind, tier: MetricTier, key: &str) -> Result<Option<DateTime<Utc>>> {
+        match kind {
+            MetricObjectKind::Node => Self::first_in(&self.nodes, tier, key),
+            MetricObjectKind::Pod => Self::first_in(&self.pods, tier, key),
+            MetricObjectKind::Container => Self::first_in(&self.containers, tier, key),
+            MetricObjectKind::Pvc => Self::first_in(&self.pvcs, tier, key),
+            MetricObjectKind::NodeNetwork => Self::first_in(&self.node_networks, tier, key),
+            MetricObjectKind::NodeSystem => Self::first_in(&self.node_systems, tier, key),
+        }
+    }
+
+    fn watermark(&self, kind: MetricObjectKind, tier: MetricTier, key: &str) -> Option<DateTime<Utc>> {
+        self.watermarks.read().ok()?.get(&(kind, tier, key.to_string())).copied()
+    }
+
+    fn set_watermark(&self, kind: MetricObjectKind, tier: MetricTier, key: &str, end: DateTime<Utc>) -> Result<()> {
+        self.watermarks
+            .write()
+            .map_err(|_| anyhow!("Metric memory watermarks poisoned"))?
+            .insert((kind, tier, key.to_string()), end);
+        Ok(())
+    }
+
+    fn remove_object(&self, kind: MetricObjectKind, key: &str) -> Result<()> {
+        if let Ok(mut watermarks) = self.watermarks.write() {
+            watermarks.retain(|(k, _, object), _| *k != kind || object != key);
+        }
+        match kind {
+            MetricObjectKind::Node => Self::remove_in(&self.nodes, key),
+            MetricObjectKind::Pod => Self::remove_in(&self.pods, key),
+            MetricObjectKind::Container => Self::remove_in(&self.containers, key),
+            MetricObjectKind::Pvc => Self::remove_in(&self.pvcs, key),
+            MetricObjectKind::NodeNetwork => Self::remove_in(&self.node_networks, key),
+            MetricObjectKind::NodeSystem => Self::remove_in(&self.node_systems, key),
+        }
+    }
+}
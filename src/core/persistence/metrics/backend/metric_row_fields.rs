@@ -0,0 +1,64 @@
+//! Field-level access to metric rows for backends that do not store them as
+//! `.rcd` lines. Rows are handled through their serde representation, so new
+//! entity fields need no changes here.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Suffixes of the rollup statistics stored next to a gauge column.
+const STAT_SUFFIXES: [&str; 4] = ["_max", "_min", "_p95", "_p99"];
+
+/// Value columns of an entity: every serialized field except `time`.
+///
+/// Derived from the entity itself, so fields added later become columns on the
+/// next start without a hand-written migration.
+pub fn entity_columns<T: Serialize + Default>() -> Vec<String> {
+    match serde_json::to_value(T::default()) {
+        Ok(Value::Object(fields)) => fields
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| name != "time")
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Timestamp of a row.
+pub fn row_time<T: Serialize>(row: &T) -> Result<DateTime<Utc>> {
+    let Value::Object(fields) = serde_json::to_value(row)? else {
+        return Err(anyhow!("metric row did not serialize to an object"));
+    };
+    serde_json::from_value(fields.get("time").cloned().unwrap_or_default())
+        .context("metric row has no valid time")
+}
+
+fn is_stat_column(columns: &[String], column: &str) -> bool {
+    STAT_SUFFIXES
+        .iter()
+        .any(|s| column.strip_suffix(s).is_some_and(|base| columns.iter().any(|c| c == base)))
+}
+
+/// Clears every value but `column_name` (and its rollup statistics),
+/// matching what the fs adapters return for a column query.
+pub fn keep_column<T: Serialize + DeserializeOwned>(columns: &[String], row: T, column_name: &str) -> Result<T> {
+    let field = column_name.to_ascii_lowercase();
+    let known = columns.contains(&field);
+
+    let Value::Object(mut fields) = serde_json::to_value(row)? else {
+        return Err(anyhow!("metric row did not serialize to an object"));
+    };
+    for column in columns {
+        let keep = if known {
+            *column == field || STAT_SUFFIXES.iter().any(|s| *column == format!("{}{}", field, s))
+        } else {
+            !is_stat_column(columns, column)
+        };
+        if !keep {
+            fields.insert(column.clone(), Value::Null);
+        }
+    }
+    Ok(serde_json::from_value(Value::Object(fields))?)
+}
@@ -0,0 +1,272 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::params_from_iter;
+use rusqlite::types::Value as SqlValue;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::core::persistence::metrics::backend::metric_row_fields::{entity_columns, keep_column, row_time};
+use crate::core::persistence::metrics::backend::metric_sqlite_db::{metric_table_name, MetricSqliteDb};
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricObjectKind;
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::quota::storage_quota_manager::record_written;
+
+/// Adapter for one object kind and tier of the `sqlite` backend.
+///
+/// Rows live in `metric_{kind}_{tier}` keyed by `(key, time)`, so appending a
+/// row for an existing timestamp replaces it. Hour and day rows are rolled up
+/// from this backend's own lower tiers.
+pub struct MetricSqliteAdapter<T> {
+    db: Arc<MetricSqliteDb>,
+    kind: MetricObjectKind,
+    tier: MetricTier,
+    columns: Arc<Vec<String>>,
+    _row: PhantomData<fn() -> T>,
+}
+
+impl<T> MetricSqliteAdapter<T>
+where
+    T: Serialize + DeserializeOwned + Default + MetricRollup,
+{
+    pub fn new(db: Arc<MetricSqliteDb>, kind: MetricObjectKind, tier: MetricTier) -> Self {
+        Self {
+            db,
+            kind,
+            tier,
+            columns: Arc::new(entity_columns::<T>()),
+            _row: PhantomData,
+        }
+    }
+
+    fn table(&self) -> String {
+        metric_table_name(self.kind, self.tier)
+    }
+
+    /// Adapter of another tier of the same object kind.
+    fn tier_adapter(&self, tier: MetricTier) -> Self {
+        Self {
+            db: self.db.clone(),
+            kind: self.kind,
+            tier,
+            columns: self.columns.clone(),
+            _row: PhantomData,
+        }
+    }
+
+    fn column_list(&self) -> String {
+        self.columns
+            .iter()
+            .map(|c| format!("\"{}\"", c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Flattens a row into SQL values in `columns` order.
+    /// Unsigned integers are stored as INTEGER, anything else as JSON text.
+    fn to_sql_values(&self, key: &str, row: &T) -> Result<Vec<SqlValue>> {
+        let time = row_time(row)?;
+        let Value::Object(fields) = serde_json::to_value(row)? else {
+            return Err(anyhow!("metric row did not serialize to an object"));
+        };
+
+        let mut values = vec![SqlValue::Text(key.to_string()), SqlValue::Integer(time.timestamp())];
+        for column in self.columns.iter() {
+            values.push(match fields.get(column) {
+                None | Some(Value::Null) => SqlValue::Null,
+                Some(Value::Number(n)) if n.is_u64() => SqlValue::Integer(n.as_u64().unwrap_or_default() as i64),
+                Some(other) => SqlValue::Text(other.to_string()),
+            });
+        }
+        Ok(values)
+    }
+
+    fn read_sql_row(&self, row: &rusqlite::Row<'_>) -> rusqlite::Result<Map<String, Value>> {
+        let mut fields = Map::new();
+        let time: i64 = row.get(0)?;
+        fields.insert(
+            "time".to_string(),
+            serde_json::to_value(DateTime::from_timestamp(time, 0).unwrap_or_default()).unwrap_or_default(),
+        );
+        for (i, column) in self.columns.iter().enumerate() {
+            let value = match row.get::<_, SqlValue>(i + 1)? {
+                SqlValue::Integer(v) => Value::from(v as u64),
+                SqlValue::Real(v) => Value::from(v),
+                SqlValue::Text(v) => serde_json::from_str(&v).unwrap_or(Value::String(v)),
+                SqlValue::Null | SqlValue::Blob(_) => Value::Null,
+            };
+            fields.insert(column.clone(), value);
+        }
+        Ok(fields)
+    }
+
+    fn insert_rows(&self, key: &str, rows: &[T]) -> Result<()> {
+        let values = rows
+            .iter()
+            .map(|row| self.to_sql_values(key, row))
+            .collect::<Result<Vec<_>>>()?;
+        let sql = format!(
+            "INSERT OR REPLACE INTO \"{}\" (key, time, {}) VALUES ({})",
+            self.table(),
+            self.column_list(),
+            vec!["?"; self.columns.len() + 2].join(", ")
+        );
+
+        self.db.with_conn(|conn| {
+            let tx = conn.transaction()?;
+            {
+                let mut stmt = tx.prepare_cached(&sql)?;
+                for row in &values {
+                    stmt.execute(params_from_iter(row.iter()))?;
+                }
+            }
+            tx.commit()
+        })?;
+        record_written(values.iter().flatten().map(sql_value_len).sum());
+        Ok(())
+    }
+
+    fn select_between(
+        &self,
+        key: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        let sql = format!(
+            "SELECT time, {} FROM \"{}\" WHERE key = ?1 AND time >= ?2 AND time <= ?3 ORDER BY time LIMIT ?4 OFFSET ?5",
+            self.column_list(),
+            self.table()
+        );
+        let limit = limit.map(|l| l as i64).unwrap_or(-1);
+        let offset = offset.unwrap_or(0) as i64;
+
+        let rows = self.db.with_conn(|conn| {
+            let mut stmt = conn.prepare_cached(&sql)?;
+            let rows = stmt
+                .query_map(
+                    rusqlite::params![key, start.timestamp(), end.timestamp(), limit, offset],
+                    |row| self.read_sql_row(row),
+                )?
+                .collect::<rusqlite::Result<Vec<_>>>();
+            rows
+        })?;
+
+        rows.into_iter()
+            .map(|fields| serde_json::from_value(Value::Object(fields)).map_err(Into::into))
+            .collect()
+    }
+}
+
+impl<T> MetricFsAdapterBase<T> for MetricSqliteAdapter<T>
+where
+    T: Serialize + DeserializeOwned + Default + MetricRollup,
+{
+    fn append_row(&self, name: &str, data: &T) -> Result<()> {
+        self.insert_rows(name, std::slice::from_ref(data))
+    }
+
+    fn append_row_aggregated(&self, name: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<()> {
+        let aggregated = self
+            .aggregate_between(name, start, end)?
+            .ok_or_else(|| anyhow!("no source data found for aggregation"))?;
+        self.append_row(name, &aggregated)
+    }
+
+    fn aggregate_between(&self, name: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Option<T>> {
+        if self.tier == MetricTier::Minute {
+            return Err(anyhow!("minute rows are collected, not aggregated"));
+        }
+        let minute_rows = self
+            .tier_adapter(MetricTier::Minute)
+            .select_between(name, start, end, None, None)?;
+        if self.tier == MetricTier::Hour {
+            return Ok(T::rollup_minutes(&minute_rows, end));
+        }
+
+        // Hour rows are stamped with the end of their hour; the one at `start`
+        // closes the previous day and must not be counted twice.
+        // Rows are stored with whole-second timestamps.
+        let hour_rows = self.tier_adapter(MetricTier::Hour).select_between(
+            name,
+            start + chrono::Duration::seconds(1),
+            end,
+            None,
+            None,
+        )?;
+        Ok(T::rollup_hours(&hour_rows, &minute_rows, end))
+    }
+
+    fn upsert_rows(&self, name: &str, rows: &[T]) -> Result<()> {
+        self.insert_rows(name, rows)
+    }
+
+    fn cleanup_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
+        let sql = format!("DELETE FROM \"{}\" WHERE key = ?1 AND time < ?2", self.table());
+        self.db
+            .with_conn(|conn| conn.execute(&sql, rusqlite::params![name, before.timestamp()]))
+            .with_context(|| format!("Failed to delete old rows of '{}' from {}", name, self.table()))?;
+        Ok(())
+    }
+
+    fn archive_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
+        let table = self.table();
+        let columns = self.column_list();
+        let copy = format!(
+            "INSERT OR REPLACE INTO \"{table}_archive\" (key, time, {columns})
+             SELECT key, time, {columns} FROM \"{table}\" WHERE key = ?1 AND time < ?2"
+        );
+        let delete = format!("DELETE FROM \"{}\" WHERE key = ?1 AND time < ?2", table);
+
+        self.db
+            .with_conn(|conn| {
+                let tx = conn.transaction()?;
+                tx.execute(&copy, rusqlite::params![name, before.timestamp()])?;
+                tx.execute(&delete, rusqlite::params![name, before.timestamp()])?;
+                tx.commit()
+            })
+            .with_context(|| format!("Failed to archive old rows of '{}' from {}", name, table))
+    }
+
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        self.select_between(object_name, start, end, limit, offset)?
+            .into_iter()
+            .map(|row| keep_column(&self.columns, row, column_name))
+            .collect()
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<T>> {
+        self.select_between(object_name, start, end, limit, offset)
+    }
+}
+
+/// Approximate bytes a value takes in a row, for the quota's write estimate.
+fn sql_value_len(value: &SqlValue) -> u64 {
+    match value {
+        SqlValue::Null => 1,
+        SqlValue::Integer(_) | SqlValue::Real(_) => 8,
+        SqlValue::Text(v) => v.len() as u64,
+        SqlValue::Blob(v) => v.len() as u64,
+    }
+}
@@ -0,0 +1,254 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use rusqlite::{params, OptionalExtension};
+use tracing::warn;
+
+use crate::core::persistence::metrics::backend::metric_sqlite_adapter::MetricSqliteAdapter;
+use crate::core::persistence::metrics::backend::metric_row_fields::entity_columns;
+use crate::core::persistence::metrics::backend::metric_sqlite_db::{metric_table_name, MetricSqliteDb, WATERMARK_TABLE};
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{
+    MetricBackendKind, MetricObjectKind, MetricStorageBackend,
+};
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Embedded SQLite backend: all kinds and tiers in one database file.
+pub struct MetricSqliteBackend {
+    db: Arc<MetricSqliteDb>,
+}
+
+impl MetricSqliteBackend {
+    /// Opens (or creates) the database and brings its tables up to date.
+    pub fn open(path: &Path) -> Result<Self> {
+        let backend = Self { db: Arc::new(MetricSqliteDb::open(path)?) };
+        backend.ensure_tables()?;
+        Ok(backend)
+    }
+
+    fn ensure_tables(&self) -> Result<()> {
+        self.db.ensure_watermark_table()?;
+        for tier in MetricTier::ALL {
+            self.db.ensure_table(&metric_table_name(MetricObjectKind::Node, tier), &entity_columns::<MetricNodeEntity>())?;
+            self.db.ensure_table(&metric_table_name(MetricObjectKind::Pod, tier), &entity_columns::<MetricPodEntity>())?;
+            self.db.ensure_table(
+                &metric_table_name(MetricObjectKind::Container, tier),
+                &entity_columns::<MetricContainerEntity>(),
+            )?;
+            self.db.ensure_table(&metric_table_name(MetricObjectKind::Pvc, tier), &entity_columns::<MetricPvcEntity>())?;
+            self.db.ensure_table(
+                &metric_table_name(MetricObjectKind::NodeNetwork, tier),
+                &entity_columns::<MetricNodeNetworkEntity>(),
+            )?;
+            self.db.ensure_table(
+                &metric_table_name(MetricObjectKind::NodeSystem, tier),
+                &entity_columns::<MetricNodeSystemEntity>(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl MetricStorageBackend for MetricSqliteBackend {
+    fn kind(&self) -> MetricBackendKind {
+        MetricBackendKind::Sqlite
+    }
+
+    fn checkpoint(&self) -> Result<()> {
+        self.db.checkpoint()
+    }
+
+    fn close(&self) -> Result<()> {
+        self.db.close()
+    }
+
+    /// A restored data directory may hold an older database, or none at all.
+    fn reopen(&self) -> Result<()> {
+        self.db.reopen()?;
+        self.ensure_tables()
+    }
+
+    fn node(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeEntity>> {
+        Arc::new(MetricSqliteAdapter::new(self.db.clone(), MetricObjectKind::Node, tier))
+    }
+
+    fn pod(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricPodEntity>> {
+        Arc::new(MetricSqliteAdapter::new(self.db.clone(), MetricObjectKind::Pod, tier))
+    }
+
+    fn container(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricContainerEntity>> {
+        Arc::new(MetricSqliteAdapter::new(self.db.clone(), MetricObjectKind::Container, tier))
+    }
+
+    fn pvc(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricPvcEntity>> {
+        Arc::new(MetricSqliteAdapter::new(self.db.clone(), MetricObjectKind::Pvc, tier))
+    }
+
+    fn node_network(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeNetworkEntity>> {
+        Arc::new(MetricSqliteAdapter::new(self.db.clone(), MetricObjectKind::NodeNetwork, tier))
+    }
+
+    fn node_system(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeSystemEntity>> {
+        Arc::new(MetricSqliteAdapter::new(self.db.clone(), MetricObjectKind::NodeSystem, tier))
+    }
+
+    fn keys(&self, kind: MetricObjectKind) -> Result<Vec<String>> {
+        let sql = MetricTier::ALL
+            .iter()
+            .map(|tier| format!("SELECT key FROM \"{}\"", metric_table_name(kind, *tier)))
+            .collect::<Vec<_>>()
+            .join(" UNION ")
+            + " ORDER BY key";
+
+        self.db.with_conn(|conn| {
+            let mut stmt = conn.prepare_cached(&sql)?;
+            let keys = stmt
+                .query_map([], |row| row.get::<_, String>(0))?
+                .collect::<rusqlite::Result<Vec<_>>>();
+            keys
+        })
+    }
+
+    fn first_time(&self, kind: MetricObjectKind, tier: MetricTier, key: &str) -> Result<Option<DateTime<Utc>>> {
+        let sql = format!("SELECT MIN(time) FROM \"{}\" WHERE key = ?1", metric_table_name(kind, tier));
+        let first: Option<i64> = self
+            .db
+            .with_conn(|conn| conn.query_row(&sql, [key], |row| row.get(0)))?;
+        Ok(first.and_then(|t| DateTime::from_timestamp(t, 0)))
+    }
+
+    fn remove_object(&self, kind: MetricObjectKind, key: &str) -> Result<()> {
+        self.db.with_conn(|conn| {
+            let tx = conn.transaction()?;
+            for tier in MetricTier::ALL {
+                let table = metric_table_name(kind, tier);
+                tx.execute(&format!("DELETE FROM \"{}\" WHERE key = ?1", table), [key])?;
+                tx.execute(
+                    &format!("DELETE FROM \"{}\" WHERE tbl = ?1 AND key = ?2", WATERMARK_TABLE),
+                    [table.as_str(), key],
+                )?;
+            }
+            tx.commit()
+        })
+    }
+
+    fn watermark(&self, kind: MetricObjectKind, tier: MetricTier, key: &str) -> Option<DateTime<Utc>> {
+        let sql = format!("SELECT time FROM \"{}\" WHERE tbl = ?1 AND key = ?2", WATERMARK_TABLE);
+        let table = metric_table_name(kind, tier);
+        let time = self.db.with_conn(|conn| {
+            conn.query_row(&sql, [table.as_str(), key], |row| row.get::<_, i64>(0))
+                .optional()
+        });
+        match time {
+            Ok(time) => time.and_then(|t| DateTime::from_timestamp(t, 0)),
+            Err(e) => {
+                warn!("⚠️ Failed to read watermark of {} '{}': {}", table, key, e);
+                None
+            }
+        }
+    }
+
+    fn set_watermark(&self, kind: MetricObjectKind, tier: MetricTier, key: &str, end: DateTime<Utc>) -> Result<()> {
+        let sql = format!(
+            "INSERT INTO \"{}\" (tbl, key, time) VALUES (?1, ?2, ?3)
+             ON CONFLICT (tbl, key) DO UPDATE SET time = excluded.time",
+            WATERMARK_TABLE
+        );
+        let table = metric_table_name(kind, tier);
+        self.db
+            .with_conn(|conn| conn.execute(&sql, params![table, key, end.timestamp()]))
+            .map(|_| ())
+    }
+
+    fn evict_oldest_period(&self, tier: MetricTier, before: NaiveDate) -> Result<Option<NaiveDate>> {
+        let tables: Vec<String> = MetricObjectKind::ALL
+            .iter()
+            .map(|kind| metric_table_name(*kind, tier))
+            .collect();
+        let oldest_sql = format!(
+            "SELECT MIN(t) FROM ({})",
+            tables
+                .iter()
+                .map(|t| format!("SELECT MIN(time) AS t FROM \"{}\"", t))
+                .collect::<Vec<_>>()
+                .join(" UNION ALL ")
+        );
+        let oldest: Option<i64> = self.db.with_conn(|conn| conn.query_row(&oldest_sql, [], |row| row.get(0)))?;
+        let Some(period) = oldest
+            .and_then(|t| DateTime::from_timestamp(t, 0))
+            .map(|t| tier.period_start(t.date_naive()))
+            .filter(|period| *period < before)
+        else {
+            return Ok(None);
+        };
+
+        // Every row before the end of the oldest period belongs to it.
+        let end = tier.next_period_start(period).min(before);
+        let end = end.and_hms_opt(0, 0, 0).map_or(i64::MAX, |t| t.and_utc().timestamp());
+        self.db.with_conn(|conn| {
+            let tx = conn.transaction()?;
+            for table in &tables {
+                tx.execute(&format!("DELETE FROM \"{}\" WHERE time < ?1", table), [end])?;
+            }
+            tx.commit()
+        })?;
+        self.db.vacuum()?;
+        Ok(Some(period))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::segment::test_support::scratch_dir;
+
+    #[test]
+    fn watermarks_survive_close_and_reopen() {
+        let dir = scratch_dir();
+        let backend = MetricSqliteBackend::open(&dir.join("metrics.db")).unwrap();
+        let end = DateTime::from_timestamp(1_735_693_200, 0).unwrap();
+        backend.set_watermark(MetricObjectKind::Pod, MetricTier::Hour, "uid-1", end).unwrap();
+
+        backend.checkpoint().unwrap();
+        backend.close().unwrap();
+        assert!(backend.keys(MetricObjectKind::Pod).is_err());
+        backend.reopen().unwrap();
+
+        assert_eq!(backend.watermark(MetricObjectKind::Pod, MetricTier::Hour, "uid-1"), Some(end));
+        assert_eq!(backend.watermark(MetricObjectKind::Pod, MetricTier::Day, "uid-1"), None);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn evicts_the_oldest_closed_period_only() {
+        let dir = scratch_dir();
+        let backend = MetricSqliteBackend::open(&dir.join("metrics.db")).unwrap();
+        let minute = backend.pod(MetricTier::Minute);
+        for day in [1, 2, 3] {
+            let row = MetricPodEntity {
+                time: NaiveDate::from_ymd_opt(2025, 1, day).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc(),
+                ..Default::default()
+            };
+            minute.append_row("uid-1", &row).unwrap();
+        }
+        let today = NaiveDate::from_ymd_opt(2025, 1, 3).unwrap();
+
+        let evict = |b: &MetricSqliteBackend| b.evict_oldest_period(MetricTier::Minute, today).unwrap();
+        assert_eq!(evict(&backend), NaiveDate::from_ymd_opt(2025, 1, 1));
+        assert_eq!(evict(&backend), NaiveDate::from_ymd_opt(2025, 1, 2));
+        assert_eq!(evict(&backend), None);
+        assert_eq!(
+            backend.first_time(MetricObjectKind::Pod, MetricTier::Minute, "uid-1").unwrap(),
+            today.and_hms_opt(12, 0, 0).map(|t| t.and_utc())
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
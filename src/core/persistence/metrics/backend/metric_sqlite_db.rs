@@ -0,0 +1,157 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use rusqlite::Connection;
+
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricObjectKind;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Table holding the rows of one object kind and tier, e.g. `metric_pod_hour`.
+/// Archived rows go to the same name with an `_archive` suffix.
+pub fn metric_table_name(kind: MetricObjectKind, tier: MetricTier) -> String {
+    let tier = match tier {
+        MetricTier::Minute => "minute",
+        MetricTier::Hour => "hour",
+        MetricTier::Day => "day",
+    };
+    format!("metric_{}_{}", kind.as_str(), tier)
+}
+
+/// Table holding the aggregation watermark of every table and key.
+pub const WATERMARK_TABLE: &str = "metric_watermark";
+
+/// Single SQLite connection shared by all adapters of the `sqlite` backend.
+pub struct MetricSqliteDb {
+    path: PathBuf,
+    /// `None` while the database file is closed for a restore.
+    conn: Mutex<Option<Connection>>,
+}
+
+impl MetricSqliteDb {
+    pub fn open(path: &Path) -> Result<Self> {
+        Ok(Self {
+            path: path.to_path_buf(),
+            conn: Mutex::new(Some(Self::connect(path)?)),
+        })
+    }
+
+    fn connect(path: &Path) -> Result<Connection> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(path)
+            .with_context(|| format!("Failed to open metric database {:?}", path))?;
+
+        // WAL keeps API reads from blocking the collectors' writes.
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+        conn.busy_timeout(Duration::from_secs(5))?;
+        Ok(conn)
+    }
+
+    /// Runs `f` with exclusive access to the connection.
+    pub fn with_conn<R>(&self, f: impl FnOnce(&mut Connection) -> rusqlite::Result<R>) -> Result<R> {
+        let mut conn = self
+            .conn
+            .lock()
+            .map_err(|_| anyhow!("Metric database connection poisoned"))?;
+        let conn = conn
+            .as_mut()
+            .ok_or_else(|| anyhow!("Metric database {:?} is closed", self.path))?;
+        Ok(f(conn)?)
+    }
+
+    /// Moves every committed row from the WAL into the database file and
+    /// empties the WAL, so copying the database file alone captures all rows.
+    pub fn checkpoint(&self) -> Result<()> {
+        self.with_conn(|conn| conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(())))
+            .with_context(|| format!("Failed to checkpoint metric database {:?}", self.path))
+    }
+
+    /// Rebuilds the database file so rows deleted since the last vacuum give
+    /// their space back to the filesystem.
+    pub fn vacuum(&self) -> Result<()> {
+        self.with_conn(|conn| {
+            conn.execute_batch("VACUUM")?;
+            conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+        })
+        .with_context(|| format!("Failed to vacuum metric database {:?}", self.path))
+    }
+
+    /// Checkpoints and closes the database file, so it can be replaced on disk.
+    /// Calls fail until [`MetricSqliteDb::reopen`].
+    pub fn close(&self) -> Result<()> {
+        let mut conn = self
+            .conn
+            .lock()
+            .map_err(|_| anyhow!("Metric database connection poisoned"))?;
+        if let Some(open) = conn.take() {
+            open.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+            open.close().map_err(|(_, e)| e)?;
+        }
+        Ok(())
+    }
+
+    /// Opens the database file again after [`MetricSqliteDb::close`].
+    pub fn reopen(&self) -> Result<()> {
+        let mut conn = self
+            .conn
+            .lock()
+            .map_err(|_| anyhow!("Metric database connection poisoned"))?;
+        if conn.is_none() {
+            *conn = Some(Self::connect(&self.path)?);
+        }
+        Ok(())
+    }
+
+    /// Creates the watermark table.
+    pub fn ensure_watermark_table(&self) -> Result<()> {
+        self.with_conn(|conn| {
+            conn.execute_batch(&format!(
+                "CREATE TABLE IF NOT EXISTS \"{}\" (
+                    tbl TEXT NOT NULL,
+                    key TEXT NOT NULL,
+                    time INTEGER NOT NULL,
+                    PRIMARY KEY (tbl, key)
+                ) WITHOUT ROWID",
+                WATERMARK_TABLE
+            ))
+        })
+        .context("Failed to prepare metric watermark table")
+    }
+
+    /// Creates the live and archive tables of one kind and tier, and adds
+    /// columns for entity fields the tables do not have yet.
+    pub fn ensure_table(&self, table: &str, columns: &[String]) -> Result<()> {
+        for name in [table.to_string(), format!("{}_archive", table)] {
+            self.with_conn(|conn| {
+                conn.execute_batch(&format!(
+                    "CREATE TABLE IF NOT EXISTS \"{}\" (
+                        key TEXT NOT NULL,
+                        time INTEGER NOT NULL,
+                        PRIMARY KEY (key, time)
+                    ) WITHOUT ROWID",
+                    name
+                ))?;
+
+                let existing: Vec<String> = conn
+                    .prepare(&format!("PRAGMA table_info(\"{}\")", name))?
+                    .query_map([], |row| row.get::<_, String>(1))?
+                    .collect::<rusqlite::Result<_>>()?;
+
+                for column in columns.iter().filter(|c| !existing.contains(c)) {
+                    conn.execute_batch(&format!(
+                        "ALTER TABLE \"{}\" ADD COLUMN \"{}\" INTEGER",
+                        name, column
+                    ))?;
+                }
+                Ok(())
+            })
+            .with_context(|| format!("Failed to prepare metric table {}", name))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Available metric storage backends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricBackendKind {
+    /// `.rcd` segments under `metric/k8s/` (default)
+    Fs,
+    /// One embedded SQLite database file
+    Sqlite,
+    /// Process memory only; for tests and throwaway instances
+    Memory,
+}
+
+impl MetricBackendKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetricBackendKind::Fs => "fs",
+            MetricBackendKind::Sqlite => "sqlite",
+            MetricBackendKind::Memory => "memory",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "fs" | "file" | "files" => Some(MetricBackendKind::Fs),
+            "sqlite" => Some(MetricBackendKind::Sqlite),
+            "memory" => Some(MetricBackendKind::Memory),
+            _ => None,
+        }
+    }
+}
+
+/// Kind of object metric rows belong to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricObjectKind {
+    Node,
+    Pod,
+    Container,
+    Pvc,
+    #[serde(rename = "node_network")]
+    NodeNetwork,
+    #[serde(rename = "node_system")]
+    NodeSystem,
+}
+
+impl MetricObjectKind {
+    pub const ALL: [MetricObjectKind; 6] = [
+        MetricObjectKind::Node,
+        MetricObjectKind::Pod,
+        MetricObjectKind::Container,
+        MetricObjectKind::Pvc,
+        MetricObjectKind::NodeNetwork,
+        MetricObjectKind::NodeSystem,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetricObjectKind::Node => "node",
+            MetricObjectKind::Pod => "pod",
+            MetricObjectKind::Container => "container",
+            MetricObjectKind::Pvc => "pvc",
+            MetricObjectKind::NodeNetwork => "node_network",
+            MetricObjectKind::NodeSystem => "node_system",
+        }
+    }
+}
+
+/// Storage backend for node/pod/container/PVC/node interface/node system container metric rows.
+///
+/// Hands out one adapter per object kind and tier; repositories, collectors,
+/// processors and retention only talk to those adapters.
+pub trait MetricStorageBackend: Send + Sync {
+    fn kind(&self) -> MetricBackendKind;
+
+    fn node(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeEntity>>;
+
+    fn pod(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricPodEntity>>;
+
+    fn container(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricContainerEntity>>;
+
+    fn pvc(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricPvcEntity>>;
+
+    fn node_network(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeNetworkEntity>>;
+
+    fn node_system(&self, tier: MetricTier) -> Arc<dyn MetricFsAdapterBase<MetricNodeSystemEntity>>;
+
+    /// Keys (node names, pod UIDs, container keys, PVC keys, node interface and system container keys) with stored metrics, sorted.
+    fn keys(&self, kind: MetricObjectKind) -> Result<Vec<String>>;
+
+    /// Time of the oldest stored row of one object and tier, if any.
+    /// Bounds the range a migration has to read.
+    fn first_time(&self, kind: MetricObjectKind, tier: MetricTier, key: &str) -> Result<Option<DateTime<Utc>>>;
+
+    /// Drops everything stored for one object, all tiers included.
+    /// Used to purge objects deleted from the cluster; archives are kept.
+    fn remove_object(&self, kind: MetricObjectKind, key: &str) -> Result<()>;
+
+    /// End of the last window aggregated into `tier` for one object, if one
+    /// was recorded. Processors resume from there.
+    fn watermark(&self, kind: MetricObjectKind, tier: MetricTier, key: &str) -> Option<DateTime<Utc>>;
+
+    /// Records `end` as the last window aggregated into `tier` for one object.
+    fn set_watermark(&self, kind: MetricObjectKind, tier: MetricTier, key: &str, end: DateTime<Utc>) -> Result<()>;
+
+    /// Deletes the rows of the oldest period of `tier` starting before
+    /// `before`, for every object, to bring the data directory under the
+    /// storage quota. Returns the start of the evicted period, or `None` when
+    /// nothing is left to evict.
+    ///
+    /// Backends whose rows do not count toward the data directory evict nothing.
+    #[allow(unused_variables)]
+    fn evict_oldest_period(&self, tier: MetricTier, before: NaiveDate) -> Result<Option<NaiveDate>> {
+        Ok(None)
+    }
+
+    /// Makes the backend's files under the data directory complete on their
+    /// own, so a copy of them holds every row. Called under the exclusive
+    /// storage lock before a backup.
+    fn checkpoint(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Releases the backend's files before a restore replaces them.
+    fn close(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Picks the files up again after [`MetricStorageBackend::close`].
+    fn reopen(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+//! Pluggable storage for node/pod/container metric rows.
+//!
+//! The backend is picked once at startup from `RUSTCOST_METRIC_BACKEND`:
+//! `fs` (default) keeps `.rcd` segments under `metric/k8s/`, `sqlite` keeps
+//! every row in one embedded database at `RUSTCOST_METRIC_DB_PATH`, and
+//! `memory` keeps rows in the process only.
+
+pub mod metric_fs_backend;
+pub mod metric_memory_adapter;
+pub mod metric_memory_backend;
+pub mod metric_row_fields;
+pub mod metric_sqlite_adapter;
+pub mod metric_sqlite_backend;
+pub mod metric_sqlite_db;
+pub mod metric_storage_backend_trait;
+
+use std::env;
+use std::sync::{Arc, OnceLock};
+
+use anyhow::{anyhow, Result};
+
+use crate::core::persistence::storage_path::get_rustcost_metric_db_path;
+use metric_fs_backend::MetricFsBackend;
+use metric_memory_backend::MetricMemoryBackend;
+use metric_sqlite_backend::MetricSqliteBackend;
+use metric_storage_backend_trait::{MetricBackendKind, MetricStorageBackend};
+
+static METRIC_BACKEND: OnceLock<Arc<dyn MetricStorageBackend>> = OnceLock::new();
+
+/// Backend selected by `RUSTCOST_METRIC_BACKEND`, `fs` when unset.
+pub fn configured_backend_kind() -> Result<MetricBackendKind> {
+    match env::var("RUSTCOST_METRIC_BACKEND") {
+        Ok(value) => MetricBackendKind::parse(&value)
+            .ok_or_else(|| anyhow!("Unknown RUSTCOST_METRIC_BACKEND '{}' (expected fs, sqlite or memory)", value)),
+        Err(_) => Ok(MetricBackendKind::Fs),
+    }
+}
+
+/// Opens a backend; used for the active one and for migrations between two.
+/// Every call for `memory` opens a new, empty store.
+pub fn open_metric_backend(kind: MetricBackendKind) -> Result<Arc<dyn MetricStorageBackend>> {
+    Ok(match kind {
+        MetricBackendKind::Fs => Arc::new(MetricFsBackend),
+        MetricBackendKind::Sqlite => Arc::new(MetricSqliteBackend::open(&get_rustcost_metric_db_path())?),
+        MetricBackendKind::Memory => Arc::new(MetricMemoryBackend::default()),
+    })
+}
+
+/// Opens the configured backend. Called once at startup so a bad setting or
+/// an unreadable database fails before the scheduler runs.
+pub fn init_metric_backend() -> Result<MetricBackendKind> {
+    let kind = configured_backend_kind()?;
+    if let Some(active) = METRIC_BACKEND.get() {
+        return Ok(active.kind());
+    }
+    let backend = open_metric_backend(kind)?;
+    Ok(METRIC_BACKEND.get_or_init(|| backend).kind())
+}
+
+/// The active metric backend, or an error until [`init_metric_backend`] has
+/// opened it.
+pub fn metric_backend() -> Result<Arc<dyn MetricStorageBackend>> {
+    METRIC_BACKEND
+        .get()
+        .cloned()
+        .ok_or_else(|| anyhow!("Metric storage backend is not initialised"))
+}
@@ -0,0 +1,65 @@
+//! Rollup helpers for cumulative counters (CPU time, network bytes, page faults).
+
+/// Total increase of a cumulative counter over ordered samples.
+///
+/// Works like Prometheus `increase()`: whenever a sample is lower than the one
+/// before, the counter was reset (container restart, pod re-creation) and the
+/// new value is counted as the increase since the reset. Monotonic segments on
+/// either side of a reset are summed, so restarts never zero out the window.
+///
+/// Returns `None` when there are no samples.
+pub fn increase(samples: impl IntoIterator<Item = u64>) -> Option<u64> {
+    let mut samples = samples.into_iter();
+    let mut prev = samples.next()?;
+    let mut total: u64 = 0;
+
+    for value in samples {
+        total = total.saturating_add(if value >= prev { value - prev } else { value });
+        prev = value;
+    }
+    Some(total)
+}
+
+/// Sum of per-window increases, e.g. the hour rows that make up a day.
+///
+/// Returns `None` when none of the windows has a value.
+pub fn sum_increases(increases: impl IntoIterator<Item = u64>) -> Option<u64> {
+    increases
+        .into_iter()
+        .fold(None, |acc: Option<u64>, v| Some(acc.unwrap_or(0).saturating_add(v)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn increase_of_a_monotonic_counter_is_last_minus_first() {
+        assert_eq!(increase([100, 150, 150, 400]), Some(300));
+    }
+
+    #[test]
+    fn reset_mid_window_counts_the_new_value_as_increase() {
+        // 100 → 300 (+200), reset to 50 (+50), → 80 (+30)
+        assert_eq!(increase([100, 300, 50, 80]), Some(280));
+    }
+
+    #[test]
+    fn multiple_resets_sum_every_segment() {
+        // +50, reset to 10 (+10), +20, reset to 0 (+0), +5
+        assert_eq!(increase([50, 100, 10, 30, 0, 5]), Some(85));
+    }
+
+    #[test]
+    fn single_sample_has_no_increase_and_none_has_no_value() {
+        assert_eq!(increase([42]), Some(0));
+        assert_eq!(increase([]), None);
+    }
+
+    #[test]
+    fn sum_increases_adds_windows_and_saturates() {
+        assert_eq!(sum_increases([10, 0, 5]), Some(15));
+        assert_eq!(sum_increases([u64::MAX, 1]), Some(u64::MAX));
+        assert_eq!(sum_increases([]), None);
+    }
+}
@@ -1,18 +1,27 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, NaiveDate, Utc, Datelike};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
+    fs,
     io::Write,
-    io::{BufRead, BufReader},
     path::Path,
 };
 use std::path::PathBuf;
 use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_fs_adapter::MetricContainerHourFsAdapter;
 use crate::core::persistence::metrics::k8s::path::{metric_k8s_container_key_day_dir_path, metric_k8s_container_key_day_file_path};
+use crate::core::persistence::archive::metric_archive_fs_adapter::MetricArchiveFsAdapter;
+use crate::core::persistence::metrics::segment::compression::{segment_exists, segment_period};
+use crate::core::persistence::metrics::segment::format::{
+    current_layout, encode_row, open_segment_for_append, LegacyHeader, SegmentLines,
+};
+use crate::core::persistence::metrics::segment::index::{open_segment_from, record_append, remove_segment};
+use crate::core::persistence::metrics::segment::rewrite::upsert_segment_rows;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
+use std::collections::BTreeMap;
+use crate::core::persistence::archive::path::archive_k8s_container_key_day_dir_path;
 
 /// Adapter for container hour-level metrics.
 /// Responsible for appending hour samples to the filesystem and cleaning up old data.
@@ -20,16 +29,35 @@ use crate::core::persistence::metrics::k8s::path::{metric_k8s_container_key_day_
 pub struct MetricContainerDayFsAdapter;
 
 impl MetricContainerDayFsAdapter {
+    /// Columns of a row written before rollup statistics were added.
+    const BASE_COLUMNS: usize = 11;
+
+    /// Column names in row order, to read segments that start with a legacy
+    /// named header.
+    const COLUMNS: &'static [&'static str] = &[
+        "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS", "MEMORY_USAGE_BYTES",
+        "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES", "MEMORY_PAGE_FAULTS", "FS_USED_BYTES",
+        "FS_CAPACITY_BYTES", "FS_INODES_USED", "FS_INODES", "CPU_USAGE_NANO_CORES_MAX",
+        "CPU_USAGE_NANO_CORES_MIN", "CPU_USAGE_NANO_CORES_P95", "CPU_USAGE_NANO_CORES_P99",
+        "MEMORY_WORKING_SET_BYTES_MAX", "MEMORY_WORKING_SET_BYTES_MIN",
+        "MEMORY_WORKING_SET_BYTES_P95", "MEMORY_WORKING_SET_BYTES_P99", "MEMORY_AVAILABLE_BYTES",
+        "MEMORY_MAJOR_PAGE_FAULTS", "SWAP_USAGE_BYTES", "SWAP_AVAILABLE_BYTES", "CPU_CFS_PERIODS",
+        "CPU_CFS_THROTTLED_PERIODS", "CPU_CFS_THROTTLED_NANO_SECONDS",
+    ];
+
     fn build_path_for(&self, node_key: &str, date: NaiveDate) -> PathBuf {
         let year_str = date.format("%Y").to_string();
         metric_k8s_container_key_day_file_path(node_key, &year_str)
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricContainerEntity> {
+    fn parse_line(line: &str) -> Option<MetricContainerEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Columns are only ever appended, so older rows stop early and read the
+        // missing ones as `None`; trailing columns of newer rows are ignored.
+        if parts.len() < Self::BASE_COLUMNS {
             return None;
         }
+        let col = |i: usize| parts.get(i).and_then(|v| v.parse().ok());
 
         // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
         let time = parts[0].parse::<DateTime<Utc>>().ok()?;
@@ -45,6 +73,21 @@ impl MetricContainerDayFsAdapter {
             fs_capacity_bytes: parts[8].parse().ok(),
             fs_inodes_used: parts[9].parse().ok(),
             fs_inodes: parts[10].parse().ok(),
+            cpu_usage_nano_cores_max: col(11),
+            cpu_usage_nano_cores_min: col(12),
+            cpu_usage_nano_cores_p95: col(13),
+            cpu_usage_nano_cores_p99: col(14),
+            memory_working_set_bytes_max: col(15),
+            memory_working_set_bytes_min: col(16),
+            memory_working_set_bytes_p95: col(17),
+            memory_working_set_bytes_p99: col(18),
+            memory_available_bytes: col(19),
+            memory_major_page_faults: col(20),
+            swap_usage_bytes: col(21),
+            swap_available_bytes: col(22),
+            cpu_cfs_periods: col(23),
+            cpu_cfs_throttled_periods: col(24),
+            cpu_cfs_throttled_nano_seconds: col(25),
         })
     }
 
@@ -60,6 +103,65 @@ impl MetricContainerDayFsAdapter {
     fn opt(v: Option<u64>) -> String {
         v.map(|x| x.to_string()).unwrap_or_default()
     }
+
+    fn format_row(dto: &MetricContainerEntity) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            Self::opt(dto.cpu_usage_nano_cores),
+            Self::opt(dto.cpu_usage_core_nano_seconds),
+            Self::opt(dto.memory_usage_bytes),
+            Self::opt(dto.memory_working_set_bytes),
+            Self::opt(dto.memory_rss_bytes),
+            Self::opt(dto.memory_page_faults),
+            // --- FS fields (rootfs + logs) ---
+            Self::opt(dto.fs_used_bytes),
+            Self::opt(dto.fs_capacity_bytes),
+            Self::opt(dto.fs_inodes_used),
+            Self::opt(dto.fs_inodes),
+            Self::opt(dto.cpu_usage_nano_cores_max),
+            Self::opt(dto.cpu_usage_nano_cores_min),
+            Self::opt(dto.cpu_usage_nano_cores_p95),
+            Self::opt(dto.cpu_usage_nano_cores_p99),
+            Self::opt(dto.memory_working_set_bytes_max),
+            Self::opt(dto.memory_working_set_bytes_min),
+            Self::opt(dto.memory_working_set_bytes_p95),
+            Self::opt(dto.memory_working_set_bytes_p99),
+            Self::opt(dto.memory_available_bytes),
+            Self::opt(dto.memory_major_page_faults),
+            Self::opt(dto.swap_usage_bytes),
+            Self::opt(dto.swap_available_bytes),
+            Self::opt(dto.cpu_cfs_periods),
+            Self::opt(dto.cpu_cfs_throttled_periods),
+            Self::opt(dto.cpu_cfs_throttled_nano_seconds),
+        )
+    }
+
+    /// Day files (`YYYY.rcd`) for years strictly before the cutoff year.
+    fn expired_files(&self, container_key: &str, before: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let mut expired = Vec::new();
+        let cutoff_year: i32 = before.format("%Y").to_string().parse().unwrap_or(0);
+        let dir = metric_k8s_container_key_day_dir_path(container_key);
+
+        if !dir.exists() {
+            return Ok(expired);
+        }
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if let Some(filename) = segment_period(&path) {
+                if let Ok(year) = filename.parse::<i32>() {
+                    if year < cutoff_year {
+                        expired.push(path);
+                    }
+                }
+            }
+        }
+
+        Ok(expired)
+    }
 }
 
 impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter {
@@ -75,11 +177,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
         // let new = !path.exists();
 
         // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
+        let (mut writer, offset) = open_segment_for_append(path)?;
 
         // Write header if file newly created
         // if new {
@@ -87,117 +185,88 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
         // }
 
         // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
-            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
-            Self::opt(dto.cpu_usage_nano_cores),
-            Self::opt(dto.cpu_usage_core_nano_seconds),
-            Self::opt(dto.memory_usage_bytes),
-            Self::opt(dto.memory_working_set_bytes),
-            Self::opt(dto.memory_rss_bytes),
-            Self::opt(dto.memory_page_faults),
-            // --- FS fields (rootfs + logs) ---
-            Self::opt(dto.fs_used_bytes),
-            Self::opt(dto.fs_capacity_bytes),
-            Self::opt(dto.fs_inodes_used),
-            Self::opt(dto.fs_inodes),
-        );
+        let row = Self::format_row(dto);
 
 
         // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
+        writer.write_all(encode_row(&row).as_bytes())?;
 
         // ✅ ensure everything flushed to disk
         writer.flush()?;
+
+        if let Err(e) = record_append(path, MetricTier::Day, dto.time, offset) {
+            tracing::warn!("Failed to update index for {:?}: {}", path, e);
+        }
         Ok(())
     }
 
-    /// Aggregate hour-level metrics into an dayly sample and append to day file.
-    fn append_row_aggregated(
+    /// Aggregate hour-level metrics of one window into a single day sample.
+    fn aggregate_between(
         &self,
         container_uid: &str,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
-    ) -> Result<()> {
+    ) -> Result<Option<MetricContainerEntity>> {
         // --- 1️⃣ Load hour data
         let hour_adapter = MetricContainerHourFsAdapter;
-        let rows = hour_adapter.get_row_between(start, end, container_uid, None, None)?;
+        let mut rows = hour_adapter.get_row_between(start, end, container_uid, None, None)?;
+        // Hour rows are stamped with the end of their hour; the one at `start`
+        // closes the previous day and must not be counted twice.
+        rows.retain(|r| r.time > start);
 
         if rows.is_empty() {
-            return Err(anyhow!("no hour data found for aggregation"));
+            return Ok(None);
         }
 
         // --- 2️⃣ Compute aggregates
-        let first = rows.first().unwrap();
-        let last = rows.last().unwrap();
-
-        let avg = |f: fn(&MetricContainerEntity) -> Option<u64>| -> Option<u64> {
-            let (sum, count): (u64, u64) =
-                rows.iter().filter_map(f).fold((0, 0), |(s, c), v| (s + v, c + 1));
-            if count > 0 {
-                Some(sum / count)
-            } else {
-                None
-            }
-        };
-
-        let delta = |f: fn(&MetricContainerEntity) -> Option<u64>| -> Option<u64> {
-            match (f(first), f(last)) {
-                (Some(a), Some(b)) if b >= a => Some(b - a),
-                _ => None,
-            }
-        };
-
-        let aggregated = MetricContainerEntity {
-            time: end, // time marker = end of the aggregation window
-
-            // CPU
-            cpu_usage_nano_cores: avg(|r| r.cpu_usage_nano_cores),
-            cpu_usage_core_nano_seconds: delta(|r| r.cpu_usage_core_nano_seconds),
-
-            // Memory
-            memory_usage_bytes: avg(|r| r.memory_usage_bytes),
-            memory_working_set_bytes: avg(|r| r.memory_working_set_bytes),
-            memory_rss_bytes: avg(|r| r.memory_rss_bytes),
-            memory_page_faults: delta(|r| r.memory_page_faults),
+        let minute_rows = MetricContainerMinuteFsAdapter.get_row_between(start, end, container_uid, None, None)?;
+        Ok(MetricContainerEntity::rollup_hours(&rows, &minute_rows, end))
+    }
 
-            // Ephemeral filesystem
-            fs_used_bytes: avg(|r| r.fs_used_bytes),
-            fs_capacity_bytes: last.fs_capacity_bytes,
-            fs_inodes_used: avg(|r| r.fs_inodes_used),
-            fs_inodes: last.fs_inodes,
-        };
+    /// Aggregate hour-level metrics into an dayly sample and append to day file.
+    fn append_row_aggregated(
+        &self,
+        container_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<()> {
+        let aggregated = self
+            .aggregate_between(container_uid, start, end)?
+            .ok_or_else(|| anyhow!("no hour data found for aggregation"))?;
+        self.append_row(container_uid, &aggregated)
+    }
 
-        // --- 3️⃣ Append the aggregated row into the day-level file
-        self.append_row(container_uid, &aggregated)?;
+    fn upsert_rows(&self, container_uid: &str, rows: &[MetricContainerEntity]) -> Result<()> {
+        let mut by_file: BTreeMap<PathBuf, Vec<(DateTime<Utc>, String)>> = BTreeMap::new();
+        for row in rows {
+            let line = Self::format_row(row).trim_end_matches('\n').to_string();
+            by_file
+                .entry(self.build_path_for(container_uid, row.time.date_naive()))
+                .or_default()
+                .push((row.time, line));
+        }
 
+        for (path, lines) in by_file {
+            upsert_segment_rows(&path, MetricTier::Day, lines)?;
+        }
         Ok(())
     }
 
 
 
     fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
-        let cutoff_year: i32 = before.format("%Y").to_string().parse().unwrap_or(0);
-        let dir = metric_k8s_container_key_day_dir_path(container_key);
-
-        if !dir.exists() {
-            return Ok(());
+        for path in self.expired_files(container_key, before)? {
+            remove_segment(&path)
+                .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
         }
+        Ok(())
+    }
 
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            if let Some(filename) = path.file_stem().and_then(|s| s.to_str()) {
-                if let Ok(year) = filename.parse::<i32>() {
-                    if year < cutoff_year {
-                        fs::remove_file(&path)
-                            .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
-                    }
-                }
-            }
+    fn archive_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
+        let archive_dir = archive_k8s_container_key_day_dir_path(container_key);
+        for path in self.expired_files(container_key, before)? {
+            MetricArchiveFsAdapter.archive_file(&path, &archive_dir)?;
         }
-
         Ok(())
     }
 
@@ -212,147 +281,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
         offset: Option<usize>,
     ) -> Result<Vec<MetricContainerEntity>> {
         let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricContainerEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "FS_USED_BYTES" => {
-                        let keep = row.fs_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_used_bytes = keep;
-                    }
-                    "FS_CAPACITY_BYTES" => {
-                        let keep = row.fs_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_capacity_bytes = keep;
-                    }
-                    "FS_INODES_USED" => {
-                        let keep = row.fs_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes = None;
-                        row.fs_inodes_used = keep;
-                    }
-                    "FS_INODES" => {
-                        let keep = row.fs_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        Ok(rows.iter().map(|row| row.only_column(column_name)).collect())
     }
 
     fn get_row_between(
@@ -363,19 +292,6 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<MetricContainerEntity>> {
-        const HEADER: [&str; 11] = [
-            "TIME",
-            "CPU_USAGE_NANO_CORES",
-            "CPU_USAGE_CORE_NANO_SECONDS",
-            "MEMORY_USAGE_BYTES",
-            "MEMORY_WORKING_SET_BYTES",
-            "MEMORY_RSS_BYTES",
-            "MEMORY_PAGE_FAULTS",
-            "FS_USED_BYTES",
-            "FS_CAPACITY_BYTES",
-            "FS_INODES_USED",
-            "FS_INODES",
-        ];
 
         let mut data = Vec::new();
         let mut current_date = start.naive_utc().date();
@@ -386,20 +302,24 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !segment_exists(path_obj) {
                 current_date = NaiveDate::from_ymd_opt(current_date.year() + 1, 1, 1)
                     .unwrap_or(current_date);
                 continue;
             }
 
-            if let Ok(file) = File::open(&path_obj) {
-                let reader = BufReader::new(file);
-                for line_result in reader.lines() {
+            if let Ok(file) = open_segment_from(path_obj, start) {
+                let mut legacy = None;
+                for line_result in SegmentLines::new(file) {
                     let line = match line_result {
                         Ok(ref l) if !l.trim().is_empty() => l,
                         _ => continue,
                     };
-                    if let Some(row) = Self::parse_line(&HEADER, line) {
+                    if LegacyHeader::is_header(line) {
+                        legacy = LegacyHeader::parse(line, Self::COLUMNS);
+                        continue;
+                    }
+                    if let Some(row) = Self::parse_line(&current_layout(legacy.as_ref(), line)) {
                         if row.time < start {
                             continue;
                         }
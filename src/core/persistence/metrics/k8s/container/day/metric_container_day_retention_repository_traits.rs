@@ -1,15 +1,29 @@
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 
-/// Deletes old metric files for the given container before the cutoff timestamp.
+/// Repository trait for container day metrics retention.
 pub trait MetricContainerDayRetentionRepository: Send + Sync {
     fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity>;
 
-
+    /// Deletes old metric files for the given container before the cutoff timestamp.
     fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
         self.fs_adapter().cleanup_old(container_key, before)
     }
 
+    /// Moves old metric files for the given container into the archive tree.
+    fn archive_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().archive_old(container_key, before)
+    }
+
+    /// Retires old metric files according to the configured retention policy.
+    fn apply_retention(&self, container_key: &str, before: DateTime<Utc>, policy: MetricRetentionPolicy) -> Result<()> {
+        match policy {
+            MetricRetentionPolicy::Delete => self.cleanup_old(container_key, before),
+            MetricRetentionPolicy::Archive => self.archive_old(container_key, before),
+        }
+    }
+
 }
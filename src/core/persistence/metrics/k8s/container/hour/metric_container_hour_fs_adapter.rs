@@ -1,13 +1,11 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, NaiveDate, Datelike, Utc};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
+    fs,
     io::Write,
-    io::{BufRead, BufReader},
     path::Path,
 };
 use std::path::PathBuf;
@@ -16,6 +14,16 @@ use crate::core::persistence::metrics::k8s::path::{
     metric_k8s_container_key_hour_dir_path,
     metric_k8s_container_key_hour_file_path,
 };
+use crate::core::persistence::archive::metric_archive_fs_adapter::MetricArchiveFsAdapter;
+use crate::core::persistence::metrics::segment::compression::{segment_exists, segment_period};
+use crate::core::persistence::metrics::segment::format::{
+    current_layout, encode_row, open_segment_for_append, LegacyHeader, SegmentLines,
+};
+use crate::core::persistence::metrics::segment::index::{open_segment_from, record_append, remove_segment};
+use crate::core::persistence::metrics::segment::rewrite::upsert_segment_rows;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use std::collections::BTreeMap;
+use crate::core::persistence::archive::path::archive_k8s_container_key_hour_dir_path;
 
 /// Adapter for container minute-level metrics.
 /// Responsible for appending minute samples to the filesystem and cleaning up old data.
@@ -23,16 +31,35 @@ use crate::core::persistence::metrics::k8s::path::{
 pub struct MetricContainerHourFsAdapter;
 
 impl MetricContainerHourFsAdapter {
+    /// Columns of a row written before rollup statistics were added.
+    const BASE_COLUMNS: usize = 11;
+
+    /// Column names in row order, to read segments that start with a legacy
+    /// named header.
+    const COLUMNS: &'static [&'static str] = &[
+        "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS", "MEMORY_USAGE_BYTES",
+        "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES", "MEMORY_PAGE_FAULTS", "FS_USED_BYTES",
+        "FS_CAPACITY_BYTES", "FS_INODES_USED", "FS_INODES", "CPU_USAGE_NANO_CORES_MAX",
+        "CPU_USAGE_NANO_CORES_MIN", "CPU_USAGE_NANO_CORES_P95", "CPU_USAGE_NANO_CORES_P99",
+        "MEMORY_WORKING_SET_BYTES_MAX", "MEMORY_WORKING_SET_BYTES_MIN",
+        "MEMORY_WORKING_SET_BYTES_P95", "MEMORY_WORKING_SET_BYTES_P99", "MEMORY_AVAILABLE_BYTES",
+        "MEMORY_MAJOR_PAGE_FAULTS", "SWAP_USAGE_BYTES", "SWAP_AVAILABLE_BYTES", "CPU_CFS_PERIODS",
+        "CPU_CFS_THROTTLED_PERIODS", "CPU_CFS_THROTTLED_NANO_SECONDS",
+    ];
+
     fn build_path_for(&self, container_key: &str, date: NaiveDate) -> PathBuf {
         let month_str = date.format("%Y-%m").to_string();
         metric_k8s_container_key_hour_file_path(container_key, &month_str)
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricContainerEntity> {
+    fn parse_line(line: &str) -> Option<MetricContainerEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Columns are only ever appended, so older rows stop early and read the
+        // missing ones as `None`; trailing columns of newer rows are ignored.
+        if parts.len() < Self::BASE_COLUMNS {
             return None;
         }
+        let col = |i: usize| parts.get(i).and_then(|v| v.parse().ok());
 
         // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
         let time = parts[0].parse::<DateTime<Utc>>().ok()?;
@@ -48,6 +75,21 @@ impl MetricContainerHourFsAdapter {
             fs_capacity_bytes: parts[8].parse().ok(),
             fs_inodes_used: parts[9].parse().ok(),
             fs_inodes: parts[10].parse().ok(),
+            cpu_usage_nano_cores_max: col(11),
+            cpu_usage_nano_cores_min: col(12),
+            cpu_usage_nano_cores_p95: col(13),
+            cpu_usage_nano_cores_p99: col(14),
+            memory_working_set_bytes_max: col(15),
+            memory_working_set_bytes_min: col(16),
+            memory_working_set_bytes_p95: col(17),
+            memory_working_set_bytes_p99: col(18),
+            memory_available_bytes: col(19),
+            memory_major_page_faults: col(20),
+            swap_usage_bytes: col(21),
+            swap_available_bytes: col(22),
+            cpu_cfs_periods: col(23),
+            cpu_cfs_throttled_periods: col(24),
+            cpu_cfs_throttled_nano_seconds: col(25),
         })
     }
 
@@ -63,6 +105,72 @@ impl MetricContainerHourFsAdapter {
     fn opt(v: Option<u64>) -> String {
         v.map(|x| x.to_string()).unwrap_or_default()
     }
+
+    fn format_row(dto: &MetricContainerEntity) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            Self::opt(dto.cpu_usage_nano_cores),
+            Self::opt(dto.cpu_usage_core_nano_seconds),
+            Self::opt(dto.memory_usage_bytes),
+            Self::opt(dto.memory_working_set_bytes),
+            Self::opt(dto.memory_rss_bytes),
+            Self::opt(dto.memory_page_faults),
+            // --- FS fields (rootfs + logs) ---
+            Self::opt(dto.fs_used_bytes),
+            Self::opt(dto.fs_capacity_bytes),
+            Self::opt(dto.fs_inodes_used),
+            Self::opt(dto.fs_inodes),
+            Self::opt(dto.cpu_usage_nano_cores_max),
+            Self::opt(dto.cpu_usage_nano_cores_min),
+            Self::opt(dto.cpu_usage_nano_cores_p95),
+            Self::opt(dto.cpu_usage_nano_cores_p99),
+            Self::opt(dto.memory_working_set_bytes_max),
+            Self::opt(dto.memory_working_set_bytes_min),
+            Self::opt(dto.memory_working_set_bytes_p95),
+            Self::opt(dto.memory_working_set_bytes_p99),
+            Self::opt(dto.memory_available_bytes),
+            Self::opt(dto.memory_major_page_faults),
+            Self::opt(dto.swap_usage_bytes),
+            Self::opt(dto.swap_available_bytes),
+            Self::opt(dto.cpu_cfs_periods),
+            Self::opt(dto.cpu_cfs_throttled_periods),
+            Self::opt(dto.cpu_cfs_throttled_nano_seconds),
+        )
+    }
+
+    /// Hour files (`YYYY-MM.rcd`) for months strictly before the cutoff month.
+    fn expired_files(&self, container_uid: &str, before: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let mut expired = Vec::new();
+        let dir = metric_k8s_container_key_hour_dir_path(container_uid);
+        if !dir.exists() {
+            return Ok(expired);
+        }
+
+        let before_month = NaiveDate::from_ymd_opt(before.year(), before.month() as u32, 1)
+            .expect("valid before month date");
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if let Some(stem) = segment_period(&path) {
+                // Expect YYYY-MM
+                let parts: Vec<&str> = stem.split('-').collect();
+                if parts.len() == 2 {
+                    if let (Ok(y), Ok(m)) = (parts[0].parse::<i32>(), parts[1].parse::<u32>()) {
+                        if let Some(file_month) = NaiveDate::from_ymd_opt(y, m, 1) {
+                            if file_month < before_month {
+                                expired.push(path);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(expired)
+    }
 }
 
 impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter {
@@ -78,11 +186,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
         // let new = !path.exists();
 
         // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
+        let (mut writer, offset) = open_segment_for_append(path)?;
 
         // Write header if file newly created
         // if new {
@@ -90,125 +194,84 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
         // }
 
         // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
-            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
-            Self::opt(dto.cpu_usage_nano_cores),
-            Self::opt(dto.cpu_usage_core_nano_seconds),
-            Self::opt(dto.memory_usage_bytes),
-            Self::opt(dto.memory_working_set_bytes),
-            Self::opt(dto.memory_rss_bytes),
-            Self::opt(dto.memory_page_faults),
-            // --- FS fields (rootfs + logs) ---
-            Self::opt(dto.fs_used_bytes),
-            Self::opt(dto.fs_capacity_bytes),
-            Self::opt(dto.fs_inodes_used),
-            Self::opt(dto.fs_inodes),
-        );
+        let row = Self::format_row(dto);
 
 
         // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
+        writer.write_all(encode_row(&row).as_bytes())?;
 
         // ✅ ensure everything flushed to disk
         writer.flush()?;
+
+        if let Err(e) = record_append(path, MetricTier::Hour, dto.time, offset) {
+            tracing::warn!("Failed to update index for {:?}: {}", path, e);
+        }
         Ok(())
     }
 
-    /// Aggregate minute-level metrics into an hour sample and append to hour file.
-    fn append_row_aggregated(
+    /// Aggregate minute-level metrics of one window into a single hour sample.
+    fn aggregate_between(
         &self,
         container_uid: &str,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
-    ) -> Result<()> {
+    ) -> Result<Option<MetricContainerEntity>> {
         // --- 1️⃣ Load minute data
         let minute_adapter = MetricContainerMinuteFsAdapter;
         let rows = minute_adapter.get_row_between(start, end, container_uid, None, None)?;
 
         if rows.is_empty() {
-            return Err(anyhow!("no minute data found for aggregation"));
+            return Ok(None);
         }
 
         // --- 2️⃣ Compute aggregates
-        let first = rows.first().unwrap();
-        let last = rows.last().unwrap();
-
-        let avg = |f: fn(&MetricContainerEntity) -> Option<u64>| -> Option<u64> {
-            let (sum, count): (u64, u64) =
-                rows.iter().filter_map(f).fold((0, 0), |(s, c), v| (s + v, c + 1));
-            if count > 0 {
-                Some(sum / count)
-            } else {
-                None
-            }
-        };
-
-        let delta = |f: fn(&MetricContainerEntity) -> Option<u64>| -> Option<u64> {
-            match (f(first), f(last)) {
-                (Some(a), Some(b)) if b >= a => Some(b - a),
-                _ => None,
-            }
-        };
-
-        let aggregated = MetricContainerEntity {
-            time: end, // time marker = end of the aggregation window
-
-            // CPU
-            cpu_usage_nano_cores: avg(|r| r.cpu_usage_nano_cores),
-            cpu_usage_core_nano_seconds: delta(|r| r.cpu_usage_core_nano_seconds),
-
-            // Memory
-            memory_usage_bytes: avg(|r| r.memory_usage_bytes),
-            memory_working_set_bytes: avg(|r| r.memory_working_set_bytes),
-            memory_rss_bytes: avg(|r| r.memory_rss_bytes),
-            memory_page_faults: delta(|r| r.memory_page_faults),
+        Ok(MetricContainerEntity::rollup_minutes(&rows, end))
+    }
 
-            // Ephemeral filesystem
-            fs_used_bytes: avg(|r| r.fs_used_bytes),
-            fs_capacity_bytes: last.fs_capacity_bytes,
-            fs_inodes_used: avg(|r| r.fs_inodes_used),
-            fs_inodes: last.fs_inodes,
-        };
+    /// Aggregate minute-level metrics into an hour sample and append to hour file.
+    fn append_row_aggregated(
+        &self,
+        container_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<()> {
+        let aggregated = self
+            .aggregate_between(container_uid, start, end)?
+            .ok_or_else(|| anyhow!("no minute data found for aggregation"))?;
+        self.append_row(container_uid, &aggregated)
+    }
 
-        // --- 3️⃣ Append the aggregated row into the hour-level file
-        self.append_row(container_uid, &aggregated)?;
+    fn upsert_rows(&self, container_uid: &str, rows: &[MetricContainerEntity]) -> Result<()> {
+        let mut by_file: BTreeMap<PathBuf, Vec<(DateTime<Utc>, String)>> = BTreeMap::new();
+        for row in rows {
+            let line = Self::format_row(row).trim_end_matches('\n').to_string();
+            by_file
+                .entry(self.build_path_for(container_uid, row.time.date_naive()))
+                .or_default()
+                .push((row.time, line));
+        }
 
+        for (path, lines) in by_file {
+            upsert_segment_rows(&path, MetricTier::Hour, lines)?;
+        }
         Ok(())
     }
 
 
 
     fn cleanup_old(&self, container_uid: &str, before: DateTime<Utc>) -> Result<()> {
-        let dir = metric_k8s_container_key_hour_dir_path(container_uid);
-        if !dir.exists() {
-            return Ok(())
+        for path in self.expired_files(container_uid, before)? {
+            remove_segment(&path)
+                .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
         }
+        Ok(())
+    }
 
-        let before_month = NaiveDate::from_ymd_opt(before.year(), before.month() as u32, 1)
-            .expect("valid before month date");
-
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") { continue; }
-
-            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                // Expect YYYY-MM
-                let parts: Vec<&str> = stem.split('-').collect();
-                if parts.len() == 2 {
-                    if let (Ok(y), Ok(m)) = (parts[0].parse::<i32>(), parts[1].parse::<u32>()) {
-                        if let Some(file_month) = NaiveDate::from_ymd_opt(y, m, 1) {
-                            if file_month < before_month {
-                                fs::remove_file(&path)
-                                    .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
-                            }
-                        }
-                    }
-                }
-            }
+    fn archive_old(&self, container_uid: &str, before: DateTime<Utc>) -> Result<()> {
+        let archive_dir = archive_k8s_container_key_hour_dir_path(container_uid);
+        for path in self.expired_files(container_uid, before)? {
+            MetricArchiveFsAdapter.archive_file(&path, &archive_dir)?;
         }
-
         Ok(())
     }
 
@@ -222,147 +285,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
         offset: Option<usize>,
     ) -> Result<Vec<MetricContainerEntity>> {
         let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricContainerEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "FS_USED_BYTES" => {
-                        let keep = row.fs_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_used_bytes = keep;
-                    }
-                    "FS_CAPACITY_BYTES" => {
-                        let keep = row.fs_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_capacity_bytes = keep;
-                    }
-                    "FS_INODES_USED" => {
-                        let keep = row.fs_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes = None;
-                        row.fs_inodes_used = keep;
-                    }
-                    "FS_INODES" => {
-                        let keep = row.fs_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        Ok(rows.iter().map(|row| row.only_column(column_name)).collect())
     }
 
     fn get_row_between(
@@ -384,13 +307,13 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !segment_exists(path_obj) {
                 tracing::debug!("Hour metrics file missing for {} on {}", object_name, current_date);
                 current_date = current_date.checked_add_months(Months::new(1)).unwrap_or(current_date);
                 continue;
             }
 
-            let file = match File::open(&path_obj) {
+            let file = match open_segment_from(path_obj, start) {
                 Ok(f) => f,
                 Err(e) => {
                     tracing::warn!("Cannot open {:?}: {}", path_obj, e);
@@ -399,8 +322,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
                 }
             };
 
-            let reader = BufReader::new(file);
-            let mut lines = reader.lines();
+            let mut lines = SegmentLines::new(file);
 
             // Handle empty files
             let first_line = match lines.next() {
@@ -413,25 +335,13 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
             };
 
             let mut rows = Vec::new();
-            let header: Vec<&str>;
-
-            // 2️⃣ Handle header or first data line
-            if first_line.starts_with("20") {
-                // Default header assumption (timestamp-first)
-                header = vec![
-                    "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS",
-                    "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
-                    "MEMORY_PAGE_FAULTS", "FS_USED_BYTES", "FS_CAPACITY_BYTES",
-                    "FS_INODES_USED", "FS_INODES",
-                ];
-
-                if let Some(row) = Self::parse_line(&header, &first_line) {
-                    if row.time >= start && row.time <= end {
-                        rows.push(row);
-                    }
+
+            // 2️⃣ Segments from before positional rows may start with a named header
+            let legacy = LegacyHeader::parse(&first_line, Self::COLUMNS);
+            if let Some(row) = Self::parse_line(&first_line) {
+                if row.time >= start && row.time <= end {
+                    rows.push(row);
                 }
-            } else {
-                header = first_line.split('|').collect();
             }
 
             // 3️⃣ Process all remaining lines safely
@@ -441,7 +351,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
                     _ => continue,
                 };
 
-                if let Some(row) = Self::parse_line(&header, &line) {
+                if let Some(row) = Self::parse_line(&current_layout(legacy.as_ref(), &line)) {
                     if row.time < start {
                         continue;
                     }
@@ -2,6 +2,7 @@ use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAda
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
 
 /// Repository trait for reading container minute metrics (API layer).
 pub trait MetricContainerHourRetentionRepository: Send + Sync {
@@ -11,4 +12,17 @@ pub trait MetricContainerHourRetentionRepository: Send + Sync {
     fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
         self.fs_adapter().cleanup_old(container_key, before)
     }
+
+    /// Moves old metric files for the given container into the archive tree.
+    fn archive_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().archive_old(container_key, before)
+    }
+
+    /// Retires old metric files according to the configured retention policy.
+    fn apply_retention(&self, container_key: &str, before: DateTime<Utc>, policy: MetricRetentionPolicy) -> Result<()> {
+        match policy {
+            MetricRetentionPolicy::Delete => self.cleanup_old(container_key, before),
+            MetricRetentionPolicy::Archive => self.archive_old(container_key, before),
+        }
+    }
 }
@@ -2,12 +2,8 @@ use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAda
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use anyhow::{Context, Result};
 use chrono::{DateTime, NaiveDate, Utc};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    fs,
     path::Path,
 };
 use std::path::PathBuf;
@@ -15,6 +11,15 @@ use crate::core::persistence::metrics::k8s::path::{
     metric_k8s_container_key_minute_dir_path,
     metric_k8s_container_key_minute_file_path,
 };
+use crate::core::persistence::archive::metric_archive_fs_adapter::MetricArchiveFsAdapter;
+use crate::core::persistence::metrics::segment::compression::{segment_exists, segment_period};
+use crate::core::persistence::metrics::segment::format::{current_layout, LegacyHeader, SegmentLines};
+use crate::core::persistence::metrics::segment::index::{open_segment_from, remove_segment};
+use crate::core::persistence::metrics::segment::rewrite::upsert_segment_rows;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::metrics::segment::write_buffer;
+use std::collections::BTreeMap;
+use crate::core::persistence::archive::path::archive_k8s_container_key_minute_dir_path;
 
 /// Adapter for container minute-level metrics.
 /// Responsible for appending minute samples to the filesystem and cleaning up old data.
@@ -22,16 +27,32 @@ use crate::core::persistence::metrics::k8s::path::{
 pub struct MetricContainerMinuteFsAdapter;
 
 impl MetricContainerMinuteFsAdapter {
+    /// Columns of a row written before memory pressure and process columns were added.
+    const BASE_COLUMNS: usize = 11;
+
+    /// Column names in row order, to read segments that start with a legacy
+    /// named header.
+    const COLUMNS: &'static [&'static str] = &[
+        "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS", "MEMORY_USAGE_BYTES",
+        "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES", "MEMORY_PAGE_FAULTS", "FS_USED_BYTES",
+        "FS_CAPACITY_BYTES", "FS_INODES_USED", "FS_INODES", "MEMORY_AVAILABLE_BYTES",
+        "MEMORY_MAJOR_PAGE_FAULTS", "SWAP_USAGE_BYTES", "SWAP_AVAILABLE_BYTES", "CPU_CFS_PERIODS",
+        "CPU_CFS_THROTTLED_PERIODS", "CPU_CFS_THROTTLED_NANO_SECONDS",
+    ];
+
     fn build_path_for(&self, container_key: &str, date: NaiveDate) -> PathBuf {
         let date_str = date.format("%Y-%m-%d").to_string();
         metric_k8s_container_key_minute_file_path(container_key, &date_str)
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricContainerEntity> {
+    fn parse_line(line: &str) -> Option<MetricContainerEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Columns are only ever appended, so older rows stop early and read the
+        // missing ones as `None`; trailing columns of newer rows are ignored.
+        if parts.len() < Self::BASE_COLUMNS {
             return None;
         }
+        let col = |i: usize| parts.get(i).and_then(|v| v.parse().ok());
 
         // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
         let time = parts[0].parse::<DateTime<Utc>>().ok()?;
@@ -47,6 +68,14 @@ impl MetricContainerMinuteFsAdapter {
             fs_capacity_bytes: parts[8].parse().ok(),
             fs_inodes_used: parts[9].parse().ok(),
             fs_inodes: parts[10].parse().ok(),
+            memory_available_bytes: col(11),
+            memory_major_page_faults: col(12),
+            swap_usage_bytes: col(13),
+            swap_available_bytes: col(14),
+            cpu_cfs_periods: col(15),
+            cpu_cfs_throttled_periods: col(16),
+            cpu_cfs_throttled_nano_seconds: col(17),
+            ..Default::default()
         })
     }
 
@@ -61,35 +90,10 @@ impl MetricContainerMinuteFsAdapter {
     fn opt(v: Option<u64>) -> String {
         v.map(|x| x.to_string()).unwrap_or_default()
     }
-}
-
-impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapter {
-    fn append_row(&self, container: &str, dto: &MetricContainerEntity) -> Result<()> {
-        let now_date = Utc::now().date_naive();
-        let path_str = self.build_path_for(container, now_date);
-        let path = Path::new(&path_str);
-
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
 
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
-
-        // Write header if file newly created
-        // if new {
-        //     self.ensure_header(path, &mut writer)?;
-        // }
-
-        // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+    fn format_row(dto: &MetricContainerEntity) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -102,40 +106,78 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
             Self::opt(dto.fs_capacity_bytes),
             Self::opt(dto.fs_inodes_used),
             Self::opt(dto.fs_inodes),
-        );
-
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
-        Ok(())
+            Self::opt(dto.memory_available_bytes),
+            Self::opt(dto.memory_major_page_faults),
+            Self::opt(dto.swap_usage_bytes),
+            Self::opt(dto.swap_available_bytes),
+            Self::opt(dto.cpu_cfs_periods),
+            Self::opt(dto.cpu_cfs_throttled_periods),
+            Self::opt(dto.cpu_cfs_throttled_nano_seconds),
+        )
     }
 
-    fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
+    /// Minute files (`YYYY-MM-DD.rcd`) dated strictly before the cutoff day.
+    fn expired_files(&self, container_key: &str, before: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let mut expired = Vec::new();
         let dir = metric_k8s_container_key_minute_dir_path(container_key);
         if !dir.exists() {
-            return Ok(());
+            return Ok(expired);
         }
 
         for entry in fs::read_dir(&dir)? {
             let entry = entry?;
             let path = entry.path();
 
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
-                continue;
-            }
-
-            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+            if let Some(stem) = segment_period(&path) {
                 if let Ok(file_date) = NaiveDate::parse_from_str(stem, "%Y-%m-%d") {
                     if file_date < before.date_naive() {
-                        fs::remove_file(&path)
-                            .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
+                        expired.push(path);
                     }
                 }
             }
         }
 
+        Ok(expired)
+    }
+}
+
+impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapter {
+    fn append_row(&self, container: &str, dto: &MetricContainerEntity) -> Result<()> {
+        let path = self.build_path_for(container, Utc::now().date_naive());
+
+        // Buffered; the handle stays open across ticks and the row is flushed in a batch
+        write_buffer::append_row(&path, MetricTier::Minute, dto.time, &Self::format_row(dto))
+    }
+
+    fn upsert_rows(&self, container_key: &str, rows: &[MetricContainerEntity]) -> Result<()> {
+        let mut by_file: BTreeMap<PathBuf, Vec<(DateTime<Utc>, String)>> = BTreeMap::new();
+        for row in rows {
+            let line = Self::format_row(row).trim_end_matches('\n').to_string();
+            by_file
+                .entry(self.build_path_for(container_key, row.time.date_naive()))
+                .or_default()
+                .push((row.time, line));
+        }
+
+        for (path, lines) in by_file {
+            upsert_segment_rows(&path, MetricTier::Minute, lines)?;
+        }
+        Ok(())
+    }
+
+    fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
+        for path in self.expired_files(container_key, before)? {
+            remove_segment(&path)
+                .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
+        }
+        Ok(())
+    }
+
+    fn archive_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
+        let archive_dir = archive_k8s_container_key_minute_dir_path(container_key);
+        for path in self.expired_files(container_key, before)? {
+            MetricArchiveFsAdapter.archive_file(&path, &archive_dir)?;
+        }
         Ok(())
     }
 
@@ -157,14 +199,14 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !segment_exists(path_obj) {
                 tracing::debug!("Minute metrics file missing for {} on {}", object_name, current_date);
                 current_date = current_date.succ_opt().unwrap_or(current_date);
                 continue;
             }
 
             // Safely open file
-            let file = match File::open(&path_obj) {
+            let file = match open_segment_from(path_obj, start) {
                 Ok(f) => f,
                 Err(e) => {
                     tracing::warn!("Cannot open {:?}: {}", path_obj, e);
@@ -173,8 +215,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
                 }
             };
 
-            let reader = BufReader::new(file);
-            let mut lines = reader.lines();
+            let mut lines = SegmentLines::new(file);
 
             // Skip empty files
             let first_line = match lines.next() {
@@ -186,26 +227,14 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
                 }
             };
 
-            // 2️⃣ Handle header vs. data
-            let header: Vec<&str>;
             let mut rows: Vec<MetricContainerEntity> = vec![];
 
-            if first_line.starts_with("20") {
-                // Treat as data (no header)
-                header = vec![
-                    "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS",
-                    "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
-                    "MEMORY_PAGE_FAULTS", "FS_USED_BYTES", "FS_CAPACITY_BYTES",
-                    "FS_INODES_USED", "FS_INODES"
-                ];
-
-                if let Some(row) = Self::parse_line(&header, &first_line) {
-                    if row.time >= start && row.time <= end {
-                        rows.push(row);
-                    }
+            // 2️⃣ Segments from before positional rows may start with a named header
+            let legacy = LegacyHeader::parse(&first_line, Self::COLUMNS);
+            if let Some(row) = Self::parse_line(&first_line) {
+                if row.time >= start && row.time <= end {
+                    rows.push(row);
                 }
-            } else {
-                header = first_line.split('|').collect();
             }
 
             // 3️⃣ Process remaining lines safely
@@ -215,7 +244,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
                     _ => continue,
                 };
 
-                if let Some(row) = Self::parse_line(&header, &line) {
+                if let Some(row) = Self::parse_line(&current_layout(legacy.as_ref(), &line)) {
                     if row.time < start {
                         continue;
                     }
@@ -259,146 +288,6 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
         offset: Option<usize>,
     ) -> Result<Vec<MetricContainerEntity>> {
         let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricContainerEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "FS_USED_BYTES" => {
-                        let keep = row.fs_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_used_bytes = keep;
-                    }
-                    "FS_CAPACITY_BYTES" => {
-                        let keep = row.fs_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_capacity_bytes = keep;
-                    }
-                    "FS_INODES_USED" => {
-                        let keep = row.fs_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes = None;
-                        row.fs_inodes_used = keep;
-                    }
-                    "FS_INODES" => {
-                        let keep = row.fs_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        Ok(rows.iter().map(|row| row.only_column(column_name)).collect())
     }
 }
@@ -1,4 +1,5 @@
 pub mod minute;
 pub mod hour;
 pub mod day;
-pub mod metric_container_entity;
\ No newline at end of file
+pub mod metric_container_entity;
+pub mod metric_container_rollup;
@@ -1,4 +1,7 @@
 pub mod container;
 pub mod node;
 pub mod pod;
+pub mod pvc;
+pub mod node_network;
+pub mod node_system;
 pub mod path;
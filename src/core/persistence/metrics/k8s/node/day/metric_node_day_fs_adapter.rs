@@ -1,13 +1,11 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Datelike, NaiveDate, Utc};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
+    fs,
     io::Write,
-    io::{BufRead, BufReader},
     path::Path,
 };
 use std::path::PathBuf;
@@ -16,6 +14,17 @@ use crate::core::persistence::metrics::k8s::path::{
     metric_k8s_node_key_day_dir_path,
     metric_k8s_node_key_day_file_path,
 };
+use crate::core::persistence::archive::metric_archive_fs_adapter::MetricArchiveFsAdapter;
+use crate::core::persistence::metrics::segment::compression::{segment_exists, segment_period};
+use crate::core::persistence::metrics::segment::format::{
+    current_layout, encode_row, open_segment_for_append, LegacyHeader, SegmentLines,
+};
+use crate::core::persistence::metrics::segment::index::{open_segment_from, record_append, remove_segment};
+use crate::core::persistence::metrics::segment::rewrite::upsert_segment_rows;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_fs_adapter::MetricNodeMinuteFsAdapter;
+use std::collections::BTreeMap;
+use crate::core::persistence::archive::path::archive_k8s_node_key_day_dir_path;
 
 /// Adapter for node hour-level metrics.
 /// Responsible for appending hour samples to the filesystem and cleaning up old data.
@@ -23,39 +32,85 @@ use crate::core::persistence::metrics::k8s::path::{
 pub struct MetricNodeDayFsAdapter;
 
 impl MetricNodeDayFsAdapter {
+    /// Columns of a row written before rollup statistics were added.
+    const BASE_COLUMNS: usize = 15;
+
+    /// Column names in row order, to read segments that start with a legacy
+    /// named header.
+    const COLUMNS: &'static [&'static str] = &[
+        "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS", "MEMORY_USAGE_BYTES",
+        "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES", "MEMORY_PAGE_FAULTS",
+        "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES", "NETWORK_PHYSICAL_RX_ERRORS",
+        "NETWORK_PHYSICAL_TX_ERRORS", "FS_USED_BYTES", "FS_CAPACITY_BYTES", "FS_INODES_USED",
+        "FS_INODES", "CPU_USAGE_NANO_CORES_MAX", "CPU_USAGE_NANO_CORES_MIN",
+        "CPU_USAGE_NANO_CORES_P95", "CPU_USAGE_NANO_CORES_P99", "MEMORY_WORKING_SET_BYTES_MAX",
+        "MEMORY_WORKING_SET_BYTES_MIN", "MEMORY_WORKING_SET_BYTES_P95",
+        "MEMORY_WORKING_SET_BYTES_P99", "MEMORY_AVAILABLE_BYTES", "MEMORY_MAJOR_PAGE_FAULTS",
+        "SWAP_USAGE_BYTES", "SWAP_AVAILABLE_BYTES", "PROCESS_COUNT", "PROCESS_LIMIT",
+        "IMAGE_FS_USED_BYTES", "IMAGE_FS_CAPACITY_BYTES", "IMAGE_FS_AVAILABLE_BYTES",
+        "IMAGE_FS_INODES_USED", "IMAGE_FS_INODES", "CONTAINER_FS_USED_BYTES",
+        "CONTAINER_FS_CAPACITY_BYTES", "GPU_MILLI_COUNT", "GPU_UTILIZATION_PERCENT",
+        "GPU_MEMORY_USED_BYTES", "GPU_MEMORY_TOTAL_BYTES", "GPU_POWER_MILLIWATTS",
+    ];
+
     fn build_path_for(&self, node_key: &str, date: NaiveDate) -> PathBuf {
         let year_str = date.format("%Y").to_string();
         metric_k8s_node_key_day_file_path(node_key, &year_str)
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricNodeEntity> {
-        use chrono::{DateTime, Utc};
-
+    fn parse_line(line: &str) -> Option<MetricNodeEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.is_empty() {
+        // Columns are only ever appended, so older rows stop early and read the
+        // missing ones as `None`; trailing columns of newer rows are ignored.
+        if parts.len() < Self::BASE_COLUMNS {
             return None;
         }
+        let col = |i: usize| parts.get(i).and_then(|v| v.parse().ok());
 
-        let time = DateTime::parse_from_rfc3339(parts[0])
-            .map(|dt| dt.with_timezone(&Utc))
-            .ok()?;
-
+        // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
+        let time = parts[0].parse::<DateTime<Utc>>().ok()?;
         Some(MetricNodeEntity {
             time,
-            cpu_usage_nano_cores: parts.get(1).and_then(|s| s.parse::<u64>().ok()),
-            cpu_usage_core_nano_seconds: parts.get(2).and_then(|s| s.parse::<u64>().ok()),
-            memory_usage_bytes: parts.get(3).and_then(|s| s.parse::<u64>().ok()),
-            memory_working_set_bytes: parts.get(4).and_then(|s| s.parse::<u64>().ok()),
-            memory_rss_bytes: parts.get(5).and_then(|s| s.parse::<u64>().ok()),
-            memory_page_faults: parts.get(6).and_then(|s| s.parse::<u64>().ok()),
-            network_physical_rx_bytes: parts.get(7).and_then(|s| s.parse::<u64>().ok()),
-            network_physical_tx_bytes: parts.get(8).and_then(|s| s.parse::<u64>().ok()),
-            network_physical_rx_errors: parts.get(9).and_then(|s| s.parse::<u64>().ok()),
-            network_physical_tx_errors: parts.get(10).and_then(|s| s.parse::<u64>().ok()),
-            fs_used_bytes: parts.get(11).and_then(|s| s.parse::<u64>().ok()),
-            fs_capacity_bytes: parts.get(12).and_then(|s| s.parse::<u64>().ok()),
-            fs_inodes_used: parts.get(13).and_then(|s| s.parse::<u64>().ok()),
-            fs_inodes: parts.get(14).and_then(|s| s.parse::<u64>().ok()),
+            cpu_usage_nano_cores: parts[1].parse().ok(),
+            cpu_usage_core_nano_seconds: parts[2].parse().ok(),
+            memory_usage_bytes: parts[3].parse().ok(),
+            memory_working_set_bytes: parts[4].parse().ok(),
+            memory_rss_bytes: parts[5].parse().ok(),
+            memory_page_faults: parts[6].parse().ok(),
+            network_physical_rx_bytes: parts[7].parse().ok(),
+            network_physical_tx_bytes: parts[8].parse().ok(),
+            network_physical_rx_errors: parts[9].parse().ok(),
+            network_physical_tx_errors: parts[10].parse().ok(),
+            fs_used_bytes: parts[11].parse().ok(),
+            fs_capacity_bytes: parts[12].parse().ok(),
+            fs_inodes_used: parts[13].parse().ok(),
+            fs_inodes: parts[14].parse().ok(),
+            cpu_usage_nano_cores_max: col(15),
+            cpu_usage_nano_cores_min: col(16),
+            cpu_usage_nano_cores_p95: col(17),
+            cpu_usage_nano_cores_p99: col(18),
+            memory_working_set_bytes_max: col(19),
+            memory_working_set_bytes_min: col(20),
+            memory_working_set_bytes_p95: col(21),
+            memory_working_set_bytes_p99: col(22),
+            memory_available_bytes: col(23),
+            memory_major_page_faults: col(24),
+            swap_usage_bytes: col(25),
+            swap_available_bytes: col(26),
+            process_count: col(27),
+            process_limit: col(28),
+            image_fs_used_bytes: col(29),
+            image_fs_capacity_bytes: col(30),
+            image_fs_available_bytes: col(31),
+            image_fs_inodes_used: col(32),
+            image_fs_inodes: col(33),
+            container_fs_used_bytes: col(34),
+            container_fs_capacity_bytes: col(35),
+            gpu_milli_count: col(36),
+            gpu_utilization_percent: col(37),
+            gpu_memory_used_bytes: col(38),
+            gpu_memory_total_bytes: col(39),
+            gpu_power_milliwatts: col(40),
         })
     }
 
@@ -71,6 +126,75 @@ impl MetricNodeDayFsAdapter {
     fn opt(v: Option<u64>) -> String {
         v.map(|x| x.to_string()).unwrap_or_default()
     }
+
+    fn format_row(dto: &MetricNodeEntity) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            Self::opt(dto.cpu_usage_nano_cores),
+            Self::opt(dto.cpu_usage_core_nano_seconds),
+            Self::opt(dto.memory_usage_bytes),
+            Self::opt(dto.memory_working_set_bytes),
+            Self::opt(dto.memory_rss_bytes),
+            Self::opt(dto.memory_page_faults),
+            Self::opt(dto.network_physical_rx_bytes),
+            Self::opt(dto.network_physical_tx_bytes),
+            Self::opt(dto.network_physical_rx_errors),
+            Self::opt(dto.network_physical_tx_errors),
+            Self::opt(dto.fs_used_bytes),
+            Self::opt(dto.fs_capacity_bytes),
+            Self::opt(dto.fs_inodes_used),
+            Self::opt(dto.fs_inodes),
+            Self::opt(dto.cpu_usage_nano_cores_max),
+            Self::opt(dto.cpu_usage_nano_cores_min),
+            Self::opt(dto.cpu_usage_nano_cores_p95),
+            Self::opt(dto.cpu_usage_nano_cores_p99),
+            Self::opt(dto.memory_working_set_bytes_max),
+            Self::opt(dto.memory_working_set_bytes_min),
+            Self::opt(dto.memory_working_set_bytes_p95),
+            Self::opt(dto.memory_working_set_bytes_p99),
+            Self::opt(dto.memory_available_bytes),
+            Self::opt(dto.memory_major_page_faults),
+            Self::opt(dto.swap_usage_bytes),
+            Self::opt(dto.swap_available_bytes),
+            Self::opt(dto.process_count),
+            Self::opt(dto.process_limit),
+            Self::opt(dto.image_fs_used_bytes),
+            Self::opt(dto.image_fs_capacity_bytes),
+            Self::opt(dto.image_fs_available_bytes),
+            Self::opt(dto.image_fs_inodes_used),
+            Self::opt(dto.image_fs_inodes),
+            Self::opt(dto.container_fs_used_bytes),
+            Self::opt(dto.container_fs_capacity_bytes),
+            Self::opt(dto.gpu_milli_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
+            Self::opt(dto.gpu_memory_total_bytes),
+            Self::opt(dto.gpu_power_milliwatts),
+        )
+    }
+
+    /// Day files (`YYYY.rcd`) for years strictly before the cutoff year.
+    fn expired_files(&self, node_uid: &str, before: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let mut expired = Vec::new();
+        let dir = metric_k8s_node_key_day_dir_path(node_uid);
+        if !dir.exists() { return Ok(expired); }
+
+        let cutoff_year = before.year();
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if let Some(stem) = segment_period(&path) {
+                if let Ok(year) = stem.parse::<i32>() {
+                    if year < cutoff_year {
+                        expired.push(path);
+                    }
+                }
+            }
+        }
+        Ok(expired)
+    }
 }
 
 impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
@@ -86,11 +210,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
         // let new = !path.exists();
 
         // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
+        let (mut writer, offset) = open_segment_for_append(path)?;
 
         // Write header if file newly created
         // if new {
@@ -98,122 +218,87 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
         // }
 
         // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
-            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
-            Self::opt(dto.cpu_usage_nano_cores),
-            Self::opt(dto.cpu_usage_core_nano_seconds),
-            Self::opt(dto.memory_usage_bytes),
-            Self::opt(dto.memory_working_set_bytes),
-            Self::opt(dto.memory_rss_bytes),
-            Self::opt(dto.memory_page_faults),
-            Self::opt(dto.network_physical_rx_bytes),
-            Self::opt(dto.network_physical_tx_bytes),
-            Self::opt(dto.network_physical_rx_errors),
-            Self::opt(dto.network_physical_tx_errors),
-            Self::opt(dto.fs_used_bytes),
-            Self::opt(dto.fs_capacity_bytes),
-            Self::opt(dto.fs_inodes_used),
-            Self::opt(dto.fs_inodes),
-        );
+        let row = Self::format_row(dto);
 
 
         // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
+        writer.write_all(encode_row(&row).as_bytes())?;
 
         // ✅ ensure everything flushed to disk
         writer.flush()?;
+
+        if let Err(e) = record_append(path, MetricTier::Day, dto.time, offset) {
+            tracing::warn!("Failed to update index for {:?}: {}", path, e);
+        }
         Ok(())
     }
 
-    /// Aggregate hour-level metrics into an dayly sample and append to day file.
-    fn append_row_aggregated(
+    /// Aggregate hour-level metrics of one window into a single day sample.
+    fn aggregate_between(
         &self,
         node_uid: &str,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
-    ) -> Result<()> {
+    ) -> Result<Option<MetricNodeEntity>> {
         // --- 1️⃣ Load hour data
         let hour_adapter = MetricNodeHourFsAdapter;
-        let rows = hour_adapter.get_row_between(start, end, node_uid, None, None)?;
+        let mut rows = hour_adapter.get_row_between(start, end, node_uid, None, None)?;
+        // Hour rows are stamped with the end of their hour; the one at `start`
+        // closes the previous day and must not be counted twice.
+        rows.retain(|r| r.time > start);
 
         if rows.is_empty() {
-            return Err(anyhow!("no hour data found for aggregation"));
+            return Ok(None);
         }
 
         // --- 2️⃣ Compute aggregates
-        let first = rows.first().unwrap();
-        let last = rows.last().unwrap();
-
-        let avg = |f: fn(&MetricNodeEntity) -> Option<u64>| -> Option<u64> {
-            let (sum, count): (u64, u64) =
-                rows.iter().filter_map(f).fold((0, 0), |(s, c), v| (s + v, c + 1));
-            if count > 0 {
-                Some(sum / count)
-            } else {
-                None
-            }
-        };
-
-        let delta = |f: fn(&MetricNodeEntity) -> Option<u64>| -> Option<u64> {
-            match (f(first), f(last)) {
-                (Some(a), Some(b)) if b >= a => Some(b - a),
-                _ => None,
-            }
-        };
-
-        let aggregated = MetricNodeEntity {
-            time: end, // time marker = end of the aggregation window
-
-            // CPU
-            cpu_usage_nano_cores: avg(|r| r.cpu_usage_nano_cores),
-            cpu_usage_core_nano_seconds: delta(|r| r.cpu_usage_core_nano_seconds),
-
-            // Memory
-            memory_usage_bytes: avg(|r| r.memory_usage_bytes),
-            memory_working_set_bytes: avg(|r| r.memory_working_set_bytes),
-            memory_rss_bytes: avg(|r| r.memory_rss_bytes),
-            memory_page_faults: delta(|r| r.memory_page_faults),
-
-            // Network
-            network_physical_rx_bytes: delta(|r| r.network_physical_rx_bytes),
-            network_physical_tx_bytes: delta(|r| r.network_physical_tx_bytes),
-            network_physical_rx_errors: delta(|r| r.network_physical_rx_errors),
-            network_physical_tx_errors: delta(|r| r.network_physical_tx_errors),
+        let minute_rows = MetricNodeMinuteFsAdapter.get_row_between(start, end, node_uid, None, None)?;
+        Ok(MetricNodeEntity::rollup_hours(&rows, &minute_rows, end))
+    }
 
-            // Filesystem
-            fs_used_bytes: avg(|r| r.fs_used_bytes),
-            fs_capacity_bytes: last.fs_capacity_bytes,
-            fs_inodes_used: avg(|r| r.fs_inodes_used),
-            fs_inodes: last.fs_inodes,
-        };
+    /// Aggregate hour-level metrics into an dayly sample and append to day file.
+    fn append_row_aggregated(
+        &self,
+        node_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<()> {
+        let aggregated = self
+            .aggregate_between(node_uid, start, end)?
+            .ok_or_else(|| anyhow!("no hour data found for aggregation"))?;
+        self.append_row(node_uid, &aggregated)
+    }
 
-        // --- 3️⃣ Append the aggregated row into the day-level file
-        self.append_row(node_uid, &aggregated)?;
+    fn upsert_rows(&self, node_uid: &str, rows: &[MetricNodeEntity]) -> Result<()> {
+        let mut by_file: BTreeMap<PathBuf, Vec<(DateTime<Utc>, String)>> = BTreeMap::new();
+        for row in rows {
+            let line = Self::format_row(row).trim_end_matches('\n').to_string();
+            by_file
+                .entry(self.build_path_for(node_uid, row.time.date_naive()))
+                .or_default()
+                .push((row.time, line));
+        }
 
+        for (path, lines) in by_file {
+            upsert_segment_rows(&path, MetricTier::Day, lines)?;
+        }
         Ok(())
     }
 
 
 
     fn cleanup_old(&self, node_uid: &str, before: DateTime<Utc>) -> Result<()> {
-        let dir = metric_k8s_node_key_day_dir_path(node_uid);
-        if !dir.exists() { return Ok(()); }
-
-        let cutoff_year = before.year();
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") { continue; }
+        for path in self.expired_files(node_uid, before)? {
+            remove_segment(&path)
+                .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
+        }
+        Ok(())
+    }
 
-            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                if let Ok(year) = stem.parse::<i32>() {
-                    if year < cutoff_year {
-                        fs::remove_file(&path)
-                            .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
-                    }
-                }
-            }
+    fn archive_old(&self, node_uid: &str, before: DateTime<Utc>) -> Result<()> {
+        let archive_dir = archive_k8s_node_key_day_dir_path(node_uid);
+        for path in self.expired_files(node_uid, before)? {
+            MetricArchiveFsAdapter.archive_file(&path, &archive_dir)?;
         }
         Ok(())
     }
@@ -228,255 +313,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
         offset: Option<usize>,
     ) -> Result<Vec<MetricNodeEntity>> {
         let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricNodeEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_BYTES" => {
-                        let keep = row.network_physical_rx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_rx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_BYTES" => {
-                        let keep = row.network_physical_tx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_tx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_ERRORS" => {
-                        let keep = row.network_physical_rx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_rx_errors = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_ERRORS" => {
-                        let keep = row.network_physical_tx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_tx_errors = keep;
-                    }
-                    "FS_USED_BYTES" => {
-                        let keep = row.fs_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_used_bytes = keep;
-                    }
-                    "FS_CAPACITY_BYTES" => {
-                        let keep = row.fs_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_capacity_bytes = keep;
-                    }
-                    "FS_INODES_USED" => {
-                        let keep = row.fs_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes = None;
-                        row.fs_inodes_used = keep;
-                    }
-                    "FS_INODES" => {
-                        let keep = row.fs_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        Ok(rows.iter().map(|row| row.only_column(column_name)).collect())
     }
 
     fn get_row_between(
@@ -497,7 +334,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !segment_exists(path_obj) {
                 tracing::debug!(
                 "Metric file not found for {} on {}",
                 object_name,
@@ -507,7 +344,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
                 continue;
             }
 
-            let file = match File::open(&path_obj) {
+            let file = match open_segment_from(path_obj, start) {
                 Ok(f) => f,
                 Err(e) => {
                     tracing::warn!("Could not open {:?}: {}", path_obj, e);
@@ -516,8 +353,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
                 }
             };
 
-            let reader = BufReader::new(file);
-            let mut lines = reader.lines();
+            let mut lines = SegmentLines::new(file);
 
             // 2️⃣ Try to read the first line (header or data)
             let first_line_opt = lines.next();
@@ -528,30 +364,18 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
 
             let first_line = first_line_opt.unwrap_or_else(|| Ok(String::new()))?;
             let mut rows: Vec<MetricNodeEntity> = vec![];
-            let header: Vec<&str>;
-
-            // Detect header vs direct data
-            if first_line.starts_with("20") {
-                header = vec![
-                    "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS",
-                    "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
-                    "MEMORY_PAGE_FAULTS", "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES",
-                    "NETWORK_PHYSICAL_RX_ERRORS", "NETWORK_PHYSICAL_TX_ERRORS",
-                    "FS_USED_BYTES", "FS_CAPACITY_BYTES", "FS_INODES_USED", "FS_INODES",
-                ];
-
-                if let Some(row) = Self::parse_line(&header, &first_line) {
-                    if row.time >= start && row.time <= end {
-                        rows.push(row);
-                    }
+
+            // Segments from before positional rows may start with a named header
+            let legacy = LegacyHeader::parse(&first_line, Self::COLUMNS);
+            if let Some(row) = Self::parse_line(&first_line) {
+                if row.time >= start && row.time <= end {
+                    rows.push(row);
                 }
-            } else {
-                header = first_line.split('|').collect();
             }
 
             // 3️⃣ Process the rest of the file
             for line in lines.flatten() {
-                if let Some(row) = Self::parse_line(&header, &line) {
+                if let Some(row) = Self::parse_line(&current_layout(legacy.as_ref(), &line)) {
                     if row.time < start {
                         continue;
                     }
@@ -9,4 +9,9 @@ pub trait MetricNodeDayProcessorRepository: Send + Sync {
 
     fn append_row_aggregated(&self, node_key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<()>;
 
+    /// Aggregates each window and upserts the rows, so reprocessing a window is safe.
+    fn upsert_aggregated(&self, node_key: &str, windows: &[(DateTime<Utc>, DateTime<Utc>)]) -> Result<usize> {
+        self.fs_adapter().upsert_aggregated(node_key, windows)
+    }
+
 }
@@ -1,6 +1,7 @@
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 
 /// Repository trait for reading node minute metrics (API layer).
@@ -12,5 +13,18 @@ pub trait MetricNodeDayRetentionRepository: Send + Sync {
         self.fs_adapter().cleanup_old(node_key, before)
     }
 
+    /// Moves old metric files for the given node into the archive tree.
+    fn archive_old(&self, node_key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().archive_old(node_key, before)
+    }
+
+    /// Retires old metric files according to the configured retention policy.
+    fn apply_retention(&self, node_key: &str, before: DateTime<Utc>, policy: MetricRetentionPolicy) -> Result<()> {
+        match policy {
+            MetricRetentionPolicy::Delete => self.cleanup_old(node_key, before),
+            MetricRetentionPolicy::Archive => self.archive_old(node_key, before),
+        }
+    }
+
 
 }
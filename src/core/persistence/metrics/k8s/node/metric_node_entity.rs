@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::core::persistence::metrics::rollup_stats::GaugeStats;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MetricNodeEntity {
     pub time: DateTime<Utc>,
@@ -26,4 +28,125 @@ pub struct MetricNodeEntity {
     pub fs_capacity_bytes: Option<u64>,
     pub fs_inodes_used: Option<u64>,
     pub fs_inodes: Option<u64>,
-}
\ No newline at end of file
+
+    // Memory pressure
+    pub memory_available_bytes: Option<u64>,
+    pub memory_major_page_faults: Option<u64>,
+
+    // Swap
+    pub swap_usage_bytes: Option<u64>,
+    pub swap_available_bytes: Option<u64>,
+
+    // Processes (kubelet rlimit: current count and the PID limit)
+    pub process_count: Option<u64>,
+    pub process_limit: Option<u64>,
+
+    // Runtime filesystems (image layers; writable container layers, when on a separate disk)
+    pub image_fs_used_bytes: Option<u64>,
+    pub image_fs_capacity_bytes: Option<u64>,
+    pub image_fs_available_bytes: Option<u64>,
+    pub image_fs_inodes_used: Option<u64>,
+    pub image_fs_inodes: Option<u64>,
+    pub container_fs_used_bytes: Option<u64>,
+    pub container_fs_capacity_bytes: Option<u64>,
+
+    // GPU (DCGM exporter; GPUs attached x 1000 so averaged rows keep fractions)
+    pub gpu_milli_count: Option<u64>,
+    pub gpu_utilization_percent: Option<u64>,
+    pub gpu_memory_used_bytes: Option<u64>,
+    pub gpu_memory_total_bytes: Option<u64>,
+    pub gpu_power_milliwatts: Option<u64>,
+
+    // Rollup statistics (hour/day rows only)
+    pub cpu_usage_nano_cores_max: Option<u64>,
+    pub cpu_usage_nano_cores_min: Option<u64>,
+    pub cpu_usage_nano_cores_p95: Option<u64>,
+    pub cpu_usage_nano_cores_p99: Option<u64>,
+    pub memory_working_set_bytes_max: Option<u64>,
+    pub memory_working_set_bytes_min: Option<u64>,
+    pub memory_working_set_bytes_p95: Option<u64>,
+    pub memory_working_set_bytes_p99: Option<u64>,
+}
+
+impl MetricNodeEntity {
+    pub fn cpu_stats(&self) -> GaugeStats {
+        GaugeStats {
+            max: self.cpu_usage_nano_cores_max,
+            min: self.cpu_usage_nano_cores_min,
+            p95: self.cpu_usage_nano_cores_p95,
+            p99: self.cpu_usage_nano_cores_p99,
+        }
+    }
+
+    pub fn set_cpu_stats(&mut self, stats: GaugeStats) {
+        self.cpu_usage_nano_cores_max = stats.max;
+        self.cpu_usage_nano_cores_min = stats.min;
+        self.cpu_usage_nano_cores_p95 = stats.p95;
+        self.cpu_usage_nano_cores_p99 = stats.p99;
+    }
+
+    pub fn memory_working_set_stats(&self) -> GaugeStats {
+        GaugeStats {
+            max: self.memory_working_set_bytes_max,
+            min: self.memory_working_set_bytes_min,
+            p95: self.memory_working_set_bytes_p95,
+            p99: self.memory_working_set_bytes_p99,
+        }
+    }
+
+    pub fn set_memory_working_set_stats(&mut self, stats: GaugeStats) {
+        self.memory_working_set_bytes_max = stats.max;
+        self.memory_working_set_bytes_min = stats.min;
+        self.memory_working_set_bytes_p95 = stats.p95;
+        self.memory_working_set_bytes_p99 = stats.p99;
+    }
+
+    /// Copy of the row holding only `time` and the named column (plus its
+    /// rollup statistics for `CPU_USAGE_NANO_CORES` and `MEMORY_WORKING_SET_BYTES`).
+    /// An unknown column name returns the full row.
+    pub fn only_column(&self, column_name: &str) -> Self {
+        let mut row = MetricNodeEntity { time: self.time, ..Default::default() };
+        match column_name {
+            "CPU_USAGE_NANO_CORES" => {
+                row.cpu_usage_nano_cores = self.cpu_usage_nano_cores;
+                row.set_cpu_stats(self.cpu_stats());
+            }
+            "CPU_USAGE_CORE_NANO_SECONDS" => row.cpu_usage_core_nano_seconds = self.cpu_usage_core_nano_seconds,
+            "MEMORY_USAGE_BYTES" => row.memory_usage_bytes = self.memory_usage_bytes,
+            "MEMORY_WORKING_SET_BYTES" => {
+                row.memory_working_set_bytes = self.memory_working_set_bytes;
+                row.set_memory_working_set_stats(self.memory_working_set_stats());
+            }
+            "MEMORY_RSS_BYTES" => row.memory_rss_bytes = self.memory_rss_bytes,
+            "MEMORY_PAGE_FAULTS" => row.memory_page_faults = self.memory_page_faults,
+            "NETWORK_PHYSICAL_RX_BYTES" => row.network_physical_rx_bytes = self.network_physical_rx_bytes,
+            "NETWORK_PHYSICAL_TX_BYTES" => row.network_physical_tx_bytes = self.network_physical_tx_bytes,
+            "NETWORK_PHYSICAL_RX_ERRORS" => row.network_physical_rx_errors = self.network_physical_rx_errors,
+            "NETWORK_PHYSICAL_TX_ERRORS" => row.network_physical_tx_errors = self.network_physical_tx_errors,
+            "FS_USED_BYTES" => row.fs_used_bytes = self.fs_used_bytes,
+            "FS_CAPACITY_BYTES" => row.fs_capacity_bytes = self.fs_capacity_bytes,
+            "FS_INODES_USED" => row.fs_inodes_used = self.fs_inodes_used,
+            "FS_INODES" => row.fs_inodes = self.fs_inodes,
+            "MEMORY_AVAILABLE_BYTES" => row.memory_available_bytes = self.memory_available_bytes,
+            "MEMORY_MAJOR_PAGE_FAULTS" => row.memory_major_page_faults = self.memory_major_page_faults,
+            "SWAP_USAGE_BYTES" => row.swap_usage_bytes = self.swap_usage_bytes,
+            "SWAP_AVAILABLE_BYTES" => row.swap_available_bytes = self.swap_available_bytes,
+            "PROCESS_COUNT" => row.process_count = self.process_count,
+            "PROCESS_LIMIT" => row.process_limit = self.process_limit,
+            "IMAGE_FS_USED_BYTES" => row.image_fs_used_bytes = self.image_fs_used_bytes,
+            "IMAGE_FS_CAPACITY_BYTES" => row.image_fs_capacity_bytes = self.image_fs_capacity_bytes,
+            "IMAGE_FS_AVAILABLE_BYTES" => row.image_fs_available_bytes = self.image_fs_available_bytes,
+            "IMAGE_FS_INODES_USED" => row.image_fs_inodes_used = self.image_fs_inodes_used,
+            "IMAGE_FS_INODES" => row.image_fs_inodes = self.image_fs_inodes,
+            "CONTAINER_FS_USED_BYTES" => row.container_fs_used_bytes = self.container_fs_used_bytes,
+            "CONTAINER_FS_CAPACITY_BYTES" => row.container_fs_capacity_bytes = self.container_fs_capacity_bytes,
+            "GPU_MILLI_COUNT" => row.gpu_milli_count = self.gpu_milli_count,
+            "GPU_UTILIZATION_PERCENT" => row.gpu_utilization_percent = self.gpu_utilization_percent,
+            "GPU_MEMORY_USED_BYTES" => row.gpu_memory_used_bytes = self.gpu_memory_used_bytes,
+            "GPU_MEMORY_TOTAL_BYTES" => row.gpu_memory_total_bytes = self.gpu_memory_total_bytes,
+            "GPU_POWER_MILLIWATTS" => row.gpu_power_milliwatts = self.gpu_power_milliwatts,
+            _ => return self.clone(),
+        }
+        row
+    }
+}
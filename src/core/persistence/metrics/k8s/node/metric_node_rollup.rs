@@ -0,0 +1,192 @@
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::metrics::counter::{increase, sum_increases};
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
+use crate::core::persistence::metrics::rollup_stats::GaugeStats;
+use crate::core::persistence::metrics::sample_weight::{sample_weights, weighted_avg};
+
+impl MetricRollup for MetricNodeEntity {
+    fn rollup_minutes(rows: &[Self], end: DateTime<Utc>) -> Option<Self> {
+        if rows.is_empty() {
+            return None;
+        }
+
+        let last = rows.last().unwrap();
+
+        // Gauges are weighted by the time each sample covers; the scrape interval
+        // may be shorter than a minute or change inside the window.
+        let weights = sample_weights(rows.iter().map(|r| r.time));
+        let samples = |f: fn(&MetricNodeEntity) -> Option<u64>| {
+            rows.iter().zip(&weights).filter_map(move |(r, w)| f(r).map(|v| (v, *w)))
+        };
+        let avg = |f: fn(&MetricNodeEntity) -> Option<u64>| -> Option<u64> { weighted_avg(samples(f)) };
+
+        // Counters may reset inside the window (restarts); sum the monotonic runs.
+        let delta = |f: fn(&MetricNodeEntity) -> Option<u64>| -> Option<u64> {
+            increase(rows.iter().filter_map(f))
+        };
+
+        // PID exhaustion is about the peak; an average would hide it.
+        let peak = |f: fn(&MetricNodeEntity) -> Option<u64>| -> Option<u64> { rows.iter().filter_map(f).max() };
+
+        let mut aggregated = MetricNodeEntity {
+            time: end, // time marker = end of the aggregation window
+
+            // CPU
+            cpu_usage_nano_cores: avg(|r| r.cpu_usage_nano_cores),
+            cpu_usage_core_nano_seconds: delta(|r| r.cpu_usage_core_nano_seconds),
+
+            // Memory
+            memory_usage_bytes: avg(|r| r.memory_usage_bytes),
+            memory_working_set_bytes: avg(|r| r.memory_working_set_bytes),
+            memory_rss_bytes: avg(|r| r.memory_rss_bytes),
+            memory_page_faults: delta(|r| r.memory_page_faults),
+
+            // Network
+            network_physical_rx_bytes: delta(|r| r.network_physical_rx_bytes),
+            network_physical_tx_bytes: delta(|r| r.network_physical_tx_bytes),
+            network_physical_rx_errors: delta(|r| r.network_physical_rx_errors),
+            network_physical_tx_errors: delta(|r| r.network_physical_tx_errors),
+
+            // Filesystem
+            fs_used_bytes: avg(|r| r.fs_used_bytes),
+            fs_capacity_bytes: last.fs_capacity_bytes,
+            fs_inodes_used: avg(|r| r.fs_inodes_used),
+            fs_inodes: last.fs_inodes,
+
+            // Memory pressure
+            memory_available_bytes: avg(|r| r.memory_available_bytes),
+            memory_major_page_faults: delta(|r| r.memory_major_page_faults),
+
+            // Swap
+            swap_usage_bytes: avg(|r| r.swap_usage_bytes),
+            swap_available_bytes: avg(|r| r.swap_available_bytes),
+
+            // Processes
+            process_count: peak(|r| r.process_count),
+            process_limit: last.process_limit,
+
+            // Runtime filesystems
+            image_fs_used_bytes: avg(|r| r.image_fs_used_bytes),
+            image_fs_capacity_bytes: last.image_fs_capacity_bytes,
+            image_fs_available_bytes: avg(|r| r.image_fs_available_bytes),
+            image_fs_inodes_used: avg(|r| r.image_fs_inodes_used),
+            image_fs_inodes: last.image_fs_inodes,
+            container_fs_used_bytes: avg(|r| r.container_fs_used_bytes),
+            container_fs_capacity_bytes: last.container_fs_capacity_bytes,
+
+            // GPU
+            gpu_milli_count: avg(|r| r.gpu_milli_count),
+            gpu_utilization_percent: avg(|r| r.gpu_utilization_percent),
+            gpu_memory_used_bytes: avg(|r| r.gpu_memory_used_bytes),
+            gpu_memory_total_bytes: last.gpu_memory_total_bytes,
+            gpu_power_milliwatts: avg(|r| r.gpu_power_milliwatts),
+
+            ..Default::default()
+        };
+
+        // Peaks of the window, which the averages above smooth away
+        aggregated.set_cpu_stats(GaugeStats::from_samples(samples(|r| r.cpu_usage_nano_cores)));
+        aggregated.set_memory_working_set_stats(GaugeStats::from_samples(samples(|r| r.memory_working_set_bytes)));
+
+        Some(aggregated)
+    }
+
+    fn rollup_hours(rows: &[Self], minute_rows: &[Self], end: DateTime<Utc>) -> Option<Self> {
+        if rows.is_empty() {
+            return None;
+        }
+
+        let last = rows.last().unwrap();
+
+        // Every hour row covers one hour, so they weigh the same.
+        let avg = |f: fn(&MetricNodeEntity) -> Option<u64>| -> Option<u64> {
+            weighted_avg(rows.iter().filter_map(f).map(|v| (v, 1)))
+        };
+
+        // Hour rows already hold per-hour increases of counters; the day is their sum.
+        let sum = |f: fn(&MetricNodeEntity) -> Option<u64>| -> Option<u64> {
+            sum_increases(rows.iter().filter_map(f))
+        };
+
+        // Hour rows already hold their peak.
+        let peak = |f: fn(&MetricNodeEntity) -> Option<u64>| -> Option<u64> { rows.iter().filter_map(f).max() };
+
+        let mut aggregated = MetricNodeEntity {
+            time: end, // time marker = end of the aggregation window
+
+            // CPU
+            cpu_usage_nano_cores: avg(|r| r.cpu_usage_nano_cores),
+            cpu_usage_core_nano_seconds: sum(|r| r.cpu_usage_core_nano_seconds),
+
+            // Memory
+            memory_usage_bytes: avg(|r| r.memory_usage_bytes),
+            memory_working_set_bytes: avg(|r| r.memory_working_set_bytes),
+            memory_rss_bytes: avg(|r| r.memory_rss_bytes),
+            memory_page_faults: sum(|r| r.memory_page_faults),
+
+            // Network
+            network_physical_rx_bytes: sum(|r| r.network_physical_rx_bytes),
+            network_physical_tx_bytes: sum(|r| r.network_physical_tx_bytes),
+            network_physical_rx_errors: sum(|r| r.network_physical_rx_errors),
+            network_physical_tx_errors: sum(|r| r.network_physical_tx_errors),
+
+            // Filesystem
+            fs_used_bytes: avg(|r| r.fs_used_bytes),
+            fs_capacity_bytes: last.fs_capacity_bytes,
+            fs_inodes_used: avg(|r| r.fs_inodes_used),
+            fs_inodes: last.fs_inodes,
+
+            // Memory pressure
+            memory_available_bytes: avg(|r| r.memory_available_bytes),
+            memory_major_page_faults: sum(|r| r.memory_major_page_faults),
+
+            // Swap
+            swap_usage_bytes: avg(|r| r.swap_usage_bytes),
+            swap_available_bytes: avg(|r| r.swap_available_bytes),
+
+            // Processes
+            process_count: peak(|r| r.process_count),
+            process_limit: last.process_limit,
+
+            // Runtime filesystems
+            image_fs_used_bytes: avg(|r| r.image_fs_used_bytes),
+            image_fs_capacity_bytes: last.image_fs_capacity_bytes,
+            image_fs_available_bytes: avg(|r| r.image_fs_available_bytes),
+            image_fs_inodes_used: avg(|r| r.image_fs_inodes_used),
+            image_fs_inodes: last.image_fs_inodes,
+            container_fs_used_bytes: avg(|r| r.container_fs_used_bytes),
+            container_fs_capacity_bytes: last.container_fs_capacity_bytes,
+
+            // GPU
+            gpu_milli_count: avg(|r| r.gpu_milli_count),
+            gpu_utilization_percent: avg(|r| r.gpu_utilization_percent),
+            gpu_memory_used_bytes: avg(|r| r.gpu_memory_used_bytes),
+            gpu_memory_total_bytes: last.gpu_memory_total_bytes,
+            gpu_power_milliwatts: avg(|r| r.gpu_power_milliwatts),
+
+            ..Default::default()
+        };
+
+        // Peaks of the window. Percentiles are exact while minute samples of the
+        // day are still around; afterwards they are bounded from the hour rows.
+        if minute_rows.is_empty() {
+            aggregated.set_cpu_stats(GaugeStats::from_rollups(
+                rows.iter().map(|r| r.cpu_stats().or_value(r.cpu_usage_nano_cores)),
+            ));
+            aggregated.set_memory_working_set_stats(GaugeStats::from_rollups(
+                rows.iter().map(|r| r.memory_working_set_stats().or_value(r.memory_working_set_bytes)),
+            ));
+        } else {
+            let weights = sample_weights(minute_rows.iter().map(|r| r.time));
+            let samples = |f: fn(&MetricNodeEntity) -> Option<u64>| {
+                minute_rows.iter().zip(&weights).filter_map(move |(r, w)| f(r).map(|v| (v, *w)))
+            };
+            aggregated.set_cpu_stats(GaugeStats::from_samples(samples(|r| r.cpu_usage_nano_cores)));
+            aggregated.set_memory_working_set_stats(GaugeStats::from_samples(samples(|r| r.memory_working_set_bytes)));
+        }
+
+        Some(aggregated)
+    }
+}
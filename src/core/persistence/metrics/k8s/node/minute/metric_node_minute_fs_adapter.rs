@@ -3,10 +3,7 @@ use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNode
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, NaiveDate, Utc};
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    fs,
     path::Path,
 };
 use std::path::PathBuf;
@@ -14,6 +11,15 @@ use crate::core::persistence::metrics::k8s::path::{
     metric_k8s_node_key_minute_dir_path,
     metric_k8s_node_key_minute_file_path,
 };
+use crate::core::persistence::archive::metric_archive_fs_adapter::MetricArchiveFsAdapter;
+use crate::core::persistence::metrics::segment::compression::{segment_exists, segment_period};
+use crate::core::persistence::metrics::segment::format::{current_layout, LegacyHeader, SegmentLines};
+use crate::core::persistence::metrics::segment::index::{open_segment_from, remove_segment};
+use crate::core::persistence::metrics::segment::rewrite::upsert_segment_rows;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::metrics::segment::write_buffer;
+use std::collections::BTreeMap;
+use crate::core::persistence::archive::path::archive_k8s_node_key_minute_dir_path;
 
 /// Adapter for node minute-level metrics.
 /// Responsible for appending minute samples to the filesystem and cleaning up old data.
@@ -21,16 +27,36 @@ use crate::core::persistence::metrics::k8s::path::{
 pub struct MetricNodeMinuteFsAdapter;
 
 impl MetricNodeMinuteFsAdapter {
+    /// Columns of a row written before memory pressure and process columns were added.
+    const BASE_COLUMNS: usize = 15;
+
+    /// Column names in row order, to read segments that start with a legacy
+    /// named header.
+    const COLUMNS: &'static [&'static str] = &[
+        "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS", "MEMORY_USAGE_BYTES",
+        "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES", "MEMORY_PAGE_FAULTS",
+        "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES", "NETWORK_PHYSICAL_RX_ERRORS",
+        "NETWORK_PHYSICAL_TX_ERRORS", "FS_USED_BYTES", "FS_CAPACITY_BYTES", "FS_INODES_USED",
+        "FS_INODES", "MEMORY_AVAILABLE_BYTES", "MEMORY_MAJOR_PAGE_FAULTS", "SWAP_USAGE_BYTES",
+        "SWAP_AVAILABLE_BYTES", "PROCESS_COUNT", "PROCESS_LIMIT", "IMAGE_FS_USED_BYTES",
+        "IMAGE_FS_CAPACITY_BYTES", "IMAGE_FS_AVAILABLE_BYTES", "IMAGE_FS_INODES_USED",
+        "IMAGE_FS_INODES", "CONTAINER_FS_USED_BYTES", "CONTAINER_FS_CAPACITY_BYTES",
+        "GPU_MILLI_COUNT", "GPU_UTILIZATION_PERCENT", "GPU_MEMORY_USED_BYTES",
+        "GPU_MEMORY_TOTAL_BYTES", "GPU_POWER_MILLIWATTS",
+    ];
 
     fn build_path_for(&self, node_name: &str, date: NaiveDate) -> PathBuf {
         metric_k8s_node_key_minute_file_path(node_name, &date.format("%Y-%m-%d").to_string())
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricNodeEntity> {
+    fn parse_line(line: &str) -> Option<MetricNodeEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Columns are only ever appended, so older rows stop early and read the
+        // missing ones as `None`; trailing columns of newer rows are ignored.
+        if parts.len() < Self::BASE_COLUMNS {
             return None;
         }
+        let col = |i: usize| parts.get(i).and_then(|v| v.parse().ok());
 
         // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
         let time = parts[0].parse::<DateTime<Utc>>().ok()?;
@@ -50,6 +76,25 @@ impl MetricNodeMinuteFsAdapter {
             fs_capacity_bytes: parts[12].parse().ok(),
             fs_inodes_used: parts[13].parse().ok(),
             fs_inodes: parts[14].parse().ok(),
+            memory_available_bytes: col(15),
+            memory_major_page_faults: col(16),
+            swap_usage_bytes: col(17),
+            swap_available_bytes: col(18),
+            process_count: col(19),
+            process_limit: col(20),
+            image_fs_used_bytes: col(21),
+            image_fs_capacity_bytes: col(22),
+            image_fs_available_bytes: col(23),
+            image_fs_inodes_used: col(24),
+            image_fs_inodes: col(25),
+            container_fs_used_bytes: col(26),
+            container_fs_capacity_bytes: col(27),
+            gpu_milli_count: col(28),
+            gpu_utilization_percent: col(29),
+            gpu_memory_used_bytes: col(30),
+            gpu_memory_total_bytes: col(31),
+            gpu_power_milliwatts: col(32),
+            ..Default::default()
         })
     }
 
@@ -59,38 +104,24 @@ impl MetricNodeMinuteFsAdapter {
         start: DateTime<Utc>,
         end: DateTime<Utc>,
     ) -> Result<Vec<MetricNodeEntity>> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let mut lines = reader.lines();
+        let file = open_segment_from(path, start)?;
+        let mut lines = SegmentLines::new(file);
 
         // Try to read the first line
         let first_line = lines.next().ok_or_else(|| anyhow!("empty metric file"))??;
 
         let mut data: Vec<MetricNodeEntity> = vec![];
-        let header: Vec<&str>;
-
-        if first_line.starts_with("20") {
-            // no header present, implicit default order
-            header = vec![
-                "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS",
-                "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
-                "MEMORY_PAGE_FAULTS", "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES",
-                "NETWORK_PHYSICAL_RX_ERRORS", "NETWORK_PHYSICAL_TX_ERRORS",
-                "FS_USED_BYTES", "FS_CAPACITY_BYTES", "FS_INODES_USED", "FS_INODES"
-            ];
-
-            if let Some(row) = Self::parse_line(&header, &first_line) {
-                if row.time >= start && row.time <= end {
-                    data.push(row);
-                }
+
+        // Segments from before positional rows may start with a named header
+        let legacy = LegacyHeader::parse(&first_line, Self::COLUMNS);
+        if let Some(row) = Self::parse_line(&first_line) {
+            if row.time >= start && row.time <= end {
+                data.push(row);
             }
-        } else {
-            // first line is header
-            header = first_line.split('|').collect();
         }
 
         for line in lines.flatten() {
-            if let Some(row) = Self::parse_line(&header, &line) {
+            if let Some(row) = Self::parse_line(&current_layout(legacy.as_ref(), &line)) {
                 if row.time < start {
                     continue;
                 }
@@ -114,27 +145,10 @@ impl MetricNodeMinuteFsAdapter {
     fn opt(v: Option<u64>) -> String {
         v.map(|x| x.to_string()).unwrap_or_default()
     }
-}
-
-impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeMinuteFsAdapter {
-    fn append_row(&self, node: &str, dto: &MetricNodeEntity) -> Result<()> {
 
-        let now_date = Utc::now().date_naive();
-        let path_str = self.build_path_for(node, now_date);
-        let path = Path::new(&path_str);
-
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
-        // if new {
-        //     self.ensure_header(path, &mut file)?;
-        // }
-
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+    fn format_row(dto: &MetricNodeEntity) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -150,32 +164,88 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeMinuteFsAdapter {
             Self::opt(dto.fs_capacity_bytes),
             Self::opt(dto.fs_inodes_used),
             Self::opt(dto.fs_inodes),
-        );
-
-        file.write_all(row.as_bytes())?;
-        Ok(())
+            Self::opt(dto.memory_available_bytes),
+            Self::opt(dto.memory_major_page_faults),
+            Self::opt(dto.swap_usage_bytes),
+            Self::opt(dto.swap_available_bytes),
+            Self::opt(dto.process_count),
+            Self::opt(dto.process_limit),
+            Self::opt(dto.image_fs_used_bytes),
+            Self::opt(dto.image_fs_capacity_bytes),
+            Self::opt(dto.image_fs_available_bytes),
+            Self::opt(dto.image_fs_inodes_used),
+            Self::opt(dto.image_fs_inodes),
+            Self::opt(dto.container_fs_used_bytes),
+            Self::opt(dto.container_fs_capacity_bytes),
+            Self::opt(dto.gpu_milli_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
+            Self::opt(dto.gpu_memory_total_bytes),
+            Self::opt(dto.gpu_power_milliwatts),
+        )
     }
-    fn cleanup_old(&self, node: &str, before: DateTime<Utc>) -> Result<()> {
+
+    /// Minute files (`YYYY-MM-DD.rcd`) dated strictly before the cutoff day.
+    fn expired_files(&self, node: &str, before: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let mut expired = Vec::new();
         let dir = metric_k8s_node_key_minute_dir_path(node);
         if !dir.exists() {
-            return Ok(());
+            return Ok(expired);
         }
 
         for entry in fs::read_dir(&dir)? {
             let entry = entry?;
             let path = entry.path();
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") { continue; }
 
-            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+            if let Some(stem) = segment_period(&path) {
                 if let Ok(file_date) = NaiveDate::parse_from_str(stem, "%Y-%m-%d") {
                     if file_date < before.date_naive() {
-                        fs::remove_file(&path)
-                            .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
+                        expired.push(path);
                     }
                 }
             }
         }
 
+        Ok(expired)
+    }
+}
+
+impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeMinuteFsAdapter {
+    fn append_row(&self, node: &str, dto: &MetricNodeEntity) -> Result<()> {
+        let path = self.build_path_for(node, Utc::now().date_naive());
+
+        // Buffered; the handle stays open across ticks and the row is flushed in a batch
+        write_buffer::append_row(&path, MetricTier::Minute, dto.time, &Self::format_row(dto))
+    }
+    fn upsert_rows(&self, node: &str, rows: &[MetricNodeEntity]) -> Result<()> {
+        let mut by_file: BTreeMap<PathBuf, Vec<(DateTime<Utc>, String)>> = BTreeMap::new();
+        for row in rows {
+            let line = Self::format_row(row).trim_end_matches('\n').to_string();
+            by_file
+                .entry(self.build_path_for(node, row.time.date_naive()))
+                .or_default()
+                .push((row.time, line));
+        }
+
+        for (path, lines) in by_file {
+            upsert_segment_rows(&path, MetricTier::Minute, lines)?;
+        }
+        Ok(())
+    }
+
+    fn cleanup_old(&self, node: &str, before: DateTime<Utc>) -> Result<()> {
+        for path in self.expired_files(node, before)? {
+            remove_segment(&path)
+                .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
+        }
+        Ok(())
+    }
+
+    fn archive_old(&self, node: &str, before: DateTime<Utc>) -> Result<()> {
+        let archive_dir = archive_k8s_node_key_minute_dir_path(node);
+        for path in self.expired_files(node, before)? {
+            MetricArchiveFsAdapter.archive_file(&path, &archive_dir)?;
+        }
         Ok(())
     }
 
@@ -198,7 +268,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeMinuteFsAdapter {
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if path_obj.exists() {
+            if segment_exists(path_obj) {
                 // read file and collect relevant rows
                 if let Ok(mut rows) = self.read_file_between(&path_obj, start, end) {
                     data.append(&mut rows);
@@ -233,254 +303,6 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeMinuteFsAdapter {
         offset: Option<usize>,
     ) -> Result<Vec<MetricNodeEntity>> {
         let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricNodeEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_BYTES" => {
-                        let keep = row.network_physical_rx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_rx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_BYTES" => {
-                        let keep = row.network_physical_tx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_tx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_ERRORS" => {
-                        let keep = row.network_physical_rx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_rx_errors = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_ERRORS" => {
-                        let keep = row.network_physical_tx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.network_physical_tx_errors = keep;
-                    }
-                    "FS_USED_BYTES" => {
-                        let keep = row.fs_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_used_bytes = keep;
-                    }
-                    "FS_CAPACITY_BYTES" => {
-                        let keep = row.fs_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = None;
-                        row.fs_capacity_bytes = keep;
-                    }
-                    "FS_INODES_USED" => {
-                        let keep = row.fs_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes = None;
-                        row.fs_inodes_used = keep;
-                    }
-                    "FS_INODES" => {
-                        let keep = row.fs_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.fs_used_bytes = None;
-                        row.fs_capacity_bytes = None;
-                        row.fs_inodes_used = None;
-                        row.fs_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        Ok(rows.iter().map(|row| row.only_column(column_name)).collect())
     }
 }
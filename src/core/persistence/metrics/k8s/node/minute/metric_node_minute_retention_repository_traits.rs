@@ -2,6 +2,7 @@ use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAda
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
 
 /// Repository trait for reading node minute metrics (API layer).
 pub trait MetricNodeMinuteRetentionRepository: Send + Sync {
@@ -12,4 +13,17 @@ pub trait MetricNodeMinuteRetentionRepository: Send + Sync {
         self.fs_adapter().cleanup_old(node_name, before)
     }
 
+    /// Moves old metric files for the given node into the archive tree.
+    fn archive_old(&self, node_name: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().archive_old(node_name, before)
+    }
+
+    /// Retires old metric files according to the configured retention policy.
+    fn apply_retention(&self, node_name: &str, before: DateTime<Utc>, policy: MetricRetentionPolicy) -> Result<()> {
+        match policy {
+            MetricRetentionPolicy::Delete => self.cleanup_old(node_name, before),
+            MetricRetentionPolicy::Archive => self.archive_old(node_name, before),
+        }
+    }
+
 }
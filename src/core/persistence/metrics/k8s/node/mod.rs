@@ -1,4 +1,5 @@
 pub mod day;
 pub mod hour;
 pub mod minute;
-pub mod metric_node_entity;
\ No newline at end of file
+pub mod metric_node_entity;
+pub mod metric_node_rollup;
@@ -0,0 +1,31 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+/// Repository trait for reading node network interface day metrics (API layer).
+pub trait MetricNodeNetworkDayApiRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeNetworkEntity>;
+
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        interface_key: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricNodeNetworkEntity>> {
+        self.fs_adapter()
+            .get_column_between(column_name, start, end, interface_key, limit, offset)
+    }
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        interface_key: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricNodeNetworkEntity>>;
+}
+
@@ -0,0 +1,17 @@
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+
+/// Repository trait for aggregating node network interface day metrics (processor layer).
+pub trait MetricNodeNetworkDayProcessorRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeNetworkEntity>;
+
+    fn append_row_aggregated(&self, interface_key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<()>;
+
+    /// Aggregates each window and upserts the rows, so reprocessing a window is safe.
+    fn upsert_aggregated(&self, interface_key: &str, windows: &[(DateTime<Utc>, DateTime<Utc>)]) -> Result<usize> {
+        self.fs_adapter().upsert_aggregated(interface_key, windows)
+    }
+
+}
@@ -0,0 +1,310 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, NaiveDate, Datelike, Utc};
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+};
+use std::path::PathBuf;
+use crate::core::persistence::metrics::k8s::node_network::minute::metric_node_network_minute_fs_adapter::MetricNodeNetworkMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_node_network_key_hour_dir_path,
+    metric_k8s_node_network_key_hour_file_path,
+};
+use crate::core::persistence::archive::metric_archive_fs_adapter::MetricArchiveFsAdapter;
+use crate::core::persistence::metrics::segment::compression::{segment_exists, segment_period};
+use crate::core::persistence::metrics::segment::format::{encode_row, open_segment_for_append, SegmentLines};
+use crate::core::persistence::metrics::segment::index::{open_segment_from, record_append, remove_segment};
+use crate::core::persistence::metrics::segment::rewrite::upsert_segment_rows;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use std::collections::BTreeMap;
+use crate::core::persistence::archive::path::archive_k8s_node_network_key_hour_dir_path;
+
+/// Adapter for node network interface hour-level metrics.
+/// Responsible for appending hour samples to the filesystem and cleaning up old data.
+#[derive(Debug)]
+pub struct MetricNodeNetworkHourFsAdapter;
+
+impl MetricNodeNetworkHourFsAdapter {
+    fn build_path_for(&self, interface_key: &str, date: NaiveDate) -> PathBuf {
+        let month_str = date.format("%Y-%m").to_string();
+        metric_k8s_node_network_key_hour_file_path(interface_key, &month_str)
+    }
+
+    fn parse_line(header: &[&str], line: &str) -> Option<MetricNodeNetworkEntity> {
+        let parts: Vec<&str> = line.split('|').collect();
+        if parts.len() < header.len() {
+            return None;
+        }
+
+        // TIME|RX_BYTES|TX_BYTES|RX_ERRORS|TX_ERRORS
+        let time = parts[0].parse::<DateTime<Utc>>().ok()?;
+        Some(MetricNodeNetworkEntity {
+            time,
+            rx_bytes: parts[1].parse().ok(),
+            tx_bytes: parts[2].parse().ok(),
+            rx_errors: parts[3].parse().ok(),
+            tx_errors: parts[4].parse().ok(),
+        })
+    }
+
+    fn opt(v: Option<u64>) -> String {
+        v.map(|x| x.to_string()).unwrap_or_default()
+    }
+
+    fn format_row(dto: &MetricNodeNetworkEntity) -> String {
+        format!(
+            "{}|{}|{}|{}|{}
+",
+            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            Self::opt(dto.rx_bytes),
+            Self::opt(dto.tx_bytes),
+            Self::opt(dto.rx_errors),
+            Self::opt(dto.tx_errors),
+        )
+    }
+
+    /// Hour files (`YYYY-MM.rcd`) for months strictly before the cutoff month.
+    fn expired_files(&self, interface_key: &str, before: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let mut expired = Vec::new();
+        let dir = metric_k8s_node_network_key_hour_dir_path(interface_key);
+        if !dir.exists() {
+            return Ok(expired);
+        }
+
+        let before_month = NaiveDate::from_ymd_opt(before.year(), before.month(), 1)
+            .expect("valid before month date");
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if let Some(stem) = segment_period(&path) {
+                // Expect YYYY-MM
+                let parts: Vec<&str> = stem.split('-').collect();
+                if parts.len() == 2 {
+                    if let (Ok(y), Ok(m)) = (parts[0].parse::<i32>(), parts[1].parse::<u32>()) {
+                        if let Some(file_month) = NaiveDate::from_ymd_opt(y, m, 1) {
+                            if file_month < before_month {
+                                expired.push(path);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(expired)
+    }
+}
+
+impl MetricFsAdapterBase<MetricNodeNetworkEntity> for MetricNodeNetworkHourFsAdapter {
+    fn append_row(&self, interface_key: &str, dto: &MetricNodeNetworkEntity) -> Result<()> {
+        let now_date = Utc::now().date_naive();
+        let path_str = self.build_path_for(interface_key, now_date);
+        let path = Path::new(&path_str);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let (mut writer, offset) = open_segment_for_append(path)?;
+        let row = Self::format_row(dto);
+
+        // ✅ write to buffer
+        writer.write_all(encode_row(&row).as_bytes())?;
+
+        // ✅ ensure everything flushed to disk
+        writer.flush()?;
+
+        if let Err(e) = record_append(path, MetricTier::Hour, dto.time, offset) {
+            tracing::warn!("Failed to update index for {:?}: {}", path, e);
+        }
+        Ok(())
+    }
+
+    /// Aggregate minute-level metrics of one window into a single hour sample.
+    fn aggregate_between(
+        &self,
+        interface_key: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Option<MetricNodeNetworkEntity>> {
+        // --- 1️⃣ Load minute data
+        let minute_adapter = MetricNodeNetworkMinuteFsAdapter;
+        let rows = minute_adapter.get_row_between(start, end, interface_key, None, None)?;
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        // --- 2️⃣ Compute aggregates
+        Ok(MetricNodeNetworkEntity::rollup_minutes(&rows, end))
+    }
+
+    /// Aggregate minute-level metrics into an hour sample and append to hour file.
+    fn append_row_aggregated(
+        &self,
+        interface_key: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<()> {
+        let aggregated = self
+            .aggregate_between(interface_key, start, end)?
+            .ok_or_else(|| anyhow!("no minute data found for aggregation"))?;
+        self.append_row(interface_key, &aggregated)
+    }
+
+    fn upsert_rows(&self, interface_key: &str, rows: &[MetricNodeNetworkEntity]) -> Result<()> {
+        let mut by_file: BTreeMap<PathBuf, Vec<(DateTime<Utc>, String)>> = BTreeMap::new();
+        for row in rows {
+            let line = Self::format_row(row).trim_end_matches('\n').to_string();
+            by_file
+                .entry(self.build_path_for(interface_key, row.time.date_naive()))
+                .or_default()
+                .push((row.time, line));
+        }
+
+        for (path, lines) in by_file {
+            upsert_segment_rows(&path, MetricTier::Hour, lines)?;
+        }
+        Ok(())
+    }
+
+
+
+    fn cleanup_old(&self, interface_key: &str, before: DateTime<Utc>) -> Result<()> {
+        for path in self.expired_files(interface_key, before)? {
+            remove_segment(&path)
+                .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
+        }
+        Ok(())
+    }
+
+    fn archive_old(&self, interface_key: &str, before: DateTime<Utc>) -> Result<()> {
+        let archive_dir = archive_k8s_node_network_key_hour_dir_path(interface_key);
+        for path in self.expired_files(interface_key, before)? {
+            MetricArchiveFsAdapter.archive_file(&path, &archive_dir)?;
+        }
+        Ok(())
+    }
+
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricNodeNetworkEntity>> {
+        let rows = self.get_row_between(start, end, object_name, limit, offset)?;
+        Ok(rows.iter().map(|row| row.only_column(column_name)).collect())
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricNodeNetworkEntity>> {
+        use chrono::Months;
+
+        let mut all_rows = Vec::new();
+        let mut current_date = start.date_naive();
+        let end_date = end.date_naive();
+
+        // 1️⃣ Iterate over all months that might contain data
+        while current_date <= end_date {
+            let path = self.build_path_for(object_name, current_date);
+            let path_obj = Path::new(&path);
+
+            if !segment_exists(path_obj) {
+                tracing::debug!("Hour metrics file missing for {} on {}", object_name, current_date);
+                current_date = current_date.checked_add_months(Months::new(1)).unwrap_or(current_date);
+                continue;
+            }
+
+            let file = match open_segment_from(path_obj, start) {
+                Ok(f) => f,
+                Err(e) => {
+                    tracing::warn!("Cannot open {:?}: {}", path_obj, e);
+                    current_date = current_date.checked_add_months(Months::new(1)).unwrap_or(current_date);
+                    continue;
+                }
+            };
+
+            let mut lines = SegmentLines::new(file);
+
+            // Handle empty files
+            let first_line = match lines.next() {
+                Some(Ok(line)) if !line.trim().is_empty() => line,
+                _ => {
+                    tracing::debug!("Empty or invalid metric file {:?}", path_obj);
+                    current_date = current_date.checked_add_months(Months::new(1)).unwrap_or(current_date);
+                    continue;
+                }
+            };
+
+            let mut rows = Vec::new();
+            let header: Vec<&str>;
+
+            // 2️⃣ Handle header or first data line
+            if first_line.starts_with("20") {
+                // Default header assumption (timestamp-first)
+                header = vec![
+                    "TIME",
+                    "RX_BYTES",
+                    "TX_BYTES",
+                    "RX_ERRORS",
+                    "TX_ERRORS",
+                ];
+
+                if let Some(row) = Self::parse_line(&header, &first_line) {
+                    if row.time >= start && row.time <= end {
+                        rows.push(row);
+                    }
+                }
+            } else {
+                header = first_line.split('|').collect();
+            }
+
+            // 3️⃣ Process all remaining lines safely
+            for line_result in lines {
+                let line = match line_result {
+                    Ok(l) if !l.trim().is_empty() => l,
+                    _ => continue,
+                };
+
+                if let Some(row) = Self::parse_line(&header, &line) {
+                    if row.time < start {
+                        continue;
+                    }
+                    if row.time > end {
+                        break;
+                    }
+                    rows.push(row);
+                } else {
+                    tracing::warn!("Malformed line skipped in {:?}: {}", path_obj, line);
+                }
+            }
+
+            all_rows.extend(rows);
+            current_date = current_date.checked_add_months(Months::new(1)).unwrap_or(current_date);
+        }
+
+        // 4️⃣ Sort and apply pagination
+        all_rows.sort_by_key(|r| r.time);
+        let start_idx = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(all_rows.len());
+        let slice = all_rows.into_iter().skip(start_idx).take(limit).collect::<Vec<_>>();
+
+        Ok(slice)
+    }
+
+}
@@ -0,0 +1,4 @@
+pub mod metric_node_network_hour_fs_adapter;
+pub mod metric_node_network_hour_processor_repository_trait;
+pub mod metric_node_network_hour_retention_repository_traits;
+pub mod metric_node_network_hour_api_repository_trait;
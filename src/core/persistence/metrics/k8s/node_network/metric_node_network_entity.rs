@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Storage key of one interface of a node. Node names cannot contain `_`,
+/// so the key splits back at the first one.
+pub fn node_network_metric_key(node_name: &str, interface: &str) -> String {
+    format!("{}_{}", node_name, interface)
+}
+
+/// Linux interface names are at most 15 bytes without `/` or whitespace.
+/// Anything else is refused, since the name becomes a path component.
+pub fn is_valid_interface_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 15
+        && name != "."
+        && name != ".."
+        && name.chars().all(|c| c.is_ascii_graphic() && c != '/' && c != '\\')
+}
+
+/// Splits a key built by [`node_network_metric_key`] into node and interface.
+pub fn split_node_network_key(key: &str) -> Option<(&str, &str)> {
+    key.split_once('_')
+}
+
+/// Traffic counters of one node network interface, keyed by `<node>_<interface>`.
+///
+/// Minute rows hold the cumulative counters reported by the kubelet; hour and
+/// day rows hold the increase over their window, like the node totals.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetricNodeNetworkEntity {
+    pub time: DateTime<Utc>,
+
+    // Bytes
+    pub rx_bytes: Option<u64>,
+    pub tx_bytes: Option<u64>,
+
+    // Errors
+    pub rx_errors: Option<u64>,
+    pub tx_errors: Option<u64>,
+}
+
+impl MetricNodeNetworkEntity {
+    /// Copy of the row holding only `time` and the named column.
+    /// An unknown column name returns the full row.
+    pub fn only_column(&self, column_name: &str) -> Self {
+        let mut row = MetricNodeNetworkEntity { time: self.time, ..Default::default() };
+        match column_name {
+            "RX_BYTES" => row.rx_bytes = self.rx_bytes,
+            "TX_BYTES" => row.tx_bytes = self.tx_bytes,
+            "RX_ERRORS" => row.rx_errors = self.rx_errors,
+            "TX_ERRORS" => row.tx_errors = self.tx_errors,
+            _ => return self.clone(),
+        }
+        row
+    }
+}
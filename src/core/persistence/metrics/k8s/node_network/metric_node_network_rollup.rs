@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::metrics::counter::{increase, sum_increases};
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
+
+impl MetricRollup for MetricNodeNetworkEntity {
+    fn rollup_minutes(rows: &[Self], end: DateTime<Utc>) -> Option<Self> {
+        if rows.is_empty() {
+            return None;
+        }
+
+        // Counters may reset inside the window (node reboot, interface re-created);
+        // sum the monotonic runs.
+        let delta = |f: fn(&MetricNodeNetworkEntity) -> Option<u64>| -> Option<u64> {
+            increase(rows.iter().filter_map(f))
+        };
+
+        Some(MetricNodeNetworkEntity {
+            time: end, // time marker = end of the aggregation window
+            rx_bytes: delta(|r| r.rx_bytes),
+            tx_bytes: delta(|r| r.tx_bytes),
+            rx_errors: delta(|r| r.rx_errors),
+            tx_errors: delta(|r| r.tx_errors),
+        })
+    }
+
+    fn rollup_hours(rows: &[Self], _minute_rows: &[Self], end: DateTime<Utc>) -> Option<Self> {
+        if rows.is_empty() {
+            return None;
+        }
+
+        // Hour rows already hold per-hour increases; the day is their sum.
+        let sum = |f: fn(&MetricNodeNetworkEntity) -> Option<u64>| -> Option<u64> {
+            sum_increases(rows.iter().filter_map(f))
+        };
+
+        Some(MetricNodeNetworkEntity {
+            time: end, // time marker = end of the aggregation window
+            rx_bytes: sum(|r| r.rx_bytes),
+            tx_bytes: sum(|r| r.tx_bytes),
+            rx_errors: sum(|r| r.rx_errors),
+            tx_errors: sum(|r| r.tx_errors),
+        })
+    }
+}
@@ -0,0 +1,14 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use anyhow::Result;
+
+/// Repository trait for writing node network interface minute metrics (collector layer).
+pub trait MetricNodeNetworkMinuteCollectorRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeNetworkEntity>;
+
+    /// Inserts one metric sample for a given node network interface.
+    fn append_row(&self, interface_key: &str, data: &MetricNodeNetworkEntity) -> Result<()> {
+        self.fs_adapter().append_row(interface_key, data)
+    }
+
+}
@@ -0,0 +1,29 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
+
+/// Repository trait for node network interface minute metrics retention.
+pub trait MetricNodeNetworkMinuteRetentionRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeNetworkEntity>;
+
+    /// Deletes old metric files for the given node network interface before the cutoff timestamp.
+    fn cleanup_old(&self, interface_key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().cleanup_old(interface_key, before)
+    }
+
+    /// Moves old metric files for the given node network interface into the archive tree.
+    fn archive_old(&self, interface_key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().archive_old(interface_key, before)
+    }
+
+    /// Retires old metric files according to the configured retention policy.
+    fn apply_retention(&self, interface_key: &str, before: DateTime<Utc>, policy: MetricRetentionPolicy) -> Result<()> {
+        match policy {
+            MetricRetentionPolicy::Delete => self.cleanup_old(interface_key, before),
+            MetricRetentionPolicy::Archive => self.archive_old(interface_key, before),
+        }
+    }
+
+}
@@ -0,0 +1,5 @@
+pub mod minute;
+pub mod hour;
+pub mod day;
+pub mod metric_node_network_entity;
+pub mod metric_node_network_rollup;
@@ -0,0 +1,31 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+/// Repository trait for reading node system container day metrics (API layer).
+pub trait MetricNodeSystemDayApiRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeSystemEntity>;
+
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        system_key: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricNodeSystemEntity>> {
+        self.fs_adapter()
+            .get_column_between(column_name, start, end, system_key, limit, offset)
+    }
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        system_key: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricNodeSystemEntity>>;
+}
+
@@ -0,0 +1,312 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, NaiveDate, Datelike, Utc};
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+};
+use std::path::PathBuf;
+use crate::core::persistence::metrics::k8s::node_system::minute::metric_node_system_minute_fs_adapter::MetricNodeSystemMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_node_system_key_hour_dir_path,
+    metric_k8s_node_system_key_hour_file_path,
+};
+use crate::core::persistence::archive::metric_archive_fs_adapter::MetricArchiveFsAdapter;
+use crate::core::persistence::metrics::segment::compression::{segment_exists, segment_period};
+use crate::core::persistence::metrics::segment::format::{encode_row, open_segment_for_append, SegmentLines};
+use crate::core::persistence::metrics::segment::index::{open_segment_from, record_append, remove_segment};
+use crate::core::persistence::metrics::segment::rewrite::upsert_segment_rows;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use std::collections::BTreeMap;
+use crate::core::persistence::archive::path::archive_k8s_node_system_key_hour_dir_path;
+
+/// Adapter for node system container hour-level metrics.
+/// Responsible for appending hour samples to the filesystem and cleaning up old data.
+#[derive(Debug)]
+pub struct MetricNodeSystemHourFsAdapter;
+
+impl MetricNodeSystemHourFsAdapter {
+    fn build_path_for(&self, system_key: &str, date: NaiveDate) -> PathBuf {
+        let month_str = date.format("%Y-%m").to_string();
+        metric_k8s_node_system_key_hour_file_path(system_key, &month_str)
+    }
+
+    fn parse_line(header: &[&str], line: &str) -> Option<MetricNodeSystemEntity> {
+        let parts: Vec<&str> = line.split('|').collect();
+        if parts.len() < header.len() {
+            return None;
+        }
+
+        // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|MEMORY_USAGE_BYTES|MEMORY_WORKING_SET_BYTES|MEMORY_RSS_BYTES
+        let time = parts[0].parse::<DateTime<Utc>>().ok()?;
+        Some(MetricNodeSystemEntity {
+            time,
+            cpu_usage_nano_cores: parts[1].parse().ok(),
+            cpu_usage_core_nano_seconds: parts[2].parse().ok(),
+            memory_usage_bytes: parts[3].parse().ok(),
+            memory_working_set_bytes: parts[4].parse().ok(),
+            memory_rss_bytes: parts[5].parse().ok(),
+        })
+    }
+
+    fn opt(v: Option<u64>) -> String {
+        v.map(|x| x.to_string()).unwrap_or_default()
+    }
+
+    fn format_row(dto: &MetricNodeSystemEntity) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}\n",
+            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            Self::opt(dto.cpu_usage_nano_cores),
+            Self::opt(dto.cpu_usage_core_nano_seconds),
+            Self::opt(dto.memory_usage_bytes),
+            Self::opt(dto.memory_working_set_bytes),
+            Self::opt(dto.memory_rss_bytes),
+        )
+    }
+
+    /// Hour files (`YYYY-MM.rcd`) for months strictly before the cutoff month.
+    fn expired_files(&self, system_key: &str, before: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let mut expired = Vec::new();
+        let dir = metric_k8s_node_system_key_hour_dir_path(system_key);
+        if !dir.exists() {
+            return Ok(expired);
+        }
+
+        let before_month = NaiveDate::from_ymd_opt(before.year(), before.month(), 1)
+            .expect("valid before month date");
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if let Some(stem) = segment_period(&path) {
+                // Expect YYYY-MM
+                let parts: Vec<&str> = stem.split('-').collect();
+                if parts.len() == 2 {
+                    if let (Ok(y), Ok(m)) = (parts[0].parse::<i32>(), parts[1].parse::<u32>()) {
+                        if let Some(file_month) = NaiveDate::from_ymd_opt(y, m, 1) {
+                            if file_month < before_month {
+                                expired.push(path);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(expired)
+    }
+}
+
+impl MetricFsAdapterBase<MetricNodeSystemEntity> for MetricNodeSystemHourFsAdapter {
+    fn append_row(&self, system_key: &str, dto: &MetricNodeSystemEntity) -> Result<()> {
+        let now_date = Utc::now().date_naive();
+        let path_str = self.build_path_for(system_key, now_date);
+        let path = Path::new(&path_str);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let (mut writer, offset) = open_segment_for_append(path)?;
+        let row = Self::format_row(dto);
+
+        // ✅ write to buffer
+        writer.write_all(encode_row(&row).as_bytes())?;
+
+        // ✅ ensure everything flushed to disk
+        writer.flush()?;
+
+        if let Err(e) = record_append(path, MetricTier::Hour, dto.time, offset) {
+            tracing::warn!("Failed to update index for {:?}: {}", path, e);
+        }
+        Ok(())
+    }
+
+    /// Aggregate minute-level metrics of one window into a single hour sample.
+    fn aggregate_between(
+        &self,
+        system_key: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Option<MetricNodeSystemEntity>> {
+        // --- 1️⃣ Load minute data
+        let minute_adapter = MetricNodeSystemMinuteFsAdapter;
+        let rows = minute_adapter.get_row_between(start, end, system_key, None, None)?;
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        // --- 2️⃣ Compute aggregates
+        Ok(MetricNodeSystemEntity::rollup_minutes(&rows, end))
+    }
+
+    /// Aggregate minute-level metrics into an hour sample and append to hour file.
+    fn append_row_aggregated(
+        &self,
+        system_key: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<()> {
+        let aggregated = self
+            .aggregate_between(system_key, start, end)?
+            .ok_or_else(|| anyhow!("no minute data found for aggregation"))?;
+        self.append_row(system_key, &aggregated)
+    }
+
+    fn upsert_rows(&self, system_key: &str, rows: &[MetricNodeSystemEntity]) -> Result<()> {
+        let mut by_file: BTreeMap<PathBuf, Vec<(DateTime<Utc>, String)>> = BTreeMap::new();
+        for row in rows {
+            let line = Self::format_row(row).trim_end_matches('\n').to_string();
+            by_file
+                .entry(self.build_path_for(system_key, row.time.date_naive()))
+                .or_default()
+                .push((row.time, line));
+        }
+
+        for (path, lines) in by_file {
+            upsert_segment_rows(&path, MetricTier::Hour, lines)?;
+        }
+        Ok(())
+    }
+
+
+
+    fn cleanup_old(&self, system_key: &str, before: DateTime<Utc>) -> Result<()> {
+        for path in self.expired_files(system_key, before)? {
+            remove_segment(&path)
+                .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
+        }
+        Ok(())
+    }
+
+    fn archive_old(&self, system_key: &str, before: DateTime<Utc>) -> Result<()> {
+        let archive_dir = archive_k8s_node_system_key_hour_dir_path(system_key);
+        for path in self.expired_files(system_key, before)? {
+            MetricArchiveFsAdapter.archive_file(&path, &archive_dir)?;
+        }
+        Ok(())
+    }
+
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricNodeSystemEntity>> {
+        let rows = self.get_row_between(start, end, object_name, limit, offset)?;
+        Ok(rows.iter().map(|row| row.only_column(column_name)).collect())
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricNodeSystemEntity>> {
+        use chrono::Months;
+
+        let mut all_rows = Vec::new();
+        let mut current_date = start.date_naive();
+        let end_date = end.date_naive();
+
+        // 1️⃣ Iterate over all months that might contain data
+        while current_date <= end_date {
+            let path = self.build_path_for(object_name, current_date);
+            let path_obj = Path::new(&path);
+
+            if !segment_exists(path_obj) {
+                tracing::debug!("Hour metrics file missing for {} on {}", object_name, current_date);
+                current_date = current_date.checked_add_months(Months::new(1)).unwrap_or(current_date);
+                continue;
+            }
+
+            let file = match open_segment_from(path_obj, start) {
+                Ok(f) => f,
+                Err(e) => {
+                    tracing::warn!("Cannot open {:?}: {}", path_obj, e);
+                    current_date = current_date.checked_add_months(Months::new(1)).unwrap_or(current_date);
+                    continue;
+                }
+            };
+
+            let mut lines = SegmentLines::new(file);
+
+            // Handle empty files
+            let first_line = match lines.next() {
+                Some(Ok(line)) if !line.trim().is_empty() => line,
+                _ => {
+                    tracing::debug!("Empty or invalid metric file {:?}", path_obj);
+                    current_date = current_date.checked_add_months(Months::new(1)).unwrap_or(current_date);
+                    continue;
+                }
+            };
+
+            let mut rows = Vec::new();
+            let header: Vec<&str>;
+
+            // 2️⃣ Handle header or first data line
+            if first_line.starts_with("20") {
+                // Default header assumption (timestamp-first)
+                header = vec![
+                    "TIME",
+                    "CPU_USAGE_NANO_CORES",
+                    "CPU_USAGE_CORE_NANO_SECONDS",
+                    "MEMORY_USAGE_BYTES",
+                    "MEMORY_WORKING_SET_BYTES",
+                    "MEMORY_RSS_BYTES",
+                ];
+
+                if let Some(row) = Self::parse_line(&header, &first_line) {
+                    if row.time >= start && row.time <= end {
+                        rows.push(row);
+                    }
+                }
+            } else {
+                header = first_line.split('|').collect();
+            }
+
+            // 3️⃣ Process all remaining lines safely
+            for line_result in lines {
+                let line = match line_result {
+                    Ok(l) if !l.trim().is_empty() => l,
+                    _ => continue,
+                };
+
+                if let Some(row) = Self::parse_line(&header, &line) {
+                    if row.time < start {
+                        continue;
+                    }
+                    if row.time > end {
+                        break;
+                    }
+                    rows.push(row);
+                } else {
+                    tracing::warn!("Malformed line skipped in {:?}: {}", path_obj, line);
+                }
+            }
+
+            all_rows.extend(rows);
+            current_date = current_date.checked_add_months(Months::new(1)).unwrap_or(current_date);
+        }
+
+        // 4️⃣ Sort and apply pagination
+        all_rows.sort_by_key(|r| r.time);
+        let start_idx = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(all_rows.len());
+        let slice = all_rows.into_iter().skip(start_idx).take(limit).collect::<Vec<_>>();
+
+        Ok(slice)
+    }
+
+}
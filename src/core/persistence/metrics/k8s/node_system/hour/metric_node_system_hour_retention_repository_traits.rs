@@ -0,0 +1,28 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
+
+/// Repository trait for node system container hour metrics retention.
+pub trait MetricNodeSystemHourRetentionRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeSystemEntity>;
+
+    /// Deletes old metric files for the given node system container before the cutoff timestamp.
+    fn cleanup_old(&self, system_key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().cleanup_old(system_key, before)
+    }
+
+    /// Moves old metric files for the given node system container into the archive tree.
+    fn archive_old(&self, system_key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().archive_old(system_key, before)
+    }
+
+    /// Retires old metric files according to the configured retention policy.
+    fn apply_retention(&self, system_key: &str, before: DateTime<Utc>, policy: MetricRetentionPolicy) -> Result<()> {
+        match policy {
+            MetricRetentionPolicy::Delete => self.cleanup_old(system_key, before),
+            MetricRetentionPolicy::Archive => self.archive_old(system_key, before),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod metric_node_system_hour_fs_adapter;
+pub mod metric_node_system_hour_processor_repository_trait;
+pub mod metric_node_system_hour_retention_repository_traits;
+pub mod metric_node_system_hour_api_repository_trait;
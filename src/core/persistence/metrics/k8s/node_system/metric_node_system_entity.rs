@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Name of the system container holding the pod cgroups. Its usage is the
+/// workloads', already covered by the pod and container series.
+pub const PODS_SYSTEM_CONTAINER: &str = "pods";
+
+/// Storage key of one system container of a node. Node names cannot contain
+/// `_`, so the key splits back at the first one.
+pub fn node_system_metric_key(node_name: &str, container: &str) -> String {
+    format!("{}_{}", node_name, container)
+}
+
+/// The kubelet reports fixed names (`kubelet`, `runtime`, `pods`, `misc`).
+/// Anything else is refused, since the name becomes a path component.
+pub fn is_valid_system_container_name(name: &str) -> bool {
+    !name.is_empty() && name.len() <= 63 && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// Splits a key built by [`node_system_metric_key`] into node and container.
+pub fn split_node_system_key(key: &str) -> Option<(&str, &str)> {
+    key.split_once('_')
+}
+
+/// Usage of one node system container (kubelet, runtime, pods cgroup),
+/// keyed by `<node>_<container>`.
+///
+/// Gauges are time-weighted averages on hour and day rows; the cumulative CPU
+/// counter holds the increase over their window, like the node totals.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetricNodeSystemEntity {
+    pub time: DateTime<Utc>,
+
+    // CPU
+    pub cpu_usage_nano_cores: Option<u64>,
+    pub cpu_usage_core_nano_seconds: Option<u64>,
+
+    // Memory
+    pub memory_usage_bytes: Option<u64>,
+    pub memory_working_set_bytes: Option<u64>,
+    pub memory_rss_bytes: Option<u64>,
+}
+
+impl MetricNodeSystemEntity {
+    /// Copy of the row holding only `time` and the named column.
+    /// An unknown column name returns the full row.
+    pub fn only_column(&self, column_name: &str) -> Self {
+        let mut row = MetricNodeSystemEntity { time: self.time, ..Default::default() };
+        match column_name {
+            "CPU_USAGE_NANO_CORES" => row.cpu_usage_nano_cores = self.cpu_usage_nano_cores,
+            "CPU_USAGE_CORE_NANO_SECONDS" => row.cpu_usage_core_nano_seconds = self.cpu_usage_core_nano_seconds,
+            "MEMORY_USAGE_BYTES" => row.memory_usage_bytes = self.memory_usage_bytes,
+            "MEMORY_WORKING_SET_BYTES" => row.memory_working_set_bytes = self.memory_working_set_bytes,
+            "MEMORY_RSS_BYTES" => row.memory_rss_bytes = self.memory_rss_bytes,
+            _ => return self.clone(),
+        }
+        row
+    }
+}
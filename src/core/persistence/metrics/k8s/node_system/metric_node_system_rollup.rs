@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::metrics::counter::{increase, sum_increases};
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
+use crate::core::persistence::metrics::sample_weight::{sample_weights, weighted_avg};
+
+impl MetricRollup for MetricNodeSystemEntity {
+    fn rollup_minutes(rows: &[Self], end: DateTime<Utc>) -> Option<Self> {
+        if rows.is_empty() {
+            return None;
+        }
+
+        // Gauges are weighted by the time each sample covers, like the node totals.
+        let weights = sample_weights(rows.iter().map(|r| r.time));
+        let avg = |f: fn(&MetricNodeSystemEntity) -> Option<u64>| -> Option<u64> {
+            weighted_avg(rows.iter().zip(&weights).filter_map(|(r, w)| f(r).map(|v| (v, *w))))
+        };
+
+        // The CPU counter resets when the kubelet or runtime restarts; sum the monotonic runs.
+        let delta = |f: fn(&MetricNodeSystemEntity) -> Option<u64>| -> Option<u64> {
+            increase(rows.iter().filter_map(f))
+        };
+
+        Some(MetricNodeSystemEntity {
+            time: end, // time marker = end of the aggregation window
+            cpu_usage_nano_cores: avg(|r| r.cpu_usage_nano_cores),
+            cpu_usage_core_nano_seconds: delta(|r| r.cpu_usage_core_nano_seconds),
+            memory_usage_bytes: avg(|r| r.memory_usage_bytes),
+            memory_working_set_bytes: avg(|r| r.memory_working_set_bytes),
+            memory_rss_bytes: avg(|r| r.memory_rss_bytes),
+        })
+    }
+
+    fn rollup_hours(rows: &[Self], _minute_rows: &[Self], end: DateTime<Utc>) -> Option<Self> {
+        if rows.is_empty() {
+            return None;
+        }
+
+        // Every hour row covers one hour, so they weigh the same.
+        let avg = |f: fn(&MetricNodeSystemEntity) -> Option<u64>| -> Option<u64> {
+            weighted_avg(rows.iter().filter_map(f).map(|v| (v, 1)))
+        };
+
+        // Hour rows already hold per-hour increases of the counter; the day is their sum.
+        let sum = |f: fn(&MetricNodeSystemEntity) -> Option<u64>| -> Option<u64> {
+            sum_increases(rows.iter().filter_map(f))
+        };
+
+        Some(MetricNodeSystemEntity {
+            time: end, // time marker = end of the aggregation window
+            cpu_usage_nano_cores: avg(|r| r.cpu_usage_nano_cores),
+            cpu_usage_core_nano_seconds: sum(|r| r.cpu_usage_core_nano_seconds),
+            memory_usage_bytes: avg(|r| r.memory_usage_bytes),
+            memory_working_set_bytes: avg(|r| r.memory_working_set_bytes),
+            memory_rss_bytes: avg(|r| r.memory_rss_bytes),
+        })
+    }
+}
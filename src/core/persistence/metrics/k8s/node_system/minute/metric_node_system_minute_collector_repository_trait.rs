@@ -0,0 +1,14 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use anyhow::Result;
+
+/// Repository trait for writing node system container minute metrics (collector layer).
+pub trait MetricNodeSystemMinuteCollectorRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeSystemEntity>;
+
+    /// Inserts one metric sample for a given node system container.
+    fn append_row(&self, system_key: &str, data: &MetricNodeSystemEntity) -> Result<()> {
+        self.fs_adapter().append_row(system_key, data)
+    }
+
+}
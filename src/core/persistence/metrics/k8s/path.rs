@@ -105,3 +105,102 @@ pub fn metric_k8s_container_key_minute_file_path(key: &str, yyyy_mm_dd: &str) ->
     metric_k8s_container_key_minute_dir_path(key).join(format!("{}.rcd", yyyy_mm_dd))
 }
 
+
+// --- PVC ---
+pub fn metric_k8s_pvc_dir_path() -> PathBuf {
+    k8s_root().join("pvc")
+}
+
+pub fn metric_k8s_pvc_key_dir_path(key: &str) -> PathBuf {
+    metric_k8s_pvc_dir_path().join(key)
+}
+
+pub fn metric_k8s_pvc_key_day_dir_path(key: &str) -> PathBuf {
+    metric_k8s_pvc_key_dir_path(key).join("d")
+}
+
+pub fn metric_k8s_pvc_key_hour_dir_path(key: &str) -> PathBuf {
+    metric_k8s_pvc_key_dir_path(key).join("h")
+}
+
+pub fn metric_k8s_pvc_key_minute_dir_path(key: &str) -> PathBuf {
+    metric_k8s_pvc_key_dir_path(key).join("m")
+}
+
+pub fn metric_k8s_pvc_key_day_file_path(key: &str, yyyy: &str) -> PathBuf {
+    metric_k8s_pvc_key_day_dir_path(key).join(format!("{}.rcd", yyyy))
+}
+
+pub fn metric_k8s_pvc_key_hour_file_path(key: &str, yyyy_mm: &str) -> PathBuf {
+    metric_k8s_pvc_key_hour_dir_path(key).join(format!("{}.rcd", yyyy_mm))
+}
+
+pub fn metric_k8s_pvc_key_minute_file_path(key: &str, yyyy_mm_dd: &str) -> PathBuf {
+    metric_k8s_pvc_key_minute_dir_path(key).join(format!("{}.rcd", yyyy_mm_dd))
+}
+
+// --- Node network interfaces ---
+pub fn metric_k8s_node_network_dir_path() -> PathBuf {
+    k8s_root().join("node_network")
+}
+
+pub fn metric_k8s_node_network_key_dir_path(key: &str) -> PathBuf {
+    metric_k8s_node_network_dir_path().join(key)
+}
+
+pub fn metric_k8s_node_network_key_day_dir_path(key: &str) -> PathBuf {
+    metric_k8s_node_network_key_dir_path(key).join("d")
+}
+
+pub fn metric_k8s_node_network_key_hour_dir_path(key: &str) -> PathBuf {
+    metric_k8s_node_network_key_dir_path(key).join("h")
+}
+
+pub fn metric_k8s_node_network_key_minute_dir_path(key: &str) -> PathBuf {
+    metric_k8s_node_network_key_dir_path(key).join("m")
+}
+
+pub fn metric_k8s_node_network_key_day_file_path(key: &str, yyyy: &str) -> PathBuf {
+    metric_k8s_node_network_key_day_dir_path(key).join(format!("{}.rcd", yyyy))
+}
+
+pub fn metric_k8s_node_network_key_hour_file_path(key: &str, yyyy_mm: &str) -> PathBuf {
+    metric_k8s_node_network_key_hour_dir_path(key).join(format!("{}.rcd", yyyy_mm))
+}
+
+pub fn metric_k8s_node_network_key_minute_file_path(key: &str, yyyy_mm_dd: &str) -> PathBuf {
+    metric_k8s_node_network_key_minute_dir_path(key).join(format!("{}.rcd", yyyy_mm_dd))
+}
+
+// --- Node system containers ---
+pub fn metric_k8s_node_system_dir_path() -> PathBuf {
+    k8s_root().join("node_system")
+}
+
+pub fn metric_k8s_node_system_key_dir_path(key: &str) -> PathBuf {
+    metric_k8s_node_system_dir_path().join(key)
+}
+
+pub fn metric_k8s_node_system_key_day_dir_path(key: &str) -> PathBuf {
+    metric_k8s_node_system_key_dir_path(key).join("d")
+}
+
+pub fn metric_k8s_node_system_key_hour_dir_path(key: &str) -> PathBuf {
+    metric_k8s_node_system_key_dir_path(key).join("h")
+}
+
+pub fn metric_k8s_node_system_key_minute_dir_path(key: &str) -> PathBuf {
+    metric_k8s_node_system_key_dir_path(key).join("m")
+}
+
+pub fn metric_k8s_node_system_key_day_file_path(key: &str, yyyy: &str) -> PathBuf {
+    metric_k8s_node_system_key_day_dir_path(key).join(format!("{}.rcd", yyyy))
+}
+
+pub fn metric_k8s_node_system_key_hour_file_path(key: &str, yyyy_mm: &str) -> PathBuf {
+    metric_k8s_node_system_key_hour_dir_path(key).join(format!("{}.rcd", yyyy_mm))
+}
+
+pub fn metric_k8s_node_system_key_minute_file_path(key: &str, yyyy_mm_dd: &str) -> PathBuf {
+    metric_k8s_node_system_key_minute_dir_path(key).join(format!("{}.rcd", yyyy_mm_dd))
+}
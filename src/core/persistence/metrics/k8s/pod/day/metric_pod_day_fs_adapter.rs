@@ -1,13 +1,11 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Datelike, Utc};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
+    fs,
     io::Write,
-    io::{BufRead, BufReader},
     path::Path,
 };
 use std::path::PathBuf;
@@ -16,6 +14,17 @@ use crate::core::persistence::metrics::k8s::path::{
     metric_k8s_pod_key_day_dir_path,
     metric_k8s_pod_key_day_file_path,
 };
+use crate::core::persistence::archive::metric_archive_fs_adapter::MetricArchiveFsAdapter;
+use crate::core::persistence::metrics::segment::compression::{segment_exists, segment_period};
+use crate::core::persistence::metrics::segment::format::{
+    current_layout, encode_row, open_segment_for_append, LegacyHeader, SegmentLines,
+};
+use crate::core::persistence::metrics::segment::index::{open_segment_from, record_append, remove_segment};
+use crate::core::persistence::metrics::segment::rewrite::upsert_segment_rows;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_adapter::MetricPodMinuteFsAdapter;
+use std::collections::BTreeMap;
+use crate::core::persistence::archive::path::archive_k8s_pod_key_day_dir_path;
 
 /// Adapter for pod hour-level metrics.
 /// Responsible for appending hour samples to the filesystem and cleaning up old data.
@@ -23,16 +32,39 @@ use crate::core::persistence::metrics::k8s::path::{
 pub struct MetricPodDayFsAdapter;
 
 impl MetricPodDayFsAdapter {
+    /// Columns of a row written before rollup statistics were added.
+    const BASE_COLUMNS: usize = 19;
+
+    /// Column names in row order, to read segments that start with a legacy
+    /// named header.
+    const COLUMNS: &'static [&'static str] = &[
+        "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS", "MEMORY_USAGE_BYTES",
+        "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES", "MEMORY_PAGE_FAULTS",
+        "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES", "NETWORK_PHYSICAL_RX_ERRORS",
+        "NETWORK_PHYSICAL_TX_ERRORS", "ES_USED_BYTES", "ES_CAPACITY_BYTES", "ES_INODES_USED",
+        "ES_INODES", "PV_USED_BYTES", "PV_CAPACITY_BYTES", "PV_INODES_USED", "PV_INODES",
+        "CPU_USAGE_NANO_CORES_MAX", "CPU_USAGE_NANO_CORES_MIN", "CPU_USAGE_NANO_CORES_P95",
+        "CPU_USAGE_NANO_CORES_P99", "MEMORY_WORKING_SET_BYTES_MAX", "MEMORY_WORKING_SET_BYTES_MIN",
+        "MEMORY_WORKING_SET_BYTES_P95", "MEMORY_WORKING_SET_BYTES_P99", "MEMORY_AVAILABLE_BYTES",
+        "MEMORY_MAJOR_PAGE_FAULTS", "SWAP_USAGE_BYTES", "SWAP_AVAILABLE_BYTES", "PROCESS_COUNT",
+        "CPU_CFS_PERIODS", "CPU_CFS_THROTTLED_PERIODS", "CPU_CFS_THROTTLED_NANO_SECONDS",
+        "GPU_MILLI_COUNT", "GPU_UTILIZATION_PERCENT", "GPU_MEMORY_USED_BYTES",
+        "GPU_MEMORY_TOTAL_BYTES", "GPU_POWER_MILLIWATTS",
+    ];
+
     fn build_path_for(&self, pod_uid: &str, date: chrono::NaiveDate) -> PathBuf {
         let year_str = date.format("%Y").to_string();
         metric_k8s_pod_key_day_file_path(pod_uid, &year_str)
     }
 
-    fn parse_line(header: &[&str], line: &str) -> Option<MetricPodEntity> {
+    fn parse_line(line: &str) -> Option<MetricPodEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Columns are only ever appended, so older rows stop early and read the
+        // missing ones as `None`; trailing columns of newer rows are ignored.
+        if parts.len() < Self::BASE_COLUMNS {
             return None;
         }
+        let col = |i: usize| parts.get(i).and_then(|v| v.parse().ok());
 
         // TIME|CPU_USAGE_NANO_CORES|CPU_USAGE_CORE_NANO_SECONDS|... etc.
         let time = parts[0].parse::<DateTime<Utc>>().ok()?;
@@ -56,6 +88,27 @@ impl MetricPodDayFsAdapter {
             pv_capacity_bytes: parts[16].parse().ok(),
             pv_inodes_used: parts[17].parse().ok(),
             pv_inodes: parts[18].parse().ok(),
+            cpu_usage_nano_cores_max: col(19),
+            cpu_usage_nano_cores_min: col(20),
+            cpu_usage_nano_cores_p95: col(21),
+            cpu_usage_nano_cores_p99: col(22),
+            memory_working_set_bytes_max: col(23),
+            memory_working_set_bytes_min: col(24),
+            memory_working_set_bytes_p95: col(25),
+            memory_working_set_bytes_p99: col(26),
+            memory_available_bytes: col(27),
+            memory_major_page_faults: col(28),
+            swap_usage_bytes: col(29),
+            swap_available_bytes: col(30),
+            process_count: col(31),
+            cpu_cfs_periods: col(32),
+            cpu_cfs_throttled_periods: col(33),
+            cpu_cfs_throttled_nano_seconds: col(34),
+            gpu_milli_count: col(35),
+            gpu_utilization_percent: col(36),
+            gpu_memory_used_bytes: col(37),
+            gpu_memory_total_bytes: col(38),
+            gpu_power_milliwatts: col(39),
         })
     }
 
@@ -71,6 +124,75 @@ impl MetricPodDayFsAdapter {
     fn opt(v: Option<u64>) -> String {
         v.map(|x| x.to_string()).unwrap_or_default()
     }
+
+    fn format_row(dto: &MetricPodEntity) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            Self::opt(dto.cpu_usage_nano_cores),
+            Self::opt(dto.cpu_usage_core_nano_seconds),
+            Self::opt(dto.memory_usage_bytes),
+            Self::opt(dto.memory_working_set_bytes),
+            Self::opt(dto.memory_rss_bytes),
+            Self::opt(dto.memory_page_faults),
+            Self::opt(dto.network_physical_rx_bytes),
+            Self::opt(dto.network_physical_tx_bytes),
+            Self::opt(dto.network_physical_rx_errors),
+            Self::opt(dto.network_physical_tx_errors),
+            Self::opt(dto.es_used_bytes),
+            Self::opt(dto.es_capacity_bytes),
+            Self::opt(dto.es_inodes_used),
+            Self::opt(dto.es_inodes),
+            Self::opt(dto.pv_used_bytes),
+            Self::opt(dto.pv_capacity_bytes),
+            Self::opt(dto.pv_inodes_used),
+            Self::opt(dto.pv_inodes),
+            Self::opt(dto.cpu_usage_nano_cores_max),
+            Self::opt(dto.cpu_usage_nano_cores_min),
+            Self::opt(dto.cpu_usage_nano_cores_p95),
+            Self::opt(dto.cpu_usage_nano_cores_p99),
+            Self::opt(dto.memory_working_set_bytes_max),
+            Self::opt(dto.memory_working_set_bytes_min),
+            Self::opt(dto.memory_working_set_bytes_p95),
+            Self::opt(dto.memory_working_set_bytes_p99),
+            Self::opt(dto.memory_available_bytes),
+            Self::opt(dto.memory_major_page_faults),
+            Self::opt(dto.swap_usage_bytes),
+            Self::opt(dto.swap_available_bytes),
+            Self::opt(dto.process_count),
+            Self::opt(dto.cpu_cfs_periods),
+            Self::opt(dto.cpu_cfs_throttled_periods),
+            Self::opt(dto.cpu_cfs_throttled_nano_seconds),
+            Self::opt(dto.gpu_milli_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
+            Self::opt(dto.gpu_memory_total_bytes),
+            Self::opt(dto.gpu_power_milliwatts),
+        )
+    }
+
+    /// Day files (`YYYY.rcd`) for years strictly before the cutoff year.
+    fn expired_files(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let mut expired = Vec::new();
+        let dir = metric_k8s_pod_key_day_dir_path(pod_uid);
+        if !dir.exists() { return Ok(expired); }
+
+        let cutoff_year = before.year();
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if let Some(stem) = segment_period(&path) {
+                if let Ok(year) = stem.parse::<i32>() {
+                    if year < cutoff_year {
+                        expired.push(path);
+                    }
+                }
+            }
+        }
+
+        Ok(expired)
+    }
 }
 
 impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
@@ -86,11 +208,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
         // let new = !path.exists();
 
         // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
+        let (mut writer, offset) = open_segment_for_append(path)?;
 
         // Write header if file newly created
         // if new {
@@ -98,134 +216,88 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
         // }
 
         // Format the row
-        let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
-            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
-            Self::opt(dto.cpu_usage_nano_cores),
-            Self::opt(dto.cpu_usage_core_nano_seconds),
-            Self::opt(dto.memory_usage_bytes),
-            Self::opt(dto.memory_working_set_bytes),
-            Self::opt(dto.memory_rss_bytes),
-            Self::opt(dto.memory_page_faults),
-            Self::opt(dto.network_physical_rx_bytes),
-            Self::opt(dto.network_physical_tx_bytes),
-            Self::opt(dto.network_physical_rx_errors),
-            Self::opt(dto.network_physical_tx_errors),
-            Self::opt(dto.es_used_bytes),
-            Self::opt(dto.es_capacity_bytes),
-            Self::opt(dto.es_inodes_used),
-            Self::opt(dto.es_inodes),
-            Self::opt(dto.pv_used_bytes),
-            Self::opt(dto.pv_capacity_bytes),
-            Self::opt(dto.pv_inodes_used),
-            Self::opt(dto.pv_inodes),
-        );
+        let row = Self::format_row(dto);
 
 
         // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
+        writer.write_all(encode_row(&row).as_bytes())?;
 
         // ✅ ensure everything flushed to disk
         writer.flush()?;
+
+        if let Err(e) = record_append(path, MetricTier::Day, dto.time, offset) {
+            tracing::warn!("Failed to update index for {:?}: {}", path, e);
+        }
         Ok(())
     }
 
-    /// Aggregate hour-level metrics into an dayly sample and append to day file.
-    fn append_row_aggregated(
+    /// Aggregate hour-level metrics of one window into a single day sample.
+    fn aggregate_between(
         &self,
         pod_uid: &str,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
-    ) -> Result<()> {
+    ) -> Result<Option<MetricPodEntity>> {
         // --- 1️⃣ Load hour data
         let hour_adapter = MetricPodHourFsAdapter;
-        let rows = hour_adapter.get_row_between(start, end, pod_uid, None, None)?;
+        let mut rows = hour_adapter.get_row_between(start, end, pod_uid, None, None)?;
+        // Hour rows are stamped with the end of their hour; the one at `start`
+        // closes the previous day and must not be counted twice.
+        rows.retain(|r| r.time > start);
 
         if rows.is_empty() {
-            return Err(anyhow!("no hour data found for aggregation"));
+            return Ok(None);
         }
 
         // --- 2️⃣ Compute aggregates
-        let first = rows.first().unwrap();
-        let last = rows.last().unwrap();
-
-        let avg = |f: fn(&MetricPodEntity) -> Option<u64>| -> Option<u64> {
-            let (sum, count): (u64, u64) =
-                rows.iter().filter_map(f).fold((0, 0), |(s, c), v| (s + v, c + 1));
-            if count > 0 {
-                Some(sum / count)
-            } else {
-                None
-            }
-        };
+        let minute_rows = MetricPodMinuteFsAdapter.get_row_between(start, end, pod_uid, None, None)?;
+        Ok(MetricPodEntity::rollup_hours(&rows, &minute_rows, end))
+    }
 
-        let delta = |f: fn(&MetricPodEntity) -> Option<u64>| -> Option<u64> {
-            match (f(first), f(last)) {
-                (Some(a), Some(b)) if b >= a => Some(b - a),
-                _ => None,
-            }
-        };
-
-        let aggregated = MetricPodEntity {
-            time: end, // time marker = end of the aggregation window
-
-            // CPU
-            cpu_usage_nano_cores: avg(|r| r.cpu_usage_nano_cores),
-            cpu_usage_core_nano_seconds: delta(|r| r.cpu_usage_core_nano_seconds),
-
-            // Memory
-            memory_usage_bytes: avg(|r| r.memory_usage_bytes),
-            memory_working_set_bytes: avg(|r| r.memory_working_set_bytes),
-            memory_rss_bytes: avg(|r| r.memory_rss_bytes),
-            memory_page_faults: delta(|r| r.memory_page_faults),
-
-            // Network
-            network_physical_rx_bytes: delta(|r| r.network_physical_rx_bytes),
-            network_physical_tx_bytes: delta(|r| r.network_physical_tx_bytes),
-            network_physical_rx_errors: delta(|r| r.network_physical_rx_errors),
-            network_physical_tx_errors: delta(|r| r.network_physical_tx_errors),
-
-            // Ephemeral storage
-            es_used_bytes: avg(|r| r.es_used_bytes),
-            es_capacity_bytes: last.es_capacity_bytes,
-            es_inodes_used: avg(|r| r.es_inodes_used),
-            es_inodes: last.es_inodes,
-
-            // Persistent storage
-            pv_used_bytes: avg(|r| r.pv_used_bytes),
-            pv_capacity_bytes: last.pv_capacity_bytes,
-            pv_inodes_used: avg(|r| r.pv_inodes_used),
-            pv_inodes: last.pv_inodes,
-        };
-
-        // --- 3️⃣ Append the aggregated row into the day-level file
-        self.append_row(pod_uid, &aggregated)?;
+    /// Aggregate hour-level metrics into an dayly sample and append to day file.
+    fn append_row_aggregated(
+        &self,
+        pod_uid: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<()> {
+        let aggregated = self
+            .aggregate_between(pod_uid, start, end)?
+            .ok_or_else(|| anyhow!("no hour data found for aggregation"))?;
+        self.append_row(pod_uid, &aggregated)
+    }
+
+    fn upsert_rows(&self, pod_uid: &str, rows: &[MetricPodEntity]) -> Result<()> {
+        let mut by_file: BTreeMap<PathBuf, Vec<(DateTime<Utc>, String)>> = BTreeMap::new();
+        for row in rows {
+            let line = Self::format_row(row).trim_end_matches('\n').to_string();
+            by_file
+                .entry(self.build_path_for(pod_uid, row.time.date_naive()))
+                .or_default()
+                .push((row.time, line));
+        }
 
+        for (path, lines) in by_file {
+            upsert_segment_rows(&path, MetricTier::Day, lines)?;
+        }
         Ok(())
     }
 
 
 
     fn cleanup_old(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<()> {
-        let dir = metric_k8s_pod_key_day_dir_path(pod_uid);
-        if !dir.exists() { return Ok(()); }
-
-        let cutoff_year = before.year();
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") { continue; }
-
-            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                if let Ok(year) = stem.parse::<i32>() {
-                    if year < cutoff_year {
-                        fs::remove_file(&path)
-                            .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
-                    }
-                }
-            }
+        for path in self.expired_files(pod_uid, before)? {
+            remove_segment(&path)
+                .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
         }
+        Ok(())
+    }
 
+    fn archive_old(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<()> {
+        let archive_dir = archive_k8s_pod_key_day_dir_path(pod_uid);
+        for path in self.expired_files(pod_uid, before)? {
+            MetricArchiveFsAdapter.archive_file(&path, &archive_dir)?;
+        }
         Ok(())
     }
 
@@ -239,395 +311,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
         offset: Option<usize>,
     ) -> Result<Vec<MetricPodEntity>> {
         let rows = self.get_row_between(start, end, object_name, limit, offset)?;
-        let filtered: Vec<MetricPodEntity> = rows
-            .into_iter()
-            .map(|mut row| {
-                match column_name {
-                    "CPU_USAGE_NANO_CORES" => {
-                        let keep = row.cpu_usage_nano_cores;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.cpu_usage_nano_cores = keep;
-                    }
-                    "CPU_USAGE_CORE_NANO_SECONDS" => {
-                        let keep = row.cpu_usage_core_nano_seconds;
-                        row.cpu_usage_nano_cores = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.cpu_usage_core_nano_seconds = keep;
-                    }
-                    "MEMORY_USAGE_BYTES" => {
-                        let keep = row.memory_usage_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.memory_usage_bytes = keep;
-                    }
-                    "MEMORY_WORKING_SET_BYTES" => {
-                        let keep = row.memory_working_set_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.memory_working_set_bytes = keep;
-                    }
-                    "MEMORY_RSS_BYTES" => {
-                        let keep = row.memory_rss_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.memory_rss_bytes = keep;
-                    }
-                    "MEMORY_PAGE_FAULTS" => {
-                        let keep = row.memory_page_faults;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.memory_page_faults = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_BYTES" => {
-                        let keep = row.network_physical_rx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.network_physical_rx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_BYTES" => {
-                        let keep = row.network_physical_tx_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.network_physical_tx_bytes = keep;
-                    }
-                    "NETWORK_PHYSICAL_RX_ERRORS" => {
-                        let keep = row.network_physical_rx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.network_physical_rx_errors = keep;
-                    }
-                    "NETWORK_PHYSICAL_TX_ERRORS" => {
-                        let keep = row.network_physical_tx_errors;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.network_physical_tx_errors = keep;
-                    }
-                    "ES_USED_BYTES" => {
-                        let keep = row.es_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.es_used_bytes = keep;
-                    }
-                    "ES_CAPACITY_BYTES" => {
-                        let keep = row.es_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.es_capacity_bytes = keep;
-                    }
-                    "ES_INODES_USED" => {
-                        let keep = row.es_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.es_inodes_used = keep;
-                    }
-                    "ES_INODES" => {
-                        let keep = row.es_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.es_inodes = keep;
-                    }
-                    "PV_USED_BYTES" => {
-                        let keep = row.pv_used_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.pv_used_bytes = keep;
-                    }
-                    "PV_CAPACITY_BYTES" => {
-                        let keep = row.pv_capacity_bytes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = None;
-                        row.pv_capacity_bytes = keep;
-                    }
-                    "PV_INODES_USED" => {
-                        let keep = row.pv_inodes_used;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes = None;
-                        row.pv_inodes_used = keep;
-                    }
-                    "PV_INODES" => {
-                        let keep = row.pv_inodes;
-                        row.cpu_usage_nano_cores = None;
-                        row.cpu_usage_core_nano_seconds = None;
-                        row.memory_usage_bytes = None;
-                        row.memory_working_set_bytes = None;
-                        row.memory_rss_bytes = None;
-                        row.memory_page_faults = None;
-                        row.network_physical_rx_bytes = None;
-                        row.network_physical_tx_bytes = None;
-                        row.network_physical_rx_errors = None;
-                        row.network_physical_tx_errors = None;
-                        row.es_used_bytes = None;
-                        row.es_capacity_bytes = None;
-                        row.es_inodes_used = None;
-                        row.es_inodes = None;
-                        row.pv_used_bytes = None;
-                        row.pv_capacity_bytes = None;
-                        row.pv_inodes_used = None;
-                        row.pv_inodes = keep;
-                    }
-                    _ => {}
-                }
-                row
-            })
-            .collect();
-
-        Ok(filtered)
+        Ok(rows.iter().map(|row| row.only_column(column_name)).collect())
     }
     fn get_row_between(
         &self,
@@ -649,7 +333,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
             let path = self.build_path_for(object_name, date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !segment_exists(path_obj) {
                 tracing::debug!(
                 "Day metrics file missing for pod {} in year {}",
                 object_name,
@@ -659,7 +343,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
                 continue;
             }
 
-            let file = match File::open(&path_obj) {
+            let file = match open_segment_from(path_obj, start) {
                 Ok(f) => f,
                 Err(e) => {
                     tracing::warn!("Could not open {:?}: {}", path_obj, e);
@@ -668,8 +352,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
                 }
             };
 
-            let reader = BufReader::new(file);
-            let mut lines = reader.lines();
+            let mut lines = SegmentLines::new(file);
 
             // 2️⃣ Try to read the first line (header or data)
             let first_line_opt = lines.next();
@@ -680,30 +363,18 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
 
             let first_line = first_line_opt.unwrap_or_else(|| Ok(String::new()))?;
             let mut rows: Vec<MetricPodEntity> = vec![];
-            let header: Vec<&str>;
-
-            if first_line.starts_with("20") {
-                header = vec![
-                    "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS",
-                    "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
-                    "MEMORY_PAGE_FAULTS", "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES",
-                    "NETWORK_PHYSICAL_RX_ERRORS", "NETWORK_PHYSICAL_TX_ERRORS",
-                    "ES_USED_BYTES", "ES_CAPACITY_BYTES", "ES_INODES_USED", "ES_INODES",
-                    "PV_USED_BYTES", "PV_CAPACITY_BYTES", "PV_INODES_USED", "PV_INODES"
-                ];
-
-                if let Some(row) = Self::parse_line(&header, &first_line) {
-                    if row.time >= start && row.time <= end {
-                        rows.push(row);
-                    }
+
+            // Segments from before positional rows may start with a named header
+            let legacy = LegacyHeader::parse(&first_line, Self::COLUMNS);
+            if let Some(row) = Self::parse_line(&first_line) {
+                if row.time >= start && row.time <= end {
+                    rows.push(row);
                 }
-            } else {
-                header = first_line.split('|').collect();
             }
 
             // 3️⃣ Process the remaining lines
             for line in lines.flatten() {
-                if let Some(row) = Self::parse_line(&header, &line) {
+                if let Some(row) = Self::parse_line(&current_layout(legacy.as_ref(), &line)) {
                     if row.time < start {
                         continue;
                     }
@@ -9,4 +9,9 @@ pub trait MetricPodDayProcessorRepository: Send + Sync {
 
     fn append_row_aggregated(&self, pod_key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<()>;
 
+    /// Aggregates each window and upserts the rows, so reprocessing a window is safe.
+    fn upsert_aggregated(&self, pod_key: &str, windows: &[(DateTime<Utc>, DateTime<Utc>)]) -> Result<usize> {
+        self.fs_adapter().upsert_aggregated(pod_key, windows)
+    }
+
 }
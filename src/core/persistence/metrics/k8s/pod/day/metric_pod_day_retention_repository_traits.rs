@@ -1,6 +1,7 @@
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 
 /// Repository trait for reading pod minute metrics (API layer).
@@ -11,4 +12,17 @@ pub trait MetricPodDayRetentionRepository: Send + Sync {
     fn cleanup_old(&self, pod_key: &str, before: DateTime<Utc>) -> Result<()> {
         self.fs_adapter().cleanup_old(pod_key, before)
     }
+
+    /// Moves old metric files for the given pod into the archive tree.
+    fn archive_old(&self, pod_key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().archive_old(pod_key, before)
+    }
+
+    /// Retires old metric files according to the configured retention policy.
+    fn apply_retention(&self, pod_key: &str, before: DateTime<Utc>, policy: MetricRetentionPolicy) -> Result<()> {
+        match policy {
+            MetricRetentionPolicy::Delete => self.cleanup_old(pod_key, before),
+            MetricRetentionPolicy::Archive => self.archive_old(pod_key, before),
+        }
+    }
 }
@@ -2,6 +2,7 @@ use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAda
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
 
 /// Repository trait for reading pod minute metrics (API layer).
 pub trait MetricPodHourRetentionRepository: Send + Sync {
@@ -12,4 +13,17 @@ pub trait MetricPodHourRetentionRepository: Send + Sync {
         self.fs_adapter().cleanup_old(pod_uid, before)
     }
 
+    /// Moves old metric files for the given pod into the archive tree.
+    fn archive_old(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().archive_old(pod_uid, before)
+    }
+
+    /// Retires old metric files according to the configured retention policy.
+    fn apply_retention(&self, pod_uid: &str, before: DateTime<Utc>, policy: MetricRetentionPolicy) -> Result<()> {
+        match policy {
+            MetricRetentionPolicy::Delete => self.cleanup_old(pod_uid, before),
+            MetricRetentionPolicy::Archive => self.archive_old(pod_uid, before),
+        }
+    }
+
 }
@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::core::persistence::metrics::rollup_stats::GaugeStats;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MetricPodEntity {
     pub time: DateTime<Utc>,
@@ -32,4 +34,119 @@ pub struct MetricPodEntity {
     pub pv_capacity_bytes: Option<u64>,
     pub pv_inodes_used: Option<u64>,
     pub pv_inodes: Option<u64>,
-}
\ No newline at end of file
+
+    // Memory pressure
+    pub memory_available_bytes: Option<u64>,
+    pub memory_major_page_faults: Option<u64>,
+
+    // Swap
+    pub swap_usage_bytes: Option<u64>,
+    pub swap_available_bytes: Option<u64>,
+
+    // Processes
+    pub process_count: Option<u64>,
+
+    // CPU throttling (cAdvisor CFS counters)
+    pub cpu_cfs_periods: Option<u64>,
+    pub cpu_cfs_throttled_periods: Option<u64>,
+    pub cpu_cfs_throttled_nano_seconds: Option<u64>,
+
+    // GPU (DCGM exporter; GPUs attached x 1000 so averaged rows keep fractions)
+    pub gpu_milli_count: Option<u64>,
+    pub gpu_utilization_percent: Option<u64>,
+    pub gpu_memory_used_bytes: Option<u64>,
+    pub gpu_memory_total_bytes: Option<u64>,
+    pub gpu_power_milliwatts: Option<u64>,
+
+    // Rollup statistics (hour/day rows only)
+    pub cpu_usage_nano_cores_max: Option<u64>,
+    pub cpu_usage_nano_cores_min: Option<u64>,
+    pub cpu_usage_nano_cores_p95: Option<u64>,
+    pub cpu_usage_nano_cores_p99: Option<u64>,
+    pub memory_working_set_bytes_max: Option<u64>,
+    pub memory_working_set_bytes_min: Option<u64>,
+    pub memory_working_set_bytes_p95: Option<u64>,
+    pub memory_working_set_bytes_p99: Option<u64>,
+}
+
+impl MetricPodEntity {
+    pub fn cpu_stats(&self) -> GaugeStats {
+        GaugeStats {
+            max: self.cpu_usage_nano_cores_max,
+            min: self.cpu_usage_nano_cores_min,
+            p95: self.cpu_usage_nano_cores_p95,
+            p99: self.cpu_usage_nano_cores_p99,
+        }
+    }
+
+    pub fn set_cpu_stats(&mut self, stats: GaugeStats) {
+        self.cpu_usage_nano_cores_max = stats.max;
+        self.cpu_usage_nano_cores_min = stats.min;
+        self.cpu_usage_nano_cores_p95 = stats.p95;
+        self.cpu_usage_nano_cores_p99 = stats.p99;
+    }
+
+    pub fn memory_working_set_stats(&self) -> GaugeStats {
+        GaugeStats {
+            max: self.memory_working_set_bytes_max,
+            min: self.memory_working_set_bytes_min,
+            p95: self.memory_working_set_bytes_p95,
+            p99: self.memory_working_set_bytes_p99,
+        }
+    }
+
+    pub fn set_memory_working_set_stats(&mut self, stats: GaugeStats) {
+        self.memory_working_set_bytes_max = stats.max;
+        self.memory_working_set_bytes_min = stats.min;
+        self.memory_working_set_bytes_p95 = stats.p95;
+        self.memory_working_set_bytes_p99 = stats.p99;
+    }
+
+    /// Copy of the row holding only `time` and the named column (plus its
+    /// rollup statistics for `CPU_USAGE_NANO_CORES` and `MEMORY_WORKING_SET_BYTES`).
+    /// An unknown column name returns the full row.
+    pub fn only_column(&self, column_name: &str) -> Self {
+        let mut row = MetricPodEntity { time: self.time, ..Default::default() };
+        match column_name {
+            "CPU_USAGE_NANO_CORES" => {
+                row.cpu_usage_nano_cores = self.cpu_usage_nano_cores;
+                row.set_cpu_stats(self.cpu_stats());
+            }
+            "CPU_USAGE_CORE_NANO_SECONDS" => row.cpu_usage_core_nano_seconds = self.cpu_usage_core_nano_seconds,
+            "MEMORY_USAGE_BYTES" => row.memory_usage_bytes = self.memory_usage_bytes,
+            "MEMORY_WORKING_SET_BYTES" => {
+                row.memory_working_set_bytes = self.memory_working_set_bytes;
+                row.set_memory_working_set_stats(self.memory_working_set_stats());
+            }
+            "MEMORY_RSS_BYTES" => row.memory_rss_bytes = self.memory_rss_bytes,
+            "MEMORY_PAGE_FAULTS" => row.memory_page_faults = self.memory_page_faults,
+            "NETWORK_PHYSICAL_RX_BYTES" => row.network_physical_rx_bytes = self.network_physical_rx_bytes,
+            "NETWORK_PHYSICAL_TX_BYTES" => row.network_physical_tx_bytes = self.network_physical_tx_bytes,
+            "NETWORK_PHYSICAL_RX_ERRORS" => row.network_physical_rx_errors = self.network_physical_rx_errors,
+            "NETWORK_PHYSICAL_TX_ERRORS" => row.network_physical_tx_errors = self.network_physical_tx_errors,
+            "ES_USED_BYTES" => row.es_used_bytes = self.es_used_bytes,
+            "ES_CAPACITY_BYTES" => row.es_capacity_bytes = self.es_capacity_bytes,
+            "ES_INODES_USED" => row.es_inodes_used = self.es_inodes_used,
+            "ES_INODES" => row.es_inodes = self.es_inodes,
+            "PV_USED_BYTES" => row.pv_used_bytes = self.pv_used_bytes,
+            "PV_CAPACITY_BYTES" => row.pv_capacity_bytes = self.pv_capacity_bytes,
+            "PV_INODES_USED" => row.pv_inodes_used = self.pv_inodes_used,
+            "PV_INODES" => row.pv_inodes = self.pv_inodes,
+            "MEMORY_AVAILABLE_BYTES" => row.memory_available_bytes = self.memory_available_bytes,
+            "MEMORY_MAJOR_PAGE_FAULTS" => row.memory_major_page_faults = self.memory_major_page_faults,
+            "SWAP_USAGE_BYTES" => row.swap_usage_bytes = self.swap_usage_bytes,
+            "SWAP_AVAILABLE_BYTES" => row.swap_available_bytes = self.swap_available_bytes,
+            "PROCESS_COUNT" => row.process_count = self.process_count,
+            "CPU_CFS_PERIODS" => row.cpu_cfs_periods = self.cpu_cfs_periods,
+            "CPU_CFS_THROTTLED_PERIODS" => row.cpu_cfs_throttled_periods = self.cpu_cfs_throttled_periods,
+            "CPU_CFS_THROTTLED_NANO_SECONDS" => row.cpu_cfs_throttled_nano_seconds = self.cpu_cfs_throttled_nano_seconds,
+            "GPU_MILLI_COUNT" => row.gpu_milli_count = self.gpu_milli_count,
+            "GPU_UTILIZATION_PERCENT" => row.gpu_utilization_percent = self.gpu_utilization_percent,
+            "GPU_MEMORY_USED_BYTES" => row.gpu_memory_used_bytes = self.gpu_memory_used_bytes,
+            "GPU_MEMORY_TOTAL_BYTES" => row.gpu_memory_total_bytes = self.gpu_memory_total_bytes,
+            "GPU_POWER_MILLIWATTS" => row.gpu_power_milliwatts = self.gpu_power_milliwatts,
+            _ => return self.clone(),
+        }
+        row
+    }
+}
@@ -0,0 +1,17 @@
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+
+/// Repository trait for aggregating PVC day metrics (processor layer).
+pub trait MetricPvcDayProcessorRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPvcEntity>;
+
+    fn append_row_aggregated(&self, pvc_key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<()>;
+
+    /// Aggregates each window and upserts the rows, so reprocessing a window is safe.
+    fn upsert_aggregated(&self, pvc_key: &str, windows: &[(DateTime<Utc>, DateTime<Utc>)]) -> Result<usize> {
+        self.fs_adapter().upsert_aggregated(pvc_key, windows)
+    }
+
+}
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::persistence::metrics::rollup_stats::GaugeStats;
+
+/// Storage key of a claim. Kubernetes names cannot contain `_`, so the
+/// key splits back unambiguously.
+pub fn pvc_metric_key(namespace: &str, name: &str) -> String {
+    format!("{}_{}", namespace, name)
+}
+
+/// Volume stats of one PersistentVolumeClaim, keyed by `<namespace>_<name>`.
+///
+/// Reported by the kubelet for every pod volume backed by the claim; pods
+/// sharing a claim report the same volume, which is stored once.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetricPvcEntity {
+    pub time: DateTime<Utc>,
+
+    // Bytes
+    pub used_bytes: Option<u64>,
+    pub capacity_bytes: Option<u64>,
+    pub available_bytes: Option<u64>,
+
+    // Inodes
+    pub inodes_used: Option<u64>,
+    pub inodes: Option<u64>,
+    pub inodes_free: Option<u64>,
+
+    // Rollup statistics (hour/day rows only)
+    pub used_bytes_max: Option<u64>,
+    pub used_bytes_min: Option<u64>,
+    pub used_bytes_p95: Option<u64>,
+    pub used_bytes_p99: Option<u64>,
+}
+
+impl MetricPvcEntity {
+    pub fn used_stats(&self) -> GaugeStats {
+        GaugeStats {
+            max: self.used_bytes_max,
+            min: self.used_bytes_min,
+            p95: self.used_bytes_p95,
+            p99: self.used_bytes_p99,
+        }
+    }
+
+    pub fn set_used_stats(&mut self, stats: GaugeStats) {
+        self.used_bytes_max = stats.max;
+        self.used_bytes_min = stats.min;
+        self.used_bytes_p95 = stats.p95;
+        self.used_bytes_p99 = stats.p99;
+    }
+
+    /// Copy of the row holding only `time` and the named column
+    /// (plus its rollup statistics for `USED_BYTES`).
+    /// An unknown column name returns the full row.
+    pub fn only_column(&self, column_name: &str) -> Self {
+        let mut row = MetricPvcEntity { time: self.time, ..Default::default() };
+        match column_name {
+            "USED_BYTES" => {
+                row.used_bytes = self.used_bytes;
+                row.set_used_stats(self.used_stats());
+            }
+            "CAPACITY_BYTES" => row.capacity_bytes = self.capacity_bytes,
+            "AVAILABLE_BYTES" => row.available_bytes = self.available_bytes,
+            "INODES_USED" => row.inodes_used = self.inodes_used,
+            "INODES" => row.inodes = self.inodes,
+            "INODES_FREE" => row.inodes_free = self.inodes_free,
+            _ => return self.clone(),
+        }
+        row
+    }
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::core::persistence::metrics::metric_rollup_trait::MetricRollup;
+use crate::core::persistence::metrics::rollup_stats::GaugeStats;
+use crate::core::persistence::metrics::sample_weight::{sample_weights, weighted_avg};
+
+impl MetricRollup for MetricPvcEntity {
+    fn rollup_minutes(rows: &[Self], end: DateTime<Utc>) -> Option<Self> {
+        if rows.is_empty() {
+            return None;
+        }
+
+        let last = rows.last().unwrap();
+
+        // Gauges are weighted by the time each sample covers, like the other kinds.
+        let weights = sample_weights(rows.iter().map(|r| r.time));
+        let samples = |f: fn(&MetricPvcEntity) -> Option<u64>| {
+            rows.iter().zip(&weights).filter_map(move |(r, w)| f(r).map(|v| (v, *w)))
+        };
+        let avg = |f: fn(&MetricPvcEntity) -> Option<u64>| -> Option<u64> { weighted_avg(samples(f)) };
+
+        let mut aggregated = MetricPvcEntity {
+            time: end, // time marker = end of the aggregation window
+
+            // Capacity only changes on resize; the latest value wins.
+            used_bytes: avg(|r| r.used_bytes),
+            capacity_bytes: last.capacity_bytes,
+            available_bytes: avg(|r| r.available_bytes),
+
+            inodes_used: avg(|r| r.inodes_used),
+            inodes: last.inodes,
+            inodes_free: avg(|r| r.inodes_free),
+
+            ..Default::default()
+        };
+
+        aggregated.set_used_stats(GaugeStats::from_samples(samples(|r| r.used_bytes)));
+
+        Some(aggregated)
+    }
+
+    fn rollup_hours(rows: &[Self], minute_rows: &[Self], end: DateTime<Utc>) -> Option<Self> {
+        if rows.is_empty() {
+            return None;
+        }
+
+        let last = rows.last().unwrap();
+
+        // Every hour row covers one hour, so they weigh the same.
+        let avg = |f: fn(&MetricPvcEntity) -> Option<u64>| -> Option<u64> {
+            weighted_avg(rows.iter().filter_map(f).map(|v| (v, 1)))
+        };
+
+        let mut aggregated = MetricPvcEntity {
+            time: end, // time marker = end of the aggregation window
+
+            used_bytes: avg(|r| r.used_bytes),
+            capacity_bytes: last.capacity_bytes,
+            available_bytes: avg(|r| r.available_bytes),
+
+            inodes_used: avg(|r| r.inodes_used),
+            inodes: last.inodes,
+            inodes_free: avg(|r| r.inodes_free),
+
+            ..Default::default()
+        };
+
+        // Exact while minute samples of the day are still around; afterwards
+        // bounded from the hour rows.
+        if minute_rows.is_empty() {
+            aggregated.set_used_stats(GaugeStats::from_rollups(
+                rows.iter().map(|r| r.used_stats().or_value(r.used_bytes)),
+            ));
+        } else {
+            let weights = sample_weights(minute_rows.iter().map(|r| r.time));
+            aggregated.set_used_stats(GaugeStats::from_samples(
+                minute_rows.iter().zip(&weights).filter_map(|(r, w)| r.used_bytes.map(|v| (v, *w))),
+            ));
+        }
+
+        Some(aggregated)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use anyhow::Result;
+
+/// Repository trait for writing PVC minute metrics (collector layer).
+pub trait MetricPvcMinuteCollectorRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPvcEntity>;
+
+    /// Inserts one metric sample for a given PVC.
+    fn append_row(&self, pvc_key: &str, data: &MetricPvcEntity) -> Result<()> {
+        self.fs_adapter().append_row(pvc_key, data)
+    }
+
+}
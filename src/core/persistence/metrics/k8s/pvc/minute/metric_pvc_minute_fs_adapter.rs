@@ -0,0 +1,261 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::{
+    fs,
+    path::Path,
+};
+use std::path::PathBuf;
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_pvc_key_minute_dir_path,
+    metric_k8s_pvc_key_minute_file_path,
+};
+use crate::core::persistence::archive::metric_archive_fs_adapter::MetricArchiveFsAdapter;
+use crate::core::persistence::metrics::segment::compression::{segment_exists, segment_period};
+use crate::core::persistence::metrics::segment::format::SegmentLines;
+use crate::core::persistence::metrics::segment::index::{open_segment_from, remove_segment};
+use crate::core::persistence::metrics::segment::rewrite::upsert_segment_rows;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::metrics::segment::write_buffer;
+use std::collections::BTreeMap;
+use crate::core::persistence::archive::path::archive_k8s_pvc_key_minute_dir_path;
+
+/// Adapter for PVC minute-level metrics.
+/// Responsible for appending minute samples to the filesystem and cleaning up old data.
+#[derive(Debug)]
+pub struct MetricPvcMinuteFsAdapter;
+
+impl MetricPvcMinuteFsAdapter {
+    fn build_path_for(&self, pvc_key: &str, date: NaiveDate) -> PathBuf {
+        let date_str = date.format("%Y-%m-%d").to_string();
+        metric_k8s_pvc_key_minute_file_path(pvc_key, &date_str)
+    }
+
+    fn parse_line(header: &[&str], line: &str) -> Option<MetricPvcEntity> {
+        let parts: Vec<&str> = line.split('|').collect();
+        if parts.len() < header.len() {
+            return None;
+        }
+
+        // TIME|USED_BYTES|CAPACITY_BYTES|AVAILABLE_BYTES|INODES_USED|INODES|INODES_FREE
+        let time = parts[0].parse::<DateTime<Utc>>().ok()?;
+        Some(MetricPvcEntity {
+            time,
+            used_bytes: parts[1].parse().ok(),
+            capacity_bytes: parts[2].parse().ok(),
+            available_bytes: parts[3].parse().ok(),
+            inodes_used: parts[4].parse().ok(),
+            inodes: parts[5].parse().ok(),
+            inodes_free: parts[6].parse().ok(),
+            ..Default::default()
+        })
+    }
+
+    fn opt(v: Option<u64>) -> String {
+        v.map(|x| x.to_string()).unwrap_or_default()
+    }
+
+    fn format_row(dto: &MetricPvcEntity) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}\n",
+            dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            Self::opt(dto.used_bytes),
+            Self::opt(dto.capacity_bytes),
+            Self::opt(dto.available_bytes),
+            Self::opt(dto.inodes_used),
+            Self::opt(dto.inodes),
+            Self::opt(dto.inodes_free),
+        )
+    }
+
+    /// Minute files (`YYYY-MM-DD.rcd`) dated strictly before the cutoff day.
+    fn expired_files(&self, pvc_key: &str, before: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let mut expired = Vec::new();
+        let dir = metric_k8s_pvc_key_minute_dir_path(pvc_key);
+        if !dir.exists() {
+            return Ok(expired);
+        }
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if let Some(stem) = segment_period(&path) {
+                if let Ok(file_date) = NaiveDate::parse_from_str(stem, "%Y-%m-%d") {
+                    if file_date < before.date_naive() {
+                        expired.push(path);
+                    }
+                }
+            }
+        }
+
+        Ok(expired)
+    }
+}
+
+impl MetricFsAdapterBase<MetricPvcEntity> for MetricPvcMinuteFsAdapter {
+    fn append_row(&self, pvc: &str, dto: &MetricPvcEntity) -> Result<()> {
+        let path = self.build_path_for(pvc, Utc::now().date_naive());
+
+        // Buffered; the handle stays open across ticks and the row is flushed in a batch
+        write_buffer::append_row(&path, MetricTier::Minute, dto.time, &Self::format_row(dto))
+    }
+
+    fn upsert_rows(&self, pvc_key: &str, rows: &[MetricPvcEntity]) -> Result<()> {
+        let mut by_file: BTreeMap<PathBuf, Vec<(DateTime<Utc>, String)>> = BTreeMap::new();
+        for row in rows {
+            let line = Self::format_row(row).trim_end_matches('\n').to_string();
+            by_file
+                .entry(self.build_path_for(pvc_key, row.time.date_naive()))
+                .or_default()
+                .push((row.time, line));
+        }
+
+        for (path, lines) in by_file {
+            upsert_segment_rows(&path, MetricTier::Minute, lines)?;
+        }
+        Ok(())
+    }
+
+    fn cleanup_old(&self, pvc_key: &str, before: DateTime<Utc>) -> Result<()> {
+        for path in self.expired_files(pvc_key, before)? {
+            remove_segment(&path)
+                .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
+        }
+        Ok(())
+    }
+
+    fn archive_old(&self, pvc_key: &str, before: DateTime<Utc>) -> Result<()> {
+        let archive_dir = archive_k8s_pvc_key_minute_dir_path(pvc_key);
+        for path in self.expired_files(pvc_key, before)? {
+            MetricArchiveFsAdapter.archive_file(&path, &archive_dir)?;
+        }
+        Ok(())
+    }
+
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricPvcEntity>> {
+        let mut all_rows = Vec::new();
+
+        // 1️⃣ Iterate day-by-day across the requested range
+        let mut current_date = start.date_naive();
+        let end_date = end.date_naive();
+
+        while current_date <= end_date {
+            let path = self.build_path_for(object_name, current_date);
+            let path_obj = Path::new(&path);
+
+            if !segment_exists(path_obj) {
+                tracing::debug!("Minute metrics file missing for {} on {}", object_name, current_date);
+                current_date = current_date.succ_opt().unwrap_or(current_date);
+                continue;
+            }
+
+            // Safely open file
+            let file = match open_segment_from(path_obj, start) {
+                Ok(f) => f,
+                Err(e) => {
+                    tracing::warn!("Cannot open {:?}: {}", path_obj, e);
+                    current_date = current_date.succ_opt().unwrap_or(current_date);
+                    continue;
+                }
+            };
+
+            let mut lines = SegmentLines::new(file);
+
+            // Skip empty files
+            let first_line = match lines.next() {
+                Some(Ok(line)) => line,
+                _ => {
+                    tracing::debug!("Empty metric file for {} on {}", object_name, current_date);
+                    current_date = current_date.succ_opt().unwrap_or(current_date);
+                    continue;
+                }
+            };
+
+            // 2️⃣ Handle header vs. data
+            let header: Vec<&str>;
+            let mut rows: Vec<MetricPvcEntity> = vec![];
+
+            if first_line.starts_with("20") {
+                // Treat as data (no header)
+                header = vec![
+                    "TIME",
+                    "USED_BYTES",
+                    "CAPACITY_BYTES",
+                    "AVAILABLE_BYTES",
+                    "INODES_USED",
+                    "INODES",
+                    "INODES_FREE",
+                ];
+
+                if let Some(row) = Self::parse_line(&header, &first_line) {
+                    if row.time >= start && row.time <= end {
+                        rows.push(row);
+                    }
+                }
+            } else {
+                header = first_line.split('|').collect();
+            }
+
+            // 3️⃣ Process remaining lines safely
+            for line_result in lines {
+                let line = match line_result {
+                    Ok(l) if !l.trim().is_empty() => l,
+                    _ => continue,
+                };
+
+                if let Some(row) = Self::parse_line(&header, &line) {
+                    if row.time < start {
+                        continue;
+                    }
+                    if row.time > end {
+                        break;
+                    }
+                    rows.push(row);
+                } else {
+                    tracing::warn!("Malformed line skipped in {:?}: {}", path_obj, line);
+                }
+            }
+
+            all_rows.extend(rows);
+            current_date = current_date.succ_opt().unwrap_or(current_date);
+        }
+
+        // 4️⃣ Sort and paginate
+        all_rows.sort_by_key(|r| r.time);
+        let start_idx = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(all_rows.len());
+        let paginated = all_rows.into_iter().skip(start_idx).take(limit).collect::<Vec<_>>();
+
+        tracing::debug!(
+        "Returning {} rows for {} between {} and {}",
+        paginated.len(),
+        object_name,
+        start,
+        end
+    );
+
+        Ok(paginated)
+    }
+
+    fn get_column_between(
+        &self,
+        column_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        object_name: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<MetricPvcEntity>> {
+        let rows = self.get_row_between(start, end, object_name, limit, offset)?;
+        Ok(rows.iter().map(|row| row.only_column(column_name)).collect())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
+
+/// Repository trait for PVC minute metrics retention.
+pub trait MetricPvcMinuteRetentionRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPvcEntity>;
+
+    /// Deletes old metric files for the given PVC before the cutoff timestamp.
+    fn cleanup_old(&self, pvc_key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().cleanup_old(pvc_key, before)
+    }
+
+    /// Moves old metric files for the given PVC into the archive tree.
+    fn archive_old(&self, pvc_key: &str, before: DateTime<Utc>) -> Result<()> {
+        self.fs_adapter().archive_old(pvc_key, before)
+    }
+
+    /// Retires old metric files according to the configured retention policy.
+    fn apply_retention(&self, pvc_key: &str, before: DateTime<Utc>, policy: MetricRetentionPolicy) -> Result<()> {
+        match policy {
+            MetricRetentionPolicy::Delete => self.cleanup_old(pvc_key, before),
+            MetricRetentionPolicy::Archive => self.archive_old(pvc_key, before),
+        }
+    }
+
+}
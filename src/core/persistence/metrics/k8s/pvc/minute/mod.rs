@@ -0,0 +1,4 @@
+pub mod metric_pvc_minute_fs_adapter;
+pub mod metric_pvc_minute_api_repository_trait;
+pub mod metric_pvc_minute_collector_repository_trait;
+pub mod metric_pvc_minute_retention_repository_traits;
\ No newline at end of file
@@ -18,12 +18,46 @@ pub trait MetricFsAdapterBase<T>: Send + Sync {
         unimplemented!("append_row_aggregated not used in this adapter")
     }
 
+    /// Compute the aggregated row for a window from the lower tier without writing it.
+    /// Returns `None` when the lower tier has no data for the window.
+    #[allow(unused_variables)]
+    fn aggregate_between(&self, name: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Option<T>> {
+        unimplemented!("aggregate_between not used in this adapter")
+    }
+
+    /// Write rows into the segments matching their timestamps, replacing existing
+    /// rows with the same timestamp instead of appending duplicates.
+    #[allow(unused_variables)]
+    fn upsert_rows(&self, name: &str, rows: &[T]) -> Result<()> {
+        unimplemented!("upsert_rows not used in this adapter")
+    }
+
+    /// Aggregate each window and upsert the resulting rows, so reprocessing a
+    /// window replaces its row. Windows without source data are skipped.
+    /// Returns the number of rows written.
+    fn upsert_aggregated(&self, name: &str, windows: &[(DateTime<Utc>, DateTime<Utc>)]) -> Result<usize> {
+        let mut rows = Vec::new();
+        for (start, end) in windows {
+            if let Some(row) = self.aggregate_between(name, *start, *end)? {
+                rows.push(row);
+            }
+        }
+        self.upsert_rows(name, &rows)?;
+        Ok(rows.len())
+    }
+
     /// Remove old metric files before a given timestamp
     #[allow(unused_variables)]
     fn cleanup_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
         unimplemented!("cleanup_old not used in this adapter")
     }
 
+    /// Move old metric files before a given timestamp into the archive tree
+    #[allow(unused_variables)]
+    fn archive_old(&self, name: &str, before: DateTime<Utc>) -> Result<()> {
+        unimplemented!("archive_old not used in this adapter")
+    }
+
     // === API-like ===
     /// Read a column between timestamps
     #[allow(unused_variables)]
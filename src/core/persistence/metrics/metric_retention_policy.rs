@@ -0,0 +1,27 @@
+use tracing::warn;
+
+/// What retention does with metric segments that fall outside the retention window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricRetentionPolicy {
+    /// Expired segments are removed from disk.
+    Delete,
+    /// Expired segments are compressed into the archive tree.
+    Archive,
+}
+
+impl MetricRetentionPolicy {
+    /// Accepted values of `InfoSettingEntity::retention_policy`.
+    pub const VALUES: [&'static str; 2] = ["delete", "archive"];
+
+    /// Parses the persisted setting value; unknown values fall back to `Delete`.
+    pub fn from_setting(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "archive" => Self::Archive,
+            "delete" => Self::Delete,
+            other => {
+                warn!("Unknown retention policy '{}', falling back to delete", other);
+                Self::Delete
+            }
+        }
+    }
+}
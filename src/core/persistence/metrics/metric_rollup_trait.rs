@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+
+/// Rolls the rows of one window up into a single row of the next tier.
+///
+/// Kept apart from the adapters so every storage backend produces the same
+/// hour and day rows from the same source rows.
+pub trait MetricRollup: Sized {
+    /// Hour row from the minute rows of one window, stamped with `end`.
+    /// Returns `None` when there are no rows.
+    fn rollup_minutes(rows: &[Self], end: DateTime<Utc>) -> Option<Self>;
+
+    /// Day row from the hour rows of one window, stamped with `end`.
+    /// `minute_rows` yields exact percentiles while minute data is still retained.
+    fn rollup_hours(rows: &[Self], minute_rows: &[Self], end: DateTime<Utc>) -> Option<Self>;
+}
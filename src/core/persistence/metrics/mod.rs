@@ -1,2 +1,9 @@
+pub mod backend;
+pub mod counter;
 pub mod metric_fs_adapter_base_trait;
+pub mod metric_rollup_trait;
+pub mod metric_retention_policy;
+pub mod rollup_stats;
+pub mod sample_weight;
+pub mod segment;
 pub mod k8s;
\ No newline at end of file
@@ -0,0 +1,133 @@
+//! Peak statistics stored next to the average of a gauge in hour/day rows.
+//!
+//! Averages hide spikes, so rollup rows also carry max, min, p95 and p99 of
+//! the samples they were built from. Minute rows never carry them.
+
+/// Max/min/p95/p99 of one gauge over a rollup window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GaugeStats {
+    pub max: Option<u64>,
+    pub min: Option<u64>,
+    pub p95: Option<u64>,
+    pub p99: Option<u64>,
+}
+
+impl GaugeStats {
+    /// Exact statistics over raw `(value, weight)` samples, using weighted
+    /// nearest-rank percentiles.
+    ///
+    /// Weights come from [`sample_weights`](super::sample_weight::sample_weights),
+    /// so a 15s sample counts a quarter of a 60s one. When every weight is zero
+    /// the samples count equally.
+    pub fn from_samples(samples: impl IntoIterator<Item = (u64, u64)>) -> Self {
+        let mut samples: Vec<(u64, u64)> = samples.into_iter().collect();
+        if samples.is_empty() {
+            return Self::default();
+        }
+        if samples.iter().all(|(_, w)| *w == 0) {
+            samples.iter_mut().for_each(|(_, w)| *w = 1);
+        }
+        samples.sort_unstable();
+        let total: u128 = samples.iter().map(|(_, w)| *w as u128).sum();
+
+        let rank = |p: f64| -> u64 {
+            let target = p * total as f64;
+            let mut cumulative: u128 = 0;
+            for (value, weight) in &samples {
+                cumulative += *weight as u128;
+                if *weight > 0 && cumulative as f64 >= target {
+                    return *value;
+                }
+            }
+            samples[samples.len() - 1].0
+        };
+
+        Self {
+            max: samples.last().map(|(v, _)| *v),
+            min: samples.first().map(|(v, _)| *v),
+            p95: Some(rank(0.95)),
+            p99: Some(rank(0.99)),
+        }
+    }
+
+    /// Combines the statistics of finer rollup rows once their samples are gone.
+    ///
+    /// Max and min stay exact. Percentiles cannot be merged, so the highest
+    /// finer percentile is used, which is an upper bound of the true value.
+    pub fn from_rollups(stats: impl IntoIterator<Item = GaugeStats>) -> Self {
+        stats.into_iter().fold(Self::default(), |acc, s| Self {
+            max: acc.max.max(s.max),
+            min: match (acc.min, s.min) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
+            p95: acc.p95.max(s.p95),
+            p99: acc.p99.max(s.p99),
+        })
+    }
+
+    /// Fills missing statistics with `value`.
+    ///
+    /// Used for rows written before rollups carried statistics, where the
+    /// average is the only value known for the window.
+    pub fn or_value(self, value: Option<u64>) -> Self {
+        Self {
+            max: self.max.or(value),
+            min: self.min.or(value),
+            p95: self.p95.or(value),
+            p99: self.p99.or(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles_use_nearest_rank_over_equal_samples() {
+        let stats = GaugeStats::from_samples((1..=100).rev().map(|v| (v, 60)));
+        assert_eq!(
+            stats,
+            GaugeStats { max: Some(100), min: Some(1), p95: Some(95), p99: Some(99) }
+        );
+    }
+
+    #[test]
+    fn short_samples_weigh_less_in_percentiles_but_not_in_max() {
+        // 59 minutes at 10 and a spike of 1000 held for 15 seconds
+        let samples = std::iter::repeat_n((10, 60), 59).chain([(1000, 15)]);
+        let stats = GaugeStats::from_samples(samples);
+        assert_eq!(stats.max, Some(1000));
+        assert_eq!(stats.p99, Some(10));
+
+        // Without weights the spike is 1 of 60 samples and reaches p99
+        let unweighted = GaugeStats::from_samples(std::iter::repeat_n((10, 0), 59).chain([(1000, 0)]));
+        assert_eq!(unweighted.p99, Some(1000));
+    }
+
+    #[test]
+    fn no_samples_give_no_stats() {
+        assert_eq!(GaugeStats::from_samples([]), GaugeStats::default());
+    }
+
+    #[test]
+    fn rollups_keep_exact_extremes_and_the_highest_percentiles() {
+        let hours = [
+            GaugeStats { max: Some(50), min: Some(5), p95: Some(40), p99: Some(45) },
+            GaugeStats::default(),
+            GaugeStats { max: Some(90), min: Some(20), p95: Some(30), p99: Some(80) },
+        ];
+        assert_eq!(
+            GaugeStats::from_rollups(hours),
+            GaugeStats { max: Some(90), min: Some(5), p95: Some(40), p99: Some(80) }
+        );
+        assert_eq!(GaugeStats::from_rollups([]), GaugeStats::default());
+    }
+
+    #[test]
+    fn legacy_rows_fall_back_to_their_average() {
+        let stats = GaugeStats { max: Some(9), ..Default::default() }.or_value(Some(4));
+        assert_eq!(stats, GaugeStats { max: Some(9), min: Some(4), p95: Some(4), p99: Some(4) });
+    }
+}
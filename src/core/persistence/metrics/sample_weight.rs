@@ -0,0 +1,102 @@
+//! Time weighting of gauge samples in minute rows.
+//!
+//! The collector runs every `scrape_interval_sec`, so a minute file may hold one
+//! sample per minute or four, and the interval can change halfway through an
+//! hour. A plain mean would let the 15s samples outvote the 60s ones, so each
+//! sample is weighted by the seconds since the sample before it.
+
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::info::fixed::setting::info_setting_entity::MAX_SCRAPE_INTERVAL_SEC;
+
+/// Upper bound of a single weight. A gap longer than the longest scrape
+/// interval is an outage, which the sample after it does not stand for.
+const MAX_SAMPLE_WEIGHT_SEC: u64 = MAX_SCRAPE_INTERVAL_SEC as u64;
+
+/// Weight of a lone sample, where there is no gap to measure.
+const DEFAULT_SAMPLE_WEIGHT_SEC: u64 = 60;
+
+/// Seconds each sample stands for, in the order of `times`.
+///
+/// A sample covers the gap since the previous one, capped at
+/// [`MAX_SAMPLE_WEIGHT_SEC`]. The first sample takes the weight of the second.
+/// Samples repeating the previous timestamp (the kubelet had not refreshed its
+/// stats yet) get no weight.
+pub fn sample_weights(times: impl IntoIterator<Item = DateTime<Utc>>) -> Vec<u64> {
+    let times: Vec<DateTime<Utc>> = times.into_iter().collect();
+    let mut weights: Vec<u64> = times
+        .iter()
+        .zip(times.iter().skip(1))
+        .map(|(prev, next)| ((*next - *prev).num_seconds().max(0) as u64).min(MAX_SAMPLE_WEIGHT_SEC))
+        .collect();
+
+    if !times.is_empty() {
+        let first = weights.first().copied().filter(|w| *w > 0).unwrap_or(DEFAULT_SAMPLE_WEIGHT_SEC);
+        weights.insert(0, first);
+    }
+    weights
+}
+
+/// Weighted mean of `(value, weight)` samples.
+///
+/// Falls back to the plain mean when every weight is zero.
+/// Returns `None` when there are no samples.
+pub fn weighted_avg(samples: impl IntoIterator<Item = (u64, u64)>) -> Option<u64> {
+    let (mut sum, mut total, mut plain_sum, mut count) = (0u128, 0u128, 0u128, 0u128);
+    for (value, weight) in samples {
+        sum += value as u128 * weight as u128;
+        total += weight as u128;
+        plain_sum += value as u128;
+        count += 1;
+    }
+
+    sum.checked_div(total)
+        .or_else(|| plain_sum.checked_div(count))
+        .map(|avg| avg as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn times(offsets_sec: &[i64]) -> Vec<DateTime<Utc>> {
+        let base: DateTime<Utc> = "2025-01-01T00:00:00Z".parse().unwrap();
+        offsets_sec.iter().map(|s| base + chrono::Duration::seconds(*s)).collect()
+    }
+
+    #[test]
+    fn samples_weigh_the_gap_since_the_previous_one() {
+        // Interval changes from 60s to 15s halfway
+        assert_eq!(sample_weights(times(&[0, 60, 120, 135, 150])), vec![60, 60, 60, 15, 15]);
+    }
+
+    #[test]
+    fn repeated_timestamps_get_no_weight_and_outages_are_capped() {
+        let weights = sample_weights(times(&[0, 0, 60, 60 + 4 * 3600]));
+        assert_eq!(weights, vec![DEFAULT_SAMPLE_WEIGHT_SEC, 0, 60, MAX_SAMPLE_WEIGHT_SEC]);
+    }
+
+    #[test]
+    fn lone_sample_gets_the_default_weight() {
+        assert_eq!(sample_weights(times(&[0])), vec![DEFAULT_SAMPLE_WEIGHT_SEC]);
+        assert!(sample_weights(times(&[])).is_empty());
+    }
+
+    #[test]
+    fn weighted_avg_does_not_let_short_samples_outvote_long_ones() {
+        // One 60s sample at 100 and four 15s samples at 200
+        let samples = [(100, 60), (200, 15), (200, 15), (200, 15), (200, 15)];
+        assert_eq!(weighted_avg(samples), Some(150));
+    }
+
+    #[test]
+    fn weighted_avg_falls_back_to_the_plain_mean() {
+        assert_eq!(weighted_avg([(10, 0), (20, 0)]), Some(15));
+        assert_eq!(weighted_avg([]), None);
+    }
+
+    #[test]
+    fn weighted_avg_does_not_overflow_on_large_values() {
+        assert_eq!(weighted_avg([(u64::MAX, 3600), (u64::MAX, 3600)]), Some(u64::MAX));
+    }
+}
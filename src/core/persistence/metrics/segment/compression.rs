@@ -0,0 +1,182 @@
+//! Gzip compression of closed `.rcd` segments.
+//!
+//! A segment that is no longer appended to (`YYYY-MM-DD.rcd` once the day is over,
+//! `YYYY-MM.rcd` once the month is over, ...) is replaced by `YYYY-MM-DD.rcd.gz`.
+//! Readers go through [`open_segment`], which reads the compressed and the plain
+//! file back to back, so late appends that recreate a plain file are not lost.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use super::index::remove_index;
+use super::tier::MetricTier;
+use super::write_buffer::{flush_path, release_path};
+
+const PLAIN_EXT: &str = "rcd";
+const COMPRESSED_SUFFIX: &str = ".rcd.gz";
+
+/// `2025-01-01.rcd` → `2025-01-01.rcd.gz`
+pub fn compressed_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map(|n| n.to_os_string()).unwrap_or_default();
+    name.push(".gz");
+    path.with_file_name(name)
+}
+
+/// Period stem of a plain (`X.rcd`) or compressed (`X.rcd.gz`) segment file.
+pub fn segment_period(path: &Path) -> Option<&str> {
+    let name = path.file_name()?.to_str()?;
+    name.strip_suffix(COMPRESSED_SUFFIX)
+        .or_else(|| name.strip_suffix(".rcd"))
+}
+
+/// Whether `path` is a compressed segment file.
+pub fn is_compressed_segment(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.ends_with(COMPRESSED_SUFFIX))
+}
+
+/// Whether the segment exists in plain or compressed form.
+pub fn segment_exists(path: &Path) -> bool {
+    path.exists() || compressed_path(path).exists()
+}
+
+/// Opens a segment for reading regardless of whether it has been compressed.
+///
+/// `path` is the plain `.rcd` path. If both forms exist the compressed rows are
+/// returned first, followed by rows appended after compression.
+pub fn open_segment(path: &Path) -> io::Result<Box<dyn Read + Send>> {
+    if let Err(e) = flush_path(path) {
+        tracing::warn!("{}", e);
+    }
+    let gz = compressed_path(path);
+    match (gz.exists(), path.exists()) {
+        (true, true) => Ok(Box::new(
+            MultiGzDecoder::new(BufReader::new(File::open(&gz)?)).chain(File::open(path)?),
+        )),
+        (true, false) => Ok(Box::new(MultiGzDecoder::new(BufReader::new(File::open(&gz)?)))),
+        _ => Ok(Box::new(File::open(path)?)),
+    }
+}
+
+/// Compresses one plain segment in place.
+///
+/// When a compressed segment already exists the plain rows are appended as an
+/// additional gzip member, so the two are merged without recompressing.
+pub fn compress_segment(path: &Path) -> Result<PathBuf> {
+    release_path(path)?;
+    let gz = compressed_path(path);
+    let tmp = gz.with_extension("gz.tmp");
+
+    {
+        if gz.exists() {
+            fs::copy(&gz, &tmp)
+                .with_context(|| format!("Failed to stage compressed segment {:?}", gz))?;
+        }
+        let out = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&tmp)
+            .with_context(|| format!("Failed to create {:?}", tmp))?;
+        let mut reader = BufReader::new(
+            File::open(path).with_context(|| format!("Failed to open segment {:?}", path))?,
+        );
+        let mut encoder = GzEncoder::new(BufWriter::new(out), Compression::default());
+        io::copy(&mut reader, &mut encoder)?;
+        let mut writer = encoder.finish()?;
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+    }
+
+    fs::rename(&tmp, &gz).with_context(|| format!("Failed to move {:?} into place", gz))?;
+    fs::remove_file(path).with_context(|| format!("Failed to remove compressed segment {:?}", path))?;
+    // Offsets in the sidecar refer to the plain file and are meaningless now.
+    remove_index(path)?;
+    Ok(gz)
+}
+
+/// Compresses every plain segment in `dir` whose period ended before `now`.
+///
+/// Returns the number of segments compressed.
+pub fn compress_closed_segments(dir: &Path, tier: MetricTier, now: DateTime<Utc>) -> Result<usize> {
+    if !dir.exists() {
+        return Ok(0);
+    }
+
+    let current_period = tier.period_start(now.date_naive());
+    let mut compressed = 0;
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(PLAIN_EXT) {
+            continue;
+        }
+        let Some(period) = segment_period(&path).and_then(|s| tier.parse_period(s)) else {
+            continue;
+        };
+        if period >= current_period {
+            continue;
+        }
+
+        compress_segment(&path)?;
+        compressed += 1;
+    }
+
+    Ok(compressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::segment::test_support::scratch_dir;
+
+    fn read_all(path: &Path) -> String {
+        let mut text = String::new();
+        open_segment(path).unwrap().read_to_string(&mut text).unwrap();
+        text
+    }
+
+    #[test]
+    fn closed_segments_read_back_unchanged() {
+        let dir = scratch_dir();
+        let closed = dir.join("2025-01-01.rcd");
+        let current = dir.join("2025-01-02.rcd");
+        let rows = "2025-01-01T00:00:00Z|1\n2025-01-01T23:59:00Z|2\n";
+        fs::write(&closed, rows).unwrap();
+        fs::write(&current, "2025-01-02T00:00:00Z|3\n").unwrap();
+
+        let now = "2025-01-02T12:00:00Z".parse().unwrap();
+        assert_eq!(compress_closed_segments(&dir, MetricTier::Minute, now).unwrap(), 1);
+
+        assert!(!closed.exists());
+        assert!(compressed_path(&closed).exists());
+        assert!(current.exists());
+        assert!(segment_exists(&closed));
+        assert_eq!(read_all(&closed), rows);
+    }
+
+    #[test]
+    fn late_appends_are_read_after_and_merged_into_the_compressed_rows() {
+        let dir = scratch_dir();
+        let path = dir.join("2025-01-01.rcd");
+        fs::write(&path, "2025-01-01T00:00:00Z|1\n").unwrap();
+        compress_segment(&path).unwrap();
+
+        fs::write(&path, "2025-01-01T00:01:00Z|2\n").unwrap();
+        let both = "2025-01-01T00:00:00Z|1\n2025-01-01T00:01:00Z|2\n";
+        assert_eq!(read_all(&path), both);
+
+        compress_segment(&path).unwrap();
+        assert!(!path.exists());
+        assert_eq!(read_all(&path), both);
+        assert_eq!(segment_period(&compressed_path(&path)), Some("2025-01-01"));
+    }
+}
@@ -0,0 +1,330 @@
+//! On-disk line format of `.rcd` segments.
+//!
+//! Segments created since format version 1 start with a `#RCD <version>` header
+//! line, and every row carries a CRC32 of its payload: `PAYLOAD#xxxxxxxx`.
+//! Segments written before that have neither; their rows are read unchecked.
+//!
+//! Readers skip rows whose checksum does not match and a torn last line (one
+//! without a trailing newline), so a crash mid-append never yields a half row.
+//! Torn tails are cut off at startup and before every append.
+//!
+//! Row payloads are `|`-separated and positional. Columns are only ever
+//! appended to a layout, never reordered or removed: readers accept any row
+//! holding at least the columns of the oldest layout, read missing trailing
+//! columns as empty and ignore unknown ones, so segments stay readable across
+//! upgrades and downgrades.
+
+use std::{
+    borrow::Cow,
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+/// Current segment format version, written in the header of new segments.
+pub const FORMAT_VERSION: u32 = 1;
+
+const HEADER_PREFIX: &str = "#RCD ";
+const CHECKSUM_SEP: char = '#';
+
+/// Header line of a new segment, newline included.
+pub fn header_line() -> String {
+    format!("{}{}\n", HEADER_PREFIX, FORMAT_VERSION)
+}
+
+/// Format version of a segment header line, or `None` if `line` is not one.
+pub fn parse_header(line: &str) -> Option<u32> {
+    line.trim_end().strip_prefix(HEADER_PREFIX)?.trim().parse().ok()
+}
+
+fn checksum(payload: &str) -> String {
+    format!("{:08x}", crc32fast::hash(payload.as_bytes()))
+}
+
+/// Appends the checksum to a formatted row; the result ends with a newline.
+pub fn encode_row(row: &str) -> String {
+    let payload = row.trim_end_matches('\n');
+    format!("{}{}{}\n", payload, CHECKSUM_SEP, checksum(payload))
+}
+
+/// Result of verifying one line of a segment.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RowCheck<'a> {
+    /// Row with a matching checksum; holds the payload.
+    Valid(&'a str),
+    /// Row without checksum, as written before format version 1.
+    Unchecked(&'a str),
+    /// Row whose checksum does not match its payload.
+    Corrupt,
+}
+
+/// Verifies a line (without its newline) and strips the checksum.
+pub fn check_row(line: &str) -> RowCheck<'_> {
+    match line.rsplit_once(CHECKSUM_SEP) {
+        Some((payload, sum)) if sum == checksum(payload) => RowCheck::Valid(payload),
+        Some(_) => RowCheck::Corrupt,
+        None => RowCheck::Unchecked(line),
+    }
+}
+
+/// Payload of a complete row line (without its newline) in a segment of
+/// `format_version`, or `None` for a corrupt row.
+///
+/// Once a segment has a header every row must carry a matching checksum; rows
+/// of older segments are taken as they are.
+pub fn row_payload(line: &str, format_version: Option<u32>) -> Option<&str> {
+    match check_row(line) {
+        RowCheck::Valid(payload) => Some(payload),
+        RowCheck::Unchecked(payload) if format_version.is_none() => Some(payload),
+        _ => None,
+    }
+}
+
+/// Leading header lines of a plain segment, newlines included: the `#RCD`
+/// header, a legacy named header (see [`LegacyHeader`]), both or neither.
+pub fn read_header_lines(path: &Path) -> io::Result<String> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut header = String::new();
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let format_version = parse_header(&line);
+    if format_version.is_some() && line.ends_with('\n') {
+        header.push_str(&line);
+        line.clear();
+        reader.read_line(&mut line)?;
+    }
+    if line.ends_with('\n') && row_payload(line.trim_end(), format_version).is_some_and(LegacyHeader::is_header) {
+        header.push_str(&line);
+    }
+    Ok(header)
+}
+
+/// Named header line of segments written before rows became positional
+/// (`TIME|CPU_USAGE_NANO_CORES|...`).
+///
+/// Rows of such a segment hold exactly the header's columns, in its order;
+/// [`LegacyHeader::remap`] moves them to the current layout by column name. A
+/// header name the reader does not know keeps its position, since some old
+/// headers were written with mislabelled columns. Rows of any other width were
+/// appended after an upgrade and are already in the current layout.
+#[derive(Debug, Clone)]
+pub struct LegacyHeader {
+    /// Current column of each header column, if it has one.
+    positions: Vec<Option<usize>>,
+    width: usize,
+}
+
+impl LegacyHeader {
+    /// Whether a row payload is a legacy named header.
+    pub fn is_header(payload: &str) -> bool {
+        payload.starts_with("TIME|")
+    }
+
+    /// Parses the first payload of a segment against the current `columns`,
+    /// or `None` if it is a row.
+    pub fn parse(payload: &str, columns: &[&str]) -> Option<Self> {
+        if !Self::is_header(payload) {
+            return None;
+        }
+        let positions = payload
+            .split('|')
+            .enumerate()
+            .map(|(i, name)| {
+                columns
+                    .iter()
+                    .position(|c| c.eq_ignore_ascii_case(name.trim()))
+                    .or((i < columns.len()).then_some(i))
+            })
+            .collect();
+        Some(Self { positions, width: columns.len() })
+    }
+
+    /// `row` in the current layout, or `None` if it does not have the header's width.
+    pub fn remap(&self, row: &str) -> Option<String> {
+        let parts: Vec<&str> = row.split('|').collect();
+        if parts.len() != self.positions.len() {
+            return None;
+        }
+        let mut columns = vec![""; self.width];
+        for (value, position) in parts.into_iter().zip(&self.positions) {
+            if let Some(i) = position {
+                columns[*i] = value;
+            }
+        }
+        Some(columns.join("|"))
+    }
+}
+
+/// `row` in the current layout: remapped when the segment has a legacy named
+/// header and the row has its width, as it is otherwise.
+pub fn current_layout<'a>(legacy: Option<&LegacyHeader>, row: &'a str) -> Cow<'a, str> {
+    match legacy.and_then(|h| h.remap(row)) {
+        Some(remapped) => Cow::Owned(remapped),
+        None => Cow::Borrowed(row),
+    }
+}
+
+/// Iterator over the row payloads of a segment.
+///
+/// Skips header lines, rows failing their checksum and a torn last line. The
+/// number of skipped rows is available through [`SegmentLines::corrupt_rows`]
+/// and [`SegmentLines::torn_tail`] once the iterator is exhausted.
+pub struct SegmentLines<R> {
+    reader: BufReader<R>,
+    /// Set once a header was seen; rows without checksum are corrupt from then on.
+    format_version: Option<u32>,
+    corrupt_rows: usize,
+    torn_tail: bool,
+}
+
+impl<R: Read> SegmentLines<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            reader: BufReader::new(inner),
+            format_version: None,
+            corrupt_rows: 0,
+            torn_tail: false,
+        }
+    }
+
+    /// Version from the segment header, `None` for segments written before version 1.
+    pub fn format_version(&self) -> Option<u32> {
+        self.format_version
+    }
+
+    pub fn corrupt_rows(&self) -> usize {
+        self.corrupt_rows
+    }
+
+    pub fn torn_tail(&self) -> bool {
+        self.torn_tail
+    }
+}
+
+impl<R: Read> Iterator for SegmentLines<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+
+            if !line.ends_with('\n') {
+                // Only the last line can lack its newline: an interrupted append.
+                self.torn_tail = true;
+                return None;
+            }
+            let trimmed = line.trim_end_matches(['\n', '\r']);
+            if trimmed.is_empty() {
+                continue;
+            }
+            if let Some(version) = parse_header(trimmed) {
+                self.format_version.get_or_insert(version);
+                continue;
+            }
+
+            match row_payload(trimmed, self.format_version) {
+                Some(payload) => return Some(Ok(payload.to_string())),
+                None => self.corrupt_rows += 1,
+            }
+        }
+    }
+}
+
+/// Cuts a plain segment back to its last complete line.
+///
+/// Returns the number of bytes removed.
+pub fn truncate_torn_tail(path: &Path) -> io::Result<u64> {
+    const CHUNK: u64 = 4096;
+
+    let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+    let len = file.metadata()?.len();
+    if len == 0 {
+        return Ok(0);
+    }
+
+    let mut last = [0u8; 1];
+    file.seek(SeekFrom::Start(len - 1))?;
+    file.read_exact(&mut last)?;
+    if last[0] == b'\n' {
+        return Ok(0);
+    }
+
+    // Walk backwards to the last newline; nothing after it was ever complete.
+    let mut end = len;
+    let mut keep = 0;
+    while end > 0 {
+        let start = end.saturating_sub(CHUNK);
+        let mut buf = vec![0u8; (end - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut buf)?;
+        if let Some(pos) = buf.iter().rposition(|b| *b == b'\n') {
+            keep = start + pos as u64 + 1;
+            break;
+        }
+        end = start;
+    }
+
+    file.set_len(keep)?;
+    file.sync_all()?;
+    Ok(len - keep)
+}
+
+/// Opens a plain segment for appending rows.
+///
+/// Cuts a torn tail first so the next row does not get glued onto a partial
+/// one, and writes the header into new segments. Returns the writer together
+/// with the byte offset the next row will be written at.
+pub fn open_segment_for_append(path: &Path) -> io::Result<(BufWriter<File>, u64)> {
+    if path.exists() {
+        truncate_torn_tail(path)?;
+    }
+
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut offset = file.metadata()?.len();
+    let mut writer = BufWriter::new(file);
+    if offset == 0 {
+        let header = header_line();
+        writer.write_all(header.as_bytes())?;
+        offset = header.len() as u64;
+    }
+    Ok((writer, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::segment::test_support::scratch_dir;
+
+    const COLUMNS: &[&str] = &["TIME", "CPU", "MEMORY", "DISK"];
+
+    #[test]
+    fn legacy_header_remaps_rows_by_name() {
+        let header = LegacyHeader::parse("TIME|DISK|OLD_NAME", COLUMNS).unwrap();
+
+        // DISK moves to its current column; the unknown name keeps its position.
+        assert_eq!(header.remap("t|1|2").as_deref(), Some("t||2|1"));
+        assert_eq!(current_layout(Some(&header), "t|1|2|3|4"), "t|1|2|3|4");
+        assert!(LegacyHeader::parse("2025-01-01T00:00:00Z|1", COLUMNS).is_none());
+    }
+
+    #[test]
+    fn header_lines_include_a_legacy_header_after_the_version_header() {
+        let dir = scratch_dir();
+        let path = dir.join("seg.rcd");
+        let contents = format!("{}{}{}", header_line(), encode_row("TIME|CPU"), encode_row("t|1"));
+        std::fs::write(&path, contents).unwrap();
+
+        let header = read_header_lines(&path).unwrap();
+        assert_eq!(header, format!("{}{}", header_line(), encode_row("TIME|CPU")));
+
+        std::fs::write(&path, "t|1\n").unwrap();
+        assert_eq!(read_header_lines(&path).unwrap(), "");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,110 @@
+//! Integrity checks for metric segments.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::{
+    fs::{self, File},
+    io::{BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use flate2::read::MultiGzDecoder;
+
+use super::compression::{is_compressed_segment, segment_period};
+use super::format::{truncate_torn_tail, SegmentLines, FORMAT_VERSION};
+
+/// Result of reading one segment file end to end.
+#[derive(Debug, Clone, Serialize)]
+pub struct SegmentCheck {
+    pub path: PathBuf,
+    /// `None` for segments written before format version 1.
+    pub format_version: Option<u32>,
+    pub rows: usize,
+    pub corrupt_rows: usize,
+    pub torn_tail: bool,
+    /// Set when the segment could not be read at all (e.g. a broken gzip stream).
+    pub error: Option<String>,
+}
+
+impl SegmentCheck {
+    pub fn is_damaged(&self) -> bool {
+        self.corrupt_rows > 0
+            || self.torn_tail
+            || self.error.is_some()
+            || self.format_version.is_some_and(|v| v > FORMAT_VERSION)
+    }
+}
+
+/// Reads every row of a plain or compressed segment and verifies its checksum.
+pub fn check_segment(path: &Path) -> SegmentCheck {
+    let mut check = SegmentCheck {
+        path: path.to_path_buf(),
+        format_version: None,
+        rows: 0,
+        corrupt_rows: 0,
+        torn_tail: false,
+        error: None,
+    };
+
+    let reader: Box<dyn Read> = match File::open(path) {
+        Ok(file) if is_compressed_segment(path) => Box::new(MultiGzDecoder::new(BufReader::new(file))),
+        Ok(file) => Box::new(file),
+        Err(e) => {
+            check.error = Some(e.to_string());
+            return check;
+        }
+    };
+
+    let mut lines = SegmentLines::new(reader);
+    for line in lines.by_ref() {
+        match line {
+            Ok(_) => check.rows += 1,
+            Err(e) => {
+                check.error = Some(e.to_string());
+                break;
+            }
+        }
+    }
+    check.format_version = lines.format_version();
+    check.corrupt_rows = lines.corrupt_rows();
+    check.torn_tail = lines.torn_tail();
+    check
+}
+
+/// Checks every segment (plain and compressed) in a tier directory.
+pub fn check_dir(dir: &Path) -> Result<Vec<SegmentCheck>> {
+    let mut checks = Vec::new();
+    if !dir.exists() {
+        return Ok(checks);
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if segment_period(&path).is_some() {
+            checks.push(check_segment(&path));
+        }
+    }
+    checks.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(checks)
+}
+
+/// Cuts torn tails off every plain segment in a tier directory.
+///
+/// Returns the repaired segments with the number of bytes removed from each.
+pub fn repair_dir_torn_tails(dir: &Path) -> Result<Vec<(PathBuf, u64)>> {
+    let mut repaired = Vec::new();
+    if !dir.exists() {
+        return Ok(repaired);
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if segment_period(&path).is_none() || is_compressed_segment(&path) {
+            continue;
+        }
+        let removed = truncate_torn_tail(&path)
+            .with_context(|| format!("Failed to truncate torn tail of {:?}", path))?;
+        if removed > 0 {
+            repaired.push((path, removed));
+        }
+    }
+    Ok(repaired)
+}
@@ -0,0 +1,339 @@
+//! Sparse time index sidecars for `.rcd` segments.
+//!
+//! Each plain segment `X.rcd` may have a sidecar `X.rcd.idx` holding one
+//! `EPOCH_SECS|BYTE_OFFSET` line per stride (e.g. every 10 minutes of a minute
+//! file). Readers binary-search the sidecar for the last entry at or before the
+//! requested start and seek there instead of scanning from the top of the file.
+//!
+//! Sidecars are an optimisation only: a missing, stale or unreadable index falls
+//! back to a full scan, and compressed segments are never indexed.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use super::compression::{compressed_path, open_segment};
+use super::format::{parse_header, read_header_lines, row_payload};
+use super::tier::MetricTier;
+use super::write_buffer::{flush_path, release_path};
+
+const INDEX_SUFFIX: &str = ".idx";
+
+/// Mirrors the `enable_index_file` setting; refreshed by the minute task.
+static INDEX_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enables or disables writing index entries on append.
+pub fn set_index_enabled(enabled: bool) {
+    INDEX_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn index_enabled() -> bool {
+    INDEX_ENABLED.load(Ordering::Relaxed)
+}
+
+/// `2025-01-01.rcd` → `2025-01-01.rcd.idx`
+pub fn index_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map(|n| n.to_os_string()).unwrap_or_default();
+    name.push(INDEX_SUFFIX);
+    path.with_file_name(name)
+}
+
+/// Minimum time between two index entries of a segment.
+pub fn stride(tier: MetricTier) -> Duration {
+    match tier {
+        MetricTier::Minute => Duration::minutes(10),
+        MetricTier::Hour => Duration::hours(6),
+        MetricTier::Day => Duration::days(7),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct IndexEntry {
+    epoch: i64,
+    offset: u64,
+}
+
+fn parse_entry(line: &str) -> Option<IndexEntry> {
+    let (epoch, offset) = line.split_once('|')?;
+    Some(IndexEntry {
+        epoch: epoch.trim().parse().ok()?,
+        offset: offset.trim().parse().ok()?,
+    })
+}
+
+fn read_entries(idx: &Path) -> io::Result<Vec<IndexEntry>> {
+    let reader = BufReader::new(File::open(idx)?);
+    Ok(reader
+        .lines()
+        .map_while(|l| l.ok())
+        .filter_map(|l| parse_entry(&l))
+        .collect())
+}
+
+/// Records the row written at `offset` in `path` if it starts a new stride.
+///
+/// Called by the fs adapters right after appending a row. Does nothing while
+/// `enable_index_file` is off.
+pub fn record_append(path: &Path, tier: MetricTier, time: DateTime<Utc>, offset: u64) -> Result<()> {
+    if !index_enabled() {
+        return Ok(());
+    }
+
+    let idx = index_path(path);
+    let last = if idx.exists() {
+        read_entries(&idx)?.last().copied()
+    } else if offset > 0 {
+        // Segment predates the sidecar; index it fully rather than starting mid-file.
+        rebuild_index(path, tier)?;
+        return Ok(());
+    } else {
+        None
+    };
+
+    if let Some(last) = last {
+        if time.timestamp() - last.epoch < stride(tier).num_seconds() {
+            return Ok(());
+        }
+    }
+
+    let mut f = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&idx)
+        .with_context(|| format!("Failed to open index {:?}", idx))?;
+    writeln!(f, "{}|{}", time.timestamp(), offset)?;
+    Ok(())
+}
+
+/// Byte offset to start reading `path` from to find rows at or after `start`.
+///
+/// Returns `None` when no usable index exists.
+fn seek_offset(path: &Path, start: DateTime<Utc>) -> Option<u64> {
+    let entries = read_entries(&index_path(path)).ok()?;
+    let pos = entries.partition_point(|e| e.epoch <= start.timestamp());
+    let entry = entries.get(pos.checked_sub(1)?)?;
+
+    // A sidecar pointing past the end of the segment is stale; ignore it.
+    let len = fs::metadata(path).ok()?.len();
+    (entry.offset <= len).then_some(entry.offset)
+}
+
+/// Opens a segment positioned as close as the index allows to `start`.
+///
+/// Falls back to [`open_segment`] (reading from the beginning) for compressed
+/// segments or when no usable index exists. After a seek the segment header is
+/// replayed in front of the first row, so readers still know the format version
+/// and reject rows without checksum; apart from that, the first line returned is
+/// always a complete row, so callers parse it exactly as they would without the index.
+pub fn open_segment_from(path: &Path, start: DateTime<Utc>) -> io::Result<Box<dyn Read + Send>> {
+    // Buffered rows and their index entries must be on disk before seeking.
+    if let Err(e) = flush_path(path) {
+        tracing::warn!("{}", e);
+    }
+    if compressed_path(path).exists() {
+        return open_segment(path);
+    }
+
+    match seek_offset(path, start) {
+        Some(offset) if offset > 0 => {
+            let header = read_header_lines(path)?;
+            let mut file = File::open(path)?;
+            file.seek(SeekFrom::Start(offset))?;
+            Ok(Box::new(io::Cursor::new(header.into_bytes()).chain(file)))
+        }
+        _ => open_segment(path),
+    }
+}
+
+/// Rewrites the sidecar of a plain segment from its contents.
+///
+/// Returns the number of index entries written.
+pub fn rebuild_index(path: &Path, tier: MetricTier) -> Result<usize> {
+    let idx = index_path(path);
+    let tmp = idx.with_extension("idx.tmp");
+    let stride = stride(tier).num_seconds();
+
+    let mut reader = BufReader::new(
+        File::open(path).with_context(|| format!("Failed to open segment {:?}", path))?,
+    );
+    let mut writer = BufWriter::new(File::create(&tmp)?);
+    let mut offset: u64 = 0;
+    let mut last_epoch: Option<i64> = None;
+    let mut written = 0;
+    let mut format_version = None;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        let n = reader.read_line(&mut line)?;
+        if n == 0 {
+            break;
+        }
+
+        // Only complete rows with a valid checksum (or none, in segments from
+        // before format version 1) and a leading timestamp are indexable.
+        if line.ends_with('\n') {
+            let trimmed = line.trim_end_matches(['\n', '\r']);
+            let time = match parse_header(trimmed) {
+                Some(version) => {
+                    format_version.get_or_insert(version);
+                    None
+                }
+                None => row_payload(trimmed, format_version)
+                    .and_then(|payload| payload.split('|').next())
+                    .and_then(|t| t.parse::<DateTime<Utc>>().ok()),
+            };
+            if let Some(time) = time {
+                let epoch = time.timestamp();
+                if last_epoch.is_none_or(|last| epoch - last >= stride) {
+                    writeln!(writer, "{}|{}", epoch, offset)?;
+                    last_epoch = Some(epoch);
+                    written += 1;
+                }
+            }
+        }
+        offset += n as u64;
+    }
+
+    writer.flush()?;
+    drop(writer);
+    fs::rename(&tmp, &idx).with_context(|| format!("Failed to move index {:?} into place", idx))?;
+    Ok(written)
+}
+
+/// Rebuilds the sidecars of every plain segment in `dir`.
+///
+/// Stale sidecars whose segment is gone or compressed are removed.
+/// Returns the number of segments indexed.
+pub fn rebuild_dir_indexes(dir: &Path, tier: MetricTier) -> Result<usize> {
+    if !dir.exists() {
+        return Ok(0);
+    }
+
+    let mut rebuilt = 0;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else { continue };
+
+        if let Some(segment) = name.strip_suffix(INDEX_SUFFIX) {
+            let segment = path.with_file_name(segment);
+            if !segment.exists() {
+                fs::remove_file(&path)?;
+            }
+            continue;
+        }
+
+        if path.extension().and_then(|e| e.to_str()) == Some("rcd") {
+            rebuild_index(&path, tier)?;
+            rebuilt += 1;
+        }
+    }
+    Ok(rebuilt)
+}
+
+/// Removes a segment together with its index sidecar.
+pub fn remove_segment(path: &Path) -> io::Result<()> {
+    // Rows still buffered for a segment being deleted need not reach it.
+    let _ = release_path(path);
+    fs::remove_file(path)?;
+    remove_index(path)
+}
+
+/// Removes the index sidecar of `path`, if any.
+pub fn remove_index(path: &Path) -> io::Result<()> {
+    let idx = index_path(path);
+    if idx.exists() {
+        fs::remove_file(idx)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::segment::format::{encode_row, header_line, SegmentLines};
+    use crate::core::persistence::metrics::segment::test_support::scratch_dir;
+
+    fn row(minute: u32) -> String {
+        format!("2025-01-01T00:{:02}:00Z|{}\n", minute, minute)
+    }
+
+    fn write_segment(lines: &[String]) -> PathBuf {
+        let path = scratch_dir().join("2025-01-01.rcd");
+        fs::write(&path, lines.concat()).unwrap();
+        path
+    }
+
+    #[test]
+    fn rebuild_index_skips_header_and_corrupt_rows() {
+        let corrupt = "2025-01-01T00:00:00Z|0#deadbeef\n".to_string();
+        let path = write_segment(&[header_line(), corrupt.clone(), encode_row(&row(1)), encode_row(&row(20))]);
+
+        assert_eq!(rebuild_index(&path, MetricTier::Minute).unwrap(), 2);
+        let entries = read_entries(&index_path(&path)).unwrap();
+        assert_eq!(entries[0].offset, (header_line().len() + corrupt.len()) as u64);
+        assert_eq!(entries[1].epoch - entries[0].epoch, 19 * 60);
+    }
+
+    #[test]
+    fn seek_keeps_header_and_rejects_unchecked_rows() {
+        let path = write_segment(&[
+            header_line(),
+            encode_row(&row(0)),
+            encode_row(&row(10)),
+            row(11),
+            encode_row(&row(20)),
+        ]);
+        rebuild_index(&path, MetricTier::Minute).unwrap();
+
+        let start = "2025-01-01T00:15:00Z".parse().unwrap();
+        let mut lines = SegmentLines::new(open_segment_from(&path, start).unwrap());
+        let rows: Vec<String> = lines.by_ref().map(|l| l.unwrap()).collect();
+
+        assert_eq!(rows, vec!["2025-01-01T00:10:00Z|10", "2025-01-01T00:20:00Z|20"]);
+        assert_eq!(lines.format_version(), Some(1));
+        assert_eq!(lines.corrupt_rows(), 1);
+    }
+
+    #[test]
+    fn record_append_writes_one_entry_per_stride() {
+        let path = scratch_dir().join("2025-01-01.rcd");
+        let mut offset = 0;
+        for minute in 0..25 {
+            let line = encode_row(&row(minute));
+            let time = format!("2025-01-01T00:{:02}:00Z", minute).parse().unwrap();
+            record_append(&path, MetricTier::Minute, time, offset).unwrap();
+            offset += line.len() as u64;
+        }
+
+        let epochs: Vec<i64> = read_entries(&index_path(&path)).unwrap().iter().map(|e| e.epoch).collect();
+        assert_eq!(epochs.len(), 3);
+        assert_eq!(epochs[1] - epochs[0], 10 * 60);
+        assert_eq!(epochs[2] - epochs[1], 10 * 60);
+    }
+
+    #[test]
+    fn seek_falls_back_to_a_full_read_before_the_first_entry_and_on_stale_index() {
+        let lines = [header_line(), encode_row(&row(0)), encode_row(&row(10)), encode_row(&row(20))];
+        let path = write_segment(&lines);
+        rebuild_index(&path, MetricTier::Minute).unwrap();
+
+        let read = |start: &str| {
+            let mut text = String::new();
+            open_segment_from(&path, start.parse().unwrap()).unwrap().read_to_string(&mut text).unwrap();
+            text
+        };
+        assert_eq!(read("2024-12-31T23:00:00Z"), lines.concat());
+        assert_eq!(read("2025-01-01T00:20:00Z"), [header_line(), encode_row(&row(20))].concat());
+
+        // Offsets past the end of the segment belong to an older file.
+        let epoch = "2025-01-01T00:10:00Z".parse::<DateTime<Utc>>().unwrap().timestamp();
+        fs::write(index_path(&path), format!("{}|999999\n", epoch)).unwrap();
+        assert_eq!(read("2025-01-01T00:20:00Z"), lines.concat());
+    }
+}
@@ -0,0 +1,20 @@
+pub mod compression;
+pub mod format;
+pub mod fsck;
+pub mod index;
+pub mod rewrite;
+pub mod tier;
+pub mod watermark;
+pub mod write_buffer;
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::path::PathBuf;
+
+    /// Empty directory of its own under the system temp dir.
+    pub fn scratch_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rustcost-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+}
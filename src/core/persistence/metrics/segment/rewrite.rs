@@ -0,0 +1,139 @@
+//! In-place rewrite of a segment, used when rows must be replaced rather than appended.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use super::compression::{compressed_path, open_segment, segment_exists};
+use super::format::{encode_row, header_line, open_segment_for_append, SegmentLines};
+use super::index::{index_enabled, rebuild_index, record_append, remove_index};
+use super::tier::MetricTier;
+use super::write_buffer::release_path;
+
+fn row_time(line: &str) -> Option<DateTime<Utc>> {
+    line.split('|').next()?.parse().ok()
+}
+
+/// Timestamp of the last complete row, read from the tail of the file.
+fn last_row_time(path: &Path) -> Result<Option<DateTime<Utc>>> {
+    const TAIL: u64 = 4096;
+
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(TAIL)))?;
+    let mut tail = Vec::new();
+    file.read_to_end(&mut tail)?;
+
+    // A torn last line is cut off before the next append; ignore it here too.
+    let tail = String::from_utf8_lossy(&tail);
+    let complete = tail.rfind('\n').map_or("", |i| &tail[..i]);
+    Ok(complete
+        .lines()
+        .rev()
+        .filter(|l| !l.is_empty())
+        .find_map(row_time))
+}
+
+fn append_rows(path: &Path, tier: MetricTier, rows: &[(DateTime<Utc>, String)]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let (mut writer, mut offset) = open_segment_for_append(path)?;
+
+    let mut offsets = Vec::with_capacity(rows.len());
+    for (time, line) in rows {
+        let encoded = encode_row(line);
+        writer.write_all(encoded.as_bytes())?;
+        offsets.push((*time, offset));
+        offset += encoded.len() as u64;
+    }
+    writer.flush()?;
+
+    for (time, offset) in offsets {
+        record_append(path, tier, time, offset)?;
+    }
+    Ok(())
+}
+
+/// Merges `rows` (timestamp + formatted line without newline) into the segment at `path`.
+///
+/// Existing rows with the same timestamp as a new row are dropped, and the result
+/// is written back sorted by time in the current segment format; rows failing
+/// their checksum are dropped. A compressed segment is rewritten as a plain
+/// one; the compression task picks it up again once it is closed.
+pub fn upsert_segment_rows(path: &Path, tier: MetricTier, mut rows: Vec<(DateTime<Utc>, String)>) -> Result<()> {
+    if rows.is_empty() {
+        return Ok(());
+    }
+    rows.sort_by_key(|(t, _)| *t);
+    // The file is replaced below; an open append handle would keep writing to the old one.
+    release_path(path)?;
+
+    // Common case: only new windows past the end of a plain segment.
+    if !compressed_path(path).exists() {
+        let append_only = if path.exists() {
+            last_row_time(path)?.is_some_and(|last| rows[0].0 > last)
+        } else {
+            true
+        };
+        if append_only {
+            return append_rows(path, tier, &rows);
+        }
+    }
+
+    let replaced: HashSet<DateTime<Utc>> = rows.iter().map(|(t, _)| *t).collect();
+    let mut header: Vec<String> = Vec::new();
+    let mut merged: Vec<(DateTime<Utc>, String)> = Vec::new();
+
+    if segment_exists(path) {
+        for line in SegmentLines::new(open_segment(path)?) {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            match row_time(&line) {
+                Some(t) if replaced.contains(&t) => {}
+                Some(t) => merged.push((t, line)),
+                None if merged.is_empty() => header.push(line),
+                // Unparseable rows in the middle of a file are dropped on rewrite.
+                None => {}
+            }
+        }
+    }
+
+    merged.extend(rows);
+    merged.sort_by_key(|(t, _)| *t);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("rcd.tmp");
+    {
+        let mut writer = BufWriter::new(
+            File::create(&tmp).with_context(|| format!("Failed to create {:?}", tmp))?,
+        );
+        writer.write_all(header_line().as_bytes())?;
+        for line in header.iter().chain(merged.iter().map(|(_, l)| l)) {
+            writer.write_all(encode_row(line).as_bytes())?;
+        }
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+    }
+
+    fs::rename(&tmp, path).with_context(|| format!("Failed to move {:?} into place", path))?;
+    let gz = compressed_path(path);
+    if gz.exists() {
+        fs::remove_file(&gz)?;
+    }
+
+    remove_index(path)?;
+    if index_enabled() {
+        rebuild_index(path, tier)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use chrono::{Datelike, Days, Months, NaiveDate};
+
+/// Storage tier of a metric segment; each tier keeps one `.rcd` file per period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetricTier {
+    /// `m/YYYY-MM-DD.rcd`
+    Minute,
+    /// `h/YYYY-MM.rcd`
+    Hour,
+    /// `d/YYYY.rcd`
+    Day,
+}
+
+impl MetricTier {
+    pub const ALL: [MetricTier; 3] = [MetricTier::Minute, MetricTier::Hour, MetricTier::Day];
+
+    /// Directory name of the tier under an object's metric directory.
+    pub fn dir_name(&self) -> &'static str {
+        match self {
+            MetricTier::Minute => "m",
+            MetricTier::Hour => "h",
+            MetricTier::Day => "d",
+        }
+    }
+
+    /// Period stem of the segment containing `date`.
+    pub fn period_of(&self, date: NaiveDate) -> String {
+        match self {
+            MetricTier::Minute => date.format("%Y-%m-%d").to_string(),
+            MetricTier::Hour => date.format("%Y-%m").to_string(),
+            MetricTier::Day => date.format("%Y").to_string(),
+        }
+    }
+
+    /// Parses a period stem into the first day of that period.
+    pub fn parse_period(&self, stem: &str) -> Option<NaiveDate> {
+        match self {
+            MetricTier::Minute => NaiveDate::parse_from_str(stem, "%Y-%m-%d").ok(),
+            MetricTier::Hour => NaiveDate::parse_from_str(&format!("{}-01", stem), "%Y-%m-%d").ok(),
+            MetricTier::Day => {
+                if stem.len() != 4 {
+                    return None;
+                }
+                NaiveDate::from_ymd_opt(stem.parse().ok()?, 1, 1)
+            }
+        }
+    }
+
+    /// First day of the period containing `date`.
+    pub fn period_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            MetricTier::Minute => date,
+            MetricTier::Hour => date.with_day(1).unwrap_or(date),
+            MetricTier::Day => NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap_or(date),
+        }
+    }
+
+    /// First day of the period after the one containing `date`.
+    pub fn next_period_start(&self, date: NaiveDate) -> NaiveDate {
+        let start = self.period_start(date);
+        let next = match self {
+            MetricTier::Minute => start.checked_add_days(Days::new(1)),
+            MetricTier::Hour => start.checked_add_months(Months::new(1)),
+            MetricTier::Day => start.checked_add_months(Months::new(12)),
+        };
+        next.unwrap_or(NaiveDate::MAX)
+    }
+}
@@ -0,0 +1,144 @@
+//! Per-object aggregation watermarks.
+//!
+//! Under the `fs` backend each hour/day tier directory keeps a `.watermark`
+//! file with the end of the last window that was aggregated into it; other
+//! backends keep them alongside their rows. Processors resume from there, so
+//! windows missed during a restart or outage are caught up on the next run.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const WATERMARK_FILE: &str = ".watermark";
+
+fn watermark_path(tier_dir: &Path) -> PathBuf {
+    tier_dir.join(WATERMARK_FILE)
+}
+
+/// End of the last aggregated window, if one was recorded.
+pub fn read_watermark(tier_dir: &Path) -> Option<DateTime<Utc>> {
+    fs::read_to_string(watermark_path(tier_dir))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Records `end` as the last aggregated window end.
+pub fn write_watermark(tier_dir: &Path, end: DateTime<Utc>) -> Result<()> {
+    fs::create_dir_all(tier_dir)?;
+    let path = watermark_path(tier_dir);
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, end.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        .with_context(|| format!("Failed to write watermark {:?}", tmp))?;
+    fs::rename(&tmp, &path).with_context(|| format!("Failed to move watermark {:?} into place", path))?;
+    Ok(())
+}
+
+/// Windows of length `step` that are due, ending at `until` (inclusive).
+///
+/// Starts right after `watermark` and returns at most `max_windows`, oldest
+/// first. Callers move the watermark to the end of the last one, so a longer
+/// backlog after an outage is worked off over the following runs without
+/// skipping a window. Objects without a watermark yet (e.g. right after
+/// upgrading) start `max_windows` before `until`.
+pub fn pending_windows(
+    watermark: Option<DateTime<Utc>>,
+    until: DateTime<Utc>,
+    step: Duration,
+    max_windows: usize,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let until = until.duration_trunc(step).unwrap_or(until);
+    let max_windows = max_windows.max(1);
+    let mut cursor = match watermark {
+        Some(w) => w.duration_trunc(step).unwrap_or(w),
+        None => until - step * max_windows as i32,
+    };
+
+    let mut windows = Vec::new();
+    while cursor + step <= until && windows.len() < max_windows {
+        windows.push((cursor, cursor + step));
+        cursor += step;
+    }
+    windows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::segment::test_support::scratch_dir;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn starts(windows: &[(DateTime<Utc>, DateTime<Utc>)]) -> Vec<DateTime<Utc>> {
+        windows.iter().map(|(start, _)| *start).collect()
+    }
+
+    #[test]
+    fn resumes_after_the_watermark_up_to_the_last_closed_window() {
+        let windows = pending_windows(
+            Some(at("2025-01-01T10:00:00Z")),
+            at("2025-01-01T12:30:00Z"),
+            Duration::hours(1),
+            24,
+        );
+        assert_eq!(starts(&windows), vec![at("2025-01-01T10:00:00Z"), at("2025-01-01T11:00:00Z")]);
+        assert_eq!(windows[1].1, at("2025-01-01T12:00:00Z"));
+    }
+
+    #[test]
+    fn nothing_is_due_when_the_watermark_is_current() {
+        let windows = pending_windows(
+            Some(at("2025-01-01T12:00:00Z")),
+            at("2025-01-01T12:59:59Z"),
+            Duration::hours(1),
+            24,
+        );
+        assert!(windows.is_empty());
+    }
+
+    #[test]
+    fn catch_up_after_an_outage_proceeds_oldest_first_in_batches() {
+        let until = at("2025-01-10T00:00:00Z");
+        let first_batch = pending_windows(Some(at("2025-01-01T00:00:00Z")), until, Duration::hours(1), 3);
+        assert_eq!(
+            starts(&first_batch),
+            vec![at("2025-01-01T00:00:00Z"), at("2025-01-01T01:00:00Z"), at("2025-01-01T02:00:00Z")]
+        );
+
+        let next_batch = pending_windows(Some(first_batch[2].1), until, Duration::hours(1), 3);
+        assert_eq!(next_batch[0].0, at("2025-01-01T03:00:00Z"));
+    }
+
+    #[test]
+    fn first_run_starts_the_batch_before_until() {
+        let windows = pending_windows(None, at("2025-01-10T00:00:00Z"), Duration::hours(1), 3);
+        assert_eq!(
+            starts(&windows),
+            vec![at("2025-01-09T21:00:00Z"), at("2025-01-09T22:00:00Z"), at("2025-01-09T23:00:00Z")]
+        );
+    }
+
+    #[test]
+    fn unaligned_watermark_redoes_its_window() {
+        let windows = pending_windows(
+            Some(at("2025-01-01T10:20:00Z")),
+            at("2025-01-01T11:00:00Z"),
+            Duration::hours(1),
+            24,
+        );
+        assert_eq!(starts(&windows), vec![at("2025-01-01T10:00:00Z")]);
+    }
+
+    #[test]
+    fn watermark_round_trips_through_its_file() {
+        let dir = scratch_dir().join("hour");
+        assert_eq!(read_watermark(&dir), None);
+
+        write_watermark(&dir, at("2025-01-01T12:00:00Z")).unwrap();
+        assert_eq!(read_watermark(&dir), Some(at("2025-01-01T12:00:00Z")));
+    }
+}
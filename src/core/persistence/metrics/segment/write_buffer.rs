@@ -0,0 +1,295 @@
+//! Buffered appends to plain `.rcd` segments.
+//!
+//! Minute collectors append one row per object per tick. Instead of opening,
+//! writing and flushing the segment for every row, the buffer keeps the segment
+//! open and holds encoded rows in its `BufWriter` until `metrics_batch_size`
+//! rows are pending across all segments or the flush loop runs.
+//!
+//! Anything that reads, rewrites, moves or deletes a segment must see the rows
+//! on disk first: readers call [`flush_path`], rewriters call [`release_path`]
+//! and the exclusive storage lock calls [`release_all`]. Index entries for a
+//! row are only recorded once the row itself has been flushed.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        LazyLock, Mutex, MutexGuard,
+    },
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+use crate::core::persistence::quota::storage_quota_manager::record_written;
+
+use super::format::{encode_row, open_segment_for_append};
+use super::index::{record_append, stride};
+use super::tier::MetricTier;
+
+/// Pending rows that trigger a flush when no setting has been loaded yet.
+const DEFAULT_BATCH_SIZE: usize = 500;
+
+/// Segments kept open at once; the least recently written one is closed first.
+const MAX_OPEN_SEGMENTS: usize = 2048;
+
+/// Segments not appended to for this long are closed by [`flush_all`]
+/// (deleted objects, yesterday's minute files).
+const IDLE_CLOSE_AFTER: Duration = Duration::from_secs(180);
+
+/// Mirrors the `metrics_batch_size` setting; refreshed by the minute task.
+static BATCH_SIZE: AtomicUsize = AtomicUsize::new(DEFAULT_BATCH_SIZE);
+
+static WRITE_BUFFER: LazyLock<Mutex<WriteBuffer>> = LazyLock::new(|| Mutex::new(WriteBuffer::default()));
+
+/// Sets how many pending rows trigger a flush; `0` and `1` write through.
+pub fn set_batch_size(size: u32) {
+    BATCH_SIZE.store(size.max(1) as usize, Ordering::Relaxed);
+}
+
+pub fn batch_size() -> usize {
+    BATCH_SIZE.load(Ordering::Relaxed)
+}
+
+struct OpenSegment {
+    writer: BufWriter<File>,
+    tier: MetricTier,
+    /// Byte offset the next row will be written at.
+    offset: u64,
+    /// Rows written to `writer` but not flushed yet: time and offset.
+    pending: Vec<(DateTime<Utc>, u64)>,
+    /// Time of the last row handed to the index, to skip rows inside its stride.
+    last_indexed: Option<DateTime<Utc>>,
+    last_write: Instant,
+}
+
+impl OpenSegment {
+    fn flush(&mut self, path: &Path) -> Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        self.writer
+            .flush()
+            .with_context(|| format!("Failed to flush segment {:?}", path))?;
+
+        let stride = stride(self.tier);
+        for (time, offset) in self.pending.drain(..) {
+            if self.last_indexed.is_some_and(|last| time - last < stride) {
+                continue;
+            }
+            if let Err(e) = record_append(path, self.tier, time, offset) {
+                warn!("Failed to update index for {:?}: {}", path, e);
+            }
+            self.last_indexed = Some(time);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct WriteBuffer {
+    segments: HashMap<PathBuf, OpenSegment>,
+    pending_rows: usize,
+}
+
+impl WriteBuffer {
+    fn append(&mut self, path: &Path, tier: MetricTier, time: DateTime<Utc>, row: &str) -> Result<()> {
+        if !self.segments.contains_key(path) {
+            if self.segments.len() >= MAX_OPEN_SEGMENTS {
+                self.close_least_recent();
+            }
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let (writer, offset) = open_segment_for_append(path)
+                .with_context(|| format!("Failed to open segment {:?}", path))?;
+            self.segments.insert(
+                path.to_path_buf(),
+                OpenSegment {
+                    writer,
+                    tier,
+                    offset,
+                    pending: Vec::new(),
+                    last_indexed: None,
+                    last_write: Instant::now(),
+                },
+            );
+        }
+
+        let encoded = encode_row(row);
+        let Some(segment) = self.segments.get_mut(path) else {
+            return Ok(());
+        };
+        if let Err(e) = segment.writer.write_all(encoded.as_bytes()) {
+            // The writer may hold part of the row; drop it so the next append reopens
+            // the segment and cuts the torn tail.
+            self.close(path);
+            return Err(e).with_context(|| format!("Failed to append to segment {:?}", path));
+        }
+        segment.pending.push((time, segment.offset));
+        segment.offset += encoded.len() as u64;
+        record_written(encoded.len() as u64);
+        segment.last_write = Instant::now();
+        self.pending_rows += 1;
+
+        if self.pending_rows >= batch_size() {
+            self.flush_all(false)?;
+        }
+        Ok(())
+    }
+
+    /// Flushes every segment; with `close_idle` also closes idle ones.
+    /// Keeps going past failures and returns the first one.
+    fn flush_all(&mut self, close_idle: bool) -> Result<usize> {
+        let flushed = self.pending_rows;
+        let mut first_err = None;
+        let mut failed = Vec::new();
+
+        for (path, segment) in self.segments.iter_mut() {
+            if let Err(e) = segment.flush(path) {
+                warn!("⚠️ {}", e);
+                failed.push(path.clone());
+                first_err.get_or_insert(e);
+            }
+        }
+        self.pending_rows = 0;
+
+        for path in failed {
+            self.close(&path);
+        }
+        if close_idle {
+            self.segments
+                .retain(|_, segment| segment.last_write.elapsed() < IDLE_CLOSE_AFTER);
+        }
+
+        match first_err {
+            Some(e) => Err(e),
+            None => Ok(flushed),
+        }
+    }
+
+    fn flush_path(&mut self, path: &Path) -> Result<()> {
+        let Some(segment) = self.segments.get_mut(path) else {
+            return Ok(());
+        };
+        let pending = segment.pending.len();
+        let flushed = segment.flush(path);
+        self.pending_rows = self.pending_rows.saturating_sub(pending);
+        if flushed.is_err() {
+            self.close(path);
+        }
+        flushed
+    }
+
+    /// Drops the handle of `path`; its pending rows are dropped with it.
+    fn close(&mut self, path: &Path) {
+        if let Some(segment) = self.segments.remove(path) {
+            self.pending_rows = self.pending_rows.saturating_sub(segment.pending.len());
+            // `BufWriter` would retry the write on drop; the rows are already lost.
+            let _ = segment.writer.into_parts();
+        }
+    }
+
+    fn close_least_recent(&mut self) {
+        let Some(path) = self
+            .segments
+            .iter()
+            .min_by_key(|(_, segment)| segment.last_write)
+            .map(|(path, _)| path.clone())
+        else {
+            return;
+        };
+        if let Err(e) = self.flush_path(&path) {
+            warn!("⚠️ {}", e);
+        }
+        self.close(&path);
+    }
+}
+
+fn buffer() -> MutexGuard<'static, WriteBuffer> {
+    // A panic mid-append leaves at worst a half-written row in a BufWriter,
+    // which readers already skip; keep using the buffer.
+    WRITE_BUFFER.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Appends a formatted row to the segment at `path`.
+///
+/// The row reaches the file once `metrics_batch_size` rows are pending, on the
+/// next [`flush_all`], or when the segment is read or rewritten.
+pub fn append_row(path: &Path, tier: MetricTier, time: DateTime<Utc>, row: &str) -> Result<()> {
+    buffer().append(path, tier, time, row)
+}
+
+/// Writes out every pending row and closes segments that went idle.
+///
+/// Returns the number of rows flushed.
+pub fn flush_all() -> Result<usize> {
+    buffer().flush_all(true)
+}
+
+/// Writes out the pending rows of one segment before it is read.
+pub fn flush_path(path: &Path) -> Result<()> {
+    buffer().flush_path(path)
+}
+
+/// Flushes and closes one segment before it is rewritten, moved or removed.
+pub fn release_path(path: &Path) -> Result<()> {
+    let mut buffer = buffer();
+    let flushed = buffer.flush_path(path);
+    buffer.close(path);
+    flushed
+}
+
+/// Closes every segment under `dir`, dropping its pending rows, before the
+/// directory is removed.
+pub fn release_dir(dir: &Path) {
+    let mut buffer = buffer();
+    let paths: Vec<PathBuf> = buffer
+        .segments
+        .keys()
+        .filter(|path| path.starts_with(dir))
+        .cloned()
+        .collect();
+    for path in paths {
+        buffer.close(&path);
+    }
+}
+
+/// Flushes and closes every segment; used on shutdown and by the exclusive lock.
+pub fn release_all() -> Result<usize> {
+    let mut buffer = buffer();
+    let flushed = buffer.flush_all(false);
+    buffer.segments.clear();
+    flushed
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::segment::format::header_line;
+    use crate::core::persistence::metrics::segment::test_support::scratch_dir;
+    use crate::core::persistence::storage_lock::blocking_lock_for_write;
+
+    #[test]
+    fn rows_stay_buffered_until_the_segment_is_flushed() {
+        // Like any writer, hold the shared lock so no exclusive lock flushes the buffer meanwhile.
+        let _guard = blocking_lock_for_write();
+        let path = scratch_dir().join("m").join("2025-01-01.rcd");
+        let time: DateTime<Utc> = "2025-01-01T00:00:00Z".parse().unwrap();
+
+        append_row(&path, MetricTier::Minute, time, "row-1").unwrap();
+        append_row(&path, MetricTier::Minute, time + chrono::Duration::minutes(1), "row-2").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "");
+
+        flush_path(&path).unwrap();
+        let expected = format!("{}{}{}", header_line(), encode_row("row-1"), encode_row("row-2"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+
+        release_path(&path).unwrap();
+    }
+}
@@ -1,3 +1,8 @@
+pub mod archive;
+pub mod backup;
+pub mod export;
 pub mod info;
 pub mod metrics;
+pub mod quota;
+pub mod storage_lock;
 pub mod storage_path;
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod storage_usage;
+pub mod storage_quota_manager;
@@ -0,0 +1,238 @@
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::{debug, warn};
+
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{MetricBackendKind, MetricStorageBackend};
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_container_dir_path, metric_k8s_node_dir_path, metric_k8s_node_network_dir_path,
+    metric_k8s_node_system_dir_path, metric_k8s_pod_dir_path, metric_k8s_pvc_dir_path,
+};
+use crate::core::persistence::metrics::segment::compression::segment_period;
+use crate::core::persistence::metrics::segment::index::{index_path, remove_segment};
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+use super::storage_usage::StorageUsage;
+
+const BYTES_PER_GB: u64 = 1024 * 1024 * 1024;
+
+/// Usage ratio above which a warning is logged even though nothing is evicted yet.
+const WARN_RATIO: f64 = 0.9;
+
+/// Tiers eligible for eviction, in the order they are sacrificed.
+const EVICTION_ORDER: [MetricTier; 2] = [MetricTier::Minute, MetricTier::Hour];
+
+/// Usage found by the last full walk; `u64::MAX` until the first one.
+static MEASURED_BYTES: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// Bytes written under the data directory since the last full walk.
+static WRITTEN_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Counts bytes written since the last full walk, so [`StorageQuotaManager::check`]
+/// can tell without walking whether the limit may have been reached.
+pub fn record_written(bytes: u64) {
+    WRITTEN_BYTES.fetch_add(bytes, Ordering::Relaxed);
+}
+
+/// Drops the measured usage after the data directory was replaced or rewritten
+/// wholesale, so the next [`StorageQuotaManager::check`] walks it again.
+pub fn forget_measured_usage() {
+    MEASURED_BYTES.store(u64::MAX, Ordering::Relaxed);
+}
+
+/// Usage of the last full walk plus the bytes written since; `None` before the
+/// first walk. Compression and deletions are only picked up by the next walk,
+/// so this errs on the high side.
+pub fn estimated_usage() -> Option<u64> {
+    match MEASURED_BYTES.load(Ordering::Relaxed) {
+        u64::MAX => None,
+        measured => Some(measured.saturating_add(WRITTEN_BYTES.load(Ordering::Relaxed))),
+    }
+}
+
+/// Outcome of one quota enforcement pass.
+#[derive(Debug, Clone, Serialize)]
+pub struct QuotaReport {
+    pub limit_bytes: u64,
+    pub used_bytes_before: u64,
+    pub used_bytes_after: u64,
+    pub evicted_segments: usize,
+    pub evicted_bytes: u64,
+    /// Still above the limit after evicting everything eligible.
+    pub over_quota: bool,
+}
+
+/// Enforces `max_storage_gb` over everything under the data directory (see
+/// [`StorageUsage::quota_bytes`]).
+///
+/// When usage exceeds the limit, closed periods are deleted oldest first: all
+/// eligible minute data, then hour data. Day data, archives, backups, info files
+/// and the periods currently being written are never evicted. Under the `fs` backend that means
+/// whole segments; other backends delete the rows of a period through
+/// [`MetricStorageBackend::evict_oldest_period`].
+#[derive(Debug)]
+pub struct StorageQuotaManager {
+    limit_bytes: u64,
+}
+
+impl StorageQuotaManager {
+    /// `max_storage_gb == 0` disables the quota.
+    pub fn new(max_storage_gb: u32) -> Option<Self> {
+        (max_storage_gb > 0).then(|| Self {
+            limit_bytes: max_storage_gb as u64 * BYTES_PER_GB,
+        })
+    }
+
+    pub fn limit_bytes(&self) -> u64 {
+        self.limit_bytes
+    }
+
+    /// Enforces the quota only when the estimated usage (see [`estimated_usage`])
+    /// exceeds the limit; cheap enough to run before every collection.
+    pub fn check(&self, now: DateTime<Utc>) -> Result<Option<QuotaReport>> {
+        if estimated_usage().is_some_and(|used| used <= self.limit_bytes) {
+            return Ok(None);
+        }
+        self.enforce(now).map(Some)
+    }
+
+    /// Walks the data directory and evicts until usage is under the limit.
+    pub fn enforce(&self, now: DateTime<Utc>) -> Result<QuotaReport> {
+        // Reset first: rows written during the walk are counted twice rather than missed.
+        WRITTEN_BYTES.store(0, Ordering::Relaxed);
+        let used_before = StorageUsage::measure()?.quota_bytes();
+        let mut used = used_before;
+        let mut evicted_segments = 0;
+        let mut evicted_bytes = 0;
+
+        if used as f64 >= self.limit_bytes as f64 * WARN_RATIO {
+            warn!(
+                "⚠️ Storage usage {} of {} bytes ({:.1}%)",
+                used,
+                self.limit_bytes,
+                used as f64 * 100.0 / self.limit_bytes as f64
+            );
+        }
+
+        let backend = metric_backend()?;
+        for tier in EVICTION_ORDER {
+            if used <= self.limit_bytes {
+                break;
+            }
+            let (segments, bytes) = match backend.kind() {
+                MetricBackendKind::Fs => self.evict_segments(tier, now, &mut used)?,
+                _ => self.evict_periods(backend.as_ref(), tier, now, &mut used)?,
+            };
+            evicted_segments += segments;
+            evicted_bytes += bytes;
+        }
+
+        MEASURED_BYTES.store(used, Ordering::Relaxed);
+        let over_quota = used > self.limit_bytes;
+        if over_quota {
+            warn!(
+                "❌ Storage quota exceeded: {} of {} bytes used after evicting all minute/hour data; day data, archives and backups are kept",
+                used, self.limit_bytes
+            );
+        }
+
+        Ok(QuotaReport {
+            limit_bytes: self.limit_bytes,
+            used_bytes_before: used_before,
+            used_bytes_after: used,
+            evicted_segments,
+            evicted_bytes,
+            over_quota,
+        })
+    }
+
+    /// Deletes closed segments of `tier`, oldest first, until `used` is under the limit.
+    fn evict_segments(&self, tier: MetricTier, now: DateTime<Utc>, used: &mut u64) -> Result<(usize, u64)> {
+        let (mut evicted_segments, mut evicted_bytes) = (0, 0);
+        for (_, path) in Self::closed_segments(tier, now)? {
+            if *used <= self.limit_bytes {
+                break;
+            }
+
+            let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0)
+                + fs::metadata(index_path(&path)).map(|m| m.len()).unwrap_or(0);
+            match remove_segment(&path) {
+                Ok(()) => {
+                    debug!("🗑️ Evicted {:?} ({} bytes) to stay under quota", path, size);
+                    *used = used.saturating_sub(size);
+                    evicted_segments += 1;
+                    evicted_bytes += size;
+                }
+                Err(e) => warn!("⚠️ Failed to evict {:?}: {}", path, e),
+            }
+        }
+        Ok((evicted_segments, evicted_bytes))
+    }
+
+    /// Has a backend without segments delete whole closed periods of `tier`,
+    /// oldest first, until `used` is under the limit. Each evicted period
+    /// counts as one segment.
+    fn evict_periods(
+        &self,
+        backend: &dyn MetricStorageBackend,
+        tier: MetricTier,
+        now: DateTime<Utc>,
+        used: &mut u64,
+    ) -> Result<(usize, u64)> {
+        let current_period = tier.period_start(now.date_naive());
+        let (mut evicted_periods, mut evicted_bytes) = (0, 0);
+        while *used > self.limit_bytes {
+            let Some(period) = backend.evict_oldest_period(tier, current_period)? else {
+                break;
+            };
+            let remaining = StorageUsage::measure()?.quota_bytes();
+            let freed = used.saturating_sub(remaining);
+            debug!("🗑️ Evicted {:?} rows of the period from {} ({} bytes) to stay under quota", tier, period, freed);
+            *used = remaining;
+            evicted_periods += 1;
+            evicted_bytes += freed;
+        }
+        Ok((evicted_periods, evicted_bytes))
+    }
+
+    /// Closed segments of `tier` for every node, pod, container, PVC, node interface and node system container, oldest first.
+    fn closed_segments(tier: MetricTier, now: DateTime<Utc>) -> Result<Vec<(NaiveDate, PathBuf)>> {
+        let current_period = tier.period_start(now.date_naive());
+        let mut segments = Vec::new();
+
+        for base_dir in [
+            metric_k8s_node_dir_path(),
+            metric_k8s_pod_dir_path(),
+            metric_k8s_container_dir_path(),
+            metric_k8s_pvc_dir_path(),
+            metric_k8s_node_network_dir_path(),
+            metric_k8s_node_system_dir_path(),
+        ] {
+            if !base_dir.exists() {
+                continue;
+            }
+            for key_entry in fs::read_dir(&base_dir)? {
+                let dir = key_entry?.path().join(tier.dir_name());
+                if !dir.is_dir() {
+                    continue;
+                }
+                for entry in fs::read_dir(&dir)? {
+                    let path = entry?.path();
+                    let Some(period) = segment_period(&path).and_then(|s| tier.parse_period(s)) else {
+                        continue;
+                    };
+                    if period < current_period {
+                        segments.push((period, path));
+                    }
+                }
+            }
+        }
+
+        segments.sort();
+        Ok(segments)
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path};
+
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+
+/// Bytes used by live metric segments per tier.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TierUsage {
+    pub minute: u64,
+    pub hour: u64,
+    pub day: u64,
+}
+
+/// Disk usage under `get_rustcost_base_path()`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StorageUsage {
+    pub total_bytes: u64,
+    /// Live metric segments (`metric/...`), including index sidecars.
+    pub metric_bytes: u64,
+    /// Segments moved away by the `"archive"` retention policy (`archive/...`).
+    pub archive_bytes: u64,
+    /// Backup archives in the default backup directory (`backup/...`).
+    pub backup_bytes: u64,
+    /// Info files (`info/...`).
+    pub info_bytes: u64,
+    pub other_bytes: u64,
+    pub by_tier: TierUsage,
+    /// Live metric bytes per object kind (`node`, `pod`, `container`).
+    pub by_kind: BTreeMap<String, u64>,
+}
+
+impl StorageUsage {
+    /// Walks the whole data directory and classifies every file.
+    pub fn measure() -> Result<Self> {
+        let base = get_rustcost_base_path();
+        let mut usage = StorageUsage::default();
+        if base.exists() {
+            usage.walk(&base, &base)?;
+        }
+        Ok(usage)
+    }
+
+    /// Bytes counted against `max_storage_gb`: everything under the data
+    /// directory, archives, backups and info files included. Only metric data
+    /// is evicted, but the limit is on the disk the data directory occupies.
+    pub fn quota_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    fn walk(&mut self, base: &Path, dir: &Path) -> Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            let path = entry.path();
+
+            if file_type.is_dir() {
+                self.walk(base, &path)?;
+            } else if file_type.is_file() {
+                let size = entry.metadata()?.len();
+                let rel = path.strip_prefix(base).unwrap_or(&path);
+                self.add(rel, size);
+            }
+        }
+        Ok(())
+    }
+
+    fn add(&mut self, rel: &Path, size: u64) {
+        let parts: Vec<&str> = rel
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(s) => s.to_str(),
+                _ => None,
+            })
+            .collect();
+
+        self.total_bytes += size;
+        match parts.as_slice() {
+            // metric/k8s/{kind}/{key}/{m|h|d}/{file}
+            ["metric", "k8s", kind, _key, tier, _file] => {
+                self.metric_bytes += size;
+                *self.by_kind.entry(kind.to_string()).or_default() += size;
+                match *tier {
+                    "m" => self.by_tier.minute += size,
+                    "h" => self.by_tier.hour += size,
+                    "d" => self.by_tier.day += size,
+                    _ => {}
+                }
+            }
+            ["metric", ..] => self.metric_bytes += size,
+            ["archive", ..] => self.archive_bytes += size,
+            ["backup", ..] => self.backup_bytes += size,
+            ["info", ..] => self.info_bytes += size,
+            _ => self.other_bytes += size,
+        }
+    }
+}
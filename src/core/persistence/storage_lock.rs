@@ -0,0 +1,61 @@
+//! Process-wide lock over the data directory.
+//!
+//! Scheduler tasks hold the shared side while they write, so any number of them
+//! may run together. Backup and restore take the exclusive side to see (or
+//! replace) a tree that no task is halfway through writing.
+
+use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use tracing::warn;
+
+use crate::core::persistence::metrics::segment::write_buffer::release_all;
+
+static STORAGE_LOCK: RwLock<()> = RwLock::const_new(());
+
+/// Shared guard for routine writers (collectors, aggregators, retention).
+pub async fn lock_for_write() -> RwLockReadGuard<'static, ()> {
+    STORAGE_LOCK.read().await
+}
+
+/// [`lock_for_write`] for blocking threads, e.g. inside `spawn_blocking`.
+/// Must not be called from an async context.
+pub fn blocking_lock_for_write() -> RwLockReadGuard<'static, ()> {
+    STORAGE_LOCK.blocking_read()
+}
+
+/// Exclusive guard for operations that need a quiescent data directory.
+///
+/// Buffered rows are written out and every segment handle is closed first, so
+/// the tree on disk is complete and can be copied or replaced.
+pub async fn lock_exclusive() -> RwLockWriteGuard<'static, ()> {
+    let guard = STORAGE_LOCK.write().await;
+    if let Err(e) = release_all() {
+        warn!("⚠️ Failed to flush buffered metric rows: {}", e);
+    }
+    guard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    const WAIT: Duration = Duration::from_secs(5);
+
+    #[tokio::test]
+    async fn guards_release_the_lock_when_dropped() {
+        let shared = lock_for_write().await;
+        assert!(STORAGE_LOCK.try_write().is_err());
+        drop(shared);
+
+        let exclusive = timeout(WAIT, lock_exclusive())
+            .await
+            .expect("exclusive side free once the shared guard is dropped");
+        assert!(STORAGE_LOCK.try_read().is_err());
+        drop(exclusive);
+
+        let _shared = timeout(WAIT, lock_for_write())
+            .await
+            .expect("shared side free once the exclusive guard is dropped");
+    }
+}
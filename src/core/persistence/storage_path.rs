@@ -10,6 +10,31 @@ pub fn get_rustcost_base_path() -> PathBuf {
         .unwrap_or_else(|_| PathBuf::from("data"))
 }
 
+/// Returns the directory backups are written to, using `RUSTCOST_BACKUP_PATH` if set.
+/// Defaults to `backup/` under the base path, beside the `info/` and `metric/`
+/// trees a backup captures.
+pub fn get_rustcost_backup_path() -> PathBuf {
+    env::var("RUSTCOST_BACKUP_PATH")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| get_rustcost_base_path().join("backup"))
+}
+
+/// Returns the directory metric exports are written to, using `RUSTCOST_EXPORT_PATH` if set.
+/// Defaults to `export/`, next to (not inside) the data directory.
+pub fn get_rustcost_export_path() -> PathBuf {
+    env::var("RUSTCOST_EXPORT_PATH")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("export"))
+}
+
+/// Returns the SQLite database file of the `sqlite` metric backend, using
+/// `RUSTCOST_METRIC_DB_PATH` if set. Defaults to `metric/metrics.db` under the base path.
+pub fn get_rustcost_metric_db_path() -> PathBuf {
+    env::var("RUSTCOST_METRIC_DB_PATH")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| get_rustcost_base_path().join("metric").join("metrics.db"))
+}
+
 // Re-export info path builders from the new module
 pub use crate::core::persistence::info::path::{
     info_setting_path,
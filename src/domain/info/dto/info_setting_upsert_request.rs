@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
+use crate::core::persistence::info::fixed::setting::info_setting_entity::{
+    MAX_SCRAPE_INTERVAL_SEC, MIN_SCRAPE_INTERVAL_SEC,
+};
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
 
 /// Represents an upsert (create/update) request for InfoSettingEntity.
 /// All fields are optional to allow partial updates.
@@ -23,7 +27,7 @@ pub struct InfoSettingUpsertRequest {
     pub day_retention_years: Option<u32>,
 
     /// Retention behavior: "delete" or "archive".
-    #[validate(length(min = 3))]
+    #[validate(custom(function = "validate_retention_policy"))]
     pub retention_policy: Option<String>,
 
     // ===== File-based Persistence Options =====
@@ -33,14 +37,15 @@ pub struct InfoSettingUpsertRequest {
     /// Whether to create `.idx` index sidecar files for faster reads.
     pub enable_index_file: Option<bool>,
 
-    /// Maximum local storage capacity in gigabytes before cleanup triggers.
+    /// Maximum local storage capacity in GiB before the oldest minute/hour data is evicted (`0` disables the quota).
     pub max_storage_gb: Option<u32>,
 
-    /// Enables on-disk compression (gzip or zstd).
+    /// Enables gzip compression of closed metric segments (`.rcd.gz`).
     pub compression_enabled: Option<bool>,
 
     // ===== Metrics Collection =====
-    /// Scrape interval in seconds (e.g. 60 = every minute).
+    /// Scrape interval in seconds (e.g. 60 = every minute), between 10 and 300.
+    #[validate(custom(function = "validate_scrape_interval"))]
     pub scrape_interval_sec: Option<u32>,
 
     /// Number of metrics batched together when written to disk.
@@ -109,3 +114,19 @@ pub struct InfoSettingUpsertRequest {
     #[validate(url)]
     pub k8s_api_url: Option<String>,
 }
+
+fn validate_retention_policy(value: &str) -> Result<(), ValidationError> {
+    if MetricRetentionPolicy::VALUES.contains(&value.trim().to_lowercase().as_str()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("retention_policy_must_be_delete_or_archive"))
+    }
+}
+
+fn validate_scrape_interval(value: u32) -> Result<(), ValidationError> {
+    if (MIN_SCRAPE_INTERVAL_SEC..=MAX_SCRAPE_INTERVAL_SEC).contains(&value) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("scrape_interval_sec_out_of_range"))
+    }
+}
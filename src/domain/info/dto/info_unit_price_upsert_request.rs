@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::NetworkInterfaceClass;
+
 /// Represents an upsert (create/update) request for `InfoUnitPriceEntity`.
 ///
 /// All fields are optional to allow partial updates.
@@ -41,4 +43,8 @@ pub struct InfoUnitPriceUpsertRequest {
 
     /// Price per GB transferred to external networks (internet egress).
     pub network_external_gb: Option<f64>,
+
+    /// Price tier of node interface egress by interface name or `prefix*`;
+    /// replaces the existing rules.
+    pub network_interface_classes: Option<Vec<NetworkInterfaceClass>>,
 }
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use std::sync::Arc;
 use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
 use crate::core::persistence::info::k8s::container::info_container_api_repository_trait::InfoContainerApiRepository;
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
@@ -6,18 +7,18 @@ use crate::core::persistence::info::k8s::container::info_container_fs_adapter::I
 
 /// API-side repository implementation for container info.
 pub struct InfoK8sContainerApiRepositoryImpl {
-    adapter: InfoContainerFsAdapter,
+    pub adapter: Arc<dyn InfoDynamicFsAdapterTrait<InfoContainerEntity>>,
 }
 
 impl Default for InfoK8sContainerApiRepositoryImpl {
     fn default() -> Self {
-        Self { adapter: InfoContainerFsAdapter }
+        Self { adapter: Arc::new(InfoContainerFsAdapter) }
     }
 }
 
 impl InfoContainerApiRepository for InfoK8sContainerApiRepositoryImpl {
     fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoContainerEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn read(&self, container_key: &str) -> Result<InfoContainerEntity> {
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use std::sync::Arc;
 use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
 use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
@@ -6,18 +7,18 @@ use crate::core::persistence::info::k8s::node::info_node_fs_adapter::InfoNodeFsA
 
 /// API-side repository implementation for node info.
 pub struct InfoK8sNodeApiRepositoryImpl {
-    adapter: InfoNodeFsAdapter,
+    pub adapter: Arc<dyn InfoDynamicFsAdapterTrait<InfoNodeEntity>>,
 }
 
 impl Default for InfoK8sNodeApiRepositoryImpl {
     fn default() -> Self {
-        Self { adapter: InfoNodeFsAdapter }
+        Self { adapter: Arc::new(InfoNodeFsAdapter) }
     }
 }
 
 impl InfoNodeApiRepository for InfoK8sNodeApiRepositoryImpl {
     fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoNodeEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn read(&self, node_name: &str) -> Result<InfoNodeEntity> {
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use std::sync::Arc;
 use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
 use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
@@ -6,18 +7,18 @@ use crate::core::persistence::info::k8s::pod::info_pod_fs_adapter::InfoPodFsAdap
 
 /// API-side repository implementation for pod info.
 pub struct InfoK8sPodApiRepositoryImpl {
-    adapter: InfoPodFsAdapter,
+    pub adapter: Arc<dyn InfoDynamicFsAdapterTrait<InfoPodEntity>>,
 }
 
 impl Default for InfoK8sPodApiRepositoryImpl {
     fn default() -> Self {
-        Self { adapter: InfoPodFsAdapter }
+        Self { adapter: Arc::new(InfoPodFsAdapter) }
     }
 }
 
 impl InfoPodApiRepository for InfoK8sPodApiRepositoryImpl {
     fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoPodEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn read(&self, pod_uid: &str) -> Result<InfoPodEntity> {
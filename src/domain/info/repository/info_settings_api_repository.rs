@@ -1,4 +1,5 @@
 use anyhow::Result;
+use std::sync::Arc;
 use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
 use crate::core::persistence::info::fixed::setting::info_setting_api_repository_trait::InfoSettingApiRepository;
 use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
@@ -8,20 +9,18 @@ use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::Inf
 ///
 /// Bridges the collector logic with the filesystem adapter layer.
 pub struct InfoSettingApiRepositoryImpl {
-    adapter: InfoSettingFsAdapter,
+    pub adapter: Arc<dyn InfoFixedFsAdapterTrait<InfoSettingEntity>>,
 }
 
 impl Default for InfoSettingApiRepositoryImpl {
     fn default() -> Self {
-        Self {
-            adapter: InfoSettingFsAdapter,
-        }
+        Self { adapter: Arc::new(InfoSettingFsAdapter) }
     }
 }
 
 impl InfoSettingApiRepository for InfoSettingApiRepositoryImpl {
     fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoSettingEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn read(&self) -> Result<InfoSettingEntity> {
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use std::sync::Arc;
 use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_api_repository_trait::InfoUnitPriceApiRepository;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
@@ -6,20 +7,18 @@ use crate::core::persistence::info::fixed::unit_price::info_unit_price_fs_adapte
 
 /// API-side repository implementation for managing unit price info.
 pub struct InfoUnitPriceApiRepositoryImpl {
-    adapter: InfoUnitPriceFsAdapter,
+    pub adapter: Arc<dyn InfoFixedFsAdapterTrait<InfoUnitPriceEntity>>,
 }
 
 impl Default for InfoUnitPriceApiRepositoryImpl {
     fn default() -> Self {
-        Self {
-            adapter: InfoUnitPriceFsAdapter,
-        }
+        Self { adapter: Arc::new(InfoUnitPriceFsAdapter) }
     }
 }
 
 impl InfoUnitPriceApiRepository for InfoUnitPriceApiRepositoryImpl {
     fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoUnitPriceEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn read(&self) -> Result<InfoUnitPriceEntity> {
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use std::sync::Arc;
 use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
 use crate::core::persistence::info::fixed::version::info_version_api_repository_trait::InfoVersionApiRepository;
 use crate::core::persistence::info::fixed::version::info_version_entity::InfoVersionEntity;
@@ -6,18 +7,18 @@ use crate::core::persistence::info::fixed::version::info_version_fs_adapter::Inf
 
 /// API-side repository implementation for version info.
 pub struct InfoVersionApiRepositoryImpl {
-    adapter: InfoVersionFsAdapter,
+    pub adapter: Arc<dyn InfoFixedFsAdapterTrait<InfoVersionEntity>>,
 }
 
 impl Default for InfoVersionApiRepositoryImpl {
     fn default() -> Self {
-        Self { adapter: InfoVersionFsAdapter }
+        Self { adapter: Arc::new(InfoVersionFsAdapter) }
     }
 }
 
 impl InfoVersionApiRepository for InfoVersionApiRepositoryImpl {
     fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoVersionEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn read(&self) -> Result<InfoVersionEntity> {
@@ -4,19 +4,16 @@ use serde_json::to_string_pretty;
 use tracing::debug;
 
 use crate::api::dto::info_dto::K8sListQuery;
-use crate::core::client::k8s::util::{build_client, read_token};
+use crate::app_state::AppState;
 use crate::core::persistence::info::k8s::container::info_container_api_repository_trait::InfoContainerApiRepository;
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
-use crate::core::persistence::info::path::info_k8s_container_dir_path;
 use crate::domain::info::repository::info_k8s_container_api_repository::InfoK8sContainerApiRepositoryImpl;
-use std::fs;
 use crate::core::client::k8s::client_k8s_container_mapper::map_container_status_to_info_container_entity;
-use crate::core::client::k8s::client_k8s_pod::{fetch_pod_by_name_and_namespace, fetch_pods, fetch_pods_by_namespace, fetch_pods_by_node};
 use crate::domain::info::dto::info_k8s_container_patch_request::InfoK8sContainerPatchRequest;
 
 /// Fetch one container info by its unique ID, with cache + refresh if stale.
-pub async fn get_info_k8s_container(container_id: String) -> Result<InfoContainerEntity> {
-    let repo = InfoK8sContainerApiRepositoryImpl::default();
+pub async fn get_info_k8s_container(state: &AppState, container_id: String) -> Result<InfoContainerEntity> {
+    let repo = InfoK8sContainerApiRepositoryImpl { adapter: state.info.container() };
 
     // 1️⃣ Try reading existing entity from repo
     if let Ok(existing) = repo.read(&container_id) {
@@ -35,10 +32,7 @@ pub async fn get_info_k8s_container(container_id: String) -> Result<InfoContaine
         ) {
             debug!("🔄 Cache expired; fetching fresh container info for '{}'", container_id);
 
-            let token = read_token()?;
-            let client = build_client()?;
-
-            let pod = fetch_pod_by_name_and_namespace(&token, &client, &ns, &pod_name).await?;
+            let pod = state.k8s.pod_by_name_and_namespace(&ns, &pod_name).await?;
             let status = pod.status
                 .as_ref()
                 .and_then(|s| s.container_statuses.iter().find(|cs| cs.name == container_name));
@@ -83,32 +77,24 @@ pub async fn get_info_k8s_container(container_id: String) -> Result<InfoContaine
 
 /// List containers — supports optional filters: namespace, pod_name, node_name.
 /// List containers — supports optional filters: namespace, pod_name, node_name.
-pub async fn list_k8s_containers(filter: K8sListQuery) -> Result<Vec<InfoContainerEntity>> {
-    let token = read_token()?;
-    let client = build_client()?;
-    let repo = InfoK8sContainerApiRepositoryImpl::default();
+pub async fn list_k8s_containers(state: &AppState, filter: K8sListQuery) -> Result<Vec<InfoContainerEntity>> {
+    let repo = InfoK8sContainerApiRepositoryImpl { adapter: state.info.container() };
 
     let mut fresh_entities = Vec::new();
     let mut expired_entities = Vec::new();
 
     // 1️⃣ Load cache
-    let container_dir = info_k8s_container_dir_path();
-    if container_dir.exists() {
-        if let Ok(entries) = fs::read_dir(&container_dir) {
-            for entry in entries.flatten() {
-                let id = entry.file_name().to_string_lossy().to_string();
-                if let Ok(existing) = repo.read(&id) {
-                    if let Some(ts) = existing.last_updated_info_at {
-                        if Utc::now().signed_duration_since(ts) <= Duration::hours(1) {
-                            debug!("✅ Using cached container info for '{}'", id);
-                            fresh_entities.push(existing);
-                            continue;
-                        }
-                    }
-                    debug!("⚠️ Cache expired for '{}'", id);
-                    expired_entities.push(existing);
+    for id in repo.keys()? {
+        if let Ok(existing) = repo.read(&id) {
+            if let Some(ts) = existing.last_updated_info_at {
+                if Utc::now().signed_duration_since(ts) <= Duration::hours(1) {
+                    debug!("✅ Using cached container info for '{}'", id);
+                    fresh_entities.push(existing);
+                    continue;
                 }
             }
+            debug!("⚠️ Cache expired for '{}'", id);
+            expired_entities.push(existing);
         }
     }
 
@@ -125,11 +111,11 @@ pub async fn list_k8s_containers(filter: K8sListQuery) -> Result<Vec<InfoContain
 
     // 3️⃣ Select appropriate fetcher
     let pods = if let Some(ns) = &filter.namespace {
-        fetch_pods_by_namespace(&token, &client, ns).await?
+        state.k8s.pods_by_namespace(ns).await?
     } else if let Some(node) = &filter.node_name {
-        fetch_pods_by_node(&token, &client, node).await?
+        state.k8s.pods_by_node(node).await?
     } else {
-        fetch_pods(&token, &client).await?
+        state.k8s.pods().await?
     };
 
     debug!("Fetched {} pod(s) from API", pods.items.len());
@@ -177,10 +163,11 @@ pub async fn list_k8s_containers(filter: K8sListQuery) -> Result<Vec<InfoContain
 }
 
 pub async fn patch_info_k8s_container(
+    state: &AppState,
     id: String,
     patch: InfoK8sContainerPatchRequest,
 ) -> Result<serde_json::Value> {
-    let repo = InfoK8sContainerApiRepositoryImpl::default();
+    let repo = InfoK8sContainerApiRepositoryImpl { adapter: state.info.container() };
 
     // 1️⃣ Load existing record
     let mut entity = repo.read(&id)
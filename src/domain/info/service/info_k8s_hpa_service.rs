@@ -1,13 +1,9 @@
 use anyhow::Result;
 use serde_json::Value;
-use crate::core::client::k8s::client_k8s_hpa;
-use crate::core::client::k8s::util::{build_client, read_token};
+use crate::app_state::AppState;
+use crate::core::client::k8s::k8s_client_trait::K8sResourceKind;
 
-pub async fn get_k8s_hpas() -> Result<Value> {
-    let token = read_token()?;
-    let client = build_client()?;
-
-    let v = client_k8s_hpa::fetch_horizontal_pod_autoscalers(&token, &client).await?;
+pub async fn get_k8s_hpas(state: &AppState) -> Result<Value> {
+    let v = state.k8s.resources(K8sResourceKind::HorizontalPodAutoscaler).await?;
     Ok(v)
 }
-
@@ -1,13 +1,9 @@
 use anyhow::Result;
 use serde_json::Value;
-use crate::core::client::k8s::client_k8s_limit_range;
-use crate::core::client::k8s::util::{build_client, read_token};
+use crate::app_state::AppState;
+use crate::core::client::k8s::k8s_client_trait::K8sResourceKind;
 
-pub async fn get_k8s_limit_ranges() -> Result<Value> {
-    let token = read_token()?;
-    let client = build_client()?;
-
-    let v = client_k8s_limit_range::fetch_limit_ranges(&token, &client).await?;
+pub async fn get_k8s_limit_ranges(state: &AppState) -> Result<Value> {
+    let v = state.k8s.resources(K8sResourceKind::LimitRange).await?;
     Ok(v)
 }
-
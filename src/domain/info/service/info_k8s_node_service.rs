@@ -1,18 +1,16 @@
 use anyhow::{anyhow, Result};
 use chrono::{Duration, Utc};
+use crate::app_state::AppState;
 use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
+use crate::core::persistence::info::k8s::info_source::{is_k8s_source, SOURCE_RUSTEXPORTER};
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
 use crate::domain::info::repository::info_k8s_node_api_repository::InfoK8sNodeApiRepositoryImpl;
 use tracing::{debug};
-use crate::core::client::k8s::client_k8s_node::{fetch_node_by_name, fetch_nodes};
 use crate::core::client::k8s::client_k8s_node_mapper::map_node_to_node_info_entity;
-use crate::core::client::k8s::util::{build_client, read_token};
-use crate::domain::info::dto::info_k8s_container_patch_request::InfoK8sContainerPatchRequest;
 use crate::domain::info::dto::info_k8s_node_patch_request::InfoK8sNodePatchRequest;
-use crate::domain::info::repository::info_k8s_container_api_repository::InfoK8sContainerApiRepositoryImpl;
 
-pub async fn get_info_k8s_node(node_name: String) -> Result<InfoNodeEntity> {
-    let repo = InfoK8sNodeApiRepositoryImpl::default();
+pub async fn get_info_k8s_node(state: &AppState, node_name: String) -> Result<InfoNodeEntity> {
+    let repo = InfoK8sNodeApiRepositoryImpl { adapter: state.info.node() };
 
     // Load existing entity
     let entity = repo.read(&node_name)?;
@@ -23,15 +21,12 @@ pub async fn get_info_k8s_node(node_name: String) -> Result<InfoNodeEntity> {
         Some(last) => now.signed_duration_since(last) > Duration::hours(1),
     };
 
-    if needs_refresh {
+    // Exporter hosts are kept fresh by their collector; the API does not know them.
+    if needs_refresh && is_k8s_source(entity.source.as_deref()) {
         debug!("Node '{}' info is missing or stale — refreshing from K8s API", node_name);
 
-        // Build K8s client
-        let token = read_token()?;
-        let client = build_client()?;
-
         // Fetch from K8s API
-        let node = fetch_node_by_name(&token, &client, &node_name).await?;
+        let node = state.k8s.node_by_name(&node_name).await?;
         let updated_entity = map_node_to_node_info_entity(&node)?;
 
         // Save refreshed info
@@ -54,22 +49,18 @@ pub async fn get_info_k8s_node(node_name: String) -> Result<InfoNodeEntity> {
 
 
 /// Lists all Kubernetes nodes, refreshing local cache if older than 1h
-pub async fn list_k8s_nodes() -> Result<Vec<InfoNodeEntity>> {
+pub async fn list_k8s_nodes(state: &AppState) -> Result<Vec<InfoNodeEntity>> {
     debug!("Listing all Kubernetes nodes");
 
-    // 1️⃣ Build client & token
-    let token = read_token()?;
-    let client = build_client()?;
-
-    // 2️⃣ Fetch nodes from K8s API
-    let node_list = fetch_nodes(&token, &client).await?;
+    // 1️⃣ Fetch nodes from K8s API
+    let node_list = state.k8s.nodes().await?;
     debug!("Fetched {} node(s) from API", node_list.items.len());
 
-    // 3️⃣ Repository to persist/update
-    let repo = InfoK8sNodeApiRepositoryImpl::default();
+    // 2️⃣ Repository to persist/update
+    let repo = InfoK8sNodeApiRepositoryImpl { adapter: state.info.node() };
     let mut result_entities = Vec::new();
 
-    // 4️⃣ Process each node
+    // 3️⃣ Process each node
     for node in node_list.items.iter() {
         let node_name = node.metadata.name.clone();
 
@@ -101,12 +92,33 @@ pub async fn list_k8s_nodes() -> Result<Vec<InfoNodeEntity>> {
     Ok(result_entities)
 }
 
+/// Kubernetes nodes plus the live hosts reported by RustExporters, for the
+/// metric and cost views that cover every machine.
+pub async fn list_cost_nodes(state: &AppState) -> Result<Vec<InfoNodeEntity>> {
+    let mut nodes = list_k8s_nodes(state).await?;
+
+    let repo = InfoK8sNodeApiRepositoryImpl { adapter: state.info.node() };
+    for key in repo.keys()? {
+        if nodes.iter().any(|n| n.node_name.as_deref() == Some(key.as_str())) {
+            continue;
+        }
+        let Ok(entity) = repo.read(&key) else { continue };
+        if entity.source.as_deref() == Some(SOURCE_RUSTEXPORTER) && !entity.deleted.unwrap_or(false) {
+            nodes.push(entity);
+        }
+    }
+
+    debug!("Listed {} node(s) including exporter hosts", nodes.len());
+    Ok(nodes)
+}
+
 
 pub async fn patch_info_k8s_node(
+    state: &AppState,
     id: String,
     patch: InfoK8sNodePatchRequest,
 ) -> Result<serde_json::Value> {
-    let repo = InfoK8sNodeApiRepositoryImpl::default();
+    let repo = InfoK8sNodeApiRepositoryImpl { adapter: state.info.node() };
 
     // 1️⃣ Load existing record
     let mut entity = repo
@@ -2,20 +2,15 @@ use anyhow::{anyhow, Result};
 use chrono::{Duration, Utc};
 use tracing::debug;
 use crate::api::dto::info_dto::K8sListQuery;
-use crate::core::client::k8s::client_k8s_pod::{fetch_pod_by_name_and_namespace, fetch_pod_by_uid, fetch_pods, fetch_pods_by_label, fetch_pods_by_namespace, fetch_pods_by_node};
+use crate::app_state::AppState;
 use crate::core::client::k8s::client_k8s_pod_mapper::map_pod_to_info_pod_entity;
-use crate::core::client::k8s::util::{build_client, read_token};
 use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
-use crate::core::persistence::info::path::info_k8s_pod_dir_path;
 use crate::domain::info::repository::info_k8s_pod_api_repository::InfoK8sPodApiRepositoryImpl;
-use std::fs;
-use crate::domain::info::dto::info_k8s_node_patch_request::InfoK8sNodePatchRequest;
 use crate::domain::info::dto::info_k8s_pod_patch_request::InfoK8sPodPatchRequest;
-use crate::domain::info::repository::info_k8s_node_api_repository::InfoK8sNodeApiRepositoryImpl;
 
-pub async fn get_info_k8s_pod(pod_uid: String) -> Result<InfoPodEntity> {
-    let repo = InfoK8sPodApiRepositoryImpl::default();
+pub async fn get_info_k8s_pod(state: &AppState, pod_uid: String) -> Result<InfoPodEntity> {
+    let repo = InfoK8sPodApiRepositoryImpl { adapter: state.info.pod() };
 
     // 1️⃣ Try read existing entity from repo
     if let Ok(existing) = repo.read(&pod_uid) {
@@ -30,11 +25,8 @@ pub async fn get_info_k8s_pod(pod_uid: String) -> Result<InfoPodEntity> {
         if let (Some(ns), Some(name)) = (existing.namespace.clone(), existing.pod_name.clone()) {
             debug!("🔄 Cached data expired; fetching fresh pod info for '{}'", pod_uid);
 
-            let token = read_token()?;
-            let client = build_client()?;
-
             // 2️⃣ Fetch from K8s API
-            let pod = fetch_pod_by_name_and_namespace(&token, &client, &ns, &name).await?;
+            let pod = state.k8s.pod_by_name_and_namespace(&ns, &name).await?;
             debug!("📦 Retrieved Pod '{}' in ns '{}'", name, ns);
 
 
@@ -57,12 +49,9 @@ pub async fn get_info_k8s_pod(pod_uid: String) -> Result<InfoPodEntity> {
 
     // 5️⃣ No existing record: fetch fresh by UID (requires cluster-level list)
     debug!("🔍 No cache found; fetching pod '{}' by UID directly", pod_uid);
-    let token = read_token()?;
-    let client = build_client()?;
-
 
     // Fallback: if only UID known, use list+filter (requires RBAC permissions)
-    let pod = fetch_pod_by_uid(&token, &client, &pod_uid).await?;
+    let pod = state.k8s.pod_by_uid(&pod_uid).await?;
     let mut entity = map_pod_to_info_pod_entity(&pod)?;
     entity.last_updated_info_at = Some(Utc::now());
     entity.pod_uid = Some(pod_uid.clone());
@@ -73,33 +62,25 @@ pub async fn get_info_k8s_pod(pod_uid: String) -> Result<InfoPodEntity> {
 
 
 /// List Pods — supports optional filters: namespace, labelSelector, nodeName.
-pub async fn list_k8s_pods(filter: K8sListQuery) -> Result<Vec<InfoPodEntity>> {
-    let token = read_token()?;
-    let client = build_client()?;
-    let repo = InfoK8sPodApiRepositoryImpl::default();
+pub async fn list_k8s_pods(state: &AppState, filter: K8sListQuery) -> Result<Vec<InfoPodEntity>> {
+    let repo = InfoK8sPodApiRepositoryImpl { adapter: state.info.pod() };
 
     let mut cached_entities = Vec::new();
     let mut expired_or_missing = false;
 
-    // 1️⃣ Try loading from filesystem cache via repo
-    let pod_dir = info_k8s_pod_dir_path();
-    if pod_dir.exists() {
-        if let Ok(entries) = fs::read_dir(&pod_dir) {
-            for entry in entries.flatten() {
-                let pod_uid = entry.file_name().to_string_lossy().to_string();
-                if let Ok(existing) = repo.read(&pod_uid) {
-                    if let Some(ts) = existing.last_updated_info_at {
-                        if Utc::now().signed_duration_since(ts) <= Duration::hours(1) {
-                            debug!("✅ Using cached pod info for '{}'", pod_uid);
-                            cached_entities.push(existing);
-                            continue;
-                        }
-                    }
+    // 1️⃣ Try loading from the info cache via repo
+    for pod_uid in repo.keys()? {
+        if let Ok(existing) = repo.read(&pod_uid) {
+            if let Some(ts) = existing.last_updated_info_at {
+                if Utc::now().signed_duration_since(ts) <= Duration::hours(1) {
+                    debug!("✅ Using cached pod info for '{}'", pod_uid);
+                    cached_entities.push(existing);
+                    continue;
                 }
-                debug!("⚠️ Cache expired or missing for '{}'", pod_uid);
-                expired_or_missing = true;
             }
         }
+        debug!("⚠️ Cache expired or missing for '{}'", pod_uid);
+        expired_or_missing = true;
     }
 
     // 2️⃣ If all pods were fresh, return cached data only
@@ -112,13 +93,13 @@ pub async fn list_k8s_pods(filter: K8sListQuery) -> Result<Vec<InfoPodEntity>> {
 
     // 3️⃣ Select appropriate fetcher
     let pod_list = if let Some(ns) = &filter.namespace {
-        fetch_pods_by_namespace(&token, &client, ns).await?
+        state.k8s.pods_by_namespace(ns).await?
     } else if let Some(label) = &filter.label_selector {
-        fetch_pods_by_label(&token, &client, label).await?
+        state.k8s.pods_by_label(label).await?
     } else if let Some(node) = &filter.node_name {
-        fetch_pods_by_node(&token, &client, node).await?
+        state.k8s.pods_by_node(node).await?
     } else {
-        fetch_pods(&token, &client).await?
+        state.k8s.pods().await?
     };
 
     debug!("Fetched {} pod(s) from API", pod_list.items.len());
@@ -140,10 +121,11 @@ pub async fn list_k8s_pods(filter: K8sListQuery) -> Result<Vec<InfoPodEntity>> {
 }
 
 pub async fn patch_info_k8s_pod(
+    state: &AppState,
     id: String,
     patch: InfoK8sPodPatchRequest,
 ) -> Result<serde_json::Value> {
-    let repo = InfoK8sPodApiRepositoryImpl::default();
+    let repo = InfoK8sPodApiRepositoryImpl { adapter: state.info.pod() };
 
     // 1️⃣ Load existing record
     let mut entity = repo
@@ -1,12 +1,9 @@
 use anyhow::Result;
 use serde_json::Value;
-use crate::core::client::k8s::client_k8s_namespace;
-use crate::core::client::k8s::util::{build_client, read_token};
+use crate::app_state::AppState;
+use crate::core::client::k8s::k8s_client_trait::K8sResourceKind;
 
-pub async fn get_k8s_namespaces() -> Result<Value> {
-    let token = read_token()?;
-    let client = build_client()?;
-
-    let namespaces = client_k8s_namespace::fetch_namespaces(&token, &client).await?;
+pub async fn get_k8s_namespaces(state: &AppState) -> Result<Value> {
+    let namespaces = state.k8s.resources(K8sResourceKind::Namespace).await?;
     Ok(namespaces)
 }
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use crate::app_state::AppState;
 use serde_json::{Value};
 use crate::core::persistence::info::fixed::setting::info_setting_api_repository_trait::InfoSettingApiRepository;
 use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
@@ -6,14 +7,14 @@ use crate::domain::info::dto::info_setting_upsert_request::InfoSettingUpsertRequ
 use crate::domain::info::repository::info_settings_api_repository::InfoSettingApiRepositoryImpl;
 
 
-pub async fn get_info_settings() -> Result<InfoSettingEntity> {
-    let repo = InfoSettingApiRepositoryImpl::default();
+pub async fn get_info_settings(state: &AppState) -> Result<InfoSettingEntity> {
+    let repo = InfoSettingApiRepositoryImpl { adapter: state.info.setting() };
     let settings = repo.read()?;
     Ok(settings)
 }
 
-pub async fn upsert_info_settings(req: InfoSettingUpsertRequest) -> Result<Value> {
-    let repo = InfoSettingApiRepositoryImpl::default();
+pub async fn upsert_info_settings(state: &AppState, req: InfoSettingUpsertRequest) -> Result<Value> {
+    let repo = InfoSettingApiRepositoryImpl { adapter: state.info.setting() };
 
     let mut settings = repo.read()?;
     settings.apply_update(req);
@@ -1,17 +1,18 @@
 use anyhow::Result;
+use crate::app_state::AppState;
 use serde_json::{Value};
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_api_repository_trait::InfoUnitPriceApiRepository;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
 use crate::domain::info::repository::info_unit_price_api_repository::InfoUnitPriceApiRepositoryImpl;
 
-pub async fn get_info_unit_prices() -> Result<InfoUnitPriceEntity> {
-    let repo = InfoUnitPriceApiRepositoryImpl::default();
+pub async fn get_info_unit_prices(state: &AppState) -> Result<InfoUnitPriceEntity> {
+    let repo = InfoUnitPriceApiRepositoryImpl { adapter: state.info.unit_price() };
     let entity = repo.read()?;
     Ok(entity)
 }
-pub async fn upsert_info_unit_prices(req: InfoUnitPriceUpsertRequest) -> Result<Value> {
-    let repo = InfoUnitPriceApiRepositoryImpl::default();
+pub async fn upsert_info_unit_prices(state: &AppState, req: InfoUnitPriceUpsertRequest) -> Result<Value> {
+    let repo = InfoUnitPriceApiRepositoryImpl { adapter: state.info.unit_price() };
 
     let mut unit_prices = repo.read()?;
     unit_prices.apply_update(req);
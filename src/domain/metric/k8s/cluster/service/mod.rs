@@ -2,11 +2,12 @@ use serde_json::{Value};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use crate::api::dto::metrics_dto::RangeQuery;
+use crate::app_state::AppState;
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_api_repository_trait::MetricNodeDayApiRepository;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_api_repository_trait::MetricNodeHourApiRepository;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_api_repository_trait::MetricNodeMinuteApiRepository;
-use crate::domain::metric::k8s::common::dto::{CommonMetricValuesDto, CostMetricDto, FilesystemMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto, NetworkMetricDto, UniversalMetricPointDto};
+use crate::domain::metric::k8s::common::dto::{CommonMetricValuesDto, CostMetricDto, FilesystemMetricDto, GpuMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto, NetworkMetricDto, UniversalMetricPointDto};
 use crate::domain::metric::k8s::common::util::k8s_metric_determine_granularity::determine_granularity;
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
@@ -16,15 +17,17 @@ use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::{Metri
 use crate::domain::metric::k8s::common::dto::metric_k8s_cost_trend_dto::{MetricCostTrendDto, MetricCostTrendResponseDto};
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_efficiency_dto::{MetricRawEfficiencyDto, MetricRawEfficiencyResponseDto};
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::{MetricRawSummaryDto, MetricRawSummaryResponseDto};
-use crate::domain::metric::k8s::common::service_helpers::resolve_time_window;
+use crate::domain::metric::k8s::common::service_helpers::{gpu_cost, resolve_time_window};
+use crate::domain::metric::k8s::node_system::service::build_node_cost_split;
 
 pub async fn get_metric_k8s_cluster_raw(
+    state: &AppState,
     node_info_list: Vec<InfoNodeEntity>,
     q: RangeQuery,
 ) -> Result<Value, anyhow::Error> {
 
     let window = resolve_time_window(&q);
-    let repo = resolve_k8s_metric_repository(&MetricScope::Node, &window.granularity);
+    let repo = resolve_k8s_metric_repository(state.metrics.as_ref(), &MetricScope::Node, &window.granularity);
 
     let mut aggregated_points: Vec<UniversalMetricPointDto> = vec![];
 
@@ -64,6 +67,13 @@ pub async fn get_metric_k8s_cluster_raw(
                     tx_errors: m.network_physical_tx_errors.map(|v| v as f64),
                     ..Default::default()
                 }),
+                gpu: GpuMetricDto::from_values(
+                    m.gpu_milli_count,
+                    m.gpu_utilization_percent,
+                    m.gpu_memory_used_bytes,
+                    m.gpu_memory_total_bytes,
+                    m.gpu_power_milliwatts,
+                ),
                 ..Default::default()
             };
 
@@ -94,11 +104,12 @@ pub async fn get_metric_k8s_cluster_raw(
 
 /// Summarize raw cluster resource usage (CPU, memory, storage, network)
 pub async fn get_metric_k8s_cluster_raw_summary(
+    state: &AppState,
     node_info_list: Vec<InfoNodeEntity>,
     q: RangeQuery,
 ) -> Result<Value> {
     // 1️⃣ Retrieve the raw metrics for the time range
-    let raw_value = get_metric_k8s_cluster_raw(node_info_list.clone(), q.clone()).await?;
+    let raw_value = get_metric_k8s_cluster_raw(state, node_info_list.clone(), q.clone()).await?;
     let cluster_metrics: MetricGetResponseDto = serde_json::from_value(raw_value)?;
 
     // 2️⃣ Prepare accumulators
@@ -177,12 +188,13 @@ pub async fn get_metric_k8s_cluster_raw_summary(
 
 /// Compute derived cluster costs based on node metrics and unit prices
 pub async fn get_metric_k8s_cluster_cost(
+    state: &AppState,
     node_info_list: Vec<InfoNodeEntity>,
     unit_prices: InfoUnitPriceEntity,
     q: RangeQuery,
 ) -> Result<Value> {
     // 1️⃣ Get raw cluster metrics first
-    let mut raw_value = get_metric_k8s_cluster_raw(node_info_list, q).await?;
+    let mut raw_value = get_metric_k8s_cluster_raw(state, node_info_list, q).await?;
     let mut resp: MetricGetResponseDto = serde_json::from_value(raw_value)?;
 
     // 2️⃣ Compute cost per metric point
@@ -217,11 +229,15 @@ pub async fn get_metric_k8s_cluster_cost(
                     gb * (unit_prices.storage_gb_hour / 3600.0)
                 });
 
+            // --- GPU cost ---
+            let gpu_cost_usd = point.gpu.as_ref().and_then(|g| g.count).map(|n| gpu_cost(n, &unit_prices));
+
             // --- Sum up total ---
             let total_cost_usd = Some(
                 cpu_cost_usd.unwrap_or(0.0)
                     + memory_cost_usd.unwrap_or(0.0)
-                    + storage_cost_usd.unwrap_or(0.0),
+                    + storage_cost_usd.unwrap_or(0.0)
+                    + gpu_cost_usd.unwrap_or(0.0),
             );
 
             // --- Store in cost field ---
@@ -230,6 +246,8 @@ pub async fn get_metric_k8s_cluster_cost(
                 cpu_cost_usd,
                 memory_cost_usd,
                 storage_cost_usd,
+                gpu_cost_usd,
+                ..Default::default()
             });
         }
     }
@@ -241,12 +259,14 @@ pub async fn get_metric_k8s_cluster_cost(
 
 /// Summarize total cluster cost across all time points and resources
 pub async fn get_metric_k8s_cluster_cost_summary(
+    state: &AppState,
     node_info_list: Vec<InfoNodeEntity>,
     unit_prices: InfoUnitPriceEntity,
     q: RangeQuery,
 ) -> Result<Value> {
     // 1️⃣ Get detailed cluster cost metrics
-    let raw_value = get_metric_k8s_cluster_cost(node_info_list, unit_prices.clone(), q).await?;
+    let node_cost_split = build_node_cost_split(state, &node_info_list, &unit_prices, &q)?;
+    let raw_value = get_metric_k8s_cluster_cost(state, node_info_list, unit_prices.clone(), q).await?;
     let cluster_cost: MetricGetResponseDto = serde_json::from_value(raw_value)?;
 
     // 2️⃣ Aggregate totals
@@ -257,6 +277,7 @@ pub async fn get_metric_k8s_cluster_cost_summary(
             if let Some(c) = point.cost {
                 summary.cpu_cost_usd += c.cpu_cost_usd.unwrap_or(0.0);
                 summary.memory_cost_usd += c.memory_cost_usd.unwrap_or(0.0);
+                summary.gpu_cost_usd += c.gpu_cost_usd.unwrap_or(0.0);
 
                 // Split storage cost into ephemeral + persistent if available
                 let ephemeral_cost = point
@@ -297,6 +318,8 @@ pub async fn get_metric_k8s_cluster_cost_summary(
         }
     }
 
+    summary.node_cost_split = Some(node_cost_split);
+
     // 3️⃣ Build and serialize DTO
     let summary_dto = MetricCostSummaryResponseDto {
         start: cluster_cost.start,
@@ -313,12 +336,13 @@ pub async fn get_metric_k8s_cluster_cost_summary(
 
 /// Analyze cluster cost trend (growth, regression, prediction)
 pub async fn get_metric_k8s_cluster_cost_trend(
+    state: &AppState,
     node_info_list: Vec<InfoNodeEntity>,
     unit_prices: InfoUnitPriceEntity,
     q: RangeQuery,
 ) -> Result<Value> {
     // 1️⃣ Get detailed cost metrics
-    let raw_value = get_metric_k8s_cluster_cost(node_info_list, unit_prices.clone(), q).await?;
+    let raw_value = get_metric_k8s_cluster_cost(state, node_info_list, unit_prices.clone(), q).await?;
     let cluster_cost: MetricGetResponseDto = serde_json::from_value(raw_value)?;
 
     // 2️⃣ Extract cost over time
@@ -393,11 +417,12 @@ pub async fn get_metric_k8s_cluster_cost_trend(
 
 /// Compute cluster-level resource efficiency (CPU, memory, storage)
 pub async fn get_metric_k8s_cluster_raw_efficiency(
+    state: &AppState,
     node_info_list: Vec<InfoNodeEntity>,
     q: RangeQuery,
 ) -> Result<Value> {
     // 1️⃣ Get summarized usage metrics
-    let raw_value = get_metric_k8s_cluster_raw_summary(node_info_list.clone(), q.clone()).await?;
+    let raw_value = get_metric_k8s_cluster_raw_summary(state, node_info_list.clone(), q.clone()).await?;
     let summary: MetricRawSummaryResponseDto = serde_json::from_value(raw_value)?;
 
     // 2️⃣ Compute total allocatable capacity from node info
@@ -456,7 +481,50 @@ pub async fn get_metric_k8s_cluster_raw_efficiency(
 }
 
 
+/// GPU counts are summed rather than averaged: every GPU of the cluster is billed.
 fn aggregate_cluster_points(points: Vec<UniversalMetricPointDto>) -> Vec<UniversalMetricPointDto> {
-    let mut map: HashMap<i64, Vec<UniversalMetricPointDto>> = HashMap::new(); for p in points { let ts = p.time.timestamp(); map.entry(ts).or_default().push(p); } let mut aggregated: Vec<UniversalMetricPointDto> = Vec::new(); for (ts, pts) in map { let len = pts.len() as f64; if len == 0.0 { continue; } let mut cpu_usage = 0.0; let mut mem_usage = 0.0; for p in &pts { cpu_usage += p.cpu_memory.cpu_usage_nano_cores.unwrap_or(0.0); mem_usage += p.cpu_memory.memory_usage_bytes.unwrap_or(0.0); } aggregated.push(UniversalMetricPointDto { time: chrono::DateTime::<Utc>::from_timestamp(ts, 0).unwrap(), cpu_memory: CommonMetricValuesDto { cpu_usage_nano_cores: Some(cpu_usage / len), memory_usage_bytes: Some(mem_usage / len), ..Default::default() }, ..Default::default() }); } aggregated.sort_by_key(|p| p.time); aggregated }
+    let mut map: HashMap<i64, Vec<UniversalMetricPointDto>> = HashMap::new();
+    for p in points {
+        let ts = p.time.timestamp();
+        map.entry(ts).or_default().push(p);
+    }
+
+    let mut aggregated: Vec<UniversalMetricPointDto> = Vec::new();
+    for (ts, pts) in map {
+        let len = pts.len() as f64;
+        if len == 0.0 {
+            continue;
+        }
+
+        let mut cpu_usage = 0.0;
+        let mut mem_usage = 0.0;
+        // GPUs are summed across nodes; `None` when no node reports any
+        let mut gpu_count: Option<f64> = None;
+        for p in &pts {
+            cpu_usage += p.cpu_memory.cpu_usage_nano_cores.unwrap_or(0.0);
+            mem_usage += p.cpu_memory.memory_usage_bytes.unwrap_or(0.0);
+            if let Some(n) = p.gpu.as_ref().and_then(|g| g.count) {
+                gpu_count = Some(gpu_count.unwrap_or(0.0) + n);
+            }
+        }
+
+        aggregated.push(UniversalMetricPointDto {
+            time: chrono::DateTime::<Utc>::from_timestamp(ts, 0).unwrap(),
+            cpu_memory: CommonMetricValuesDto {
+                cpu_usage_nano_cores: Some(cpu_usage / len),
+                memory_usage_bytes: Some(mem_usage / len),
+                ..Default::default()
+            },
+            gpu: gpu_count.map(|n| GpuMetricDto {
+                count: Some(n),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    aggregated.sort_by_key(|p| p.time);
+    aggregated
+}
 
 
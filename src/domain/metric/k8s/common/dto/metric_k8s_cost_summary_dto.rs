@@ -13,7 +13,7 @@ pub struct MetricCostSummaryResponseDto {
     pub summary: MetricCostSummaryDto,
 }
 
-/// Aggregated cost breakdown (includes PV and network)
+/// Aggregated cost breakdown (includes PV, network and GPU)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MetricCostSummaryDto {
     /// Total combined cost in USD
@@ -33,4 +33,25 @@ pub struct MetricCostSummaryDto {
 
     /// Network transfer cost in USD
     pub network_cost_usd: f64,
+
+    /// GPU cost in USD
+    #[serde(default)]
+    pub gpu_cost_usd: f64,
+
+    /// Node cost split by what consumed it; cluster summaries only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_cost_split: Option<NodeCostSplitDto>,
+}
+
+/// CPU and memory cost of the nodes, split by consumer.
+///
+/// `capacity_cost_usd` prices the full node capacity. The system share is
+/// what the kubelet, runtime and other system daemons used, the workload
+/// share is the remaining measured usage, and idle is capacity nobody used.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NodeCostSplitDto {
+    pub capacity_cost_usd: f64,
+    pub system_cost_usd: f64,
+    pub workload_cost_usd: f64,
+    pub idle_cost_usd: f64,
 }
@@ -35,7 +35,7 @@ pub struct MetricSeriesDto {
     pub points: Vec<UniversalMetricPointDto>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum MetricGranularity {
     Minute,
@@ -46,6 +46,9 @@ pub enum MetricGranularity {
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::rollup_stats::GaugeStats;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UniversalMetricPointDto {
     pub time: DateTime<Utc>,
@@ -61,8 +64,34 @@ pub struct UniversalMetricPointDto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub storage: Option<StorageMetricDto>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swap: Option<SwapMetricDto>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processes: Option<ProcessMetricDto>,
+
+    /// CFS throttling from cAdvisor; pod and container points only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub throttling: Option<ThrottlingMetricDto>,
+
+    /// GPUs from the DCGM exporter; node and pod points only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpu: Option<GpuMetricDto>,
+
+    /// Filesystems of the container runtime; node points only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime_fs: Option<RuntimeFsMetricDto>,
+
+    /// Usage of the kubelet, container runtime and other system daemons; node points only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_overhead: Option<SystemOverheadMetricDto>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cost: Option<CostMetricDto>, // <-- add this
+
+    /// Peak statistics of hour/day points; absent for minute points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<RollupStatsDto>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -98,6 +127,199 @@ pub struct CommonMetricValuesDto {
     pub memory_working_set_bytes: Option<f64>,
     pub memory_rss_bytes: Option<f64>,
     pub memory_page_faults: Option<f64>,
+    pub memory_available_bytes: Option<f64>,
+    pub memory_major_page_faults: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SwapMetricDto {
+    pub usage_bytes: Option<f64>,
+    pub available_bytes: Option<f64>,
+}
+
+impl SwapMetricDto {
+    /// Returns `None` when swap is not reported (swap disabled, rows written before it was stored).
+    pub fn from_values(usage_bytes: Option<u64>, available_bytes: Option<u64>) -> Option<Self> {
+        (usage_bytes.is_some() || available_bytes.is_some()).then(|| Self {
+            usage_bytes: usage_bytes.map(|v| v as f64),
+            available_bytes: available_bytes.map(|v| v as f64),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProcessMetricDto {
+    /// Peak number of processes over hour/day points.
+    pub count: Option<f64>,
+    /// PID limit of the node; node points only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<f64>,
+}
+
+impl ProcessMetricDto {
+    pub fn from_values(count: Option<u64>, limit: Option<u64>) -> Option<Self> {
+        (count.is_some() || limit.is_some()).then(|| Self {
+            count: count.map(|v| v as f64),
+            limit: limit.map(|v| v as f64),
+        })
+    }
+}
+
+/// CFS throttling counters of a pod or container.
+///
+/// Minute points hold the cumulative counters as scraped; hour and day points
+/// hold their increase over the window, so the ratio covers that window only.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ThrottlingMetricDto {
+    pub periods: Option<f64>,
+    pub throttled_periods: Option<f64>,
+    pub throttled_seconds: Option<f64>,
+    /// Share of enforcement periods in which the CPU limit was hit, 0.0 – 1.0.
+    pub throttled_ratio: Option<f64>,
+}
+
+impl ThrottlingMetricDto {
+    /// Returns `None` without CFS counters (no CPU limit, cAdvisor not scraped).
+    pub fn from_values(periods: Option<u64>, throttled_periods: Option<u64>, throttled_nano_seconds: Option<u64>) -> Option<Self> {
+        (periods.is_some() || throttled_periods.is_some()).then(|| Self {
+            periods: periods.map(|v| v as f64),
+            throttled_periods: throttled_periods.map(|v| v as f64),
+            throttled_seconds: throttled_nano_seconds.map(|v| v as f64 / 1_000_000_000.0),
+            throttled_ratio: match (periods, throttled_periods) {
+                (Some(p), Some(t)) if p > 0 => Some(t as f64 / p as f64),
+                _ => None,
+            },
+        })
+    }
+}
+
+/// GPUs of a node, or those assigned to a pod.
+///
+/// `count` is fractional for MIG instances and shared GPUs, and on hour and
+/// day points, which average it over the window.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GpuMetricDto {
+    pub count: Option<f64>,
+    pub utilization_percent: Option<f64>,
+    pub memory_used_bytes: Option<f64>,
+    pub memory_total_bytes: Option<f64>,
+    pub power_watts: Option<f64>,
+}
+
+impl GpuMetricDto {
+    /// Returns `None` without GPUs (no DCGM exporter, rows written before GPUs were stored).
+    pub fn from_values(
+        milli_count: Option<u64>,
+        utilization_percent: Option<u64>,
+        memory_used_bytes: Option<u64>,
+        memory_total_bytes: Option<u64>,
+        power_milliwatts: Option<u64>,
+    ) -> Option<Self> {
+        milli_count.filter(|c| *c > 0).map(|c| Self {
+            count: Some(c as f64 / 1000.0),
+            utilization_percent: utilization_percent.map(|v| v as f64),
+            memory_used_bytes: memory_used_bytes.map(|v| v as f64),
+            memory_total_bytes: memory_total_bytes.map(|v| v as f64),
+            power_watts: power_milliwatts.map(|v| v as f64 / 1000.0),
+        })
+    }
+}
+
+/// Image and container filesystems of a node's container runtime.
+///
+/// Both usually sit on the node root filesystem; `container_fs` is only
+/// reported apart when the writable layers live on their own disk.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RuntimeFsMetricDto {
+    pub image_fs: Option<FilesystemMetricDto>,
+    /// Free bytes of the image filesystem, as the kubelet's eviction manager sees them.
+    pub image_fs_available_bytes: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_fs: Option<FilesystemMetricDto>,
+}
+
+impl RuntimeFsMetricDto {
+    /// Returns `None` when the runtime reports no filesystem stats
+    /// (rows written before they were stored).
+    pub fn from_entity(entity: &MetricNodeEntity) -> Option<Self> {
+        let image_fs = (entity.image_fs_used_bytes.is_some() || entity.image_fs_capacity_bytes.is_some()).then(|| {
+            FilesystemMetricDto {
+                used_bytes: entity.image_fs_used_bytes.map(|v| v as f64),
+                capacity_bytes: entity.image_fs_capacity_bytes.map(|v| v as f64),
+                inodes_used: entity.image_fs_inodes_used.map(|v| v as f64),
+                inodes: entity.image_fs_inodes.map(|v| v as f64),
+            }
+        });
+        let container_fs = (entity.container_fs_used_bytes.is_some() || entity.container_fs_capacity_bytes.is_some())
+            .then(|| FilesystemMetricDto {
+                used_bytes: entity.container_fs_used_bytes.map(|v| v as f64),
+                capacity_bytes: entity.container_fs_capacity_bytes.map(|v| v as f64),
+                inodes_used: None,
+                inodes: None,
+            });
+
+        (image_fs.is_some() || container_fs.is_some()).then(|| Self {
+            image_fs,
+            image_fs_available_bytes: entity.image_fs_available_bytes.map(|v| v as f64),
+            container_fs,
+        })
+    }
+}
+
+/// Summed usage of a node's system containers other than the pods cgroup.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SystemOverheadMetricDto {
+    pub cpu_usage_nano_cores: Option<f64>,
+    pub memory_usage_bytes: Option<f64>,
+    pub memory_working_set_bytes: Option<f64>,
+    /// Cost of the overhead at the node's unit prices; cost endpoints only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RollupStatsDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_usage_nano_cores: Option<GaugeStatsDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_working_set_bytes: Option<GaugeStatsDto>,
+    /// Used bytes of a PVC; PVC points only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub persistent_used_bytes: Option<GaugeStatsDto>,
+}
+
+impl RollupStatsDto {
+    /// Returns `None` when the row carries no statistics (minute rows, old rollups).
+    pub fn from_stats(cpu: GaugeStats, memory_working_set: GaugeStats) -> Option<Self> {
+        let dto = Self {
+            cpu_usage_nano_cores: GaugeStatsDto::from_stats(cpu),
+            memory_working_set_bytes: GaugeStatsDto::from_stats(memory_working_set),
+            ..Default::default()
+        };
+        (dto.cpu_usage_nano_cores.is_some() || dto.memory_working_set_bytes.is_some()).then_some(dto)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GaugeStatsDto {
+    pub max: Option<f64>,
+    pub min: Option<f64>,
+    pub p95: Option<f64>,
+    pub p99: Option<f64>,
+}
+
+impl GaugeStatsDto {
+    pub fn from_stats(stats: GaugeStats) -> Option<Self> {
+        if stats == GaugeStats::default() {
+            return None;
+        }
+        Some(Self {
+            max: stats.max.map(|v| v as f64),
+            min: stats.min.map(|v| v as f64),
+            p95: stats.p95.map(|v| v as f64),
+            p99: stats.p99.map(|v| v as f64),
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -109,6 +331,9 @@ pub enum MetricScope {
     Container,
     Namespace,
     Deployment,
+    Pvc,
+    NodeNetwork,
+    NodeSystem,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -117,5 +342,11 @@ pub struct CostMetricDto {
     pub cpu_cost_usd: Option<f64>,
     pub memory_cost_usd: Option<f64>,
     pub storage_cost_usd: Option<f64>,
+    /// Egress cost; node interface endpoints only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_cost_usd: Option<f64>,
+    /// Cost of the GPUs at `gpu_hour`; node, pod and cluster points with GPUs only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpu_cost_usd: Option<f64>,
 }
 
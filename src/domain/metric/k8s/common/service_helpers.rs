@@ -169,6 +169,11 @@ pub fn build_raw_summary_value(
     Ok(serde_json::to_value(dto)?)
 }
 
+/// Per-second cost of `gpus` GPUs; they are billed whole, busy or not.
+pub fn gpu_cost(gpus: f64, unit_prices: &InfoUnitPriceEntity) -> f64 {
+    gpus * (unit_prices.gpu_hour / 3600.0)
+}
+
 pub fn apply_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPriceEntity) {
     for series in &mut response.series {
         for point in &mut series.points {
@@ -191,10 +196,13 @@ pub fn apply_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPr
                     gb * (unit_prices.storage_gb_hour / 3600.0)
                 });
 
+            let gpu_cost_usd = point.gpu.as_ref().and_then(|g| g.count).map(|n| gpu_cost(n, unit_prices));
+
             let total_cost_usd = Some(
                 cpu_cost_usd.unwrap_or(0.0)
                     + memory_cost_usd.unwrap_or(0.0)
-                    + storage_cost_usd.unwrap_or(0.0),
+                    + storage_cost_usd.unwrap_or(0.0)
+                    + gpu_cost_usd.unwrap_or(0.0),
             );
 
             point.cost = Some(CostMetricDto {
@@ -202,6 +210,8 @@ pub fn apply_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPr
                 cpu_cost_usd,
                 memory_cost_usd,
                 storage_cost_usd,
+                gpu_cost_usd,
+                ..Default::default()
             });
         }
     }
@@ -220,6 +230,7 @@ pub fn build_cost_summary_dto(
             if let Some(cost) = &point.cost {
                 summary.cpu_cost_usd += cost.cpu_cost_usd.unwrap_or(0.0);
                 summary.memory_cost_usd += cost.memory_cost_usd.unwrap_or(0.0);
+                summary.gpu_cost_usd += cost.gpu_cost_usd.unwrap_or(0.0);
 
                 let ephemeral_cost = point
                     .filesystem
@@ -426,6 +437,8 @@ pub fn aggregate_points(points: Vec<UniversalMetricPointDto>) -> Vec<UniversalMe
 
 pub fn aggregate_cost_points(series: &[MetricSeriesDto]) -> Vec<UniversalMetricPointDto> {
     let mut map: HashMap<i64, (chrono::DateTime<Utc>, f64, f64, f64, f64)> = HashMap::new();
+    // Only timestamps with GPUs get a GPU cost
+    let mut gpu_costs: HashMap<i64, f64> = HashMap::new();
 
     for s in series {
         for point in &s.points {
@@ -438,13 +451,16 @@ pub fn aggregate_cost_points(series: &[MetricSeriesDto]) -> Vec<UniversalMetricP
                 entry.2 += cost.cpu_cost_usd.unwrap_or(0.0);
                 entry.3 += cost.memory_cost_usd.unwrap_or(0.0);
                 entry.4 += cost.storage_cost_usd.unwrap_or(0.0);
+                if let Some(gpu) = cost.gpu_cost_usd {
+                    *gpu_costs.entry(point.time.timestamp()).or_default() += gpu;
+                }
             }
         }
     }
 
     let mut aggregated = Vec::new();
 
-    for (_, (time, total, cpu, mem, storage)) in map {
+    for (ts, (time, total, cpu, mem, storage)) in map {
         aggregated.push(UniversalMetricPointDto {
             time,
             cost: Some(CostMetricDto {
@@ -452,6 +468,8 @@ pub fn aggregate_cost_points(series: &[MetricSeriesDto]) -> Vec<UniversalMetricP
                 cpu_cost_usd: Some(cpu),
                 memory_cost_usd: Some(mem),
                 storage_cost_usd: Some(storage),
+                gpu_cost_usd: gpu_costs.get(&ts).copied(),
+                ..Default::default()
             }),
             ..Default::default()
         });
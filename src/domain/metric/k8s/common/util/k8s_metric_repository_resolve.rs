@@ -1,40 +1,86 @@
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricStorageBackend;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
 use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
+use crate::domain::metric::k8s::container::repository::metric_container_day_api_repository::MetricContainerDayApiRepositoryImpl;
+use crate::domain::metric::k8s::container::repository::metric_container_hour_api_repository::MetricContainerHourApiRepositoryImpl;
+use crate::domain::metric::k8s::container::repository::metric_container_minute_api_repository::MetricContainerMinuteApiRepositoryImpl;
+use crate::domain::metric::k8s::node::repository::metric_node_day_api_repository::MetricNodeDayApiRepositoryImpl;
+use crate::domain::metric::k8s::node::repository::metric_node_hour_api_repository::MetricNodeHourApiRepositoryImpl;
+use crate::domain::metric::k8s::node::repository::metric_node_minute_api_repository::MetricNodeMinuteApiRepositoryImpl;
+use crate::domain::metric::k8s::node_network::repository::metric_node_network_day_api_repository::MetricNodeNetworkDayApiRepositoryImpl;
+use crate::domain::metric::k8s::node_network::repository::metric_node_network_hour_api_repository::MetricNodeNetworkHourApiRepositoryImpl;
+use crate::domain::metric::k8s::node_network::repository::metric_node_network_minute_api_repository::MetricNodeNetworkMinuteApiRepositoryImpl;
+use crate::domain::metric::k8s::node_system::repository::metric_node_system_day_api_repository::MetricNodeSystemDayApiRepositoryImpl;
+use crate::domain::metric::k8s::node_system::repository::metric_node_system_hour_api_repository::MetricNodeSystemHourApiRepositoryImpl;
+use crate::domain::metric::k8s::node_system::repository::metric_node_system_minute_api_repository::MetricNodeSystemMinuteApiRepositoryImpl;
+use crate::domain::metric::k8s::pod::repository::metric_pod_day_api_repository::MetricPodDayApiRepositoryImpl;
+use crate::domain::metric::k8s::pod::repository::metric_pod_hour_api_repository::MetricPodHourApiRepositoryImpl;
+use crate::domain::metric::k8s::pod::repository::metric_pod_minute_api_repository::MetricPodMinuteApiRepositoryImpl;
+use crate::domain::metric::k8s::pvc::repository::metric_pvc_day_api_repository::MetricPvcDayApiRepositoryImpl;
+use crate::domain::metric::k8s::pvc::repository::metric_pvc_hour_api_repository::MetricPvcHourApiRepositoryImpl;
+use crate::domain::metric::k8s::pvc::repository::metric_pvc_minute_api_repository::MetricPvcMinuteApiRepositoryImpl;
 use super::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
 
-/// Resolve metric repository variant from metric scope and granularity.
+/// Resolve metric repository variant from metric scope and granularity,
+/// reading from the given backend.
 pub fn resolve_k8s_metric_repository(
+    backend: &dyn MetricStorageBackend,
     scope: &MetricScope,
     granularity: &MetricGranularity,
 ) -> K8sMetricRepositoryVariant {
     use crate::domain::metric::k8s::common::dto::MetricGranularity::*;
     use K8sMetricRepositoryVariant::*;
 
+    let node = || match granularity {
+        Minute => NodeMinute(MetricNodeMinuteApiRepositoryImpl { adapter: backend.node(MetricTier::Minute) }),
+        Hour => NodeHour(MetricNodeHourApiRepositoryImpl { adapter: backend.node(MetricTier::Hour) }),
+        Day => NodeDay(MetricNodeDayApiRepositoryImpl { adapter: backend.node(MetricTier::Day) }),
+    };
+    let pod = || match granularity {
+        Minute => PodMinute(MetricPodMinuteApiRepositoryImpl { adapter: backend.pod(MetricTier::Minute) }),
+        Hour => PodHour(MetricPodHourApiRepositoryImpl { adapter: backend.pod(MetricTier::Hour) }),
+        Day => PodDay(MetricPodDayApiRepositoryImpl { adapter: backend.pod(MetricTier::Day) }),
+    };
+
     match scope {
-        MetricScope::Node => match granularity {
-            Minute => NodeMinute(Default::default()),
-            Hour => NodeHour(Default::default()),
-            Day => NodeDay(Default::default()),
-        },
-        MetricScope::Pod => match granularity {
-            Minute => PodMinute(Default::default()),
-            Hour => PodHour(Default::default()),
-            Day => PodDay(Default::default()),
-        },
+        MetricScope::Node => node(),
+        MetricScope::Pod => pod(),
         MetricScope::Container => match granularity {
-            Minute => ContainerMinute(Default::default()),
-            Hour => ContainerHour(Default::default()),
-            Day => ContainerDay(Default::default()),
+            Minute => ContainerMinute(MetricContainerMinuteApiRepositoryImpl {
+                adapter: backend.container(MetricTier::Minute),
+            }),
+            Hour => ContainerHour(MetricContainerHourApiRepositoryImpl { adapter: backend.container(MetricTier::Hour) }),
+            Day => ContainerDay(MetricContainerDayApiRepositoryImpl { adapter: backend.container(MetricTier::Day) }),
+        },
+        MetricScope::Pvc => match granularity {
+            Minute => PvcMinute(MetricPvcMinuteApiRepositoryImpl { adapter: backend.pvc(MetricTier::Minute) }),
+            Hour => PvcHour(MetricPvcHourApiRepositoryImpl { adapter: backend.pvc(MetricTier::Hour) }),
+            Day => PvcDay(MetricPvcDayApiRepositoryImpl { adapter: backend.pvc(MetricTier::Day) }),
         },
-        MetricScope::Cluster => match granularity {
-            // For cluster, reuse node-level repos
-            Minute => NodeMinute(Default::default()),
-            Hour => NodeHour(Default::default()),
-            Day => NodeDay(Default::default()),
+        MetricScope::NodeNetwork => match granularity {
+            Minute => NodeNetworkMinute(MetricNodeNetworkMinuteApiRepositoryImpl {
+                adapter: backend.node_network(MetricTier::Minute),
+            }),
+            Hour => NodeNetworkHour(MetricNodeNetworkHourApiRepositoryImpl {
+                adapter: backend.node_network(MetricTier::Hour),
+            }),
+            Day => NodeNetworkDay(MetricNodeNetworkDayApiRepositoryImpl {
+                adapter: backend.node_network(MetricTier::Day),
+            }),
         },
-        MetricScope::Namespace | MetricScope::Deployment => match granularity {
-            Minute => PodMinute(Default::default()),
-            Hour => PodHour(Default::default()),
-            Day => PodDay(Default::default()),
+        MetricScope::NodeSystem => match granularity {
+            Minute => NodeSystemMinute(MetricNodeSystemMinuteApiRepositoryImpl {
+                adapter: backend.node_system(MetricTier::Minute),
+            }),
+            Hour => NodeSystemHour(MetricNodeSystemHourApiRepositoryImpl {
+                adapter: backend.node_system(MetricTier::Hour),
+            }),
+            Day => NodeSystemDay(MetricNodeSystemDayApiRepositoryImpl {
+                adapter: backend.node_system(MetricTier::Day),
+            }),
         },
+        // For cluster, reuse node-level repos
+        MetricScope::Cluster => node(),
+        MetricScope::Namespace | MetricScope::Deployment => pod(),
     }
 }
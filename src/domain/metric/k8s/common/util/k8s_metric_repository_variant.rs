@@ -5,11 +5,19 @@ use crate::domain::metric::k8s::container::repository::metric_container_minute_a
 use crate::domain::metric::k8s::node::repository::metric_node_day_api_repository::MetricNodeDayApiRepositoryImpl;
 use crate::domain::metric::k8s::node::repository::metric_node_hour_api_repository::MetricNodeHourApiRepositoryImpl;
 use crate::domain::metric::k8s::node::repository::metric_node_minute_api_repository::MetricNodeMinuteApiRepositoryImpl;
+use crate::domain::metric::k8s::node_network::repository::metric_node_network_day_api_repository::MetricNodeNetworkDayApiRepositoryImpl;
+use crate::domain::metric::k8s::node_network::repository::metric_node_network_hour_api_repository::MetricNodeNetworkHourApiRepositoryImpl;
+use crate::domain::metric::k8s::node_network::repository::metric_node_network_minute_api_repository::MetricNodeNetworkMinuteApiRepositoryImpl;
+use crate::domain::metric::k8s::node_system::repository::metric_node_system_day_api_repository::MetricNodeSystemDayApiRepositoryImpl;
+use crate::domain::metric::k8s::node_system::repository::metric_node_system_hour_api_repository::MetricNodeSystemHourApiRepositoryImpl;
+use crate::domain::metric::k8s::node_system::repository::metric_node_system_minute_api_repository::MetricNodeSystemMinuteApiRepositoryImpl;
 use crate::domain::metric::k8s::pod::repository::metric_pod_day_api_repository::MetricPodDayApiRepositoryImpl;
 use crate::domain::metric::k8s::pod::repository::metric_pod_hour_api_repository::MetricPodHourApiRepositoryImpl;
 use crate::domain::metric::k8s::pod::repository::metric_pod_minute_api_repository::MetricPodMinuteApiRepositoryImpl;
+use crate::domain::metric::k8s::pvc::repository::metric_pvc_day_api_repository::MetricPvcDayApiRepositoryImpl;
+use crate::domain::metric::k8s::pvc::repository::metric_pvc_hour_api_repository::MetricPvcHourApiRepositoryImpl;
+use crate::domain::metric::k8s::pvc::repository::metric_pvc_minute_api_repository::MetricPvcMinuteApiRepositoryImpl;
 
-#[derive(Debug)]
 pub enum K8sMetricRepositoryVariant {
     // Node
     NodeMinute(MetricNodeMinuteApiRepositoryImpl),
@@ -25,4 +33,19 @@ pub enum K8sMetricRepositoryVariant {
     ContainerMinute(MetricContainerMinuteApiRepositoryImpl),
     ContainerHour(MetricContainerHourApiRepositoryImpl),
     ContainerDay(MetricContainerDayApiRepositoryImpl),
+
+    // PVC
+    PvcMinute(MetricPvcMinuteApiRepositoryImpl),
+    PvcHour(MetricPvcHourApiRepositoryImpl),
+    PvcDay(MetricPvcDayApiRepositoryImpl),
+
+    // Node network interfaces
+    NodeNetworkMinute(MetricNodeNetworkMinuteApiRepositoryImpl),
+    NodeNetworkHour(MetricNodeNetworkHourApiRepositoryImpl),
+    NodeNetworkDay(MetricNodeNetworkDayApiRepositoryImpl),
+
+    // Node system containers
+    NodeSystemMinute(MetricNodeSystemMinuteApiRepositoryImpl),
+    NodeSystemHour(MetricNodeSystemHourApiRepositoryImpl),
+    NodeSystemDay(MetricNodeSystemDayApiRepositoryImpl),
 }
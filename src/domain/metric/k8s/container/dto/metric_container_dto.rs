@@ -15,6 +15,13 @@ pub struct MetricContainerDto {
     pub fs_capacity_bytes: Option<u64>,
     pub fs_inodes_used: Option<u64>,
     pub fs_inodes: Option<u64>,
+    pub memory_available_bytes: Option<u64>,
+    pub memory_major_page_faults: Option<u64>,
+    pub swap_usage_bytes: Option<u64>,
+    pub swap_available_bytes: Option<u64>,
+    pub cpu_cfs_periods: Option<u64>,
+    pub cpu_cfs_throttled_periods: Option<u64>,
+    pub cpu_cfs_throttled_nano_seconds: Option<u64>,
 }
 
 impl From<MetricContainerEntity> for MetricContainerDto {
@@ -31,6 +38,13 @@ impl From<MetricContainerEntity> for MetricContainerDto {
             fs_capacity_bytes: e.fs_capacity_bytes,
             fs_inodes_used: e.fs_inodes_used,
             fs_inodes: e.fs_inodes,
+            memory_available_bytes: e.memory_available_bytes,
+            memory_major_page_faults: e.memory_major_page_faults,
+            swap_usage_bytes: e.swap_usage_bytes,
+            swap_available_bytes: e.swap_available_bytes,
+            cpu_cfs_periods: e.cpu_cfs_periods,
+            cpu_cfs_throttled_periods: e.cpu_cfs_throttled_periods,
+            cpu_cfs_throttled_nano_seconds: e.cpu_cfs_throttled_nano_seconds,
         }
     }
 }
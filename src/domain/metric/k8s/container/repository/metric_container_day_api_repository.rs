@@ -1,16 +1,13 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_api_repository_trait::MetricContainerDayApiRepository;
-use crate::core::persistence::metrics::k8s::container::day::metric_container_day_fs_adapter::MetricContainerDayFsAdapter;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use chrono::{DateTime, Utc};
+use std::sync::Arc;
 
-#[derive(Debug)]
-pub struct MetricContainerDayApiRepositoryImpl { pub adapter: MetricContainerDayFsAdapter }
-
-impl Default for MetricContainerDayApiRepositoryImpl { fn default() -> Self { Self { adapter: MetricContainerDayFsAdapter } } }
+pub struct MetricContainerDayApiRepositoryImpl { pub adapter: Arc<dyn MetricFsAdapterBase<MetricContainerEntity>> }
 
 impl MetricContainerDayApiRepository for MetricContainerDayApiRepositoryImpl {
-    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> { &self.adapter }
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> { self.adapter.as_ref() }
     fn get_row_between(
         &self,
         start: DateTime<Utc>,
@@ -2,8 +2,10 @@ use anyhow::{Result};
 use serde_json::Value;
 
 use crate::api::dto::{info_dto::K8sListQuery, metrics_dto::RangeQuery};
+use crate::app_state::AppState;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::info_source::{exporter_container_key, is_k8s_source};
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_api_repository_trait::MetricContainerDayApiRepository;
 use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_api_repository_trait::MetricContainerHourApiRepository;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
@@ -11,7 +13,7 @@ use crate::core::persistence::metrics::k8s::container::minute::metric_container_
 use crate::domain::info::service::{info_k8s_container_service, info_unit_price_service};
 use crate::domain::metric::k8s::common::dto::{
     CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto,
-    UniversalMetricPointDto,
+    RollupStatsDto, SwapMetricDto, ThrottlingMetricDto, UniversalMetricPointDto,
 };
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
 use crate::domain::metric::k8s::common::service_helpers::{
@@ -22,8 +24,12 @@ use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::res
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
 
 fn container_metric_key(info: &InfoContainerEntity) -> Option<String> {
-    match (&info.pod_uid, &info.container_name) {
-        (Some(pod_uid), Some(container_name)) => Some(format!("{}-{}", pod_uid, container_name)),
+    match (&info.pod_uid, &info.container_name, &info.node_name) {
+        (Some(pod_uid), Some(container_name), _) => Some(format!("{}-{}", pod_uid, container_name)),
+        // Exporter containers have no pod; they are keyed by their host.
+        (None, Some(container_name), Some(host)) if !is_k8s_source(info.source.as_deref()) => {
+            Some(exporter_container_key(host, container_name))
+        }
         _ => None,
     }
 }
@@ -59,6 +65,8 @@ fn metric_container_entity_to_point(entity: MetricContainerEntity) -> UniversalM
             memory_working_set_bytes: entity.memory_working_set_bytes.map(|v| v as f64),
             memory_rss_bytes: entity.memory_rss_bytes.map(|v| v as f64),
             memory_page_faults: entity.memory_page_faults.map(|v| v as f64),
+            memory_available_bytes: entity.memory_available_bytes.map(|v| v as f64),
+            memory_major_page_faults: entity.memory_major_page_faults.map(|v| v as f64),
         },
         filesystem: Some(FilesystemMetricDto {
             used_bytes: entity.fs_used_bytes.map(|v| v as f64),
@@ -66,21 +74,29 @@ fn metric_container_entity_to_point(entity: MetricContainerEntity) -> UniversalM
             inodes_used: entity.fs_inodes_used.map(|v| v as f64),
             inodes: entity.fs_inodes.map(|v| v as f64),
         }),
+        swap: SwapMetricDto::from_values(entity.swap_usage_bytes, entity.swap_available_bytes),
+        throttling: ThrottlingMetricDto::from_values(
+            entity.cpu_cfs_periods,
+            entity.cpu_cfs_throttled_periods,
+            entity.cpu_cfs_throttled_nano_seconds,
+        ),
+        stats: RollupStatsDto::from_stats(entity.cpu_stats(), entity.memory_working_set_stats()),
         ..Default::default()
     }
 }
 
 async fn build_container_raw_data(
+    state: &AppState,
     q: RangeQuery,
     target_container_id: Option<String>,
 ) -> Result<(MetricGetResponseDto, Vec<InfoContainerEntity>)> {
     let window = resolve_time_window(&q);
-    let repo = resolve_k8s_metric_repository(&MetricScope::Container, &window.granularity);
+    let repo = resolve_k8s_metric_repository(state.metrics.as_ref(), &MetricScope::Container, &window.granularity);
 
     let container_infos = if let Some(container_id) = target_container_id.clone() {
-        vec![info_k8s_container_service::get_info_k8s_container(container_id).await?]
+        vec![info_k8s_container_service::get_info_k8s_container(state, container_id).await?]
     } else {
-        info_k8s_container_service::list_k8s_containers(K8sListQuery {
+        info_k8s_container_service::list_k8s_containers(state, K8sListQuery {
             namespace: q.namespace.clone(),
             label_selector: None,
             node_name: None,
@@ -131,27 +147,28 @@ fn sum_container_requests(containers: &[InfoContainerEntity]) -> (f64, f64) {
 }
 
 async fn build_container_cost_response(
+    state: &AppState,
     q: RangeQuery,
     target: Option<String>,
     unit_prices: InfoUnitPriceEntity,
 ) -> Result<MetricGetResponseDto> {
-    let (mut response, _) = build_container_raw_data(q, target).await?;
+    let (mut response, _) = build_container_raw_data(state, q, target).await?;
     apply_costs(&mut response, &unit_prices);
     Ok(response)
 }
 
-pub async fn get_metric_k8s_containers_raw(q: RangeQuery) -> Result<Value> {
-    let (response, _) = build_container_raw_data(q, None).await?;
+pub async fn get_metric_k8s_containers_raw(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let (response, _) = build_container_raw_data(state, q, None).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_containers_raw_summary(q: RangeQuery) -> Result<Value> {
-    let (response, containers) = build_container_raw_data(q, None).await?;
+pub async fn get_metric_k8s_containers_raw_summary(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let (response, containers) = build_container_raw_data(state, q, None).await?;
     build_raw_summary_value(&response, MetricScope::Container, containers.len())
 }
 
-pub async fn get_metric_k8s_containers_raw_efficiency(q: RangeQuery) -> Result<Value> {
-    let (response, containers) = build_container_raw_data(q.clone(), None).await?;
+pub async fn get_metric_k8s_containers_raw_efficiency(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let (response, containers) = build_container_raw_data(state, q.clone(), None).await?;
     let summary_value = build_raw_summary_value(&response, MetricScope::Container, containers.len())?;
     let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
 
@@ -167,18 +184,18 @@ pub async fn get_metric_k8s_containers_raw_efficiency(q: RangeQuery) -> Result<V
     )
 }
 
-pub async fn get_metric_k8s_container_raw(container_id: String, q: RangeQuery) -> Result<Value> {
-    let (response, _) = build_container_raw_data(q, Some(container_id)).await?;
+pub async fn get_metric_k8s_container_raw(state: &AppState, container_id: String, q: RangeQuery) -> Result<Value> {
+    let (response, _) = build_container_raw_data(state, q, Some(container_id)).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_container_raw_summary(container_id: String, q: RangeQuery) -> Result<Value> {
-    let (response, _) = build_container_raw_data(q, Some(container_id)).await?;
+pub async fn get_metric_k8s_container_raw_summary(state: &AppState, container_id: String, q: RangeQuery) -> Result<Value> {
+    let (response, _) = build_container_raw_data(state, q, Some(container_id)).await?;
     build_raw_summary_value(&response, MetricScope::Container, 1)
 }
 
-pub async fn get_metric_k8s_container_raw_efficiency(container_id: String, q: RangeQuery) -> Result<Value> {
-    let (response, containers) = build_container_raw_data(q.clone(), Some(container_id)).await?;
+pub async fn get_metric_k8s_container_raw_efficiency(state: &AppState, container_id: String, q: RangeQuery) -> Result<Value> {
+    let (response, containers) = build_container_raw_data(state, q.clone(), Some(container_id)).await?;
     let summary_value = build_raw_summary_value(&response, MetricScope::Container, 1)?;
     let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
 
@@ -194,43 +211,43 @@ pub async fn get_metric_k8s_container_raw_efficiency(container_id: String, q: Ra
     )
 }
 
-pub async fn get_metric_k8s_containers_cost(q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_container_cost_response(q, None, unit_prices).await?;
+pub async fn get_metric_k8s_containers_cost(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_container_cost_response(state, q, None, unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_containers_cost_summary(q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_container_cost_response(q, None, unit_prices.clone()).await?;
+pub async fn get_metric_k8s_containers_cost_summary(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_container_cost_response(state, q, None, unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Container, None, &unit_prices);
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_containers_cost_trend(q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_container_cost_response(q, None, unit_prices).await?;
+pub async fn get_metric_k8s_containers_cost_trend(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_container_cost_response(state, q, None, unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Container, None)?;
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_container_cost(container_id: String, q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_container_cost_response(q, Some(container_id.clone()), unit_prices).await?;
+pub async fn get_metric_k8s_container_cost(state: &AppState, container_id: String, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_container_cost_response(state, q, Some(container_id.clone()), unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_container_cost_summary(container_id: String, q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+pub async fn get_metric_k8s_container_cost_summary(state: &AppState, container_id: String, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
     let response =
-        build_container_cost_response(q, Some(container_id.clone()), unit_prices.clone()).await?;
+        build_container_cost_response(state, q, Some(container_id.clone()), unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Container, Some(container_id), &unit_prices);
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_container_cost_trend(container_id: String, q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_container_cost_response(q, Some(container_id.clone()), unit_prices).await?;
+pub async fn get_metric_k8s_container_cost_trend(state: &AppState, container_id: String, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_container_cost_response(state, q, Some(container_id.clone()), unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Container, Some(container_id))?;
     Ok(serde_json::to_value(dto)?)
 }
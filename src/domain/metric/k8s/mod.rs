@@ -1,9 +1,12 @@
-//! K8s metrics subdomain (cluster, node, pod, container)
+//! K8s metrics subdomain (cluster, node, node network, node system, pod, container, pvc)
 
 pub mod cluster;
 pub mod node;
 pub mod pod;
 pub mod container;
+pub mod pvc;
+pub mod node_network;
+pub mod node_system;
 pub mod namespace;
 pub mod deployment;
 pub mod common;
@@ -3,6 +3,7 @@ use serde_json::{json, Value};
 use std::collections::HashMap;
 
 use crate::api::dto::{info_dto::K8sListQuery, metrics_dto::RangeQuery};
+use crate::app_state::AppState;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
 use crate::domain::info::service::{info_k8s_pod_service, info_unit_price_service};
 use crate::domain::metric::k8s::common::dto::{MetricGetResponseDto, MetricScope, MetricSeriesDto};
@@ -42,10 +43,11 @@ fn build_namespace_response(
 }
 
 async fn build_namespace_cost_response(
+    state: &AppState,
     namespace: &str,
     mut per_pod_response: MetricGetResponseDto,
 ) -> Result<MetricGetResponseDto> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
     apply_costs(&mut per_pod_response, &unit_prices);
     let points = aggregate_cost_points(&per_pod_response.series);
 
@@ -64,8 +66,8 @@ async fn build_namespace_cost_response(
     })
 }
 
-async fn namespace_pods(namespace: &str) -> Result<Vec<InfoPodEntity>> {
-    let pods = info_k8s_pod_service::list_k8s_pods(K8sListQuery {
+async fn namespace_pods(state: &AppState, namespace: &str) -> Result<Vec<InfoPodEntity>> {
+    let pods = info_k8s_pod_service::list_k8s_pods(state, K8sListQuery {
         namespace: Some(namespace.to_string()),
         label_selector: None,
         node_name: None,
@@ -79,8 +81,8 @@ async fn namespace_pods(namespace: &str) -> Result<Vec<InfoPodEntity>> {
     Ok(pods)
 }
 
-pub async fn get_metric_k8s_namespaces_raw(q: RangeQuery) -> Result<Value> {
-    let all_pods = info_k8s_pod_service::list_k8s_pods(K8sListQuery {
+pub async fn get_metric_k8s_namespaces_raw(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let all_pods = info_k8s_pod_service::list_k8s_pods(state, K8sListQuery {
         namespace: None,
         label_selector: None,
         node_name: None,
@@ -96,7 +98,7 @@ pub async fn get_metric_k8s_namespaces_raw(q: RangeQuery) -> Result<Value> {
     let mut base_response: Option<MetricGetResponseDto> = None;
 
     for (ns, pods) in ns_map {
-        let per_pod = build_pod_response_from_infos(q.clone(), pods, Some(ns.clone()))?;
+        let per_pod = build_pod_response_from_infos(state, q.clone(), pods, Some(ns.clone()))?;
         let aggregated = build_namespace_response(&ns, &per_pod);
         if base_response.is_none() {
             base_response = Some(aggregated.clone());
@@ -113,22 +115,22 @@ pub async fn get_metric_k8s_namespaces_raw(q: RangeQuery) -> Result<Value> {
     Ok(json!({ "status": "no data" }))
 }
 
-pub async fn get_metric_k8s_namespace_raw(namespace: String, q: RangeQuery) -> Result<Value> {
-    let pods = namespace_pods(&namespace).await?;
-    let per_pod = build_pod_response_from_infos(q, pods, Some(namespace.clone()))?;
+pub async fn get_metric_k8s_namespace_raw(state: &AppState, namespace: String, q: RangeQuery) -> Result<Value> {
+    let pods = namespace_pods(state, &namespace).await?;
+    let per_pod = build_pod_response_from_infos(state, q, pods, Some(namespace.clone()))?;
     let aggregated = build_namespace_response(&namespace, &per_pod);
     Ok(serde_json::to_value(aggregated)?)
 }
 
-pub async fn get_metric_k8s_namespace_raw_summary(namespace: String, q: RangeQuery) -> Result<Value> {
-    let pods = namespace_pods(&namespace).await?;
-    let per_pod = build_pod_response_from_infos(q, pods.clone(), Some(namespace.clone()))?;
+pub async fn get_metric_k8s_namespace_raw_summary(state: &AppState, namespace: String, q: RangeQuery) -> Result<Value> {
+    let pods = namespace_pods(state, &namespace).await?;
+    let per_pod = build_pod_response_from_infos(state, q, pods.clone(), Some(namespace.clone()))?;
     let aggregated = build_namespace_response(&namespace, &per_pod);
     build_raw_summary_value(&aggregated, MetricScope::Namespace, pods.len())
 }
 
-pub async fn get_metric_k8s_namespaces_raw_summary(q: RangeQuery) -> Result<Value> {
-    let all_pods = info_k8s_pod_service::list_k8s_pods(K8sListQuery {
+pub async fn get_metric_k8s_namespaces_raw_summary(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let all_pods = info_k8s_pod_service::list_k8s_pods(state, K8sListQuery {
         namespace: None,
         label_selector: None,
         node_name: None,
@@ -139,7 +141,7 @@ pub async fn get_metric_k8s_namespaces_raw_summary(q: RangeQuery) -> Result<Valu
         return Ok(json!({ "status": "no data" }));
     }
 
-    let per_pod = build_pod_response_from_infos(q, all_pods.clone(), None)?;
+    let per_pod = build_pod_response_from_infos(state, q, all_pods.clone(), None)?;
     let aggregated = build_namespace_response("all", &per_pod);
     build_raw_summary_value(&aggregated, MetricScope::Namespace, all_pods.len())
 }
@@ -159,13 +161,14 @@ pub async fn get_metric_k8s_namespaces_raw_efficiency(_q: RangeQuery) -> Result<
 }
 
 async fn build_namespace_cost(
+    state: &AppState,
     namespace: Option<String>,
     q: RangeQuery,
 ) -> Result<MetricGetResponseDto> {
     let pods = if let Some(ns) = namespace.clone() {
-        namespace_pods(&ns).await?
+        namespace_pods(state, &ns).await?
     } else {
-        info_k8s_pod_service::list_k8s_pods(K8sListQuery {
+        info_k8s_pod_service::list_k8s_pods(state, K8sListQuery {
             namespace: None,
             label_selector: None,
             node_name: None,
@@ -177,32 +180,32 @@ async fn build_namespace_cost(
         return Err(anyhow!("no pods available for namespace cost calculation"));
     }
 
-    let per_pod = build_pod_response_from_infos(q, pods, namespace.clone())?;
+    let per_pod = build_pod_response_from_infos(state, q, pods, namespace.clone())?;
     Ok(build_namespace_response(namespace.as_deref().unwrap_or("all"), &per_pod))
 }
 
-pub async fn get_metric_k8s_namespaces_cost(q: RangeQuery) -> Result<Value> {
-    let aggregated = build_namespace_cost(None, q).await?;
+pub async fn get_metric_k8s_namespaces_cost(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let aggregated = build_namespace_cost(state, None, q).await?;
     Ok(serde_json::to_value(aggregated)?)
 }
 
-pub async fn get_metric_k8s_namespace_cost(namespace: String, q: RangeQuery) -> Result<Value> {
-    let aggregated = build_namespace_cost(Some(namespace), q).await?;
+pub async fn get_metric_k8s_namespace_cost(state: &AppState, namespace: String, q: RangeQuery) -> Result<Value> {
+    let aggregated = build_namespace_cost(state, Some(namespace), q).await?;
     Ok(serde_json::to_value(aggregated)?)
 }
 
-pub async fn get_metric_k8s_namespaces_cost_summary(q: RangeQuery) -> Result<Value> {
-    let aggregated = build_namespace_cost(None, q.clone()).await?;
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+pub async fn get_metric_k8s_namespaces_cost_summary(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let aggregated = build_namespace_cost(state, None, q.clone()).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
     let mut cost_response = aggregated.clone();
     apply_costs(&mut cost_response, &unit_prices);
     let dto = build_cost_summary_dto(&cost_response, MetricScope::Namespace, None, &unit_prices);
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_namespace_cost_summary(namespace: String, q: RangeQuery) -> Result<Value> {
-    let aggregated = build_namespace_cost(Some(namespace.clone()), q.clone()).await?;
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+pub async fn get_metric_k8s_namespace_cost_summary(state: &AppState, namespace: String, q: RangeQuery) -> Result<Value> {
+    let aggregated = build_namespace_cost(state, Some(namespace.clone()), q.clone()).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
     let mut cost_response = aggregated.clone();
     apply_costs(&mut cost_response, &unit_prices);
     let dto = build_cost_summary_dto(
@@ -214,18 +217,18 @@ pub async fn get_metric_k8s_namespace_cost_summary(namespace: String, q: RangeQu
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_namespaces_cost_trend(q: RangeQuery) -> Result<Value> {
-    let aggregated = build_namespace_cost(None, q.clone()).await?;
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+pub async fn get_metric_k8s_namespaces_cost_trend(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let aggregated = build_namespace_cost(state, None, q.clone()).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
     let mut cost_response = aggregated.clone();
     apply_costs(&mut cost_response, &unit_prices);
     let dto = build_cost_trend_dto(&cost_response, MetricScope::Namespace, None)?;
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_namespace_cost_trend(namespace: String, q: RangeQuery) -> Result<Value> {
-    let aggregated = build_namespace_cost(Some(namespace.clone()), q.clone()).await?;
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+pub async fn get_metric_k8s_namespace_cost_trend(state: &AppState, namespace: String, q: RangeQuery) -> Result<Value> {
+    let aggregated = build_namespace_cost(state, Some(namespace.clone()), q.clone()).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
     let mut cost_response = aggregated.clone();
     apply_costs(&mut cost_response, &unit_prices);
     let dto = build_cost_trend_dto(
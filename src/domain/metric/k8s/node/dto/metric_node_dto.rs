@@ -19,6 +19,19 @@ pub struct MetricNodeDto {
     pub fs_capacity_bytes: Option<u64>,
     pub fs_inodes_used: Option<u64>,
     pub fs_inodes: Option<u64>,
+    pub memory_available_bytes: Option<u64>,
+    pub memory_major_page_faults: Option<u64>,
+    pub swap_usage_bytes: Option<u64>,
+    pub swap_available_bytes: Option<u64>,
+    pub process_count: Option<u64>,
+    pub process_limit: Option<u64>,
+    pub image_fs_used_bytes: Option<u64>,
+    pub image_fs_capacity_bytes: Option<u64>,
+    pub image_fs_available_bytes: Option<u64>,
+    pub image_fs_inodes_used: Option<u64>,
+    pub image_fs_inodes: Option<u64>,
+    pub container_fs_used_bytes: Option<u64>,
+    pub container_fs_capacity_bytes: Option<u64>,
 }
 
 impl From<MetricNodeEntity> for MetricNodeDto {
@@ -39,6 +52,19 @@ impl From<MetricNodeEntity> for MetricNodeDto {
             fs_capacity_bytes: e.fs_capacity_bytes,
             fs_inodes_used: e.fs_inodes_used,
             fs_inodes: e.fs_inodes,
+            memory_available_bytes: e.memory_available_bytes,
+            memory_major_page_faults: e.memory_major_page_faults,
+            swap_usage_bytes: e.swap_usage_bytes,
+            swap_available_bytes: e.swap_available_bytes,
+            process_count: e.process_count,
+            process_limit: e.process_limit,
+            image_fs_used_bytes: e.image_fs_used_bytes,
+            image_fs_capacity_bytes: e.image_fs_capacity_bytes,
+            image_fs_available_bytes: e.image_fs_available_bytes,
+            image_fs_inodes_used: e.image_fs_inodes_used,
+            image_fs_inodes: e.image_fs_inodes,
+            container_fs_used_bytes: e.container_fs_used_bytes,
+            container_fs_capacity_bytes: e.container_fs_capacity_bytes,
         }
     }
 }
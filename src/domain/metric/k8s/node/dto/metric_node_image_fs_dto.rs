@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::metric::k8s::common::dto::MetricGetResponseDto;
+
+/// Image filesystem series of nodes plus a per-node risk table, most at risk first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricNodeImageFsGetResponseDto {
+    #[serde(flatten)]
+    pub metrics: MetricGetResponseDto,
+    pub nodes: Vec<NodeImageFsUsageDto>,
+}
+
+/// How close a node's image filesystem is to kubelet intervention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageFsRisk {
+    /// No image filesystem stats in the window
+    Unknown,
+    Ok,
+    /// Usage above the kubelet's image GC high threshold; unused images get deleted
+    ImageGc,
+    /// Free space or inodes below the kubelet's hard eviction threshold; pods get evicted
+    DiskPressure,
+}
+
+/// Image filesystem usage of one node over the window, next to the images it holds.
+///
+/// Usage, capacity and free bytes are the latest sample of the window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeImageFsUsageDto {
+    pub node_name: String,
+
+    /// Images on the node, from the node info
+    pub image_count: Option<u32>,
+    pub image_total_size_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub image_names: Vec<String>,
+
+    pub used_bytes: Option<f64>,
+    pub capacity_bytes: Option<f64>,
+    pub available_bytes: Option<f64>,
+    pub usage_percent: Option<f64>,
+    pub peak_usage_percent: Option<f64>,
+    pub inodes_usage_percent: Option<f64>,
+
+    /// Share of the used bytes taken by the images the node lists
+    pub image_share_percent: Option<f64>,
+    /// Whether images share the node root filesystem, so image growth also
+    /// counts against `nodefs` eviction
+    pub shares_node_fs: Option<bool>,
+
+    pub risk: ImageFsRisk,
+}
@@ -1 +1,2 @@
 pub mod metric_node_dto;
+pub mod metric_node_image_fs_dto;
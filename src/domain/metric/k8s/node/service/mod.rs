@@ -2,6 +2,7 @@ use anyhow::{anyhow, Result};
 use serde_json::Value;
 
 use crate::api::dto::metrics_dto::RangeQuery;
+use crate::app_state::AppState;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_api_repository_trait::MetricNodeDayApiRepository;
@@ -10,8 +11,8 @@ use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNode
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_api_repository_trait::MetricNodeMinuteApiRepository;
 use crate::domain::info::service::{info_k8s_node_service, info_unit_price_service};
 use crate::domain::metric::k8s::common::dto::{
-    CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto,
-    NetworkMetricDto, UniversalMetricPointDto,
+    CommonMetricValuesDto, FilesystemMetricDto, GpuMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto,
+    NetworkMetricDto, ProcessMetricDto, RollupStatsDto, RuntimeFsMetricDto, SwapMetricDto, UniversalMetricPointDto,
 };
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
 use crate::domain::metric::k8s::common::service_helpers::{
@@ -20,6 +21,10 @@ use crate::domain::metric::k8s::common::service_helpers::{
 };
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
+use crate::domain::metric::k8s::node::dto::metric_node_image_fs_dto::{
+    ImageFsRisk, MetricNodeImageFsGetResponseDto, NodeImageFsUsageDto,
+};
+use crate::domain::metric::k8s::node_system::service::{apply_system_overhead_costs, attach_system_overhead};
 
 fn fetch_node_points(
     repo: &K8sMetricRepositoryVariant,
@@ -46,6 +51,8 @@ fn metric_node_entity_to_point(entity: MetricNodeEntity) -> UniversalMetricPoint
             memory_working_set_bytes: entity.memory_working_set_bytes.map(|v| v as f64),
             memory_rss_bytes: entity.memory_rss_bytes.map(|v| v as f64),
             memory_page_faults: entity.memory_page_faults.map(|v| v as f64),
+            memory_available_bytes: entity.memory_available_bytes.map(|v| v as f64),
+            memory_major_page_faults: entity.memory_major_page_faults.map(|v| v as f64),
         },
         filesystem: Some(FilesystemMetricDto {
             used_bytes: entity.fs_used_bytes.map(|v| v as f64),
@@ -59,21 +66,33 @@ fn metric_node_entity_to_point(entity: MetricNodeEntity) -> UniversalMetricPoint
             rx_errors: entity.network_physical_rx_errors.map(|v| v as f64),
             tx_errors: entity.network_physical_tx_errors.map(|v| v as f64),
         }),
+        swap: SwapMetricDto::from_values(entity.swap_usage_bytes, entity.swap_available_bytes),
+        processes: ProcessMetricDto::from_values(entity.process_count, entity.process_limit),
+        runtime_fs: RuntimeFsMetricDto::from_entity(&entity),
+        gpu: GpuMetricDto::from_values(
+            entity.gpu_milli_count,
+            entity.gpu_utilization_percent,
+            entity.gpu_memory_used_bytes,
+            entity.gpu_memory_total_bytes,
+            entity.gpu_power_milliwatts,
+        ),
+        stats: RollupStatsDto::from_stats(entity.cpu_stats(), entity.memory_working_set_stats()),
         ..Default::default()
     }
 }
 
 async fn build_node_raw_data(
+    state: &AppState,
     q: RangeQuery,
     target: Option<String>,
 ) -> Result<(MetricGetResponseDto, Vec<InfoNodeEntity>)> {
     let window = resolve_time_window(&q);
-    let repo = resolve_k8s_metric_repository(&MetricScope::Node, &window.granularity);
+    let repo = resolve_k8s_metric_repository(state.metrics.as_ref(), &MetricScope::Node, &window.granularity);
 
     let node_infos = if let Some(node_name) = target.clone() {
-        vec![info_k8s_node_service::get_info_k8s_node(node_name).await?]
+        vec![info_k8s_node_service::get_info_k8s_node(state, node_name).await?]
     } else {
-        info_k8s_node_service::list_k8s_nodes().await?
+        info_k8s_node_service::list_cost_nodes(state).await?
     };
 
     let mut series = Vec::new();
@@ -92,7 +111,7 @@ async fn build_node_raw_data(
         });
     }
 
-    let response = MetricGetResponseDto {
+    let mut response = MetricGetResponseDto {
         start: window.start,
         end: window.end,
         scope: "node".to_string(),
@@ -100,6 +119,7 @@ async fn build_node_raw_data(
         granularity: window.granularity.clone(),
         series,
     };
+    attach_system_overhead(state, &mut response)?;
 
     Ok((response, node_infos))
 }
@@ -123,19 +143,19 @@ fn sum_node_allocations(nodes: &[InfoNodeEntity]) -> (f64, f64, f64) {
 }
 
 
-pub async fn get_metric_k8s_nodes_raw(q: RangeQuery) -> Result<Value> {
-    let (response, _) = build_node_raw_data(q, None).await?;
+pub async fn get_metric_k8s_nodes_raw(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let (response, _) = build_node_raw_data(state, q, None).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_nodes_raw_summary(q: RangeQuery) -> Result<Value> {
-    let (response, node_infos) = build_node_raw_data(q, None).await?;
+pub async fn get_metric_k8s_nodes_raw_summary(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let (response, node_infos) = build_node_raw_data(state, q, None).await?;
     build_raw_summary_value(&response, MetricScope::Node, node_infos.len())
 }
 
-pub async fn get_metric_k8s_nodes_raw_efficiency(q: RangeQuery) -> Result<Value> {
+pub async fn get_metric_k8s_nodes_raw_efficiency(state: &AppState, q: RangeQuery) -> Result<Value> {
     let (summary_value, node_infos) = {
-        let (response, infos) = build_node_raw_data(q.clone(), None).await?;
+        let (response, infos) = build_node_raw_data(state, q.clone(), None).await?;
         let summary_json = build_raw_summary_value(&response, MetricScope::Node, infos.len())?;
         (summary_json, infos)
     };
@@ -145,18 +165,18 @@ pub async fn get_metric_k8s_nodes_raw_efficiency(q: RangeQuery) -> Result<Value>
     build_efficiency_value(summary, MetricScope::Node, total_cpu, total_mem, total_storage)
 }
 
-pub async fn get_metric_k8s_node_raw(node_name: String, q: RangeQuery) -> Result<Value> {
-    let (response, _) = build_node_raw_data(q, Some(node_name.clone())).await?;
+pub async fn get_metric_k8s_node_raw(state: &AppState, node_name: String, q: RangeQuery) -> Result<Value> {
+    let (response, _) = build_node_raw_data(state, q, Some(node_name.clone())).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_node_raw_summary(node_name: String, q: RangeQuery) -> Result<Value> {
-    let (response, _) = build_node_raw_data(q, Some(node_name)).await?;
+pub async fn get_metric_k8s_node_raw_summary(state: &AppState, node_name: String, q: RangeQuery) -> Result<Value> {
+    let (response, _) = build_node_raw_data(state, q, Some(node_name)).await?;
     build_raw_summary_value(&response, MetricScope::Node, 1)
 }
 
-pub async fn get_metric_k8s_node_raw_efficiency(node_name: String, q: RangeQuery) -> Result<Value> {
-    let (response, node_infos) = build_node_raw_data(q.clone(), Some(node_name)).await?;
+pub async fn get_metric_k8s_node_raw_efficiency(state: &AppState, node_name: String, q: RangeQuery) -> Result<Value> {
+    let (response, node_infos) = build_node_raw_data(state, q.clone(), Some(node_name)).await?;
     let summary_value = build_raw_summary_value(&response, MetricScope::Node, 1)?;
     let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
     let (total_cpu, total_mem, total_storage) = sum_node_allocations(&node_infos);
@@ -164,51 +184,165 @@ pub async fn get_metric_k8s_node_raw_efficiency(node_name: String, q: RangeQuery
 }
 
 async fn build_node_cost_response(
+    state: &AppState,
     q: RangeQuery,
     target: Option<String>,
     unit_prices: InfoUnitPriceEntity,
 ) -> Result<MetricGetResponseDto> {
-    let (mut response, _) = build_node_raw_data(q, target).await?;
+    let (mut response, _) = build_node_raw_data(state, q, target).await?;
     apply_costs(&mut response, &unit_prices);
+    apply_system_overhead_costs(&mut response, &unit_prices);
     Ok(response)
 }
 
-pub async fn get_metric_k8s_nodes_cost(q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_node_cost_response(q, None, unit_prices).await?;
+pub async fn get_metric_k8s_nodes_cost(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_node_cost_response(state, q, None, unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_nodes_cost_summary(q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_node_cost_response(q, None, unit_prices.clone()).await?;
+pub async fn get_metric_k8s_nodes_cost_summary(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_node_cost_response(state, q, None, unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Node, None, &unit_prices);
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_nodes_cost_trend(q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_node_cost_response(q, None, unit_prices).await?;
+pub async fn get_metric_k8s_nodes_cost_trend(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_node_cost_response(state, q, None, unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Node, None)?;
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_node_cost(node_name: String, q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_node_cost_response(q, Some(node_name.clone()), unit_prices).await?;
+pub async fn get_metric_k8s_node_cost(state: &AppState, node_name: String, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_node_cost_response(state, q, Some(node_name.clone()), unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_node_cost_summary(node_name: String, q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_node_cost_response(q, Some(node_name.clone()), unit_prices.clone()).await?;
+pub async fn get_metric_k8s_node_cost_summary(state: &AppState, node_name: String, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_node_cost_response(state, q, Some(node_name.clone()), unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Node, Some(node_name), &unit_prices);
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_node_cost_trend(node_name: String, q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_node_cost_response(q, Some(node_name.clone()), unit_prices).await?;
+pub async fn get_metric_k8s_node_cost_trend(state: &AppState, node_name: String, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_node_cost_response(state, q, Some(node_name.clone()), unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Node, Some(node_name))?;
     Ok(serde_json::to_value(dto)?)
 }
+
+/// Kubelet default `imageGCHighThresholdPercent`.
+const IMAGE_GC_HIGH_THRESHOLD_PERCENT: f64 = 85.0;
+/// Kubelet default hard eviction thresholds `imagefs.available` and `imagefs.inodesFree`.
+const EVICTION_IMAGE_FS_AVAILABLE_PERCENT: f64 = 15.0;
+const EVICTION_IMAGE_FS_INODES_FREE_PERCENT: f64 = 5.0;
+
+fn percent(part: Option<f64>, whole: Option<f64>) -> Option<f64> {
+    match (part, whole) {
+        (Some(p), Some(w)) if w > 0.0 => Some(p / w * 100.0),
+        _ => None,
+    }
+}
+
+fn image_fs_usage(node: &InfoNodeEntity, series: &MetricSeriesDto) -> NodeImageFsUsageDto {
+    let samples: Vec<(&UniversalMetricPointDto, &FilesystemMetricDto)> = series
+        .points
+        .iter()
+        .filter_map(|p| p.runtime_fs.as_ref()?.image_fs.as_ref().map(|fs| (p, fs)))
+        .collect();
+
+    let latest = samples.last();
+    let used_bytes = latest.and_then(|(_, fs)| fs.used_bytes);
+    let capacity_bytes = latest.and_then(|(_, fs)| fs.capacity_bytes);
+    let available_bytes = latest.and_then(|(p, _)| p.runtime_fs.as_ref()?.image_fs_available_bytes);
+    let usage_percent = percent(used_bytes, capacity_bytes);
+    let inodes_usage_percent = latest.and_then(|(_, fs)| percent(fs.inodes_used, fs.inodes));
+    let peak_usage_percent = samples
+        .iter()
+        .filter_map(|(_, fs)| percent(fs.used_bytes, fs.capacity_bytes))
+        .reduce(f64::max);
+
+    let available_percent = percent(available_bytes, capacity_bytes);
+    let risk = if latest.is_none() {
+        ImageFsRisk::Unknown
+    } else if available_percent.is_some_and(|p| p < EVICTION_IMAGE_FS_AVAILABLE_PERCENT)
+        || inodes_usage_percent.is_some_and(|p| 100.0 - p < EVICTION_IMAGE_FS_INODES_FREE_PERCENT)
+    {
+        ImageFsRisk::DiskPressure
+    } else if usage_percent.is_some_and(|p| p >= IMAGE_GC_HIGH_THRESHOLD_PERCENT) {
+        ImageFsRisk::ImageGc
+    } else {
+        ImageFsRisk::Ok
+    };
+
+    // The kubelet reports the root filesystem again as image fs when images
+    // are not on a disk of their own.
+    let node_fs_capacity = latest.and_then(|(p, _)| p.filesystem.as_ref()?.capacity_bytes);
+    let shares_node_fs = capacity_bytes.zip(node_fs_capacity).map(|(image, node)| image == node);
+
+    NodeImageFsUsageDto {
+        node_name: series.key.clone(),
+        image_count: node.image_count,
+        image_total_size_bytes: node.image_total_size_bytes,
+        image_names: node.image_names.clone().unwrap_or_default(),
+        used_bytes,
+        capacity_bytes,
+        available_bytes,
+        usage_percent,
+        peak_usage_percent,
+        inodes_usage_percent,
+        image_share_percent: percent(node.image_total_size_bytes.map(|v| v as f64), used_bytes),
+        shares_node_fs,
+        risk,
+    }
+}
+
+async fn build_node_image_fs_response(
+    state: &AppState,
+    q: RangeQuery,
+    target: Option<String>,
+) -> Result<MetricNodeImageFsGetResponseDto> {
+    let (mut metrics, node_infos) = build_node_raw_data(state, q, target).await?;
+
+    let mut nodes: Vec<NodeImageFsUsageDto> = metrics
+        .series
+        .iter()
+        .zip(&node_infos)
+        .map(|(series, node)| image_fs_usage(node, series))
+        .collect();
+
+    // Most at risk first, fullest first within a risk
+    nodes.sort_by(|a, b| {
+        b.risk
+            .cmp(&a.risk)
+            .then(b.usage_percent.unwrap_or(0.0).total_cmp(&a.usage_percent.unwrap_or(0.0)))
+    });
+
+    // Keep the series to the filesystems this view is about
+    for series in &mut metrics.series {
+        for point in &mut series.points {
+            *point = UniversalMetricPointDto {
+                time: point.time,
+                filesystem: point.filesystem.take(),
+                runtime_fs: point.runtime_fs.take(),
+                ..Default::default()
+            };
+        }
+    }
+
+    Ok(MetricNodeImageFsGetResponseDto { metrics, nodes })
+}
+
+pub async fn get_metric_k8s_nodes_image_fs(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let response = build_node_image_fs_response(state, q, None).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_node_image_fs(state: &AppState, node_name: String, q: RangeQuery) -> Result<Value> {
+    let response = build_node_image_fs_response(state, q, Some(node_name)).await?;
+    Ok(serde_json::to_value(response)?)
+}
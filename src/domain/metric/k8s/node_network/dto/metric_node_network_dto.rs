@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::domain::metric::k8s::common::dto::MetricGetResponseDto;
+
+/// Unit price tier a node interface's egress is billed at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkPriceClass {
+    /// Same availability zone, e.g. a storage network
+    Local,
+    /// Same region
+    Regional,
+    /// Internet egress
+    #[default]
+    External,
+}
+
+impl NetworkPriceClass {
+    pub fn price_per_gb(&self, unit_prices: &InfoUnitPriceEntity) -> f64 {
+        match self {
+            NetworkPriceClass::Local => unit_prices.network_local_gb,
+            NetworkPriceClass::Regional => unit_prices.network_regional_gb,
+            NetworkPriceClass::External => unit_prices.network_external_gb,
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "local" => Some(NetworkPriceClass::Local),
+            "regional" => Some(NetworkPriceClass::Regional),
+            "external" => Some(NetworkPriceClass::External),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetworkPriceClass::Local => "local",
+            NetworkPriceClass::Regional => "regional",
+            NetworkPriceClass::External => "external",
+        }
+    }
+}
+
+/// Metric series of node network interfaces plus a per-interface traffic
+/// table, busiest egress first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricNodeNetworkGetResponseDto {
+    #[serde(flatten)]
+    pub metrics: MetricGetResponseDto,
+    pub interfaces: Vec<NodeInterfaceUsageDto>,
+}
+
+/// Traffic of one node interface over the window.
+///
+/// Series points hold what is stored: cumulative counters at minute
+/// granularity, per-window increases at hour and day granularity. The totals
+/// below are increases either way.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NodeInterfaceUsageDto {
+    /// Storage key, `<node>_<interface>`
+    pub key: String,
+    pub node_name: String,
+    pub interface: String,
+
+    pub rx_bytes: Option<u64>,
+    pub tx_bytes: Option<u64>,
+    pub rx_errors: Option<u64>,
+    pub tx_errors: Option<u64>,
+
+    /// Price tier the egress was billed at; cost endpoints only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_class: Option<NetworkPriceClass>,
+    /// Egress cost of the window; cost endpoints only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+}
+
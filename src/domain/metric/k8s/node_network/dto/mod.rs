@@ -0,0 +1 @@
+pub mod metric_node_network_dto;
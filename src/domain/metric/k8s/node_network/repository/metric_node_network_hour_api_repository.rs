@@ -0,0 +1,23 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_network::hour::metric_node_network_hour_api_repository_trait::MetricNodeNetworkHourApiRepository;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+pub struct MetricNodeNetworkHourApiRepositoryImpl { pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeNetworkEntity>> }
+
+impl MetricNodeNetworkHourApiRepository for MetricNodeNetworkHourApiRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeNetworkEntity> { self.adapter.as_ref() }
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        interface_key: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> anyhow::Result<Vec<MetricNodeNetworkEntity>> {
+        self.fs_adapter()
+            .get_row_between(start, end, interface_key, limit, offset)
+    }
+}
+
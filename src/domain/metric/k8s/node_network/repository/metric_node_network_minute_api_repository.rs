@@ -0,0 +1,11 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_network::minute::metric_node_network_minute_api_repository_trait::MetricNodeNetworkMinuteApiRepository;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use std::sync::Arc;
+
+pub struct MetricNodeNetworkMinuteApiRepositoryImpl { pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeNetworkEntity>> }
+
+impl MetricNodeNetworkMinuteApiRepository for MetricNodeNetworkMinuteApiRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeNetworkEntity> { self.adapter.as_ref() }
+}
+
@@ -0,0 +1,3 @@
+pub mod metric_node_network_day_api_repository;
+pub mod metric_node_network_hour_api_repository;
+pub mod metric_node_network_minute_api_repository;
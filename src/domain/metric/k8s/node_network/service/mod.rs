@@ -0,0 +1,258 @@
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricObjectKind;
+use crate::core::persistence::metrics::counter::{increase, sum_increases};
+use crate::core::persistence::metrics::k8s::node_network::day::metric_node_network_day_api_repository_trait::MetricNodeNetworkDayApiRepository;
+use crate::core::persistence::metrics::k8s::node_network::hour::metric_node_network_hour_api_repository_trait::MetricNodeNetworkHourApiRepository;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::{
+    split_node_network_key, MetricNodeNetworkEntity,
+};
+use crate::core::persistence::metrics::k8s::node_network::minute::metric_node_network_minute_api_repository_trait::MetricNodeNetworkMinuteApiRepository;
+use crate::domain::info::service::info_unit_price_service;
+use crate::domain::metric::k8s::common::dto::{
+    CostMetricDto, MetricGetResponseDto, MetricGranularity, MetricScope, MetricSeriesDto, NetworkMetricDto,
+    UniversalMetricPointDto,
+};
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::{
+    MetricCostSummaryDto, MetricCostSummaryResponseDto,
+};
+use crate::domain::metric::k8s::common::service_helpers::{resolve_time_window, TimeWindow, BYTES_PER_GB};
+use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
+use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
+use crate::domain::metric::k8s::node_network::dto::metric_node_network_dto::{
+    MetricNodeNetworkGetResponseDto, NetworkPriceClass, NodeInterfaceUsageDto,
+};
+
+fn fetch_interface_rows(
+    repo: &K8sMetricRepositoryVariant,
+    interface_key: &str,
+    window: &TimeWindow,
+) -> Result<Vec<MetricNodeNetworkEntity>> {
+    match repo {
+        K8sMetricRepositoryVariant::NodeNetworkMinute(r) => {
+            r.get_row_between(window.start, window.end, interface_key, None, None)
+        }
+        K8sMetricRepositoryVariant::NodeNetworkHour(r) => {
+            r.get_row_between(window.start, window.end, interface_key, None, None)
+        }
+        K8sMetricRepositoryVariant::NodeNetworkDay(r) => {
+            r.get_row_between(window.start, window.end, interface_key, None, None)
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+fn metric_node_network_entity_to_point(entity: &MetricNodeNetworkEntity) -> UniversalMetricPointDto {
+    UniversalMetricPointDto {
+        time: entity.time,
+        network: Some(NetworkMetricDto {
+            rx_bytes: entity.rx_bytes.map(|v| v as f64),
+            tx_bytes: entity.tx_bytes.map(|v| v as f64),
+            rx_errors: entity.rx_errors.map(|v| v as f64),
+            tx_errors: entity.tx_errors.map(|v| v as f64),
+        }),
+        ..Default::default()
+    }
+}
+
+/// Minute rows hold the kubelet's cumulative counters; hour and day rows
+/// already hold the increase of their window.
+fn is_cumulative(granularity: &MetricGranularity) -> bool {
+    matches!(granularity, MetricGranularity::Minute)
+}
+
+/// Increase of one counter over the window.
+fn window_total(
+    rows: &[MetricNodeNetworkEntity],
+    cumulative: bool,
+    f: fn(&MetricNodeNetworkEntity) -> Option<u64>,
+) -> Option<u64> {
+    let values = rows.iter().filter_map(f);
+    if cumulative {
+        increase(values)
+    } else {
+        sum_increases(values)
+    }
+}
+
+fn interface_usage(
+    key: &str,
+    node_name: &str,
+    interface: &str,
+    rows: &[MetricNodeNetworkEntity],
+    cumulative: bool,
+) -> NodeInterfaceUsageDto {
+    NodeInterfaceUsageDto {
+        key: key.to_string(),
+        node_name: node_name.to_string(),
+        interface: interface.to_string(),
+        rx_bytes: window_total(rows, cumulative, |r| r.rx_bytes),
+        tx_bytes: window_total(rows, cumulative, |r| r.tx_bytes),
+        rx_errors: window_total(rows, cumulative, |r| r.rx_errors),
+        tx_errors: window_total(rows, cumulative, |r| r.tx_errors),
+        network_class: None,
+        cost_usd: None,
+    }
+}
+
+async fn build_node_network_raw_data(
+    state: &AppState,
+    q: RangeQuery,
+    target: Option<String>,
+) -> Result<MetricNodeNetworkGetResponseDto> {
+    let window = resolve_time_window(&q);
+    let repo = resolve_k8s_metric_repository(state.metrics.as_ref(), &MetricScope::NodeNetwork, &window.granularity);
+    let cumulative = is_cumulative(&window.granularity);
+
+    // Keys are matched against what is stored, so nothing from the request
+    // reaches a storage path.
+    let mut series = Vec::new();
+    let mut interfaces = Vec::new();
+    for key in state.metrics.keys(MetricObjectKind::NodeNetwork)? {
+        let Some((node_name, interface)) = split_node_network_key(&key) else { continue };
+        if target.as_deref().is_some_and(|t| t != node_name) {
+            continue;
+        }
+        if q.interface.as_deref().is_some_and(|i| i != interface) {
+            continue;
+        }
+
+        let rows = fetch_interface_rows(&repo, &key, &window)?;
+        interfaces.push(interface_usage(&key, node_name, interface, &rows, cumulative));
+        series.push(MetricSeriesDto {
+            key: key.clone(),
+            name: format!("{}/{}", node_name, interface),
+            scope: MetricScope::NodeNetwork,
+            points: rows.iter().map(metric_node_network_entity_to_point).collect(),
+        });
+    }
+
+    // Busiest egress first
+    interfaces.sort_by_key(|i| std::cmp::Reverse(i.tx_bytes));
+
+    Ok(MetricNodeNetworkGetResponseDto {
+        metrics: MetricGetResponseDto {
+            start: window.start,
+            end: window.end,
+            scope: "node_network".to_string(),
+            target,
+            granularity: window.granularity.clone(),
+            series,
+        },
+        interfaces,
+    })
+}
+
+/// Prices the transmitted bytes of every point at the tier of its interface:
+/// the first unit price `network_interface_classes` rule matching it, else
+/// `fallback_class`. Totals each interface.
+///
+/// Only egress is billed; cloud providers do not charge for ingress. Minute
+/// points are priced on the increase since the previous point.
+fn apply_node_network_costs(
+    response: &mut MetricNodeNetworkGetResponseDto,
+    unit_prices: &InfoUnitPriceEntity,
+    fallback_class: NetworkPriceClass,
+) {
+    let cumulative = is_cumulative(&response.metrics.granularity);
+
+    for series in &mut response.metrics.series {
+        let interface = split_node_network_key(&series.key).map_or("", |(_, interface)| interface);
+        let class = unit_prices.network_class_of(interface, fallback_class);
+        let price_per_gb = class.price_per_gb(unit_prices);
+
+        let mut series_cost = 0.0;
+        let mut prev_tx: Option<u64> = None;
+        for point in &mut series.points {
+            let tx = point.network.as_ref().and_then(|n| n.tx_bytes).map(|v| v as u64);
+            let egress_bytes = if cumulative {
+                let delta = prev_tx.zip(tx).and_then(|(prev, cur)| increase([prev, cur]));
+                prev_tx = tx.or(prev_tx);
+                delta
+            } else {
+                tx
+            };
+
+            let network_cost_usd = egress_bytes.map(|bytes| bytes as f64 / BYTES_PER_GB * price_per_gb);
+            series_cost += network_cost_usd.unwrap_or(0.0);
+
+            point.cost = Some(CostMetricDto {
+                total_cost_usd: Some(network_cost_usd.unwrap_or(0.0)),
+                network_cost_usd,
+                ..Default::default()
+            });
+        }
+
+        if let Some(usage) = response.interfaces.iter_mut().find(|i| i.key == series.key) {
+            usage.network_class = Some(class);
+            usage.cost_usd = Some(series_cost);
+        }
+    }
+}
+
+fn build_node_network_cost_summary_dto(response: &MetricNodeNetworkGetResponseDto) -> MetricCostSummaryResponseDto {
+    let total: f64 = response.interfaces.iter().filter_map(|i| i.cost_usd).sum();
+
+    MetricCostSummaryResponseDto {
+        start: response.metrics.start,
+        end: response.metrics.end,
+        scope: MetricScope::NodeNetwork,
+        target: response.metrics.target.clone(),
+        granularity: response.metrics.granularity.clone(),
+        summary: MetricCostSummaryDto {
+            total_cost_usd: total,
+            network_cost_usd: total,
+            ..Default::default()
+        },
+    }
+}
+
+async fn build_node_network_cost_response(
+    state: &AppState,
+    q: RangeQuery,
+    target: Option<String>,
+) -> Result<MetricNodeNetworkGetResponseDto> {
+    let unit_prices: InfoUnitPriceEntity = info_unit_price_service::get_info_unit_prices(state).await?;
+    let fallback_class = q.network_class.unwrap_or_default();
+    let mut response = build_node_network_raw_data(state, q, target).await?;
+    apply_node_network_costs(&mut response, &unit_prices, fallback_class);
+    Ok(response)
+}
+
+pub async fn get_metric_k8s_nodes_network_raw(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let response = build_node_network_raw_data(state, q, None).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_node_network_raw(state: &AppState, node_name: String, q: RangeQuery) -> Result<Value> {
+    let response = build_node_network_raw_data(state, q, Some(node_name)).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_nodes_network_cost(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let response = build_node_network_cost_response(state, q, None).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_nodes_network_cost_summary(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let response = build_node_network_cost_response(state, q, None).await?;
+    Ok(serde_json::to_value(build_node_network_cost_summary_dto(&response))?)
+}
+
+pub async fn get_metric_k8s_node_network_cost(state: &AppState, node_name: String, q: RangeQuery) -> Result<Value> {
+    let response = build_node_network_cost_response(state, q, Some(node_name)).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_node_network_cost_summary(
+    state: &AppState,
+    node_name: String,
+    q: RangeQuery,
+) -> Result<Value> {
+    let response = build_node_network_cost_response(state, q, Some(node_name)).await?;
+    Ok(serde_json::to_value(build_node_network_cost_summary_dto(&response))?)
+}
@@ -0,0 +1,23 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_system::hour::metric_node_system_hour_api_repository_trait::MetricNodeSystemHourApiRepository;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+pub struct MetricNodeSystemHourApiRepositoryImpl { pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeSystemEntity>> }
+
+impl MetricNodeSystemHourApiRepository for MetricNodeSystemHourApiRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeSystemEntity> { self.adapter.as_ref() }
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        system_key: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> anyhow::Result<Vec<MetricNodeSystemEntity>> {
+        self.fs_adapter()
+            .get_row_between(start, end, system_key, limit, offset)
+    }
+}
+
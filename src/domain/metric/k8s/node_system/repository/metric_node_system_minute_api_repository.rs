@@ -0,0 +1,11 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_system::minute::metric_node_system_minute_api_repository_trait::MetricNodeSystemMinuteApiRepository;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use std::sync::Arc;
+
+pub struct MetricNodeSystemMinuteApiRepositoryImpl { pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeSystemEntity>> }
+
+impl MetricNodeSystemMinuteApiRepository for MetricNodeSystemMinuteApiRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeSystemEntity> { self.adapter.as_ref() }
+}
+
@@ -0,0 +1,3 @@
+pub mod metric_node_system_day_api_repository;
+pub mod metric_node_system_hour_api_repository;
+pub mod metric_node_system_minute_api_repository;
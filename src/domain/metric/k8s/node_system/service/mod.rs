@@ -0,0 +1,229 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricObjectKind;
+use crate::core::persistence::metrics::k8s::node::day::metric_node_day_api_repository_trait::MetricNodeDayApiRepository;
+use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_api_repository_trait::MetricNodeHourApiRepository;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_api_repository_trait::MetricNodeMinuteApiRepository;
+use crate::core::persistence::metrics::k8s::node_system::day::metric_node_system_day_api_repository_trait::MetricNodeSystemDayApiRepository;
+use crate::core::persistence::metrics::k8s::node_system::hour::metric_node_system_hour_api_repository_trait::MetricNodeSystemHourApiRepository;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::{
+    split_node_system_key, MetricNodeSystemEntity, PODS_SYSTEM_CONTAINER,
+};
+use crate::core::persistence::metrics::k8s::node_system::minute::metric_node_system_minute_api_repository_trait::MetricNodeSystemMinuteApiRepository;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::NodeCostSplitDto;
+use crate::domain::metric::k8s::common::dto::{
+    MetricGetResponseDto, MetricGranularity, MetricScope, SystemOverheadMetricDto,
+};
+use crate::domain::metric::k8s::common::service_helpers::{resolve_time_window, TimeWindow, BYTES_PER_GB};
+use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
+use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
+
+/// Keys of the system containers of `node_name` that count as overhead.
+/// The pods cgroup is left out; its usage belongs to the workloads.
+fn overhead_keys(state: &AppState, node_name: &str) -> Result<Vec<String>> {
+    Ok(state
+        .metrics
+        .keys(MetricObjectKind::NodeSystem)?
+        .into_iter()
+        .filter(|key| {
+            split_node_system_key(key)
+                .is_some_and(|(node, container)| node == node_name && container != PODS_SYSTEM_CONTAINER)
+        })
+        .collect())
+}
+
+fn fetch_system_rows(
+    repo: &K8sMetricRepositoryVariant,
+    system_key: &str,
+    window: &TimeWindow,
+) -> Result<Vec<MetricNodeSystemEntity>> {
+    match repo {
+        K8sMetricRepositoryVariant::NodeSystemMinute(r) => {
+            r.get_row_between(window.start, window.end, system_key, None, None)
+        }
+        K8sMetricRepositoryVariant::NodeSystemHour(r) => {
+            r.get_row_between(window.start, window.end, system_key, None, None)
+        }
+        K8sMetricRepositoryVariant::NodeSystemDay(r) => {
+            r.get_row_between(window.start, window.end, system_key, None, None)
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+/// How far a system sample may be from a node sample and still be joined.
+///
+/// Minute samples are stamped by the kubelet per cgroup and drift a little
+/// from the node row; hour and day rows share their window end exactly.
+fn join_tolerance(granularity: &MetricGranularity) -> Duration {
+    match granularity {
+        MetricGranularity::Minute => Duration::seconds(30),
+        _ => Duration::zero(),
+    }
+}
+
+/// Rows of one system container, sorted by time.
+struct SystemSeries(Vec<MetricNodeSystemEntity>);
+
+impl SystemSeries {
+    /// Row closest to `time`, if one lies within `tolerance`.
+    fn nearest(&self, time: DateTime<Utc>, tolerance: Duration) -> Option<&MetricNodeSystemEntity> {
+        let idx = self.0.partition_point(|r| r.time < time);
+        [idx.checked_sub(1), Some(idx)]
+            .into_iter()
+            .flatten()
+            .filter_map(|i| self.0.get(i))
+            .filter(|r| (r.time - time).abs() <= tolerance)
+            .min_by_key(|r| (r.time - time).abs())
+    }
+}
+
+/// Overhead of all system containers of one node at `time`, or `None` when
+/// none of them has a sample close enough.
+fn overhead_at(series: &[SystemSeries], time: DateTime<Utc>, tolerance: Duration) -> Option<SystemOverheadMetricDto> {
+    let rows: Vec<&MetricNodeSystemEntity> = series.iter().filter_map(|s| s.nearest(time, tolerance)).collect();
+    if rows.is_empty() {
+        return None;
+    }
+
+    let sum = |f: fn(&MetricNodeSystemEntity) -> Option<u64>| -> Option<f64> {
+        rows.iter()
+            .filter_map(|r| f(r))
+            .fold(None, |acc, v| Some(acc.unwrap_or(0.0) + v as f64))
+    };
+
+    Some(SystemOverheadMetricDto {
+        cpu_usage_nano_cores: sum(|r| r.cpu_usage_nano_cores),
+        memory_usage_bytes: sum(|r| r.memory_usage_bytes),
+        memory_working_set_bytes: sum(|r| r.memory_working_set_bytes),
+        cost_usd: None,
+    })
+}
+
+fn load_overhead_series(
+    state: &AppState,
+    repo: &K8sMetricRepositoryVariant,
+    node_name: &str,
+    window: &TimeWindow,
+) -> Result<Vec<SystemSeries>> {
+    let mut series = Vec::new();
+    for key in overhead_keys(state, node_name)? {
+        let mut rows = fetch_system_rows(repo, &key, window)?;
+        rows.sort_by_key(|r| r.time);
+        series.push(SystemSeries(rows));
+    }
+    Ok(series)
+}
+
+/// Per-second cost of CPU and memory usage, priced like `apply_costs`.
+fn usage_cost(nano_cores: Option<f64>, memory_bytes: Option<f64>, unit_prices: &InfoUnitPriceEntity) -> f64 {
+    let cpu = nano_cores.unwrap_or(0.0) / 1_000_000_000.0 * (unit_prices.cpu_core_hour / 3600.0);
+    let memory = memory_bytes.unwrap_or(0.0) / BYTES_PER_GB * (unit_prices.memory_gb_hour / 3600.0);
+    cpu + memory
+}
+
+/// Adds the system overhead to every point of a node response.
+///
+/// Series are keyed by node name, as built by the node service.
+pub fn attach_system_overhead(state: &AppState, response: &mut MetricGetResponseDto) -> Result<()> {
+    let window = TimeWindow {
+        start: response.start,
+        end: response.end,
+        granularity: response.granularity.clone(),
+    };
+    let repo = resolve_k8s_metric_repository(state.metrics.as_ref(), &MetricScope::NodeSystem, &window.granularity);
+    let tolerance = join_tolerance(&window.granularity);
+
+    for series in &mut response.series {
+        let system_series = load_overhead_series(state, &repo, &series.key, &window)?;
+        if system_series.is_empty() {
+            continue;
+        }
+        for point in &mut series.points {
+            point.system_overhead = overhead_at(&system_series, point.time, tolerance);
+        }
+    }
+    Ok(())
+}
+
+/// Prices the system overhead of every point of a node response.
+pub fn apply_system_overhead_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPriceEntity) {
+    for series in &mut response.series {
+        for point in &mut series.points {
+            if let Some(overhead) = point.system_overhead.as_mut() {
+                overhead.cost_usd = Some(usage_cost(
+                    overhead.cpu_usage_nano_cores,
+                    overhead.memory_usage_bytes,
+                    unit_prices,
+                ));
+            }
+        }
+    }
+}
+
+fn fetch_node_rows(
+    repo: &K8sMetricRepositoryVariant,
+    node_name: &str,
+    window: &TimeWindow,
+) -> Result<Vec<MetricNodeEntity>> {
+    match repo {
+        K8sMetricRepositoryVariant::NodeMinute(r) => r.get_row_between(node_name, window.start, window.end),
+        K8sMetricRepositoryVariant::NodeHour(r) => r.get_row_between(node_name, window.start, window.end),
+        K8sMetricRepositoryVariant::NodeDay(r) => r.get_row_between(node_name, window.start, window.end),
+        _ => Ok(vec![]),
+    }
+}
+
+/// Splits the CPU and memory cost of `nodes` into system, workload and idle.
+///
+/// Every node sample is priced the way `apply_costs` prices usage: its full
+/// capacity, its measured usage, and the overhead of its system containers.
+/// The workload share is usage minus overhead, idle is capacity minus usage.
+pub fn build_node_cost_split(
+    state: &AppState,
+    nodes: &[InfoNodeEntity],
+    unit_prices: &InfoUnitPriceEntity,
+    q: &RangeQuery,
+) -> Result<NodeCostSplitDto> {
+    let window = resolve_time_window(q);
+    let node_repo = resolve_k8s_metric_repository(state.metrics.as_ref(), &MetricScope::Node, &window.granularity);
+    let system_repo =
+        resolve_k8s_metric_repository(state.metrics.as_ref(), &MetricScope::NodeSystem, &window.granularity);
+    let tolerance = join_tolerance(&window.granularity);
+
+    let mut split = NodeCostSplitDto::default();
+    for node in nodes {
+        let Some(node_name) = node.node_name.as_deref() else { continue };
+
+        let capacity_cost = usage_cost(
+            node.cpu_capacity_cores.map(|c| c as f64 * 1_000_000_000.0),
+            node.memory_capacity_bytes.map(|b| b as f64),
+            unit_prices,
+        );
+        let system_series = load_overhead_series(state, &system_repo, node_name, &window)?;
+
+        for row in fetch_node_rows(&node_repo, node_name, &window)? {
+            let used = usage_cost(
+                row.cpu_usage_nano_cores.map(|v| v as f64),
+                row.memory_usage_bytes.map(|v| v as f64),
+                unit_prices,
+            );
+            let system = overhead_at(&system_series, row.time, tolerance)
+                .map(|o| usage_cost(o.cpu_usage_nano_cores, o.memory_usage_bytes, unit_prices))
+                .unwrap_or(0.0)
+                .min(used);
+
+            split.capacity_cost_usd += capacity_cost;
+            split.system_cost_usd += system;
+            split.workload_cost_usd += used - system;
+            split.idle_cost_usd += (capacity_cost - used).max(0.0);
+        }
+    }
+    Ok(split)
+}
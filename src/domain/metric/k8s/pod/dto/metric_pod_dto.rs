@@ -23,6 +23,14 @@ pub struct MetricPodDto {
     pub pv_capacity_bytes: Option<u64>,
     pub pv_inodes_used: Option<u64>,
     pub pv_inodes: Option<u64>,
+    pub memory_available_bytes: Option<u64>,
+    pub memory_major_page_faults: Option<u64>,
+    pub swap_usage_bytes: Option<u64>,
+    pub swap_available_bytes: Option<u64>,
+    pub process_count: Option<u64>,
+    pub cpu_cfs_periods: Option<u64>,
+    pub cpu_cfs_throttled_periods: Option<u64>,
+    pub cpu_cfs_throttled_nano_seconds: Option<u64>,
 }
 
 impl From<MetricPodEntity> for MetricPodDto {
@@ -47,6 +55,14 @@ impl From<MetricPodEntity> for MetricPodDto {
             pv_capacity_bytes: e.pv_capacity_bytes,
             pv_inodes_used: e.pv_inodes_used,
             pv_inodes: e.pv_inodes,
+            memory_available_bytes: e.memory_available_bytes,
+            memory_major_page_faults: e.memory_major_page_faults,
+            swap_usage_bytes: e.swap_usage_bytes,
+            swap_available_bytes: e.swap_available_bytes,
+            process_count: e.process_count,
+            cpu_cfs_periods: e.cpu_cfs_periods,
+            cpu_cfs_throttled_periods: e.cpu_cfs_throttled_periods,
+            cpu_cfs_throttled_nano_seconds: e.cpu_cfs_throttled_nano_seconds,
         }
     }
 }
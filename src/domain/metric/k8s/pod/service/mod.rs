@@ -3,6 +3,7 @@ use serde_json::Value;
 use std::collections::HashSet;
 
 use crate::api::dto::{info_dto::K8sListQuery, metrics_dto::RangeQuery};
+use crate::app_state::AppState;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
@@ -14,8 +15,9 @@ use crate::domain::info::service::{
     info_k8s_container_service, info_k8s_pod_service, info_unit_price_service,
 };
 use crate::domain::metric::k8s::common::dto::{
-    CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto,
-    NetworkMetricDto, StorageMetricDto, UniversalMetricPointDto,
+    CommonMetricValuesDto, FilesystemMetricDto, GpuMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto,
+    NetworkMetricDto, ProcessMetricDto, RollupStatsDto, StorageMetricDto, SwapMetricDto,
+    ThrottlingMetricDto, UniversalMetricPointDto,
 };
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
 use crate::domain::metric::k8s::common::service_helpers::{
@@ -66,6 +68,8 @@ fn metric_pod_entity_to_point(entity: MetricPodEntity) -> UniversalMetricPointDt
             memory_working_set_bytes: entity.memory_working_set_bytes.map(|v| v as f64),
             memory_rss_bytes: entity.memory_rss_bytes.map(|v| v as f64),
             memory_page_faults: entity.memory_page_faults.map(|v| v as f64),
+            memory_available_bytes: entity.memory_available_bytes.map(|v| v as f64),
+            memory_major_page_faults: entity.memory_major_page_faults.map(|v| v as f64),
         },
         filesystem: Some(ephemeral_fs.clone()),
         storage: Some(StorageMetricDto {
@@ -78,18 +82,34 @@ fn metric_pod_entity_to_point(entity: MetricPodEntity) -> UniversalMetricPointDt
             rx_errors: entity.network_physical_rx_errors.map(|v| v as f64),
             tx_errors: entity.network_physical_tx_errors.map(|v| v as f64),
         }),
+        swap: SwapMetricDto::from_values(entity.swap_usage_bytes, entity.swap_available_bytes),
+        processes: ProcessMetricDto::from_values(entity.process_count, None),
+        throttling: ThrottlingMetricDto::from_values(
+            entity.cpu_cfs_periods,
+            entity.cpu_cfs_throttled_periods,
+            entity.cpu_cfs_throttled_nano_seconds,
+        ),
+        gpu: GpuMetricDto::from_values(
+            entity.gpu_milli_count,
+            entity.gpu_utilization_percent,
+            entity.gpu_memory_used_bytes,
+            entity.gpu_memory_total_bytes,
+            entity.gpu_power_milliwatts,
+        ),
+        stats: RollupStatsDto::from_stats(entity.cpu_stats(), entity.memory_working_set_stats()),
         ..Default::default()
     }
 }
 
 async fn build_pod_raw_data(
+    state: &AppState,
     q: RangeQuery,
     target_pod_uid: Option<String>,
 ) -> Result<(MetricGetResponseDto, Vec<InfoPodEntity>)> {
     let pod_infos = if let Some(pod_uid) = target_pod_uid.clone() {
-        vec![info_k8s_pod_service::get_info_k8s_pod(pod_uid).await?]
+        vec![info_k8s_pod_service::get_info_k8s_pod(state, pod_uid).await?]
     } else {
-        info_k8s_pod_service::list_k8s_pods(K8sListQuery {
+        info_k8s_pod_service::list_k8s_pods(state, K8sListQuery {
             namespace: q.namespace.clone(),
             label_selector: None,
             node_name: None,
@@ -97,17 +117,18 @@ async fn build_pod_raw_data(
         .await?
     };
 
-    let response = build_pod_series_for_infos(&q, &pod_infos, target_pod_uid.clone())?;
+    let response = build_pod_series_for_infos(state, &q, &pod_infos, target_pod_uid.clone())?;
     Ok((response, pod_infos))
 }
 
 fn build_pod_series_for_infos(
+    state: &AppState,
     q: &RangeQuery,
     pod_infos: &[InfoPodEntity],
     target: Option<String>,
 ) -> Result<MetricGetResponseDto> {
     let window = resolve_time_window(q);
-    let repo = resolve_k8s_metric_repository(&MetricScope::Pod, &window.granularity);
+    let repo = resolve_k8s_metric_repository(state.metrics.as_ref(), &MetricScope::Pod, &window.granularity);
 
     let mut series = Vec::new();
     for pod in pod_infos.iter() {
@@ -141,11 +162,12 @@ fn build_pod_series_for_infos(
 }
 
 pub(crate) fn build_pod_response_from_infos(
+    state: &AppState,
     q: RangeQuery,
     pod_infos: Vec<InfoPodEntity>,
     target: Option<String>,
 ) -> Result<MetricGetResponseDto> {
-    build_pod_series_for_infos(&q, &pod_infos, target)
+    build_pod_series_for_infos(state, &q, &pod_infos, target)
 }
 
 fn collect_pod_uids(pods: &[InfoPodEntity]) -> Vec<String> {
@@ -187,27 +209,28 @@ fn sum_container_requests(
 }
 
 async fn build_pod_cost_response(
+    state: &AppState,
     q: RangeQuery,
     target: Option<String>,
     unit_prices: InfoUnitPriceEntity,
 ) -> Result<MetricGetResponseDto> {
-    let (mut response, _) = build_pod_raw_data(q, target).await?;
+    let (mut response, _) = build_pod_raw_data(state, q, target).await?;
     apply_costs(&mut response, &unit_prices);
     Ok(response)
 }
 
-pub async fn get_metric_k8s_pods_raw(q: RangeQuery) -> Result<Value> {
-    let (response, _) = build_pod_raw_data(q, None).await?;
+pub async fn get_metric_k8s_pods_raw(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let (response, _) = build_pod_raw_data(state, q, None).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_pods_raw_summary(q: RangeQuery) -> Result<Value> {
-    let (response, pod_infos) = build_pod_raw_data(q, None).await?;
+pub async fn get_metric_k8s_pods_raw_summary(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let (response, pod_infos) = build_pod_raw_data(state, q, None).await?;
     build_raw_summary_value(&response, MetricScope::Pod, pod_infos.len())
 }
 
-pub async fn get_metric_k8s_pods_raw_efficiency(q: RangeQuery) -> Result<Value> {
-    let (response, pod_infos) = build_pod_raw_data(q.clone(), None).await?;
+pub async fn get_metric_k8s_pods_raw_efficiency(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let (response, pod_infos) = build_pod_raw_data(state, q.clone(), None).await?;
     let summary_value = build_raw_summary_value(&response, MetricScope::Pod, pod_infos.len())?;
     let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
 
@@ -217,7 +240,7 @@ pub async fn get_metric_k8s_pods_raw_efficiency(q: RangeQuery) -> Result<Value>
     }
 
     let namespace_hint = q.namespace.or_else(|| derive_namespace_hint(&pod_infos));
-    let containers = info_k8s_container_service::list_k8s_containers(K8sListQuery {
+    let containers = info_k8s_container_service::list_k8s_containers(state, K8sListQuery {
         namespace: namespace_hint,
         label_selector: None,
         node_name: None,
@@ -237,18 +260,18 @@ pub async fn get_metric_k8s_pods_raw_efficiency(q: RangeQuery) -> Result<Value>
     )
 }
 
-pub async fn get_metric_k8s_pod_raw(pod_uid: String, q: RangeQuery) -> Result<Value> {
-    let (response, _) = build_pod_raw_data(q, Some(pod_uid)).await?;
+pub async fn get_metric_k8s_pod_raw(state: &AppState, pod_uid: String, q: RangeQuery) -> Result<Value> {
+    let (response, _) = build_pod_raw_data(state, q, Some(pod_uid)).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_pod_raw_summary(pod_uid: String, q: RangeQuery) -> Result<Value> {
-    let (response, _) = build_pod_raw_data(q, Some(pod_uid)).await?;
+pub async fn get_metric_k8s_pod_raw_summary(state: &AppState, pod_uid: String, q: RangeQuery) -> Result<Value> {
+    let (response, _) = build_pod_raw_data(state, q, Some(pod_uid)).await?;
     build_raw_summary_value(&response, MetricScope::Pod, 1)
 }
 
-pub async fn get_metric_k8s_pod_raw_efficiency(pod_uid: String, q: RangeQuery) -> Result<Value> {
-    let (response, pod_infos) = build_pod_raw_data(q.clone(), Some(pod_uid.clone())).await?;
+pub async fn get_metric_k8s_pod_raw_efficiency(state: &AppState, pod_uid: String, q: RangeQuery) -> Result<Value> {
+    let (response, pod_infos) = build_pod_raw_data(state, q.clone(), Some(pod_uid.clone())).await?;
     let summary_value = build_raw_summary_value(&response, MetricScope::Pod, 1)?;
     let summary: MetricRawSummaryResponseDto = serde_json::from_value(summary_value)?;
 
@@ -257,7 +280,7 @@ pub async fn get_metric_k8s_pod_raw_efficiency(pod_uid: String, q: RangeQuery) -
         .and_then(|p| p.namespace.clone())
         .or(q.namespace);
 
-    let containers = info_k8s_container_service::list_k8s_containers(K8sListQuery {
+    let containers = info_k8s_container_service::list_k8s_containers(state, K8sListQuery {
         namespace: namespace_hint,
         label_selector: None,
         node_name: None,
@@ -278,43 +301,43 @@ pub async fn get_metric_k8s_pod_raw_efficiency(pod_uid: String, q: RangeQuery) -
     )
 }
 
-pub async fn get_metric_k8s_pods_cost(q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_pod_cost_response(q, None, unit_prices).await?;
+pub async fn get_metric_k8s_pods_cost(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_pod_cost_response(state, q, None, unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_pods_cost_summary(q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_pod_cost_response(q, None, unit_prices.clone()).await?;
+pub async fn get_metric_k8s_pods_cost_summary(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_pod_cost_response(state, q, None, unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Pod, None, &unit_prices);
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_pods_cost_trend(q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_pod_cost_response(q, None, unit_prices).await?;
+pub async fn get_metric_k8s_pods_cost_trend(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_pod_cost_response(state, q, None, unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Pod, None)?;
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_pod_cost(pod_uid: String, q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_pod_cost_response(q, Some(pod_uid.clone()), unit_prices).await?;
+pub async fn get_metric_k8s_pod_cost(state: &AppState, pod_uid: String, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_pod_cost_response(state, q, Some(pod_uid.clone()), unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
 
-pub async fn get_metric_k8s_pod_cost_summary(pod_uid: String, q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+pub async fn get_metric_k8s_pod_cost_summary(state: &AppState, pod_uid: String, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
     let response =
-        build_pod_cost_response(q, Some(pod_uid.clone()), unit_prices.clone()).await?;
+        build_pod_cost_response(state, q, Some(pod_uid.clone()), unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Pod, Some(pod_uid), &unit_prices);
     Ok(serde_json::to_value(dto)?)
 }
 
-pub async fn get_metric_k8s_pod_cost_trend(pod_uid: String, q: RangeQuery) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-    let response = build_pod_cost_response(q, Some(pod_uid.clone()), unit_prices).await?;
+pub async fn get_metric_k8s_pod_cost_trend(state: &AppState, pod_uid: String, q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let response = build_pod_cost_response(state, q, Some(pod_uid.clone()), unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Pod, Some(pod_uid))?;
     Ok(serde_json::to_value(dto)?)
 }
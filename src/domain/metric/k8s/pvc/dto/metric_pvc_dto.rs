@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::metric::k8s::common::dto::MetricGetResponseDto;
+
+/// Metric series of PVCs plus a per-claim usage table, fullest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPvcGetResponseDto {
+    #[serde(flatten)]
+    pub metrics: MetricGetResponseDto,
+    pub pvcs: Vec<PvcUsageDto>,
+}
+
+/// Latest usage of one PVC in the window, joined with the claim's metadata.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PvcUsageDto {
+    /// Storage key, `<namespace>_<name>`
+    pub key: String,
+    pub namespace: String,
+    pub name: String,
+
+    // Claim metadata; absent when the claim is gone from the API server
+    pub storage_class: Option<String>,
+    pub volume_name: Option<String>,
+    pub phase: Option<String>,
+    /// `spec.resources.requests.storage`
+    pub requested_bytes: Option<u64>,
+
+    /// Filesystem size reported by the kubelet, else `status.capacity.storage`
+    pub capacity_bytes: Option<u64>,
+    pub used_bytes: Option<u64>,
+    pub available_bytes: Option<u64>,
+    /// `used_bytes / capacity_bytes`, 0.0–1.0
+    pub usage_ratio: Option<f64>,
+
+    /// Storage cost of the window; cost endpoints only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+}
@@ -0,0 +1,23 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::pvc::hour::metric_pvc_hour_api_repository_trait::MetricPvcHourApiRepository;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+pub struct MetricPvcHourApiRepositoryImpl { pub adapter: Arc<dyn MetricFsAdapterBase<MetricPvcEntity>> }
+
+impl MetricPvcHourApiRepository for MetricPvcHourApiRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPvcEntity> { self.adapter.as_ref() }
+    fn get_row_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        pvc_key: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> anyhow::Result<Vec<MetricPvcEntity>> {
+        self.fs_adapter()
+            .get_row_between(start, end, pvc_key, limit, offset)
+    }
+}
+
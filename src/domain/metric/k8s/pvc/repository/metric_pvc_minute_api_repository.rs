@@ -0,0 +1,11 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::pvc::minute::metric_pvc_minute_api_repository_trait::MetricPvcMinuteApiRepository;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use std::sync::Arc;
+
+pub struct MetricPvcMinuteApiRepositoryImpl { pub adapter: Arc<dyn MetricFsAdapterBase<MetricPvcEntity>> }
+
+impl MetricPvcMinuteApiRepository for MetricPvcMinuteApiRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPvcEntity> { self.adapter.as_ref() }
+}
+
@@ -0,0 +1,3 @@
+pub mod metric_pvc_day_api_repository;
+pub mod metric_pvc_hour_api_repository;
+pub mod metric_pvc_minute_api_repository;
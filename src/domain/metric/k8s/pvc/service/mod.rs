@@ -0,0 +1,313 @@
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::app_state::AppState;
+use crate::core::client::k8s::client_k8s_container_mapper::parse_memory_bytes;
+use crate::core::client::k8s::k8s_client_trait::K8sResourceKind;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::metrics::k8s::pvc::day::metric_pvc_day_api_repository_trait::MetricPvcDayApiRepository;
+use crate::core::persistence::metrics::k8s::pvc::hour::metric_pvc_hour_api_repository_trait::MetricPvcHourApiRepository;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::{pvc_metric_key, MetricPvcEntity};
+use crate::core::persistence::metrics::k8s::pvc::minute::metric_pvc_minute_api_repository_trait::MetricPvcMinuteApiRepository;
+use crate::domain::info::service::info_unit_price_service;
+use crate::domain::metric::k8s::common::dto::{
+    CostMetricDto, FilesystemMetricDto, GaugeStatsDto, MetricGetResponseDto, MetricScope, MetricSeriesDto,
+    RollupStatsDto, StorageMetricDto, UniversalMetricPointDto,
+};
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::{
+    MetricCostSummaryDto, MetricCostSummaryResponseDto,
+};
+use crate::domain::metric::k8s::common::service_helpers::{
+    build_cost_trend_dto, resolve_time_window, TimeWindow, BYTES_PER_GB,
+};
+use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
+use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
+use crate::domain::metric::k8s::pvc::dto::metric_pvc_dto::{MetricPvcGetResponseDto, PvcUsageDto};
+
+/// Claim metadata taken from the API server's PVC list.
+struct PvcClaim {
+    namespace: String,
+    name: String,
+    storage_class: Option<String>,
+    volume_name: Option<String>,
+    phase: Option<String>,
+    requested_bytes: Option<u64>,
+    capacity_bytes: Option<u64>,
+}
+
+fn parse_claims(list: &Value) -> Vec<PvcClaim> {
+    list["items"]
+        .as_array()
+        .map(|items| items.iter().filter_map(parse_claim).collect())
+        .unwrap_or_default()
+}
+
+fn parse_claim(item: &Value) -> Option<PvcClaim> {
+    let str_at = |ptr: &str| item.pointer(ptr).and_then(Value::as_str).map(str::to_string);
+    let bytes_at = |ptr: &str| item.pointer(ptr).and_then(Value::as_str).and_then(parse_memory_bytes);
+
+    Some(PvcClaim {
+        namespace: str_at("/metadata/namespace")?,
+        name: str_at("/metadata/name")?,
+        storage_class: str_at("/spec/storageClassName"),
+        volume_name: str_at("/spec/volumeName"),
+        phase: str_at("/status/phase"),
+        requested_bytes: bytes_at("/spec/resources/requests/storage"),
+        capacity_bytes: bytes_at("/status/capacity/storage"),
+    })
+}
+
+/// Namespace and claim names end up in a storage path; only accept what
+/// Kubernetes accepts.
+fn validate_name(kind: &str, value: &str) -> Result<()> {
+    let valid = !value.is_empty()
+        && value.len() <= 253
+        && value != "."
+        && value != ".."
+        && value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.');
+    if valid {
+        Ok(())
+    } else {
+        Err(anyhow!("Invalid {} name '{}'", kind, value))
+    }
+}
+
+fn fetch_pvc_rows(
+    repo: &K8sMetricRepositoryVariant,
+    pvc_key: &str,
+    window: &TimeWindow,
+) -> Result<Vec<MetricPvcEntity>> {
+    match repo {
+        K8sMetricRepositoryVariant::PvcMinute(r) => r.get_row_between(window.start, window.end, pvc_key, None, None),
+        K8sMetricRepositoryVariant::PvcHour(r) => r.get_row_between(window.start, window.end, pvc_key, None, None),
+        K8sMetricRepositoryVariant::PvcDay(r) => r.get_row_between(window.start, window.end, pvc_key, None, None),
+        _ => Ok(vec![]),
+    }
+}
+
+fn metric_pvc_entity_to_point(entity: &MetricPvcEntity) -> UniversalMetricPointDto {
+    UniversalMetricPointDto {
+        time: entity.time,
+        storage: Some(StorageMetricDto {
+            ephemeral: None,
+            persistent: Some(FilesystemMetricDto {
+                used_bytes: entity.used_bytes.map(|v| v as f64),
+                capacity_bytes: entity.capacity_bytes.map(|v| v as f64),
+                inodes_used: entity.inodes_used.map(|v| v as f64),
+                inodes: entity.inodes.map(|v| v as f64),
+            }),
+        }),
+        stats: GaugeStatsDto::from_stats(entity.used_stats()).map(|used| RollupStatsDto {
+            persistent_used_bytes: Some(used),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Usage at the end of the window. The kubelet's capacity is the real
+/// filesystem size and wins over the claim's status.
+fn pvc_usage(key: &str, namespace: &str, name: &str, claim: Option<&PvcClaim>, rows: &[MetricPvcEntity]) -> PvcUsageDto {
+    let last = rows.last();
+    let capacity_bytes = last
+        .and_then(|r| r.capacity_bytes)
+        .filter(|c| *c > 0)
+        .or(claim.and_then(|c| c.capacity_bytes));
+    let used_bytes = last.and_then(|r| r.used_bytes);
+    let usage_ratio = match (used_bytes, capacity_bytes) {
+        (Some(used), Some(capacity)) if capacity > 0 => Some(used as f64 / capacity as f64),
+        _ => None,
+    };
+
+    PvcUsageDto {
+        key: key.to_string(),
+        namespace: namespace.to_string(),
+        name: name.to_string(),
+        storage_class: claim.and_then(|c| c.storage_class.clone()),
+        volume_name: claim.and_then(|c| c.volume_name.clone()),
+        phase: claim.and_then(|c| c.phase.clone()),
+        requested_bytes: claim.and_then(|c| c.requested_bytes),
+        capacity_bytes,
+        used_bytes,
+        available_bytes: last.and_then(|r| r.available_bytes),
+        usage_ratio,
+        cost_usd: None,
+    }
+}
+
+async fn build_pvc_raw_data(
+    state: &AppState,
+    q: RangeQuery,
+    target: Option<(String, String)>,
+) -> Result<MetricPvcGetResponseDto> {
+    if let Some((namespace, name)) = &target {
+        validate_name("namespace", namespace)?;
+        validate_name("PVC", name)?;
+    }
+
+    let window = resolve_time_window(&q);
+    let repo = resolve_k8s_metric_repository(state.metrics.as_ref(), &MetricScope::Pvc, &window.granularity);
+
+    let claims = match state.k8s.resources(K8sResourceKind::PersistentVolumeClaim).await {
+        Ok(list) => parse_claims(&list),
+        // A single claim is still served from its stored metrics, without metadata
+        Err(e) if target.is_some() => {
+            warn!("Failed to list PVCs, returning metrics without metadata: {}", e);
+            Vec::new()
+        }
+        Err(e) => return Err(e),
+    };
+
+    let targets: Vec<(String, String, Option<&PvcClaim>)> = match &target {
+        Some((namespace, name)) => {
+            let claim = claims.iter().find(|c| &c.namespace == namespace && &c.name == name);
+            vec![(namespace.clone(), name.clone(), claim)]
+        }
+        None => claims
+            .iter()
+            .filter(|c| q.namespace.as_ref().is_none_or(|ns| &c.namespace == ns))
+            .map(|c| (c.namespace.clone(), c.name.clone(), Some(c)))
+            .collect(),
+    };
+
+    let mut series = Vec::new();
+    let mut pvcs = Vec::new();
+    for (namespace, name, claim) in targets {
+        let key = pvc_metric_key(&namespace, &name);
+        let rows = fetch_pvc_rows(&repo, &key, &window)?;
+
+        pvcs.push(pvc_usage(&key, &namespace, &name, claim, &rows));
+        series.push(MetricSeriesDto {
+            key,
+            name: format!("{}/{}", namespace, name),
+            scope: MetricScope::Pvc,
+            points: rows.iter().map(metric_pvc_entity_to_point).collect(),
+        });
+    }
+
+    // Fullest claims first; claims without stats last
+    pvcs.sort_by(|a, b| b.usage_ratio.unwrap_or(-1.0).total_cmp(&a.usage_ratio.unwrap_or(-1.0)));
+
+    Ok(MetricPvcGetResponseDto {
+        metrics: MetricGetResponseDto {
+            start: window.start,
+            end: window.end,
+            scope: "pvc".to_string(),
+            target: target.map(|(namespace, name)| pvc_metric_key(&namespace, &name)),
+            granularity: window.granularity.clone(),
+            series,
+        },
+        pvcs,
+    })
+}
+
+/// Prices the used bytes of every point at `storage_gb_hour`, with the same
+/// per-point convention as `apply_costs`, and totals each claim.
+fn apply_pvc_costs(response: &mut MetricPvcGetResponseDto, unit_prices: &InfoUnitPriceEntity) {
+    for series in &mut response.metrics.series {
+        let mut series_cost = 0.0;
+        for point in &mut series.points {
+            let storage_cost_usd = point
+                .storage
+                .as_ref()
+                .and_then(|s| s.persistent.as_ref())
+                .and_then(|fs| fs.used_bytes)
+                .map(|bytes| bytes / BYTES_PER_GB * (unit_prices.storage_gb_hour / 3600.0));
+            series_cost += storage_cost_usd.unwrap_or(0.0);
+
+            point.cost = Some(CostMetricDto {
+                total_cost_usd: Some(storage_cost_usd.unwrap_or(0.0)),
+                storage_cost_usd,
+                ..Default::default()
+            });
+        }
+
+        if let Some(usage) = response.pvcs.iter_mut().find(|p| p.key == series.key) {
+            usage.cost_usd = Some(series_cost);
+        }
+    }
+}
+
+fn build_pvc_cost_summary_dto(response: &MetricPvcGetResponseDto) -> MetricCostSummaryResponseDto {
+    let total: f64 = response.pvcs.iter().filter_map(|p| p.cost_usd).sum();
+
+    MetricCostSummaryResponseDto {
+        start: response.metrics.start,
+        end: response.metrics.end,
+        scope: MetricScope::Pvc,
+        target: response.metrics.target.clone(),
+        granularity: response.metrics.granularity.clone(),
+        summary: MetricCostSummaryDto {
+            total_cost_usd: total,
+            persistent_storage_cost_usd: total,
+            ..Default::default()
+        },
+    }
+}
+
+async fn build_pvc_cost_response(
+    state: &AppState,
+    q: RangeQuery,
+    target: Option<(String, String)>,
+) -> Result<MetricPvcGetResponseDto> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices(state).await?;
+    let mut response = build_pvc_raw_data(state, q, target).await?;
+    apply_pvc_costs(&mut response, &unit_prices);
+    Ok(response)
+}
+
+pub async fn get_metric_k8s_pvcs_raw(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let response = build_pvc_raw_data(state, q, None).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_pvc_raw(state: &AppState, namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let response = build_pvc_raw_data(state, q, Some((namespace, name))).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_pvcs_cost(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let response = build_pvc_cost_response(state, q, None).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_pvcs_cost_summary(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let response = build_pvc_cost_response(state, q, None).await?;
+    Ok(serde_json::to_value(build_pvc_cost_summary_dto(&response))?)
+}
+
+pub async fn get_metric_k8s_pvcs_cost_trend(state: &AppState, q: RangeQuery) -> Result<Value> {
+    let response = build_pvc_cost_response(state, q, None).await?;
+    let dto = build_cost_trend_dto(&response.metrics, MetricScope::Pvc, None)?;
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_pvc_cost(state: &AppState, namespace: String, name: String, q: RangeQuery) -> Result<Value> {
+    let response = build_pvc_cost_response(state, q, Some((namespace, name))).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+pub async fn get_metric_k8s_pvc_cost_summary(
+    state: &AppState,
+    namespace: String,
+    name: String,
+    q: RangeQuery,
+) -> Result<Value> {
+    let response = build_pvc_cost_response(state, q, Some((namespace, name))).await?;
+    Ok(serde_json::to_value(build_pvc_cost_summary_dto(&response))?)
+}
+
+pub async fn get_metric_k8s_pvc_cost_trend(
+    state: &AppState,
+    namespace: String,
+    name: String,
+    q: RangeQuery,
+) -> Result<Value> {
+    let response = build_pvc_cost_response(state, q, Some((namespace, name))).await?;
+    let dto = build_cost_trend_dto(&response.metrics, MetricScope::Pvc, response.metrics.target.clone())?;
+    Ok(serde_json::to_value(dto)?)
+}
@@ -1,6 +1,12 @@
 //! System domain DTOs
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::core::persistence::export::export_writer::ExportFormat;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricBackendKind;
+use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StatusDto {
@@ -18,3 +24,150 @@ pub struct BackupJobDto {
     pub state: String,
 }
 
+/// One backup archive in the backup directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupDto {
+    pub name: String,
+    pub size_bytes: u64,
+    pub modified_at: Option<DateTime<Utc>>,
+}
+
+/// Body for `POST /system/backup/restore`.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct BackupRestoreRequest {
+    /// File name of a backup in the backup directory (see `GET /system/backups`).
+    #[validate(length(min = 1, max = 128))]
+    pub name: String,
+}
+
+/// Query for `GET /system/archives`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArchiveListQuery {
+    pub scope: MetricScope,
+    /// Object key (node name, pod UID or container key); all objects when omitted.
+    pub key: Option<String>,
+    /// Tier to list; all tiers when omitted.
+    pub granularity: Option<MetricGranularity>,
+}
+
+/// Body for `POST /system/archives/restore`.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ArchiveRestoreRequest {
+    pub scope: MetricScope,
+    #[validate(length(min = 1))]
+    pub key: String,
+    pub granularity: MetricGranularity,
+    /// Period stem: `YYYY-MM-DD` (minute), `YYYY-MM` (hour) or `YYYY` (day).
+    #[validate(length(min = 4, max = 10))]
+    pub period: String,
+}
+
+/// One archived period of one object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedPeriodDto {
+    pub scope: MetricScope,
+    pub key: String,
+    pub granularity: MetricGranularity,
+    pub period: String,
+    pub size_bytes: u64,
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+/// Body for `POST /system/resync`.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ResyncRequest {
+    /// `node`, `pod` or `container`.
+    pub scope: MetricScope,
+    /// Objects to rebuild; every object of the scope when omitted.
+    #[validate(length(min = 1))]
+    pub keys: Option<Vec<String>>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Tiers to rebuild (`hour`, `day`); both when omitted. Hour runs before day.
+    #[validate(length(min = 1))]
+    pub granularities: Option<Vec<MetricGranularity>>,
+}
+
+/// State of a background job (resync, export).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// Progress of one resync job, as returned by `GET /system/resync/{id}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResyncJobDto {
+    pub id: String,
+    pub state: JobState,
+    pub scope: MetricScope,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub granularities: Vec<MetricGranularity>,
+    pub keys_total: usize,
+    pub keys_done: usize,
+    pub windows_total: usize,
+    pub windows_done: usize,
+    /// Aggregated rows written (windows without source data are skipped).
+    pub rows_written: usize,
+    /// Per-object failures; the job keeps going past them.
+    pub errors: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// Body for `POST /system/storage/migrate`.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct StorageMigrateRequest {
+    /// Backend to copy rows from (`fs` or `sqlite`).
+    pub from: MetricBackendKind,
+    /// Backend to copy rows into; rows with the same timestamp are replaced.
+    pub to: MetricBackendKind,
+    /// `node`, `pod` and/or `container`; all three when omitted.
+    #[validate(length(min = 1))]
+    pub scopes: Option<Vec<MetricScope>>,
+}
+
+/// Body for `POST /system/export`.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ExportRequest {
+    /// `node`, `pod` or `container`.
+    pub scope: MetricScope,
+    pub granularity: MetricGranularity,
+    /// Objects to export; every object of the scope when omitted.
+    #[validate(length(min = 1))]
+    pub keys: Option<Vec<String>>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// `parquet` (default) or `csv`.
+    pub format: Option<ExportFormat>,
+    /// Directory to write into, relative to the export directory; the export
+    /// directory itself when omitted.
+    #[validate(length(min = 1, max = 256))]
+    pub target_dir: Option<String>,
+}
+
+/// Progress of one export job, as returned by `GET /system/export/{id}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportJobDto {
+    pub id: String,
+    pub state: JobState,
+    pub scope: MetricScope,
+    pub granularity: MetricGranularity,
+    pub format: ExportFormat,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub target_dir: String,
+    pub keys_total: usize,
+    pub keys_done: usize,
+    pub rows_written: usize,
+    /// Files written so far, one per day partition.
+    pub files: Vec<String>,
+    /// Per-object failures; the job keeps going past them.
+    pub errors: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,165 @@
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::core::persistence::archive::metric_archive_fs_adapter::MetricArchiveFsAdapter;
+use crate::core::persistence::archive::path::*;
+use crate::core::persistence::metrics::k8s::path::*;
+use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
+use crate::domain::system::dto::{ArchiveListQuery, ArchiveRestoreRequest, ArchivedPeriodDto};
+
+const ALL_GRANULARITIES: [MetricGranularity; 3] =
+    [MetricGranularity::Minute, MetricGranularity::Hour, MetricGranularity::Day];
+
+/// Lists archived metric periods for one scope, optionally narrowed to a key and tier.
+pub async fn list_archives(q: ArchiveListQuery) -> Result<Value> {
+    let keys = match q.key {
+        Some(key) => {
+            validate_key(&key)?;
+            vec![key]
+        }
+        None => list_archived_keys(&q.scope)?,
+    };
+    let granularities = match q.granularity {
+        Some(g) => vec![g],
+        None => ALL_GRANULARITIES.to_vec(),
+    };
+
+    let adapter = MetricArchiveFsAdapter;
+    let mut periods: Vec<ArchivedPeriodDto> = Vec::new();
+    for key in &keys {
+        for granularity in &granularities {
+            let dir = archive_dir_for(&q.scope, key, granularity)?;
+            for segment in adapter.list_segments(&dir)? {
+                periods.push(ArchivedPeriodDto {
+                    scope: q.scope.clone(),
+                    key: key.clone(),
+                    granularity: granularity.clone(),
+                    period: segment.period,
+                    size_bytes: segment.size_bytes,
+                    archived_at: segment.archived_at,
+                });
+            }
+        }
+    }
+
+    Ok(json!({
+        "total": periods.len(),
+        "archives": periods,
+    }))
+}
+
+/// Restores one archived period back into the live metric tree.
+pub async fn restore_archive(req: ArchiveRestoreRequest) -> Result<Value> {
+    validate_key(&req.key)?;
+    validate_period(&req.granularity, &req.period)?;
+
+    let archive_dir = archive_dir_for(&req.scope, &req.key, &req.granularity)?;
+    let live_dir = live_dir_for(&req.scope, &req.key, &req.granularity)?;
+    let restored = MetricArchiveFsAdapter.restore_segment(&archive_dir, &live_dir, &req.period)?;
+
+    Ok(json!({
+        "message": "Archive restored successfully",
+        "period": req.period,
+        "path": restored.to_string_lossy(),
+    }))
+}
+
+fn archive_dir_for(scope: &MetricScope, key: &str, granularity: &MetricGranularity) -> Result<PathBuf> {
+    use MetricGranularity::*;
+    Ok(match (scope, granularity) {
+        (MetricScope::Node, Minute) => archive_k8s_node_key_minute_dir_path(key),
+        (MetricScope::Node, Hour) => archive_k8s_node_key_hour_dir_path(key),
+        (MetricScope::Node, Day) => archive_k8s_node_key_day_dir_path(key),
+        (MetricScope::Pod, Minute) => archive_k8s_pod_key_minute_dir_path(key),
+        (MetricScope::Pod, Hour) => archive_k8s_pod_key_hour_dir_path(key),
+        (MetricScope::Pod, Day) => archive_k8s_pod_key_day_dir_path(key),
+        (MetricScope::Container, Minute) => archive_k8s_container_key_minute_dir_path(key),
+        (MetricScope::Container, Hour) => archive_k8s_container_key_hour_dir_path(key),
+        (MetricScope::Container, Day) => archive_k8s_container_key_day_dir_path(key),
+        (MetricScope::Pvc, Minute) => archive_k8s_pvc_key_minute_dir_path(key),
+        (MetricScope::Pvc, Hour) => archive_k8s_pvc_key_hour_dir_path(key),
+        (MetricScope::Pvc, Day) => archive_k8s_pvc_key_day_dir_path(key),
+        (MetricScope::NodeNetwork, Minute) => archive_k8s_node_network_key_minute_dir_path(key),
+        (MetricScope::NodeSystem, Minute) => archive_k8s_node_system_key_minute_dir_path(key),
+        (MetricScope::NodeNetwork, Hour) => archive_k8s_node_network_key_hour_dir_path(key),
+        (MetricScope::NodeSystem, Hour) => archive_k8s_node_system_key_hour_dir_path(key),
+        (MetricScope::NodeNetwork, Day) => archive_k8s_node_network_key_day_dir_path(key),
+        (MetricScope::NodeSystem, Day) => archive_k8s_node_system_key_day_dir_path(key),
+        (other, _) => return Err(anyhow!("Archives are not kept for scope {:?}", other)),
+    })
+}
+
+fn live_dir_for(scope: &MetricScope, key: &str, granularity: &MetricGranularity) -> Result<PathBuf> {
+    use MetricGranularity::*;
+    Ok(match (scope, granularity) {
+        (MetricScope::Node, Minute) => metric_k8s_node_key_minute_dir_path(key),
+        (MetricScope::Node, Hour) => metric_k8s_node_key_hour_dir_path(key),
+        (MetricScope::Node, Day) => metric_k8s_node_key_day_dir_path(key),
+        (MetricScope::Pod, Minute) => metric_k8s_pod_key_minute_dir_path(key),
+        (MetricScope::Pod, Hour) => metric_k8s_pod_key_hour_dir_path(key),
+        (MetricScope::Pod, Day) => metric_k8s_pod_key_day_dir_path(key),
+        (MetricScope::Container, Minute) => metric_k8s_container_key_minute_dir_path(key),
+        (MetricScope::Container, Hour) => metric_k8s_container_key_hour_dir_path(key),
+        (MetricScope::Container, Day) => metric_k8s_container_key_day_dir_path(key),
+        (MetricScope::Pvc, Minute) => metric_k8s_pvc_key_minute_dir_path(key),
+        (MetricScope::Pvc, Hour) => metric_k8s_pvc_key_hour_dir_path(key),
+        (MetricScope::Pvc, Day) => metric_k8s_pvc_key_day_dir_path(key),
+        (MetricScope::NodeNetwork, Minute) => metric_k8s_node_network_key_minute_dir_path(key),
+        (MetricScope::NodeSystem, Minute) => metric_k8s_node_system_key_minute_dir_path(key),
+        (MetricScope::NodeNetwork, Hour) => metric_k8s_node_network_key_hour_dir_path(key),
+        (MetricScope::NodeSystem, Hour) => metric_k8s_node_system_key_hour_dir_path(key),
+        (MetricScope::NodeNetwork, Day) => metric_k8s_node_network_key_day_dir_path(key),
+        (MetricScope::NodeSystem, Day) => metric_k8s_node_system_key_day_dir_path(key),
+        (other, _) => return Err(anyhow!("Metrics are not stored for scope {:?}", other)),
+    })
+}
+
+fn list_archived_keys(scope: &MetricScope) -> Result<Vec<String>> {
+    let base = match scope {
+        MetricScope::Node => archive_k8s_node_dir_path(),
+        MetricScope::Pod => archive_k8s_pod_dir_path(),
+        MetricScope::Container => archive_k8s_container_dir_path(),
+        MetricScope::Pvc => archive_k8s_pvc_dir_path(),
+        MetricScope::NodeNetwork => archive_k8s_node_network_dir_path(),
+        MetricScope::NodeSystem => archive_k8s_node_system_dir_path(),
+        other => return Err(anyhow!("Archives are not kept for scope {:?}", other)),
+    };
+
+    let mut keys = Vec::new();
+    if !base.exists() {
+        return Ok(keys);
+    }
+    for entry in fs::read_dir(&base)? {
+        let entry = entry?;
+        if entry.path().is_dir() {
+            if let Some(key) = entry.file_name().to_str() {
+                keys.push(key.to_string());
+            }
+        }
+    }
+    keys.sort();
+    Ok(keys)
+}
+
+/// Object keys become directory names, so reject anything that could escape the tree.
+pub(super) fn validate_key(key: &str) -> Result<()> {
+    if key.is_empty() || key.contains('/') || key.contains('\\') || key.contains("..") {
+        return Err(anyhow!("Invalid object key '{}'", key));
+    }
+    Ok(())
+}
+
+fn validate_period(granularity: &MetricGranularity, period: &str) -> Result<()> {
+    let valid = match granularity {
+        MetricGranularity::Minute => NaiveDate::parse_from_str(period, "%Y-%m-%d").is_ok(),
+        MetricGranularity::Hour => NaiveDate::parse_from_str(&format!("{}-01", period), "%Y-%m-%d").is_ok(),
+        MetricGranularity::Day => period.len() == 4 && period.parse::<i32>().is_ok(),
+    };
+    if !valid {
+        return Err(anyhow!("Invalid period '{}' for {:?} granularity", period, granularity));
+    }
+    Ok(())
+}
@@ -1,7 +1,184 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use axum::body::BodyDataStream;
+use chrono::Utc;
+use futures_util::StreamExt;
 use serde_json::{json, Value};
+use std::env;
+use std::path::PathBuf;
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
 
+use crate::core::persistence::backup::backup_fs_adapter::BackupFsAdapter;
+use crate::core::persistence::backup::backup_manifest::{BackupCounts, BackupManifest, BACKUP_FORMAT_VERSION};
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::quota::storage_quota_manager::{forget_measured_usage, record_written};
+use crate::core::persistence::storage_lock::lock_exclusive;
+use crate::core::persistence::storage_path::{get_rustcost_backup_path, get_rustcost_base_path};
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::version::info_version_fs_adapter::InfoVersionFsAdapter;
+use crate::domain::system::dto::{BackupDto, BackupRestoreRequest};
+
+const DEFAULT_BACKUP_UPLOAD_MAX_MB: u64 = 4096;
+
+/// Largest backup upload accepted, in bytes; `RUSTCOST_BACKUP_UPLOAD_MAX_MB`
+/// (4 GiB by default).
+pub fn backup_upload_limit() -> u64 {
+    let mb = env::var("RUSTCOST_BACKUP_UPLOAD_MAX_MB")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or(DEFAULT_BACKUP_UPLOAD_MAX_MB);
+    mb.saturating_mul(1024 * 1024)
+}
+
+/// Writes a tar snapshot of `info/` and `metric/` into the backup directory.
+///
+/// Scheduler tasks are paused for the duration so the snapshot is consistent.
 pub async fn backup() -> Result<Value> {
-    Ok(json!({"backup": "scheduled"}))
+    // Backups are of the files on disk, so the version comes from there too.
+    let version = InfoVersionFsAdapter.read().ok();
+    let manifest = BackupManifest {
+        format_version: BACKUP_FORMAT_VERSION,
+        created_at: Utc::now(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        git_version: version.as_ref().map(|v| v.git_version.clone()),
+        git_commit: version.as_ref().map(|v| v.git_commit.clone()),
+        counts: BackupCounts::default(),
+        files: Vec::new(),
+    };
+
+    let _guard = lock_exclusive().await;
+    let (path, manifest) = tokio::task::spawn_blocking(move || {
+        // An embedded database keeps recent rows in its WAL until checkpointed.
+        metric_backend()?.checkpoint()?;
+        BackupFsAdapter.create(&get_rustcost_base_path(), &get_rustcost_backup_path(), manifest)
+    })
+    .await??;
+    debug!("✅ Backup written to {:?}", path);
+    let size_bytes = std::fs::metadata(&path)?.len();
+    record_written(size_bytes);
+
+    Ok(json!({
+        "message": "Backup created successfully",
+        "name": path.file_name().map(|n| n.to_string_lossy().into_owned()),
+        "path": path.to_string_lossy(),
+        "size_bytes": size_bytes,
+        "created_at": manifest.created_at,
+        "format_version": manifest.format_version,
+        "counts": manifest.counts,
+    }))
+}
+
+/// Lists backups in the backup directory, newest first.
+pub async fn list_backups() -> Result<Value> {
+    let backups: Vec<BackupDto> = BackupFsAdapter
+        .list(&get_rustcost_backup_path())?
+        .into_iter()
+        .map(|b| BackupDto {
+            name: b.name,
+            size_bytes: b.size_bytes,
+            modified_at: b.modified_at,
+        })
+        .collect();
+
+    Ok(json!({
+        "total": backups.len(),
+        "backups": backups,
+    }))
+}
+
+/// Resolves a backup name to its file, for download.
+pub fn backup_file_path(name: &str) -> Result<PathBuf> {
+    let path = resolve_backup_name(name)?;
+    if !path.exists() {
+        return Err(anyhow!("Backup '{}' not found", name));
+    }
+    Ok(path)
 }
 
+/// Stores an uploaded backup (e.g. downloaded from another cluster) in the backup directory.
+///
+/// The upload is rejected unless it carries a readable manifest, and cut off
+/// once it grows past [`backup_upload_limit`].
+pub async fn upload_backup(name: String, mut body: BodyDataStream) -> Result<Value> {
+    let dest = resolve_backup_name(&name)?;
+    if dest.exists() {
+        return Err(anyhow!("Backup '{}' already exists", name));
+    }
+
+    tokio::fs::create_dir_all(get_rustcost_backup_path()).await?;
+    let tmp = dest.with_extension("tar.tmp");
+    let mut file = tokio::fs::File::create(&tmp).await?;
+    let limit = backup_upload_limit();
+    let mut size: u64 = 0;
+    while let Some(chunk) = body.next().await {
+        let chunk = match chunk {
+            Ok(c) => c,
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&tmp).await;
+                return Err(anyhow!("Upload interrupted: {}", e));
+            }
+        };
+        size += chunk.len() as u64;
+        if size > limit {
+            drop(file);
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(anyhow!("Backup upload exceeds the limit of {} bytes", limit));
+        }
+        file.write_all(&chunk).await?;
+    }
+    file.sync_all().await?;
+    drop(file);
+
+    let check = tmp.clone();
+    let manifest = match tokio::task::spawn_blocking(move || BackupFsAdapter.read_manifest(&check)).await? {
+        Ok(m) => m,
+        Err(e) => {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(anyhow!("Uploaded file is not a valid backup: {}", e));
+        }
+    };
+    tokio::fs::rename(&tmp, &dest).await?;
+    record_written(size);
+
+    Ok(json!({
+        "message": "Backup uploaded successfully",
+        "name": name,
+        "size_bytes": size,
+        "created_at": manifest.created_at,
+        "counts": manifest.counts,
+    }))
+}
+
+/// Replaces the live `info/` and `metric/` trees with a backup after verifying its manifest.
+pub async fn restore_backup(req: BackupRestoreRequest) -> Result<Value> {
+    let archive = backup_file_path(&req.name)?;
+
+    let _guard = lock_exclusive().await;
+    let manifest = tokio::task::spawn_blocking(move || {
+        // The backend's files are swapped underneath it, so it lets go of them
+        // first and opens the restored ones afterwards, even if the restore failed.
+        let backend = metric_backend()?;
+        backend.close()?;
+        let restored = BackupFsAdapter.restore(&archive, &get_rustcost_base_path());
+        backend.reopen()?;
+        restored
+    })
+    .await??;
+    forget_measured_usage();
+
+    Ok(json!({
+        "message": "Backup restored successfully",
+        "name": req.name,
+        "created_at": manifest.created_at,
+        "app_version": manifest.app_version,
+        "counts": manifest.counts,
+    }))
+}
+
+fn resolve_backup_name(name: &str) -> Result<PathBuf> {
+    if !BackupFsAdapter::is_valid_backup_name(name) {
+        return Err(anyhow!("Invalid backup name '{}'", name));
+    }
+    Ok(get_rustcost_backup_path().join(name))
+}
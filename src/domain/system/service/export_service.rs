@@ -0,0 +1,417 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use tracing::{debug, error};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::core::persistence::export::export_writer::{
+    create_export_writer, ExportColumn, ExportColumnType, ExportFormat, ExportRow, ExportWriter,
+};
+use crate::core::persistence::metrics::backend::metric_row_fields::{entity_columns, row_time};
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricObjectKind;
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::storage_lock::lock_for_write;
+use crate::core::persistence::storage_path::get_rustcost_export_path;
+use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
+use crate::domain::system::dto::{ExportJobDto, ExportRequest, JobState};
+
+use super::archive_service::validate_key;
+
+/// Finished jobs kept around for the status endpoint.
+const MAX_KEPT_JOBS: usize = 50;
+
+/// Per-object errors kept on a job; further ones are only logged.
+const MAX_JOB_ERRORS: usize = 100;
+
+/// Info fields joined onto every pod row.
+const POD_INFO_COLUMNS: [&str; 9] = [
+    "namespace", "pod_name", "node_name", "owner_kind", "owner_name", "team", "service", "env", "label",
+];
+
+/// Info fields joined onto every container row.
+const CONTAINER_INFO_COLUMNS: [&str; 9] = [
+    "namespace", "pod_uid", "container_name", "node_name", "image", "team", "service", "env", "labels",
+];
+
+static EXPORT_JOBS: LazyLock<Mutex<HashMap<String, ExportJobDto>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// What one export job reads and where it writes.
+struct ExportSpec {
+    tier: MetricTier,
+    format: ExportFormat,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    dir: PathBuf,
+}
+
+/// Starts a background job that writes stored metric rows to Parquet or CSV.
+///
+/// Rows in `[start, end]` are written to one file per UTC day under
+/// `{target}/{scope}/{granularity}/date=YYYY-MM-DD/`, with the object's info
+/// fields as extra columns for pods and containers. Exporting the same day
+/// again replaces its file.
+pub async fn export(state: &AppState, req: ExportRequest) -> Result<Value> {
+    if req.start >= req.end {
+        return Err(anyhow!("start must be before end"));
+    }
+    let kind = object_kind(&req.scope)?;
+    let format = req.format.unwrap_or_default();
+    let target = target_dir(req.target_dir.as_deref())?;
+
+    let keys = match req.keys {
+        Some(keys) => {
+            for key in &keys {
+                validate_key(key)?;
+            }
+            keys
+        }
+        None => state.metrics.keys(kind)?,
+    };
+
+    let spec = ExportSpec {
+        tier: match req.granularity {
+            MetricGranularity::Minute => MetricTier::Minute,
+            MetricGranularity::Hour => MetricTier::Hour,
+            MetricGranularity::Day => MetricTier::Day,
+        },
+        format,
+        start: req.start,
+        end: req.end,
+        dir: target
+            .join(kind.as_str())
+            .join(granularity_name(&req.granularity)),
+    };
+
+    let job = ExportJobDto {
+        id: Uuid::new_v4().to_string(),
+        state: JobState::Queued,
+        scope: req.scope.clone(),
+        granularity: req.granularity.clone(),
+        format,
+        start: req.start,
+        end: req.end,
+        target_dir: target.display().to_string(),
+        keys_total: keys.len(),
+        keys_done: 0,
+        rows_written: 0,
+        files: Vec::new(),
+        errors: Vec::new(),
+        created_at: Utc::now(),
+        finished_at: None,
+    };
+    let id = job.id.clone();
+    insert_job(job.clone());
+
+    let state = state.clone();
+    tokio::spawn(async move {
+        // Reads alongside the scheduler but never inside a backup/restore.
+        let _guard = lock_for_write().await;
+        update_job(&id, |j| j.state = JobState::Running);
+
+        let job_id = id.clone();
+        let result = tokio::task::spawn_blocking(move || run_job(&job_id, &state, kind, &keys, &spec))
+            .await
+            .map_err(anyhow::Error::from)
+            .and_then(|r| r);
+
+        update_job(&id, |j| {
+            j.finished_at = Some(Utc::now());
+            j.state = match &result {
+                Ok(()) => JobState::Completed,
+                Err(e) => {
+                    j.errors.push(e.to_string());
+                    JobState::Failed
+                }
+            };
+        });
+        match result {
+            Ok(()) => debug!("✅ Export job {} completed", id),
+            Err(e) => error!("❌ Export job {} failed: {}", id, e),
+        }
+    });
+
+    Ok(json!({
+        "message": "Export started",
+        "job": job,
+    }))
+}
+
+/// Returns the state of one export job.
+pub async fn get_export_job(id: &str) -> Result<Value> {
+    let jobs = EXPORT_JOBS.lock().map_err(|_| anyhow!("Export job registry poisoned"))?;
+    let job = jobs
+        .get(id)
+        .ok_or_else(|| anyhow!("Export job '{}' not found", id))?;
+    Ok(json!(job))
+}
+
+/// Lists known export jobs, newest first.
+pub async fn list_export_jobs() -> Result<Value> {
+    let jobs = EXPORT_JOBS.lock().map_err(|_| anyhow!("Export job registry poisoned"))?;
+    let mut list: Vec<&ExportJobDto> = jobs.values().collect();
+    list.sort_by_key(|j| std::cmp::Reverse(j.created_at));
+    Ok(json!({
+        "total": list.len(),
+        "jobs": list,
+    }))
+}
+
+fn object_kind(scope: &MetricScope) -> Result<MetricObjectKind> {
+    match scope {
+        MetricScope::Node => Ok(MetricObjectKind::Node),
+        MetricScope::Pod => Ok(MetricObjectKind::Pod),
+        MetricScope::Container => Ok(MetricObjectKind::Container),
+        MetricScope::Pvc => Ok(MetricObjectKind::Pvc),
+        MetricScope::NodeNetwork => Ok(MetricObjectKind::NodeNetwork),
+        MetricScope::NodeSystem => Ok(MetricObjectKind::NodeSystem),
+        other => Err(anyhow!("Export is not supported for scope {:?}", other)),
+    }
+}
+
+fn granularity_name(granularity: &MetricGranularity) -> &'static str {
+    match granularity {
+        MetricGranularity::Minute => "minute",
+        MetricGranularity::Hour => "hour",
+        MetricGranularity::Day => "day",
+    }
+}
+
+/// Resolves `target_dir` under the export directory; it may not climb out of it.
+fn target_dir(target_dir: Option<&str>) -> Result<PathBuf> {
+    let root = get_rustcost_export_path();
+    let Some(dir) = target_dir else {
+        return Ok(root);
+    };
+    let relative = Path::new(dir);
+    if !relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(anyhow!("Invalid target directory '{}'", dir));
+    }
+    Ok(root.join(relative))
+}
+
+fn run_job(id: &str, state: &AppState, kind: MetricObjectKind, keys: &[String], spec: &ExportSpec) -> Result<()> {
+    let tier = spec.tier;
+    match kind {
+        MetricObjectKind::Node => export_keys(id, state.metrics.node(tier).as_ref(), &[], |_| None, keys, spec),
+        MetricObjectKind::Pod => {
+            let info = state.info.pod();
+            export_keys(
+                id,
+                state.metrics.pod(tier).as_ref(),
+                &POD_INFO_COLUMNS,
+                |key| info.read(key).ok().and_then(|e| info_fields(&e)),
+                keys,
+                spec,
+            )
+        }
+        MetricObjectKind::Container => {
+            let info = state.info.container();
+            export_keys(
+                id,
+                state.metrics.container(tier).as_ref(),
+                &CONTAINER_INFO_COLUMNS,
+                |key| info.read(key).ok().and_then(|e| info_fields(&e)),
+                keys,
+                spec,
+            )
+        }
+        MetricObjectKind::Pvc => export_keys(id, state.metrics.pvc(tier).as_ref(), &[], |_| None, keys, spec),
+        MetricObjectKind::NodeNetwork => {
+            export_keys(id, state.metrics.node_network(tier).as_ref(), &[], |_| None, keys, spec)
+        }
+        MetricObjectKind::NodeSystem => {
+            export_keys(id, state.metrics.node_system(tier).as_ref(), &[], |_| None, keys, spec)
+        }
+    }
+}
+
+/// Serialized info entity with snake_case keys, matching the export columns.
+fn info_fields<E: Serialize>(entity: &E) -> Option<Map<String, Value>> {
+    match serde_json::to_value(entity) {
+        Ok(Value::Object(fields)) => Some(fields.into_iter().map(|(k, v)| (snake_case(&k), v)).collect()),
+        _ => None,
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            out.push('_');
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn export_keys<T: Serialize + Default>(
+    id: &str,
+    adapter: &dyn MetricFsAdapterBase<T>,
+    info_columns: &[&str],
+    info_of: impl Fn(&str) -> Option<Map<String, Value>>,
+    keys: &[String],
+    spec: &ExportSpec,
+) -> Result<()> {
+    let metric_columns = entity_columns::<T>();
+    let mut columns = vec![
+        ExportColumn::new("time", ExportColumnType::Timestamp),
+        ExportColumn::new("key", ExportColumnType::Text),
+    ];
+    columns.extend(info_columns.iter().map(|c| ExportColumn::new(*c, ExportColumnType::Text)));
+    columns.extend(metric_columns.iter().map(|c| ExportColumn::new(c, ExportColumnType::UInt64)));
+
+    // One open file per day partition; keys are appended to them in turn.
+    let mut writers: BTreeMap<NaiveDate, Box<dyn ExportWriter>> = BTreeMap::new();
+
+    for key in keys {
+        let rows = match adapter.get_row_between(spec.start, spec.end, key, None, None) {
+            Ok(rows) => rows,
+            Err(e) => {
+                error!("⚠️ Export of '{}' failed: {}", key, e);
+                update_job(id, |j| {
+                    j.keys_done += 1;
+                    if j.errors.len() < MAX_JOB_ERRORS {
+                        j.errors.push(format!("{}: {}", key, e));
+                    }
+                });
+                continue;
+            }
+        };
+
+        let info = if info_columns.is_empty() { None } else { info_of(key) };
+        if !info_columns.is_empty() && info.is_none() {
+            debug!("No info for '{}'; exporting without metadata", key);
+        }
+
+        let mut by_day: BTreeMap<NaiveDate, Vec<ExportRow>> = BTreeMap::new();
+        for row in &rows {
+            let time = row_time(row)?;
+            let Value::Object(mut fields) = serde_json::to_value(row)? else {
+                return Err(anyhow!("metric row did not serialize to an object"));
+            };
+
+            let mut values: ExportRow = Vec::with_capacity(columns.len());
+            values.push(fields.remove("time").unwrap_or_default());
+            values.push(Value::String(key.clone()));
+            for column in info_columns {
+                values.push(info.as_ref().and_then(|i| i.get(*column)).cloned().unwrap_or_default());
+            }
+            for column in &metric_columns {
+                values.push(fields.remove(column).unwrap_or_default());
+            }
+            by_day.entry(time.date_naive()).or_default().push(values);
+        }
+
+        for (day, day_rows) in by_day {
+            let writer = match writers.entry(day) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let path = spec
+                        .dir
+                        .join(format!("date={}", day.format("%Y-%m-%d")))
+                        .join(format!("data.{}", spec.format.extension()));
+                    entry.insert(create_export_writer(spec.format, &columns, &path)?)
+                }
+            };
+            writer.write_rows(&day_rows)?;
+        }
+
+        update_job(id, |j| {
+            j.keys_done += 1;
+            j.rows_written += rows.len();
+        });
+    }
+
+    for (_, writer) in writers {
+        let path = writer.finish()?;
+        update_job(id, |j| j.files.push(path.display().to_string()));
+    }
+    Ok(())
+}
+
+fn insert_job(job: ExportJobDto) {
+    let Ok(mut jobs) = EXPORT_JOBS.lock() else { return };
+
+    // Drop the oldest finished jobs once the registry is full.
+    while jobs.len() >= MAX_KEPT_JOBS {
+        let oldest = jobs
+            .values()
+            .filter(|j| j.finished_at.is_some())
+            .min_by_key(|j| j.created_at)
+            .map(|j| j.id.clone());
+        match oldest {
+            Some(id) => jobs.remove(&id),
+            None => break,
+        };
+    }
+    jobs.insert(job.id.clone(), job);
+}
+
+fn update_job(id: &str, f: impl FnOnce(&mut ExportJobDto)) {
+    if let Ok(mut jobs) = EXPORT_JOBS.lock() {
+        if let Some(job) = jobs.get_mut(id) {
+            f(job);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::backend::metric_memory_backend::MetricMemoryBackend;
+    use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricStorageBackend;
+    use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+    use crate::core::persistence::metrics::segment::test_support::scratch_dir;
+    use chrono::Duration;
+
+    #[test]
+    fn rows_are_written_per_day_with_the_info_columns() {
+        let backend = MetricMemoryBackend::default();
+        let adapter = backend.pod(MetricTier::Minute);
+        let start: DateTime<Utc> = "2025-01-01T23:58:00Z".parse().unwrap();
+        for i in 0..3u64 {
+            let row = MetricPodEntity {
+                time: start + Duration::minutes(i as i64),
+                cpu_usage_nano_cores: Some(100 + i),
+                ..Default::default()
+            };
+            adapter.append_row("uid-1", &row).unwrap();
+        }
+        let spec = ExportSpec {
+            tier: MetricTier::Minute,
+            format: ExportFormat::Csv,
+            start,
+            end: start + Duration::hours(1),
+            dir: scratch_dir(),
+        };
+        let info_of = |key: &str| {
+            (key == "uid-1").then(|| Map::from_iter([("namespace".to_string(), json!("default"))]))
+        };
+
+        export_keys("job", adapter.as_ref(), &POD_INFO_COLUMNS, info_of, &["uid-1".to_string()], &spec).unwrap();
+
+        let read = |day: &str| std::fs::read_to_string(spec.dir.join(format!("date={}/data.csv", day))).unwrap();
+        let first_day = read("2025-01-01");
+        let second_day = read("2025-01-02");
+        assert!(first_day.starts_with("time,key,namespace,"), "{}", first_day);
+        assert_eq!(first_day.lines().count(), 3);
+        assert_eq!(second_day.lines().count(), 2);
+        let row = second_day.lines().nth(1).unwrap();
+        assert!(row.starts_with("2025-01-02T00:00:00+00:00,uid-1,default,"), "{}", row);
+        assert!(row.contains(",102,"), "{}", row);
+        std::fs::remove_dir_all(&spec.dir).unwrap();
+    }
+}
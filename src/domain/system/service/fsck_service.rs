@@ -0,0 +1,155 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+use tracing::{error, info};
+
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_container_dir_path, metric_k8s_node_dir_path, metric_k8s_node_network_dir_path,
+    metric_k8s_node_system_dir_path, metric_k8s_pod_dir_path, metric_k8s_pvc_dir_path,
+};
+use crate::core::persistence::metrics::segment::format::FORMAT_VERSION;
+use crate::core::persistence::metrics::segment::fsck::{check_dir, repair_dir_torn_tails};
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::metrics::segment::write_buffer::flush_all;
+use crate::core::persistence::storage_lock::lock_for_write;
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+/// Tier directory of one object, e.g. `metric/k8s/pod/{uid}/h`.
+struct TierDir {
+    kind: &'static str,
+    key: String,
+    tier: MetricTier,
+    path: PathBuf,
+}
+
+fn tier_dirs() -> Result<Vec<TierDir>> {
+    let mut dirs = Vec::new();
+    for (kind, base_dir) in [
+        ("node", metric_k8s_node_dir_path()),
+        ("pod", metric_k8s_pod_dir_path()),
+        ("container", metric_k8s_container_dir_path()),
+        ("pvc", metric_k8s_pvc_dir_path()),
+        ("node_network", metric_k8s_node_network_dir_path()),
+        ("node_system", metric_k8s_node_system_dir_path()),
+    ] {
+        if !base_dir.exists() {
+            continue;
+        }
+        for entry in fs::read_dir(&base_dir)? {
+            let key_dir = entry?.path();
+            if !key_dir.is_dir() {
+                continue;
+            }
+            let key = key_dir
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            for tier in MetricTier::ALL {
+                dirs.push(TierDir {
+                    kind,
+                    key: key.clone(),
+                    tier,
+                    path: key_dir.join(tier.dir_name()),
+                });
+            }
+        }
+    }
+    Ok(dirs)
+}
+
+fn granularity(tier: MetricTier) -> MetricGranularity {
+    match tier {
+        MetricTier::Minute => MetricGranularity::Minute,
+        MetricTier::Hour => MetricGranularity::Hour,
+        MetricTier::Day => MetricGranularity::Day,
+    }
+}
+
+/// Reads every node/pod/container segment and lists the damaged ones.
+///
+/// A segment is damaged when rows fail their checksum, its last line is torn,
+/// it cannot be read, or its header carries a newer format version.
+pub async fn fsck() -> Result<Value> {
+    // Shared with the scheduler: appends keep running, restores wait.
+    let _guard = lock_for_write().await;
+
+    tokio::task::spawn_blocking(|| {
+        // Check what the minute task has appended so far, not what was last flushed.
+        if let Err(e) = flush_all() {
+            error!("❌ Failed to flush buffered metric rows: {}", e);
+        }
+
+        let mut checked = 0;
+        let mut rows = 0;
+        let mut legacy = 0;
+        let mut damaged = Vec::new();
+
+        for dir in tier_dirs()? {
+            for check in check_dir(&dir.path)? {
+                checked += 1;
+                rows += check.rows;
+                if check.format_version.is_none() {
+                    legacy += 1;
+                }
+                if check.is_damaged() {
+                    damaged.push(json!({
+                        "kind": dir.kind,
+                        "key": dir.key,
+                        "granularity": granularity(dir.tier),
+                        "file": check.path.file_name().map(|n| n.to_string_lossy().into_owned()),
+                        "format_version": check.format_version,
+                        "rows": check.rows,
+                        "corrupt_rows": check.corrupt_rows,
+                        "torn_tail": check.torn_tail,
+                        "error": check.error,
+                    }));
+                }
+            }
+        }
+
+        Ok(json!({
+            "format_version": FORMAT_VERSION,
+            "segments_checked": checked,
+            "rows_checked": rows,
+            "legacy_segments": legacy,
+            "damaged_count": damaged.len(),
+            "damaged": damaged,
+        }))
+    })
+    .await?
+}
+
+/// Cuts torn tails left by a crash off every plain segment.
+///
+/// Runs once at startup, before the scheduler appends anything.
+pub async fn repair_torn_tails() -> Result<Value> {
+    let _guard = lock_for_write().await;
+
+    tokio::task::spawn_blocking(|| {
+        let mut repaired = Vec::new();
+        for dir in tier_dirs()? {
+            match repair_dir_torn_tails(&dir.path) {
+                Ok(files) => {
+                    for (path, bytes) in files {
+                        info!("🩹 Truncated torn tail of {:?} ({} bytes)", path, bytes);
+                        repaired.push(json!({
+                            "kind": dir.kind,
+                            "key": dir.key,
+                            "granularity": granularity(dir.tier),
+                            "file": path.file_name().map(|n| n.to_string_lossy().into_owned()),
+                            "removed_bytes": bytes,
+                        }));
+                    }
+                }
+                Err(e) => error!("❌ Torn tail repair failed in {:?}: {}", dir.path, e),
+            }
+        }
+
+        Ok(json!({
+            "repaired_count": repaired.len(),
+            "repaired": repaired,
+        }))
+    })
+    .await?
+}
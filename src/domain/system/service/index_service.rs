@@ -0,0 +1,57 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_container_dir_path, metric_k8s_node_dir_path, metric_k8s_node_network_dir_path,
+    metric_k8s_node_system_dir_path, metric_k8s_pod_dir_path, metric_k8s_pvc_dir_path,
+};
+use crate::core::persistence::metrics::segment::index::rebuild_dir_indexes;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Rebuilds the `.idx` sidecars of every plain node/pod/container/PVC/node interface/node system container segment.
+///
+/// Used for data written before `enable_index_file` was turned on, or after
+/// sidecars were lost or edited by hand.
+pub async fn rebuild_indexes() -> Result<Value> {
+    tokio::task::spawn_blocking(|| {
+        let nodes = rebuild_kind(&metric_k8s_node_dir_path())?;
+        let pods = rebuild_kind(&metric_k8s_pod_dir_path())?;
+        let containers = rebuild_kind(&metric_k8s_container_dir_path())?;
+        let pvcs = rebuild_kind(&metric_k8s_pvc_dir_path())?;
+        let node_networks = rebuild_kind(&metric_k8s_node_network_dir_path())?;
+        let node_systems = rebuild_kind(&metric_k8s_node_system_dir_path())?;
+
+        Ok(json!({
+            "message": "Index files rebuilt",
+            "segments": {
+                "node": nodes,
+                "pod": pods,
+                "container": containers,
+                "pvc": pvcs,
+                "node_network": node_networks,
+                "node_system": node_systems,
+            },
+        }))
+    })
+    .await?
+}
+
+fn rebuild_kind(base_dir: &Path) -> Result<usize> {
+    if !base_dir.exists() {
+        return Ok(0);
+    }
+
+    let mut rebuilt = 0;
+    for entry in fs::read_dir(base_dir)? {
+        let key_dir = entry?.path();
+        if !key_dir.is_dir() {
+            continue;
+        }
+        for tier in MetricTier::ALL {
+            rebuilt += rebuild_dir_indexes(&key_dir.join(tier.dir_name()), tier)?;
+        }
+    }
+    Ok(rebuilt)
+}
@@ -1,7 +1,11 @@
-//! System services: backup, resync, healthcheck logic
+//! System services: backup, resync, export, healthcheck logic
 
 pub mod status_service;
 pub mod health_service;
 pub mod backup_service;
 pub mod resync_service;
-
+pub mod archive_service;
+pub mod index_service;
+pub mod fsck_service;
+pub mod storage_service;
+pub mod export_service;
@@ -1,7 +1,300 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, DurationRound, Utc};
 use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use tracing::{debug, error};
+use uuid::Uuid;
 
-pub async fn resync() -> Result<Value> {
-    Ok(json!({"resync": "started"}))
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricObjectKind;
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::storage_lock::blocking_lock_for_write;
+use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
+use crate::domain::system::dto::{JobState, ResyncJobDto, ResyncRequest};
+
+use super::archive_service::validate_key;
+
+/// Finished jobs kept around for the status endpoint.
+const MAX_KEPT_JOBS: usize = 50;
+
+/// Per-object errors kept on a job; further ones are only logged.
+const MAX_JOB_ERRORS: usize = 100;
+
+static RESYNC_JOBS: LazyLock<Mutex<HashMap<String, ResyncJobDto>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Starts a background job that rebuilds hour/day rows from the tier below.
+///
+/// Every window in `[start, end)` that has source data is re-aggregated and
+/// written over the existing row for that window. Windows without source data
+/// (e.g. minute data already past retention) keep their current rows.
+pub async fn resync(req: ResyncRequest) -> Result<Value> {
+    if req.start >= req.end {
+        return Err(anyhow!("start must be before end"));
+    }
+
+    let mut granularities = req
+        .granularities
+        .clone()
+        .unwrap_or_else(|| vec![MetricGranularity::Hour, MetricGranularity::Day]);
+    if granularities.contains(&MetricGranularity::Minute) {
+        return Err(anyhow!("Minute data is collected, not aggregated; only hour and day can be resynced"));
+    }
+    granularities.sort_by_key(|g| matches!(g, MetricGranularity::Day));
+    granularities.dedup();
+
+    let keys = match req.keys {
+        Some(keys) => {
+            for key in &keys {
+                validate_key(key)?;
+            }
+            keys
+        }
+        None => list_keys(&req.scope)?,
+    };
+
+    let windows_per_key: usize = granularities
+        .iter()
+        .map(|g| windows(g, req.start, req.end).len())
+        .sum();
+
+    let job = ResyncJobDto {
+        id: Uuid::new_v4().to_string(),
+        state: JobState::Queued,
+        scope: req.scope.clone(),
+        start: req.start,
+        end: req.end,
+        granularities: granularities.clone(),
+        keys_total: keys.len(),
+        keys_done: 0,
+        windows_total: windows_per_key * keys.len(),
+        windows_done: 0,
+        rows_written: 0,
+        errors: Vec::new(),
+        created_at: Utc::now(),
+        finished_at: None,
+    };
+    let id = job.id.clone();
+    insert_job(job.clone());
+
+    tokio::spawn(async move {
+        update_job(&id, |j| j.state = JobState::Running);
+
+        let job_id = id.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            run_job(&job_id, &req.scope, &keys, &granularities, req.start, req.end)
+        })
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|r| r);
+
+        update_job(&id, |j| {
+            j.finished_at = Some(Utc::now());
+            j.state = match &result {
+                Ok(()) => JobState::Completed,
+                Err(e) => {
+                    j.errors.push(e.to_string());
+                    JobState::Failed
+                }
+            };
+        });
+        match result {
+            Ok(()) => debug!("✅ Resync job {} completed", id),
+            Err(e) => error!("❌ Resync job {} failed: {}", id, e),
+        }
+    });
+
+    Ok(json!({
+        "message": "Resync started",
+        "job": job,
+    }))
+}
+
+/// Returns the state of one resync job.
+pub async fn get_resync_job(id: &str) -> Result<Value> {
+    let jobs = RESYNC_JOBS.lock().map_err(|_| anyhow!("Resync job registry poisoned"))?;
+    let job = jobs
+        .get(id)
+        .ok_or_else(|| anyhow!("Resync job '{}' not found", id))?;
+    Ok(json!(job))
 }
 
+/// Lists known resync jobs, newest first.
+pub async fn list_resync_jobs() -> Result<Value> {
+    let jobs = RESYNC_JOBS.lock().map_err(|_| anyhow!("Resync job registry poisoned"))?;
+    let mut list: Vec<&ResyncJobDto> = jobs.values().collect();
+    list.sort_by_key(|j| std::cmp::Reverse(j.created_at));
+    Ok(json!({
+        "total": list.len(),
+        "jobs": list,
+    }))
+}
+
+fn run_job(
+    id: &str,
+    scope: &MetricScope,
+    keys: &[String],
+    granularities: &[MetricGranularity],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<()> {
+    let backend = metric_backend()?;
+    match scope {
+        MetricScope::Node => resync_keys(
+            id,
+            backend.node(MetricTier::Hour).as_ref(),
+            backend.node(MetricTier::Day).as_ref(),
+            keys,
+            granularities,
+            start,
+            end,
+        ),
+        MetricScope::Pod => resync_keys(
+            id,
+            backend.pod(MetricTier::Hour).as_ref(),
+            backend.pod(MetricTier::Day).as_ref(),
+            keys,
+            granularities,
+            start,
+            end,
+        ),
+        MetricScope::Container => resync_keys(
+            id,
+            backend.container(MetricTier::Hour).as_ref(),
+            backend.container(MetricTier::Day).as_ref(),
+            keys,
+            granularities,
+            start,
+            end,
+        ),
+        MetricScope::Pvc => resync_keys(
+            id,
+            backend.pvc(MetricTier::Hour).as_ref(),
+            backend.pvc(MetricTier::Day).as_ref(),
+            keys,
+            granularities,
+            start,
+            end,
+        ),
+        MetricScope::NodeNetwork => resync_keys(
+            id,
+            backend.node_network(MetricTier::Hour).as_ref(),
+            backend.node_network(MetricTier::Day).as_ref(),
+            keys,
+            granularities,
+            start,
+            end,
+        ),
+        MetricScope::NodeSystem => resync_keys(
+            id,
+            backend.node_system(MetricTier::Hour).as_ref(),
+            backend.node_system(MetricTier::Day).as_ref(),
+            keys,
+            granularities,
+            start,
+            end,
+        ),
+        other => Err(anyhow!("Resync is not supported for scope {:?}", other)),
+    }
+}
+
+fn resync_keys<T>(
+    id: &str,
+    hour: &dyn MetricFsAdapterBase<T>,
+    day: &dyn MetricFsAdapterBase<T>,
+    keys: &[String],
+    granularities: &[MetricGranularity],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<()> {
+    for key in keys {
+        // Runs alongside the scheduler but never inside a backup/restore. The
+        // lock is taken per key so a backup waits for one key, not the job.
+        let _guard = blocking_lock_for_write();
+        for granularity in granularities {
+            let adapter = match granularity {
+                MetricGranularity::Day => day,
+                _ => hour,
+            };
+            let windows = windows(granularity, start, end);
+
+            match adapter.upsert_aggregated(key, &windows) {
+                Ok(rows) => update_job(id, |j| {
+                    j.windows_done += windows.len();
+                    j.rows_written += rows;
+                }),
+                Err(e) => {
+                    error!("⚠️ Resync of {:?} '{}' failed: {}", granularity, key, e);
+                    update_job(id, |j| {
+                        j.windows_done += windows.len();
+                        if j.errors.len() < MAX_JOB_ERRORS {
+                            j.errors.push(format!("{} ({:?}): {}", key, granularity, e));
+                        }
+                    });
+                }
+            }
+        }
+        update_job(id, |j| j.keys_done += 1);
+    }
+    Ok(())
+}
+
+/// Aligned aggregation windows of `granularity` covering `[start, end)`.
+fn windows(
+    granularity: &MetricGranularity,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let step = match granularity {
+        MetricGranularity::Day => Duration::days(1),
+        _ => Duration::hours(1),
+    };
+    let mut cursor = start.duration_trunc(step).unwrap_or(start);
+    let mut out = Vec::new();
+    while cursor < end {
+        out.push((cursor, cursor + step));
+        cursor += step;
+    }
+    out
+}
+
+fn list_keys(scope: &MetricScope) -> Result<Vec<String>> {
+    let kind = match scope {
+        MetricScope::Node => MetricObjectKind::Node,
+        MetricScope::Pod => MetricObjectKind::Pod,
+        MetricScope::Container => MetricObjectKind::Container,
+        MetricScope::Pvc => MetricObjectKind::Pvc,
+        MetricScope::NodeNetwork => MetricObjectKind::NodeNetwork,
+        MetricScope::NodeSystem => MetricObjectKind::NodeSystem,
+        other => return Err(anyhow!("Resync is not supported for scope {:?}", other)),
+    };
+    metric_backend()?.keys(kind)
+}
+
+fn insert_job(job: ResyncJobDto) {
+    let Ok(mut jobs) = RESYNC_JOBS.lock() else { return };
+
+    // Drop the oldest finished jobs once the registry is full.
+    while jobs.len() >= MAX_KEPT_JOBS {
+        let oldest = jobs
+            .values()
+            .filter(|j| j.finished_at.is_some())
+            .min_by_key(|j| j.created_at)
+            .map(|j| j.id.clone());
+        match oldest {
+            Some(id) => jobs.remove(&id),
+            None => break,
+        };
+    }
+    jobs.insert(job.id.clone(), job);
+}
+
+fn update_job(id: &str, f: impl FnOnce(&mut ResyncJobDto)) {
+    if let Ok(mut jobs) = RESYNC_JOBS.lock() {
+        if let Some(job) = jobs.get_mut(id) {
+            f(job);
+        }
+    }
+}
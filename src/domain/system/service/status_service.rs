@@ -1,7 +1,42 @@
 use anyhow::Result;
 use serde_json::{json, Value};
 
+use crate::core::client::k8s::scrape_status::scrape_statuses;
+use crate::core::persistence::quota::storage_quota_manager::StorageQuotaManager;
+use crate::core::persistence::quota::storage_usage::StorageUsage;
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::setting::info_setting_fs_adapter::InfoSettingFsAdapter;
+
 pub async fn status() -> Result<Value> {
-    Ok(json!({"status": "ok"}))
+    let settings = InfoSettingFsAdapter.read()?;
+    let usage = tokio::task::spawn_blocking(StorageUsage::measure).await??;
+
+    let limit_bytes = StorageQuotaManager::new(settings.max_storage_gb).map(|m| m.limit_bytes());
+    let usage_percent = limit_bytes.map(|limit| usage.quota_bytes() as f64 * 100.0 / limit as f64);
+    let over_quota = limit_bytes.is_some_and(|limit| usage.quota_bytes() > limit);
+
+    Ok(json!({
+        "status": "ok",
+        "storage": {
+            "max_storage_gb": settings.max_storage_gb,
+            "limit_bytes": limit_bytes,
+            "usage_percent": usage_percent,
+            "over_quota": over_quota,
+            "usage": usage,
+        },
+    }))
 }
 
+/// Latest kubelet summary scrape of every node.
+pub async fn scrape_status() -> Result<Value> {
+    let nodes = scrape_statuses();
+    let failed = nodes.iter().filter(|n| !n.success).count();
+    let slowest_ms = nodes.iter().map(|n| n.duration_ms).max();
+
+    Ok(json!({
+        "node_count": nodes.len(),
+        "failed_count": failed,
+        "slowest_ms": slowest_ms,
+        "nodes": nodes,
+    }))
+}
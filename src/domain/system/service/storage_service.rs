@@ -0,0 +1,218 @@
+use anyhow::{anyhow, Result};
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tracing::{error, info};
+
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{
+    MetricBackendKind, MetricObjectKind, MetricStorageBackend,
+};
+use crate::core::persistence::metrics::backend::{metric_backend, open_metric_backend};
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::core::persistence::quota::storage_quota_manager::forget_measured_usage;
+use crate::core::persistence::storage_lock::lock_exclusive;
+use crate::core::persistence::storage_path::get_rustcost_metric_db_path;
+use crate::domain::metric::k8s::common::dto::MetricScope;
+use crate::domain::system::dto::StorageMigrateRequest;
+
+/// Per-object errors kept in the migration report; further ones are only logged.
+const MAX_REPORTED_ERRORS: usize = 100;
+
+/// Active metric storage backend.
+pub async fn storage_backend() -> Result<Value> {
+    let backend = metric_backend()?;
+    Ok(json!({
+        "backend": backend.kind(),
+        "db_path": get_rustcost_metric_db_path(),
+    }))
+}
+
+/// Copies every stored row from one backend into another.
+///
+/// Takes the exclusive storage lock, so collection and aggregation pause until
+/// the copy is done. Existing rows in the target with the same timestamp are
+/// replaced, so an interrupted migration can simply be run again. The active
+/// backend does not change; switch `RUSTCOST_METRIC_BACKEND` and restart.
+pub async fn migrate(req: StorageMigrateRequest) -> Result<Value> {
+    if req.from == req.to {
+        return Err(anyhow!("Source and target backend are the same"));
+    }
+    if req.from == MetricBackendKind::Memory || req.to == MetricBackendKind::Memory {
+        return Err(anyhow!("The memory backend cannot be migrated from or to"));
+    }
+
+    let kinds = match &req.scopes {
+        Some(scopes) => scopes
+            .iter()
+            .map(|scope| match scope {
+                MetricScope::Node => Ok(MetricObjectKind::Node),
+                MetricScope::Pod => Ok(MetricObjectKind::Pod),
+                MetricScope::Container => Ok(MetricObjectKind::Container),
+                MetricScope::Pvc => Ok(MetricObjectKind::Pvc),
+                MetricScope::NodeNetwork => Ok(MetricObjectKind::NodeNetwork),
+                MetricScope::NodeSystem => Ok(MetricObjectKind::NodeSystem),
+                other => Err(anyhow!("Migration is not supported for scope {:?}", other)),
+            })
+            .collect::<Result<Vec<_>>>()?,
+        None => MetricObjectKind::ALL.to_vec(),
+    };
+
+    let _guard = lock_exclusive().await;
+    let from = open_metric_backend(req.from)?;
+    let to = open_metric_backend(req.to)?;
+
+    info!("🚚 Migrating metrics from {} to {}", req.from.as_str(), req.to.as_str());
+    let report = tokio::task::spawn_blocking(move || migrate_kinds(from.as_ref(), to.as_ref(), &kinds)).await?;
+    forget_measured_usage();
+    info!(
+        "✅ Migrated {} rows of {} objects from {} to {}",
+        report.rows, report.objects, req.from.as_str(), req.to.as_str()
+    );
+
+    Ok(json!({
+        "from": req.from,
+        "to": req.to,
+        "objects_copied": report.objects,
+        "rows_copied": report.rows,
+        "error_count": report.error_count,
+        "errors": report.errors,
+    }))
+}
+
+#[derive(Default)]
+struct MigrationReport {
+    objects: usize,
+    rows: usize,
+    error_count: usize,
+    errors: Vec<String>,
+}
+
+impl MigrationReport {
+    fn record_error(&mut self, message: String) {
+        error!("⚠️ Migration: {}", message);
+        self.error_count += 1;
+        if self.errors.len() < MAX_REPORTED_ERRORS {
+            self.errors.push(message);
+        }
+    }
+}
+
+fn migrate_kinds(
+    from: &dyn MetricStorageBackend,
+    to: &dyn MetricStorageBackend,
+    kinds: &[MetricObjectKind],
+) -> MigrationReport {
+    let mut report = MigrationReport::default();
+
+    for kind in kinds {
+        let keys = match from.keys(*kind) {
+            Ok(keys) => keys,
+            Err(e) => {
+                report.record_error(format!("{}: failed to list objects: {}", kind.as_str(), e));
+                continue;
+            }
+        };
+
+        for key in &keys {
+            for tier in MetricTier::ALL {
+                let copied = match kind {
+                    MetricObjectKind::Node => copy_rows(from, from.node(tier), to.node(tier), *kind, tier, key),
+                    MetricObjectKind::Pod => copy_rows(from, from.pod(tier), to.pod(tier), *kind, tier, key),
+                    MetricObjectKind::Container => {
+                        copy_rows(from, from.container(tier), to.container(tier), *kind, tier, key)
+                    }
+                    MetricObjectKind::Pvc => copy_rows(from, from.pvc(tier), to.pvc(tier), *kind, tier, key),
+                    MetricObjectKind::NodeNetwork => {
+                        copy_rows(from, from.node_network(tier), to.node_network(tier), *kind, tier, key)
+                    }
+                    MetricObjectKind::NodeSystem => {
+                        copy_rows(from, from.node_system(tier), to.node_system(tier), *kind, tier, key)
+                    }
+                };
+                match copied {
+                    Ok(rows) => report.rows += rows,
+                    Err(e) => report.record_error(format!("{} '{}' ({:?}): {}", kind.as_str(), key, tier, e)),
+                }
+            }
+            report.objects += 1;
+        }
+    }
+    report
+}
+
+/// Copies all rows of one object and tier; returns the number of rows copied.
+fn copy_rows<T>(
+    from: &dyn MetricStorageBackend,
+    source: Arc<dyn MetricFsAdapterBase<T>>,
+    target: Arc<dyn MetricFsAdapterBase<T>>,
+    kind: MetricObjectKind,
+    tier: MetricTier,
+    key: &str,
+) -> Result<usize> {
+    let Some(first) = from.first_time(kind, tier, key)? else {
+        return Ok(0);
+    };
+    // Rows are never stamped in the future, but leave room for clock skew.
+    let rows = source.get_row_between(first, Utc::now() + Duration::days(1), key, None, None)?;
+    if !rows.is_empty() {
+        target.upsert_rows(key, &rows)?;
+    }
+    Ok(rows.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::backend::metric_memory_backend::MetricMemoryBackend;
+    use crate::core::persistence::metrics::backend::metric_sqlite_backend::MetricSqliteBackend;
+    use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+    use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+    use crate::core::persistence::metrics::segment::test_support::scratch_dir;
+    use chrono::DateTime;
+
+    fn pod_rows(backend: &dyn MetricStorageBackend) -> Vec<MetricPodEntity> {
+        let start = DateTime::from_timestamp(0, 0).unwrap();
+        backend.pod(MetricTier::Minute).get_row_between(start, Utc::now(), "uid-1", None, None).unwrap()
+    }
+
+    #[test]
+    fn rows_round_trip_through_a_backend_switch() {
+        let dir = scratch_dir();
+        let memory = MetricMemoryBackend::default();
+        let start = DateTime::from_timestamp(1_735_689_600, 0).unwrap();
+        for i in 0..3u64 {
+            let row = MetricPodEntity {
+                time: start + Duration::minutes(i as i64),
+                cpu_usage_nano_cores: Some(100 + i),
+                memory_working_set_bytes: Some(1024 * (i + 1)),
+                ..Default::default()
+            };
+            memory.pod(MetricTier::Minute).append_row("uid-1", &row).unwrap();
+        }
+        let node_row = MetricNodeEntity {
+            time: start,
+            cpu_usage_nano_cores: Some(4_000),
+            ..Default::default()
+        };
+        memory.node(MetricTier::Hour).append_row("node-a", &node_row).unwrap();
+
+        let sqlite = MetricSqliteBackend::open(&dir.join("metrics.db")).unwrap();
+        let there = migrate_kinds(&memory, &sqlite, &MetricObjectKind::ALL);
+        let back = MetricMemoryBackend::default();
+        let again = migrate_kinds(&sqlite, &back, &MetricObjectKind::ALL);
+
+        assert_eq!((there.rows, there.objects, there.error_count), (4, 2, 0));
+        assert_eq!((again.rows, again.objects, again.error_count), (4, 2, 0));
+        assert_eq!(
+            serde_json::to_value(pod_rows(&back)).unwrap(),
+            serde_json::to_value(pod_rows(&memory)).unwrap()
+        );
+        let nodes = back
+            .node(MetricTier::Hour)
+            .get_row_between(start, start, "node-a", None, None)
+            .unwrap();
+        assert_eq!(serde_json::to_value(nodes).unwrap(), serde_json::to_value(vec![node_row]).unwrap());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,10 +1,20 @@
 //! Orchestration usecases for system operations
 
 use anyhow::Result;
+use axum::body::BodyDataStream;
+use std::path::PathBuf;
 use serde_json::{json, Value};
 
+use crate::app_state::AppState;
+use crate::domain::system::dto::{ArchiveListQuery, ArchiveRestoreRequest, BackupRestoreRequest, ExportRequest, ResyncRequest, StorageMigrateRequest};
+use crate::domain::system::service::{archive_service, backup_service, export_service, fsck_service, index_service, resync_service, status_service, storage_service};
+
 pub async fn status() -> Result<Value> {
-    Ok(json!({ "status": "ok" }))
+    status_service::status().await
+}
+
+pub async fn scrape_status() -> Result<Value> {
+    status_service::scrape_status().await
 }
 
 pub async fn health() -> Result<Value> {
@@ -12,12 +22,73 @@ pub async fn health() -> Result<Value> {
 }
 
 pub async fn backup() -> Result<Value> {
-    // Placeholder: trigger backup workflow
-    Ok(json!({ "backup": "scheduled" }))
+    backup_service::backup().await
+}
+
+pub async fn list_backups() -> Result<Value> {
+    backup_service::list_backups().await
+}
+
+pub fn backup_file_path(name: &str) -> Result<PathBuf> {
+    backup_service::backup_file_path(name)
+}
+
+pub async fn upload_backup(name: String, body: BodyDataStream) -> Result<Value> {
+    backup_service::upload_backup(name, body).await
+}
+
+pub async fn restore_backup(req: BackupRestoreRequest) -> Result<Value> {
+    backup_service::restore_backup(req).await
+}
+
+pub async fn resync(req: ResyncRequest) -> Result<Value> {
+    resync_service::resync(req).await
+}
+
+pub async fn get_resync_job(id: String) -> Result<Value> {
+    resync_service::get_resync_job(&id).await
+}
+
+pub async fn list_resync_jobs() -> Result<Value> {
+    resync_service::list_resync_jobs().await
 }
 
-pub async fn resync() -> Result<Value> {
-    // Placeholder: trigger resync workflow
-    Ok(json!({ "resync": "started" }))
+pub async fn export(state: &AppState, req: ExportRequest) -> Result<Value> {
+    export_service::export(state, req).await
 }
 
+pub async fn get_export_job(id: String) -> Result<Value> {
+    export_service::get_export_job(&id).await
+}
+
+pub async fn list_export_jobs() -> Result<Value> {
+    export_service::list_export_jobs().await
+}
+
+pub async fn list_archives(q: ArchiveListQuery) -> Result<Value> {
+    archive_service::list_archives(q).await
+}
+
+pub async fn restore_archive(req: ArchiveRestoreRequest) -> Result<Value> {
+    archive_service::restore_archive(req).await
+}
+
+pub async fn rebuild_indexes() -> Result<Value> {
+    index_service::rebuild_indexes().await
+}
+
+pub async fn fsck() -> Result<Value> {
+    fsck_service::fsck().await
+}
+
+pub async fn repair_torn_tails() -> Result<Value> {
+    fsck_service::repair_torn_tails().await
+}
+
+pub async fn storage_backend() -> Result<Value> {
+    storage_service::storage_backend().await
+}
+
+pub async fn migrate_storage(req: StorageMigrateRequest) -> Result<Value> {
+    storage_service::migrate(req).await
+}
@@ -2,6 +2,7 @@ use std::net::SocketAddr;
 use tokio::sync::broadcast;
 
 // --- Modules ---
+mod app_state;
 mod config;
 mod logging;
 mod domain;
@@ -15,6 +16,7 @@ pub mod core;
 // --- Imports ---
 use crate::config::config;
 // &'fixed Config
+use crate::app_state::AppState;
 use crate::routes::app_router;
 use crate::scheduler::schedule::{run_day_loop, run_hour_loop, run_minute_loop};
 use crate::scheduler::scheduler_start_all_tasks;
@@ -35,7 +37,6 @@ async fn main() {
 
 /// ✅ Run the Axum server
 async fn run_server(app_config: &crate::config::Config) {
-    let app = app_router();
     let address = format!("{}:{}", app_config.server_host(), app_config.server_port());
     let socket_addr: SocketAddr = address.parse().expect("Invalid socket address");
     let rustcost_debug_mode = std::env::var("RUSTCOST_DEBUG_MODE")
@@ -48,6 +49,24 @@ async fn run_server(app_config: &crate::config::Config) {
         .await
         .expect("Failed to bind");
 
+    // Open the metric storage backend before anything reads or writes metrics
+    let state = crate::core::persistence::metrics::backend::init_metric_backend().and_then(|kind| {
+        info!("🗄️ Metric storage backend: {}", kind.as_str());
+        AppState::from_env()
+    });
+    let app = match state {
+        Ok(state) => app_router(state),
+        Err(e) => {
+            error!("❌ Failed to open metric storage backend: {}", e);
+            return;
+        }
+    };
+
+    // Cut partial rows left by a crash before anything appends to them again
+    if let Err(e) = crate::domain::system::usecase::repair_torn_tails().await {
+        error!("❌ Torn tail repair failed: {}", e);
+    }
+
     // Keep the sender ALIVE for whole function lifetime
     let (shutdown_tx, mut shutdown_rx) = broadcast::channel::<()>(16);
 
@@ -88,4 +107,8 @@ async fn run_server(app_config: &crate::config::Config) {
         }
     }
 
+    // Wait for in-flight writes, then write out rows still buffered by the collectors
+    let _guard = crate::core::persistence::storage_lock::lock_exclusive().await;
+    info!("💾 Buffered metric rows flushed");
+
 }
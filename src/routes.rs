@@ -6,9 +6,11 @@ use axum::{
 };
 use tower_http::cors::CorsLayer;
 
+use crate::app_state::AppState;
+
 
 /// Build the main application router
-pub fn app_router() -> Router {
+pub fn app_router(state: AppState) -> Router {
     // Metrics, Info, System subrouters live under /api/v1
     let api_v1 = Router::new()
         .nest("/metrics", crate::api::routes::metrics_routes::metrics_routes())
@@ -26,6 +28,7 @@ pub fn app_router() -> Router {
         // Fallback handler for 404
         .fallback(handler_404)
         // Attach shared application state ONCE here
+        .with_state(state)
         // ✅ Apply CORS layer to all routes
         .layer(CorsLayer::very_permissive())
 }
@@ -47,3 +50,93 @@ async fn handler_404() -> impl IntoResponse {
         "The requested resource was not found",
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use chrono::{Duration, DurationRound, Utc};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::core::client::k8s::k8s_client_trait::K8sResourceKind;
+    use crate::core::client::k8s::k8s_memory_client::K8sMemoryClient;
+    use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+    use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+    fn cluster() -> Arc<K8sMemoryClient> {
+        let k8s = K8sMemoryClient::default();
+        k8s.set_nodes(vec![serde_json::from_value(json!({
+            "metadata": { "name": "node-a" },
+            "status": { "allocatable": { "cpu": "4", "memory": "8Gi" } }
+        }))
+        .unwrap()]);
+        k8s.set_resources(
+            K8sResourceKind::Namespace,
+            json!({ "items": [{ "metadata": { "name": "default" } }] }),
+        );
+        Arc::new(k8s)
+    }
+
+    async fn get_json(state: &AppState, uri: &str) -> Value {
+        let response = app_router(state.clone())
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn info_endpoints_read_the_injected_cluster() {
+        let state = AppState::in_memory(cluster());
+
+        let namespaces = get_json(&state, "/api/v1/info/k8s/namespaces").await;
+        assert_eq!(namespaces["is_successful"], true);
+        assert_eq!(namespaces["data"]["items"][0]["metadata"]["name"], "default");
+
+        let nodes = get_json(&state, "/api/v1/info/k8s/nodes").await;
+        assert_eq!(nodes["data"][0]["node_name"], "node-a");
+
+        // Listing cached the node, so the single-node endpoint finds it.
+        let node = get_json(&state, "/api/v1/info/k8s/nodes/node-a").await;
+        assert_eq!(node["data"]["node_name"], "node-a");
+
+        let missing = get_json(&state, "/api/v1/info/k8s/nodes/node-b").await;
+        assert_eq!(missing["is_successful"], false);
+    }
+
+    #[tokio::test]
+    async fn node_metrics_come_from_the_injected_storage() {
+        let state = AppState::in_memory(cluster());
+        // Node metrics are only served for nodes already in the info store.
+        get_json(&state, "/api/v1/info/k8s/nodes").await;
+        let now = Utc::now().duration_trunc(Duration::minutes(1)).unwrap();
+
+        let minute = state.metrics.node(MetricTier::Minute);
+        for i in 0..3u64 {
+            let row = MetricNodeEntity {
+                time: now - Duration::minutes(i as i64),
+                cpu_usage_nano_cores: Some(100 * (i + 1)),
+                ..Default::default()
+            };
+            minute.append_row("node-a", &row).unwrap();
+        }
+
+        let uri = format!(
+            "/api/v1/metrics/nodes/node-a/raw?start={}&end={}&granularity=minute",
+            (now - Duration::minutes(10)).naive_utc().format("%Y-%m-%dT%H:%M:%S"),
+            (now + Duration::minutes(1)).naive_utc().format("%Y-%m-%dT%H:%M:%S"),
+        );
+        let body = get_json(&state, &uri).await;
+        assert_eq!(body["is_successful"], true, "{}", body);
+
+        let points = body["data"]["series"][0]["points"].as_array().unwrap();
+        let cpu: Vec<_> = points.iter().map(|p| p["cpu_memory"]["cpu_usage_nano_cores"].clone()).collect();
+        assert_eq!(cpu, vec![json!(300.0), json!(200.0), json!(100.0)]);
+    }
+}
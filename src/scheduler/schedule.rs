@@ -1,12 +1,17 @@
-use super::tasks::{day_task, hour_task, minute_task};
+use super::tasks::{day_task, hour_task, minute_task, scrape_interval_sec};
 // src/scheduler/schedule.rs
 use anyhow::Result;
 use chrono::{Timelike, Utc};
 use tokio::sync::broadcast;
-use tokio::time::{interval, sleep, Duration, MissedTickBehavior};
+use tokio::time::{interval, sleep, Duration, Instant, MissedTickBehavior};
 use tracing::{debug, error, info, warn};
 use chrono::{Duration as ChronoDuration};
 
+use crate::core::persistence::metrics::segment::write_buffer::flush_all;
+
+/// How long appended metric rows may stay buffered before they are written out.
+const METRIC_FLUSH_INTERVAL: Duration = Duration::from_secs(15);
+
 /// Entry point — start all periodic background tasks.
 /// Call this once from your main() function.
 pub async fn scheduler_start_all_tasks(mut shutdown: broadcast::Receiver<()>) {
@@ -15,24 +20,34 @@ pub async fn scheduler_start_all_tasks(mut shutdown: broadcast::Receiver<()>) {
     let mut s1 = shutdown.resubscribe();
     let mut s2 = shutdown.resubscribe();
     let mut s3 = shutdown.resubscribe();
+    let mut s4 = shutdown.resubscribe();
 
     tokio::spawn(async move { run_minute_loop(&mut s1).await });
     tokio::spawn(async move { run_hour_loop(&mut s2).await });
     tokio::spawn(async move { run_day_loop(&mut s3).await });
+    tokio::spawn(async move { run_flush_loop(&mut s4).await });
 
     // Keep the function alive until shutdown signal is received
     let _ = shutdown.recv().await;
 }
-/// Runs every aligned minute (e.g., 12:00:00, 12:01:00 …)
+/// Runs the collectors every `scrape_interval_sec`, aligned to multiples of the
+/// interval (12:00:00, 12:00:15 … for 15s; 12:00:00, 12:01:00 … for 60s).
+///
+/// The interval is re-read before every wait, so a changed setting applies
+/// without a restart. A run that overruns the interval skips the missed ticks.
 pub async fn run_minute_loop(shutdown: &mut broadcast::Receiver<()>) {
-    align_to_next_minute().await;
-    let mut ticker = interval(Duration::from_secs(60));
-    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut current = None;
 
     loop {
+        let every = scrape_interval_sec();
+        if current != Some(every) {
+            info!(interval_sec = every, "Collector scrape interval");
+            current = Some(every);
+        }
+
         tokio::select! {
-            _ = ticker.tick() => {
-                if let Err(e) = retry_task("minute", minute_task).await {
+            _ = sleep(until_next_tick(every)) => {
+                if let Err(e) = retry_task("minute", Duration::from_secs(u64::from(every)), minute_task).await {
                     error!(?e, "minute_task failed");
                 }
             }
@@ -54,7 +69,7 @@ pub async fn run_hour_loop(shutdown: &mut broadcast::Receiver<()>) {
     loop {
         tokio::select! {
             _ = ticker.tick() => {
-                if let Err(e) = retry_task("hour", hour_task).await {
+                if let Err(e) = retry_task("hour", Duration::from_secs(3600), hour_task).await {
                     error!(?e, "hour_task failed");
                 }
             }
@@ -76,7 +91,7 @@ pub async fn run_day_loop(shutdown: &mut broadcast::Receiver<()>) {
     loop {
         tokio::select! {
             _ = ticker.tick() => {
-                if let Err(e) = retry_task("day", day_task).await {
+                if let Err(e) = retry_task("day", Duration::from_secs(86_400), day_task).await {
                     error!(?e, "day_task failed");
                 }
             }
@@ -88,17 +103,40 @@ pub async fn run_day_loop(shutdown: &mut broadcast::Receiver<()>) {
     }
 }
 
+/// Writes out buffered metric rows every [`METRIC_FLUSH_INTERVAL`].
+/// The final flush on shutdown is done by `run_server`.
+pub async fn run_flush_loop(shutdown: &mut broadcast::Receiver<()>) {
+    let mut ticker = interval(METRIC_FLUSH_INTERVAL);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                match tokio::task::spawn_blocking(flush_all).await {
+                    Ok(Ok(rows)) if rows > 0 => debug!(rows, "Flushed buffered metric rows"),
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => error!(?e, "Metric flush failed"),
+                    Err(e) => error!(?e, "Metric flush task panicked"),
+                }
+            }
+            _ = shutdown.recv() => {
+                info!("Flush loop shutting down");
+                break;
+            }
+        }
+    }
+}
+
 //
 // Alignment helpers
 //
 
-async fn align_to_next_minute() {
-    let now = Utc::now();
-    let wait = 60 - now.second() as u64;
-    if wait < 60 {
-        info!(wait_sec = wait, "Aligning to next minute");
-        sleep(Duration::from_secs(wait)).await;
-    }
+/// Time until the next multiple of `every_sec` seconds since the epoch.
+fn until_next_tick(every_sec: u32) -> Duration {
+    let every_ms = i64::from(every_sec.max(1)) * 1000;
+    let now_ms = Utc::now().timestamp_millis();
+    let next_ms = (now_ms / every_ms + 1) * every_ms;
+    Duration::from_millis((next_ms - now_ms) as u64)
 }
 
 /// Aligns to next full hour + 30 seconds
@@ -148,15 +186,19 @@ async fn align_to_next_midnight_plus_30m30s() {
 }
 
 //
-// Retry wrapper with simple backoff
+// Retry wrapper with backoff scaled to the task period
 //
 
-async fn retry_task<Fut, F>(name: &str, task: F) -> Result<()>
+/// Runs `task`, retrying a failure after 1/60 and then 1/20 of `period` (1s and
+/// 3s for a minute). A retry that would run into the next tick is skipped; that
+/// tick runs the task again anyway.
+async fn retry_task<Fut, F>(name: &str, period: Duration, task: F) -> Result<()>
 where
     F: Fn() -> Fut + Send + Sync + 'static,
     Fut: std::future::Future<Output = Result<()>> + Send,
 {
-    let retries = [1u64, 3, 10]; // seconds between retries
+    let next_tick = Instant::now() + period;
+    let retries = [period / 60, period / 20, period / 6]; // delays between retries
     for (i, delay) in retries.iter().enumerate() {
         debug!(task = name, attempt = i + 1, "Task start");
         match task().await {
@@ -166,8 +208,8 @@ where
             }
             Err(e) => {
                 warn!(task = name, attempt = i + 1, ?e, "Task failed");
-                if i < retries.len() - 1 {
-                    sleep(Duration::from_secs(*delay)).await;
+                if i < retries.len() - 1 && Instant::now() + *delay < next_tick {
+                    sleep(*delay).await;
                 } else {
                     return Err(e);
                 }
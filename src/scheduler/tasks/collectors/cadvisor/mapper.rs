@@ -0,0 +1,28 @@
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::scheduler::tasks::collectors::cadvisor::models::CadvisorStats;
+
+/// Adds the cAdvisor stats of a container to the row built from its summary.
+///
+/// Throttling only comes from cAdvisor; other values fill gaps the summary left.
+pub fn merge_into_container(row: &mut MetricContainerEntity, stats: &CadvisorStats) {
+    row.cpu_cfs_periods = stats.cpu_cfs_periods;
+    row.cpu_cfs_throttled_periods = stats.cpu_cfs_throttled_periods;
+    row.cpu_cfs_throttled_nano_seconds = stats.cpu_cfs_throttled_nano_seconds;
+
+    row.memory_working_set_bytes = row.memory_working_set_bytes.or(stats.memory_working_set_bytes);
+    row.fs_used_bytes = row.fs_used_bytes.or(stats.fs_usage_bytes);
+}
+
+/// Adds the cAdvisor stats of a pod to the row built from its summary.
+pub fn merge_into_pod(row: &mut MetricPodEntity, stats: &CadvisorStats) {
+    row.cpu_cfs_periods = stats.cpu_cfs_periods;
+    row.cpu_cfs_throttled_periods = stats.cpu_cfs_throttled_periods;
+    row.cpu_cfs_throttled_nano_seconds = stats.cpu_cfs_throttled_nano_seconds;
+
+    row.memory_working_set_bytes = row.memory_working_set_bytes.or(stats.memory_working_set_bytes);
+    row.network_physical_rx_bytes = row.network_physical_rx_bytes.or(stats.network_rx_bytes);
+    row.network_physical_tx_bytes = row.network_physical_tx_bytes.or(stats.network_tx_bytes);
+    row.network_physical_rx_errors = row.network_physical_rx_errors.or(stats.network_rx_errors);
+    row.network_physical_tx_errors = row.network_physical_tx_errors.or(stats.network_tx_errors);
+}
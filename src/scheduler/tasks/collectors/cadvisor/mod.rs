@@ -0,0 +1,8 @@
+/* Entry point */
+mod task;
+pub use task::run;
+
+/* Samples → per-container stats */
+pub mod models;
+pub mod mapper;
+pub mod repository;
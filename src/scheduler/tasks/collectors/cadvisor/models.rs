@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+/// cAdvisor stats of one cgroup (a container, or a pod as a whole).
+///
+/// Counters are cumulative, as scraped. Network counters are only set on pods,
+/// since all containers of a pod share its network namespace.
+#[derive(Debug, Clone, Default)]
+pub struct CadvisorStats {
+    // CPU throttling
+    pub cpu_cfs_periods: Option<u64>,
+    pub cpu_cfs_throttled_periods: Option<u64>,
+    pub cpu_cfs_throttled_nano_seconds: Option<u64>,
+
+    // Memory
+    pub memory_working_set_bytes: Option<u64>,
+
+    // Filesystem (summed over devices)
+    pub fs_usage_bytes: Option<u64>,
+
+    // Network (summed over interfaces)
+    pub network_rx_bytes: Option<u64>,
+    pub network_tx_bytes: Option<u64>,
+    pub network_rx_errors: Option<u64>,
+    pub network_tx_errors: Option<u64>,
+}
+
+/// Stats of one node's cAdvisor page.
+#[derive(Debug, Clone, Default)]
+pub struct CadvisorNodeStats {
+    /// Keyed like the container metrics, `<pod_uid>-<container_name>`.
+    pub containers: HashMap<String, CadvisorStats>,
+    /// Keyed by pod UID.
+    pub pods: HashMap<String, CadvisorStats>,
+}
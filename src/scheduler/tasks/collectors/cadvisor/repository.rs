@@ -0,0 +1,33 @@
+//! cAdvisor stats of the current tick, waiting for the k8s collector.
+//!
+//! The cAdvisor collector runs first and stores one entry per node; the k8s
+//! collector takes it when it writes that node's pod and container rows, so a
+//! node's stats are used at most once. Kept in memory only.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use crate::scheduler::tasks::collectors::cadvisor::models::CadvisorNodeStats;
+
+static PENDING_STATS: LazyLock<Mutex<HashMap<String, CadvisorNodeStats>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Replaces the pending stats of `node_name`.
+pub fn store_node_stats(node_name: &str, stats: CadvisorNodeStats) {
+    if let Ok(mut table) = PENDING_STATS.lock() {
+        table.insert(node_name.to_string(), stats);
+    }
+}
+
+/// Removes and returns the pending stats of `node_name`.
+pub fn take_node_stats(node_name: &str) -> Option<CadvisorNodeStats> {
+    PENDING_STATS.lock().ok()?.remove(node_name)
+}
+
+/// Drops stats left over from the previous tick (nodes whose summary failed),
+/// so a failed cAdvisor scrape never pairs old counters with a new summary.
+pub fn clear_pending() {
+    if let Ok(mut table) = PENDING_STATS.lock() {
+        table.clear();
+    }
+}
@@ -0,0 +1,221 @@
+use crate::core::client::k8s::client_k8s_node::{fetch_node_cadvisor_metrics, fetch_node_names};
+use crate::core::client::k8s::util::{build_client, read_token};
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::scheduler::tasks::collectors::cadvisor::models::{CadvisorNodeStats, CadvisorStats};
+use crate::scheduler::tasks::collectors::cadvisor::repository::{clear_pending, store_node_stats};
+use crate::scheduler::tasks::collectors::common::prometheus::{parse_text, PromSample};
+use crate::scheduler::tasks::collectors::k8s::{scrape_concurrency, scrape_timeout};
+use anyhow::{anyhow, Result};
+use futures_util::{stream, StreamExt};
+use std::collections::HashMap;
+use tracing::{debug, warn};
+
+const CFS_PERIODS: &str = "container_cpu_cfs_periods_total";
+const CFS_THROTTLED_PERIODS: &str = "container_cpu_cfs_throttled_periods_total";
+const CFS_THROTTLED_SECONDS: &str = "container_cpu_cfs_throttled_seconds_total";
+const MEMORY_WORKING_SET: &str = "container_memory_working_set_bytes";
+const FS_USAGE: &str = "container_fs_usage_bytes";
+const NETWORK_RX_BYTES: &str = "container_network_receive_bytes_total";
+const NETWORK_TX_BYTES: &str = "container_network_transmit_bytes_total";
+const NETWORK_RX_ERRORS: &str = "container_network_receive_errors_total";
+const NETWORK_TX_ERRORS: &str = "container_network_transmit_errors_total";
+
+fn is_wanted(name: &str) -> bool {
+    matches!(
+        name,
+        CFS_PERIODS
+            | CFS_THROTTLED_PERIODS
+            | CFS_THROTTLED_SECONDS
+            | MEMORY_WORKING_SET
+            | FS_USAGE
+            | NETWORK_RX_BYTES
+            | NETWORK_TX_BYTES
+            | NETWORK_RX_ERRORS
+            | NETWORK_TX_ERRORS
+    )
+}
+
+/// Collects CFS throttling and per-container usage from the kubelet's cAdvisor
+/// endpoint (`/metrics/cadvisor`), which the summary API does not expose.
+///
+/// Runs before the k8s collector, which merges the stats into the pod and
+/// container rows of the same tick. A node that fails is logged and skipped;
+/// its rows are written from the summary alone.
+pub async fn run(settings: &InfoSettingEntity) -> Result<()> {
+    debug!("Starting cAdvisor task...");
+    clear_pending();
+
+    let token = read_token()?;
+    let client = build_client()?;
+    let node_names = fetch_node_names(&token, &client).await?;
+
+    let timeout = scrape_timeout(settings);
+    let mut scrapes = stream::iter(node_names)
+        .map(|node_name| {
+            let (token, client) = (&token, &client);
+            async move {
+                let page = match tokio::time::timeout(
+                    timeout,
+                    fetch_node_cadvisor_metrics(token, client, &node_name),
+                )
+                .await
+                {
+                    Ok(result) => result,
+                    Err(_) => Err(anyhow!("timed out after {}s", timeout.as_secs())),
+                };
+                (node_name, page)
+            }
+        })
+        .buffer_unordered(scrape_concurrency());
+
+    while let Some((node_name, page)) = scrapes.next().await {
+        match page {
+            Ok(text) => {
+                let stats = build_node_stats(&parse_text(&text, is_wanted));
+                debug!(
+                    "cAdvisor stats of {}: {} container(s), {} pod(s)",
+                    node_name,
+                    stats.containers.len(),
+                    stats.pods.len()
+                );
+                store_node_stats(&node_name, stats);
+            }
+            Err(e) => warn!("⚠️ Failed to fetch cAdvisor metrics for {}: {:?}", node_name, e),
+        }
+    }
+    Ok(())
+}
+
+/// Pod UID in one cgroup path segment, for both cgroup drivers:
+/// `pod<uid>` (cgroupfs) and `kubepods-burstable-pod<uid_with_underscores>.slice` (systemd).
+fn pod_uid_of_segment(segment: &str) -> Option<String> {
+    let segment = segment.strip_suffix(".slice").unwrap_or(segment);
+    let (_, uid) = segment.rsplit_once("pod")?;
+    let uid = uid.replace('_', "-");
+    (uid.len() == 36 && uid.chars().all(|c| c.is_ascii_hexdigit() || c == '-')).then_some(uid)
+}
+
+/// Pod UID of a sample, from its cgroup path (`id` label), and whether the
+/// sample is of the pod cgroup itself rather than of a cgroup below it.
+fn locate(sample: &PromSample) -> Option<(String, bool)> {
+    let segments: Vec<&str> = sample.label("id")?.split('/').filter(|s| !s.is_empty()).collect();
+    let (pos, uid) = segments.iter().enumerate().find_map(|(i, s)| pod_uid_of_segment(s).map(|uid| (i, uid)))?;
+    Some((uid, pos + 1 == segments.len()))
+}
+
+fn add(field: &mut Option<u64>, value: Option<u64>) {
+    if let Some(v) = value {
+        *field = Some(field.unwrap_or(0) + v);
+    }
+}
+
+/// Groups the samples of one node by pod and container.
+///
+/// Samples of the pod cgroup go to the pod, samples of a named container to
+/// that container; the sandbox (`POD` or unnamed) cgroup is skipped. Network
+/// counters are per pod: every cgroup of a pod reports the same namespace, so
+/// each interface is counted once.
+fn build_node_stats(samples: &[PromSample]) -> CadvisorNodeStats {
+    let mut stats = CadvisorNodeStats::default();
+    let mut interfaces: HashMap<String, HashMap<String, CadvisorStats>> = HashMap::new();
+
+    for sample in samples {
+        let Some((pod_uid, is_pod)) = locate(sample) else { continue };
+        let value = sample.value_u64();
+
+        if let Some(interface) = sample.label("interface") {
+            let iface = interfaces.entry(pod_uid).or_default().entry(interface.to_string()).or_default();
+            match sample.name.as_str() {
+                NETWORK_RX_BYTES => iface.network_rx_bytes = value,
+                NETWORK_TX_BYTES => iface.network_tx_bytes = value,
+                NETWORK_RX_ERRORS => iface.network_rx_errors = value,
+                NETWORK_TX_ERRORS => iface.network_tx_errors = value,
+                _ => {}
+            }
+            continue;
+        }
+
+        let target = if is_pod {
+            stats.pods.entry(pod_uid).or_default()
+        } else {
+            match sample.label("container") {
+                Some(name) if !name.is_empty() && name != "POD" => {
+                    stats.containers.entry(format!("{}-{}", pod_uid, name)).or_default()
+                }
+                _ => continue,
+            }
+        };
+
+        match sample.name.as_str() {
+            CFS_PERIODS => target.cpu_cfs_periods = value,
+            CFS_THROTTLED_PERIODS => target.cpu_cfs_throttled_periods = value,
+            CFS_THROTTLED_SECONDS => {
+                target.cpu_cfs_throttled_nano_seconds = sample
+                    .value
+                    .is_finite()
+                    .then(|| (sample.value * 1_000_000_000.0).max(0.0) as u64)
+            }
+            MEMORY_WORKING_SET => target.memory_working_set_bytes = value,
+            FS_USAGE => add(&mut target.fs_usage_bytes, value),
+            _ => {}
+        }
+    }
+
+    for (pod_uid, by_interface) in interfaces {
+        let pod = stats.pods.entry(pod_uid).or_default();
+        for iface in by_interface.values() {
+            add(&mut pod.network_rx_bytes, iface.network_rx_bytes);
+            add(&mut pod.network_tx_bytes, iface.network_tx_bytes);
+            add(&mut pod.network_rx_errors, iface.network_rx_errors);
+            add(&mut pod.network_tx_errors, iface.network_tx_errors);
+        }
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UID: &str = "0f3c9a1e-2b4d-4c6e-8f01-23456789abcd";
+
+    fn sample(id: &str) -> PromSample {
+        PromSample {
+            name: MEMORY_WORKING_SET.to_string(),
+            labels: vec![("id".to_string(), id.to_string())],
+            value: 1.0,
+        }
+    }
+
+    #[test]
+    fn pod_uid_of_cgroupfs_segment() {
+        assert_eq!(pod_uid_of_segment(&format!("pod{}", UID)), Some(UID.to_string()));
+    }
+
+    #[test]
+    fn pod_uid_of_systemd_segment() {
+        let segment = format!("kubepods-burstable-pod{}.slice", UID.replace('-', "_"));
+        assert_eq!(pod_uid_of_segment(&segment), Some(UID.to_string()));
+    }
+
+    #[test]
+    fn other_segments_have_no_pod_uid() {
+        assert_eq!(pod_uid_of_segment("kubepods.slice"), None);
+        assert_eq!(pod_uid_of_segment("kubepods-burstable.slice"), None);
+        assert_eq!(pod_uid_of_segment("podnot-a-uid"), None);
+    }
+
+    #[test]
+    fn locate_tells_the_pod_cgroup_from_its_containers() {
+        let pod = format!("/kubepods/burstable/pod{}", UID);
+        assert_eq!(locate(&sample(&pod)), Some((UID.to_string(), true)));
+        assert_eq!(locate(&sample(&format!("{}/abc123", pod))), Some((UID.to_string(), false)));
+
+        let systemd = format!(
+            "/kubepods.slice/kubepods-besteffort.slice/kubepods-besteffort-pod{}.slice/cri-containerd-abc.scope",
+            UID.replace('-', "_")
+        );
+        assert_eq!(locate(&sample(&systemd)), Some((UID.to_string(), false)));
+        assert_eq!(locate(&sample("/system.slice/kubelet.service")), None);
+    }
+}
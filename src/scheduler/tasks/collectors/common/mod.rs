@@ -0,0 +1,2 @@
+/* Prometheus text format → samples, shared by the cAdvisor and DCGM collectors */
+pub(crate) mod prometheus;
@@ -0,0 +1,163 @@
+//! Parser for the Prometheus text exposition format (version 0.0.4).
+//!
+//! Only what cAdvisor and the DCGM exporter emit is handled: `# HELP` / `# TYPE`
+//! comments, untyped sample lines with optional labels and an optional
+//! timestamp. Malformed lines are skipped rather than failing the whole scrape.
+
+/// One sample line of a Prometheus text page.
+#[derive(Debug, Clone)]
+pub struct PromSample {
+    pub name: String,
+    pub labels: Vec<(String, String)>,
+    pub value: f64,
+}
+
+impl PromSample {
+    pub fn label(&self, key: &str) -> Option<&str> {
+        self.labels.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    /// Value as an integer; `NaN`, infinities and negatives are dropped.
+    pub fn value_u64(&self) -> Option<u64> {
+        (self.value.is_finite() && self.value >= 0.0).then_some(self.value as u64)
+    }
+}
+
+/// Parses every sample whose metric name passes `keep`.
+///
+/// cAdvisor pages run into megabytes; families that are not needed are
+/// dropped before their labels are decoded.
+pub fn parse_text(text: &str, keep: impl Fn(&str) -> bool) -> Vec<PromSample> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let name_end = line.find(|c: char| c == '{' || c.is_whitespace())?;
+            if !keep(&line[..name_end]) {
+                return None;
+            }
+            parse_line(line, name_end)
+        })
+        .collect()
+}
+
+fn parse_line(line: &str, name_end: usize) -> Option<PromSample> {
+    let name = &line[..name_end];
+    let mut rest = &line[name_end..];
+
+    let labels = if rest.starts_with('{') {
+        let (labels, after) = parse_labels(&rest[1..])?;
+        rest = after;
+        labels
+    } else {
+        Vec::new()
+    };
+
+    // The optional timestamp is not used: the stats are merged into the
+    // summary rows of the same tick, which carry their own time.
+    let mut fields = rest.split_whitespace();
+    let value = fields.next()?.parse::<f64>().ok()?;
+    if fields.next().is_some_and(|ts| ts.parse::<i64>().is_err()) {
+        return None;
+    }
+
+    Some(PromSample {
+        name: name.to_string(),
+        labels,
+        value,
+    })
+}
+
+/// Parses `name="value",...}` and returns the labels and the text after `}`.
+fn parse_labels(input: &str) -> Option<(Vec<(String, String)>, &str)> {
+    let mut labels = Vec::new();
+    let mut rest = input;
+
+    loop {
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix('}') {
+            return Some((labels, after));
+        }
+
+        let eq = rest.find('=')?;
+        let key = rest[..eq].trim().to_string();
+        rest = rest[eq + 1..].trim_start().strip_prefix('"')?;
+
+        // Values escape `\`, `"` and newlines with a backslash.
+        let mut value = String::new();
+        let mut chars = rest.char_indices();
+        let close = loop {
+            match chars.next()? {
+                (i, '"') => break i,
+                (_, '\\') => match chars.next()?.1 {
+                    'n' => value.push('\n'),
+                    c => value.push(c),
+                },
+                (_, c) => value.push(c),
+            }
+        };
+        labels.push((key, value));
+
+        rest = rest[close + 1..].trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_all(text: &str) -> Vec<PromSample> {
+        parse_text(text, |_| true)
+    }
+
+    #[test]
+    fn skips_comments_and_unwanted_families() {
+        let text = "# HELP up Whether the target is up\n# TYPE up gauge\nup 1\n\nother_metric 2\n";
+        let samples = parse_text(text, |name| name == "up");
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].name, "up");
+        assert_eq!(samples[0].value, 1.0);
+        assert!(samples[0].labels.is_empty());
+    }
+
+    #[test]
+    fn decodes_escapes_in_label_values() {
+        let samples = parse_all(r#"m{path="C:\\dir",msg="say \"hi\"\nbye"} 1"#);
+        assert_eq!(samples[0].label("path"), Some(r"C:\dir"));
+        assert_eq!(samples[0].label("msg"), Some("say \"hi\"\nbye"));
+    }
+
+    #[test]
+    fn label_values_may_contain_commas_and_braces() {
+        let samples = parse_all(r#"m{id="/a,b}",name="x"} 3"#);
+        assert_eq!(samples[0].label("id"), Some("/a,b}"));
+        assert_eq!(samples[0].label("name"), Some("x"));
+        assert_eq!(samples[0].value, 3.0);
+    }
+
+    #[test]
+    fn accepts_a_timestamp_and_rejects_a_malformed_one() {
+        let samples = parse_all("m{a=\"1\"} 5 1700000000000\nm{a=\"2\"} 6 soon\n");
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].label("a"), Some("1"));
+        assert_eq!(samples[0].value, 5.0);
+    }
+
+    #[test]
+    fn non_finite_values_parse_but_are_not_integers() {
+        let samples = parse_all("a NaN\nb +Inf\nc -Inf\nd -1\ne 2.5e3\n");
+        assert_eq!(samples.len(), 5);
+        assert!(samples[0].value.is_nan());
+        assert_eq!(samples[1].value, f64::INFINITY);
+        let ints: Vec<Option<u64>> = samples.iter().map(PromSample::value_u64).collect();
+        assert_eq!(ints, vec![None, None, None, None, Some(2500)]);
+    }
+
+    #[test]
+    fn malformed_lines_are_skipped() {
+        let samples = parse_all("m{a=\"unterminated} 1\nm{a=1} 2\nm\nok 4\n");
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].name, "ok");
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::scheduler::tasks::collectors::dcgm::models::GpuStats;
+
+fn milli_count(stats: &GpuStats) -> Option<u64> {
+    Some((stats.gpu_count * 1000.0).round() as u64)
+}
+
+fn utilization(stats: &GpuStats) -> Option<u64> {
+    stats.utilization_percent.map(|u| u.round() as u64)
+}
+
+/// Adds the GPUs of a node to the row built from its summary.
+pub fn merge_into_node(row: &mut MetricNodeEntity, stats: &GpuStats) {
+    row.gpu_milli_count = milli_count(stats);
+    row.gpu_utilization_percent = utilization(stats);
+    row.gpu_memory_used_bytes = stats.memory_used_bytes;
+    row.gpu_memory_total_bytes = stats.memory_total_bytes;
+    row.gpu_power_milliwatts = stats.power_milliwatts;
+}
+
+/// Adds the GPUs assigned to a pod to the row built from its summary.
+pub fn merge_into_pod(row: &mut MetricPodEntity, stats: &GpuStats) {
+    row.gpu_milli_count = milli_count(stats);
+    row.gpu_utilization_percent = utilization(stats);
+    row.gpu_memory_used_bytes = stats.memory_used_bytes;
+    row.gpu_memory_total_bytes = stats.memory_total_bytes;
+    row.gpu_power_milliwatts = stats.power_milliwatts;
+}
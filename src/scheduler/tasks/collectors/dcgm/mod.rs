@@ -0,0 +1,8 @@
+/* Entry point */
+mod task;
+pub use task::run;
+
+/* DCGM exporter page → per-GPU readings → per-node and per-pod stats */
+pub mod models;
+pub mod mapper;
+pub mod repository;
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+/// Pod a GPU is assigned to, as `(namespace, pod name)`; the exporter does not
+/// label pods with their UID.
+pub type PodKey = (String, String);
+
+/// One GPU (or MIG instance) of a DCGM exporter page.
+#[derive(Debug, Clone, Default)]
+pub struct GpuReading {
+    /// Share of a physical GPU: 1, or 1/n for each of the n MIG instances of one GPU.
+    pub share: f64,
+    pub utilization_percent: Option<f64>,
+    pub memory_used_bytes: Option<u64>,
+    /// Framebuffer used + free.
+    pub memory_total_bytes: Option<u64>,
+    pub power_milliwatts: Option<u64>,
+    pub pod: Option<PodKey>,
+}
+
+/// GPUs behind one exporter host (the `Hostname` label).
+#[derive(Debug, Clone, Default)]
+pub struct DcgmHostReadings {
+    pub hostname: Option<String>,
+    pub gpus: Vec<GpuReading>,
+}
+
+/// GPU usage of a set of GPUs: all GPUs of a node, or those assigned to a pod.
+///
+/// Memory and power are summed; utilization is averaged over the GPUs that report it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpuStats {
+    pub gpu_count: f64,
+    pub utilization_percent: Option<f64>,
+    pub memory_used_bytes: Option<u64>,
+    pub memory_total_bytes: Option<u64>,
+    pub power_milliwatts: Option<u64>,
+}
+
+impl GpuStats {
+    pub fn from_readings<'a>(readings: impl IntoIterator<Item = &'a GpuReading>) -> Self {
+        let mut stats = GpuStats::default();
+        let (mut util_sum, mut util_count) = (0.0, 0usize);
+        for gpu in readings {
+            stats.gpu_count += gpu.share;
+            if let Some(u) = gpu.utilization_percent {
+                util_sum += u;
+                util_count += 1;
+            }
+            add(&mut stats.memory_used_bytes, gpu.memory_used_bytes);
+            add(&mut stats.memory_total_bytes, gpu.memory_total_bytes);
+            add(&mut stats.power_milliwatts, gpu.power_milliwatts);
+        }
+        if util_count > 0 {
+            stats.utilization_percent = Some(util_sum / util_count as f64);
+        }
+        stats
+    }
+}
+
+fn add(field: &mut Option<u64>, value: Option<u64>) {
+    if let Some(v) = value {
+        *field = Some(field.unwrap_or(0) + v);
+    }
+}
+
+/// GPU stats of one node for the current tick.
+#[derive(Debug, Clone, Default)]
+pub struct DcgmNodeStats {
+    /// Every GPU of the node, assigned or idle.
+    pub node: GpuStats,
+    pub pods: HashMap<PodKey, GpuStats>,
+}
@@ -0,0 +1,56 @@
+//! DCGM exporter readings of the current tick, waiting for the k8s collector.
+//!
+//! The DCGM collector runs first and stores the GPUs of every exporter host;
+//! the k8s collector takes the hosts belonging to a node when it writes that
+//! node's rows, so each host is used at most once. Kept in memory only.
+
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex};
+
+use crate::scheduler::tasks::collectors::dcgm::models::{DcgmHostReadings, DcgmNodeStats, GpuStats, PodKey};
+
+static PENDING_HOSTS: LazyLock<Mutex<Vec<DcgmHostReadings>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Adds the readings of one exporter host.
+pub fn store_host_readings(readings: DcgmHostReadings) {
+    if let Ok(mut hosts) = PENDING_HOSTS.lock() {
+        hosts.push(readings);
+    }
+}
+
+/// Removes the hosts of `node_name` and returns their GPU stats.
+///
+/// A host belongs to the node when its `Hostname` label is the node name, or
+/// when one of its GPUs is assigned to a pod running on the node (`pods`); the
+/// exporter's hostname is often that of its own pod.
+pub fn take_node_stats(node_name: &str, pods: &HashSet<PodKey>) -> Option<DcgmNodeStats> {
+    let mut hosts = PENDING_HOSTS.lock().ok()?;
+    let (taken, kept): (Vec<_>, Vec<_>) = hosts.drain(..).partition(|host| {
+        host.hostname.as_deref() == Some(node_name)
+            || host.gpus.iter().any(|g| g.pod.as_ref().is_some_and(|p| pods.contains(p)))
+    });
+    *hosts = kept;
+    if taken.is_empty() {
+        return None;
+    }
+
+    let gpus: Vec<_> = taken.iter().flat_map(|h| &h.gpus).collect();
+    let mut stats = DcgmNodeStats {
+        node: GpuStats::from_readings(gpus.iter().copied()),
+        ..Default::default()
+    };
+    let pod_keys: HashSet<&PodKey> = gpus.iter().filter_map(|g| g.pod.as_ref()).collect();
+    for key in pod_keys {
+        let pod_stats = GpuStats::from_readings(gpus.iter().copied().filter(|g| g.pod.as_ref() == Some(key)));
+        stats.pods.insert(key.clone(), pod_stats);
+    }
+    Some(stats)
+}
+
+/// Drops readings left over from the previous tick (hosts no node claimed),
+/// so GPUs are never attributed twice.
+pub fn clear_pending() {
+    if let Ok(mut hosts) = PENDING_HOSTS.lock() {
+        hosts.clear();
+    }
+}
@@ -0,0 +1,246 @@
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::scheduler::tasks::collectors::dcgm::models::{DcgmHostReadings, GpuReading, PodKey};
+use crate::scheduler::tasks::collectors::dcgm::repository::{clear_pending, store_host_readings};
+use crate::scheduler::tasks::collectors::common::prometheus::{parse_text, PromSample};
+use crate::scheduler::tasks::collectors::k8s::{scrape_concurrency, scrape_timeout};
+use anyhow::{anyhow, Result};
+use futures_util::{stream, StreamExt};
+use reqwest::Client;
+use std::collections::{BTreeMap, HashMap};
+use tracing::{debug, error, warn};
+
+const GPU_UTIL: &str = "DCGM_FI_DEV_GPU_UTIL";
+const FB_USED: &str = "DCGM_FI_DEV_FB_USED";
+const FB_FREE: &str = "DCGM_FI_DEV_FB_FREE";
+const POWER_USAGE: &str = "DCGM_FI_DEV_POWER_USAGE";
+
+const MIB: f64 = 1024.0 * 1024.0;
+
+fn is_wanted(name: &str) -> bool {
+    matches!(name, GPU_UTIL | FB_USED | FB_FREE | POWER_USAGE)
+}
+
+/// Collects GPU utilization, framebuffer memory and power from DCGM exporter
+/// pages (`gpu_exporter_urls`), one URL per GPU node.
+///
+/// Runs before the k8s collector, which writes the GPUs of each node into its
+/// node row and the GPUs assigned to a pod (the exporter's `pod`/`namespace`
+/// labels) into that pod's row. A failing URL is logged and skipped.
+pub async fn run(settings: &InfoSettingEntity) -> Result<()> {
+    clear_pending();
+    if !settings.enable_gpu_exporter {
+        debug!("DCGM collector disabled");
+        return Ok(());
+    }
+
+    let urls: Vec<String> = settings
+        .gpu_exporter_urls
+        .iter()
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty())
+        .collect();
+    if urls.is_empty() {
+        warn!("⚠️ DCGM collector enabled without exporter URLs");
+        return Ok(());
+    }
+    debug!("Starting DCGM task for {} exporter(s)...", urls.len());
+
+    let client = Client::new();
+    let timeout = scrape_timeout(settings);
+    let mut scrapes = stream::iter(urls)
+        .map(|url| {
+            let client = &client;
+            async move {
+                let page = match tokio::time::timeout(timeout, fetch_page(client, &url)).await {
+                    Ok(result) => result,
+                    Err(_) => Err(anyhow!("timed out after {}s", timeout.as_secs())),
+                };
+                (url, page)
+            }
+        })
+        .buffer_unordered(scrape_concurrency());
+
+    while let Some((url, page)) = scrapes.next().await {
+        match page {
+            Ok(text) => {
+                for host in build_host_readings(&parse_text(&text, is_wanted)) {
+                    debug!("DCGM readings of {:?}: {} GPU(s)", host.hostname, host.gpus.len());
+                    store_host_readings(host);
+                }
+            }
+            Err(e) => error!("❌ DCGM exporter '{}' failed: {:?}", url, e),
+        }
+    }
+    Ok(())
+}
+
+async fn fetch_page(client: &Client, url: &str) -> Result<String> {
+    let resp = client.get(url).send().await?.error_for_status()?;
+    Ok(resp.text().await?)
+}
+
+/// Pod of a sample. Behind a Prometheus federation the exporter's own labels
+/// become `exported_pod`/`exported_namespace`, so those win.
+fn pod_of(sample: &PromSample) -> Option<PodKey> {
+    let pod = sample.label("exported_pod").or_else(|| sample.label("pod")).filter(|p| !p.is_empty())?;
+    let namespace = sample
+        .label("exported_namespace")
+        .or_else(|| sample.label("namespace"))
+        .filter(|n| !n.is_empty())?;
+    Some((namespace.to_string(), pod.to_string()))
+}
+
+/// Series of one GPU: host, physical GPU, MIG instance and pod.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct SeriesKey {
+    hostname: Option<String>,
+    gpu: String,
+    instance: Option<String>,
+    pod: Option<PodKey>,
+}
+
+#[derive(Debug, Default)]
+struct SeriesValues {
+    utilization_percent: Option<f64>,
+    fb_used_mib: Option<f64>,
+    fb_free_mib: Option<f64>,
+    power_watts: Option<f64>,
+}
+
+/// Groups the samples of one page into GPUs per host.
+///
+/// A physical GPU shows up once per MIG instance and once per pod sharing it;
+/// each series then gets an even share of the GPU, so GPU counts and power add
+/// up to the physical GPUs. Device-wide values (power; memory without MIG) are
+/// split by the same share.
+fn build_host_readings(samples: &[PromSample]) -> Vec<DcgmHostReadings> {
+    let mut series: BTreeMap<SeriesKey, SeriesValues> = BTreeMap::new();
+    for sample in samples {
+        let Some(gpu) = sample.label("UUID").or_else(|| sample.label("gpu")) else {
+            continue;
+        };
+        if !sample.value.is_finite() || sample.value < 0.0 {
+            continue;
+        }
+        let key = SeriesKey {
+            hostname: sample.label("Hostname").filter(|h| !h.is_empty()).map(str::to_string),
+            gpu: gpu.to_string(),
+            instance: sample.label("GPU_I_ID").filter(|i| !i.is_empty()).map(str::to_string),
+            pod: pod_of(sample),
+        };
+        let values = series.entry(key).or_default();
+        let value = Some(sample.value);
+        match sample.name.as_str() {
+            GPU_UTIL => values.utilization_percent = value,
+            FB_USED => values.fb_used_mib = value,
+            FB_FREE => values.fb_free_mib = value,
+            POWER_USAGE => values.power_watts = value,
+            _ => {}
+        }
+    }
+
+    let mut series_per_gpu: HashMap<(Option<String>, String), usize> = HashMap::new();
+    for key in series.keys() {
+        *series_per_gpu.entry((key.hostname.clone(), key.gpu.clone())).or_default() += 1;
+    }
+
+    let mut hosts: BTreeMap<Option<String>, Vec<GpuReading>> = BTreeMap::new();
+    for (key, values) in series {
+        let share = 1.0 / series_per_gpu[&(key.hostname.clone(), key.gpu.clone())] as f64;
+        let memory_share = if key.instance.is_some() { 1.0 } else { share };
+        let bytes = |mib: f64| (mib * MIB * memory_share).round() as u64;
+        let reading = GpuReading {
+            share,
+            utilization_percent: values.utilization_percent,
+            memory_used_bytes: values.fb_used_mib.map(bytes),
+            memory_total_bytes: values.fb_used_mib.zip(values.fb_free_mib).map(|(u, f)| bytes(u + f)),
+            power_milliwatts: values.power_watts.map(|w| (w * 1000.0 * share).round() as u64),
+            pod: key.pod,
+        };
+        hosts.entry(key.hostname).or_default().push(reading);
+    }
+
+    hosts
+        .into_iter()
+        .map(|(hostname, gpus)| DcgmHostReadings { hostname, gpus })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn readings(page: &str) -> Vec<DcgmHostReadings> {
+        build_host_readings(&parse_text(page, is_wanted))
+    }
+
+    fn pod(namespace: &str, name: &str) -> Option<PodKey> {
+        Some((namespace.to_string(), name.to_string()))
+    }
+
+    #[test]
+    fn whole_gpu_of_one_pod() {
+        let hosts = readings(concat!(
+            "DCGM_FI_DEV_GPU_UTIL{gpu=\"0\",UUID=\"GPU-a\",Hostname=\"node-1\",namespace=\"ml\",pod=\"train\"} 80\n",
+            "DCGM_FI_DEV_FB_USED{gpu=\"0\",UUID=\"GPU-a\",Hostname=\"node-1\",namespace=\"ml\",pod=\"train\"} 1024\n",
+            "DCGM_FI_DEV_FB_FREE{gpu=\"0\",UUID=\"GPU-a\",Hostname=\"node-1\",namespace=\"ml\",pod=\"train\"} 3072\n",
+            "DCGM_FI_DEV_POWER_USAGE{gpu=\"0\",UUID=\"GPU-a\",Hostname=\"node-1\",namespace=\"ml\",pod=\"train\"} 250.5\n",
+        ));
+
+        assert_eq!(hosts.len(), 1);
+        assert_eq!(hosts[0].hostname.as_deref(), Some("node-1"));
+        let gpu = &hosts[0].gpus[0];
+        assert_eq!(gpu.share, 1.0);
+        assert_eq!(gpu.utilization_percent, Some(80.0));
+        assert_eq!(gpu.memory_used_bytes, Some(1024 * 1024 * 1024));
+        assert_eq!(gpu.memory_total_bytes, Some(4 * 1024 * 1024 * 1024));
+        assert_eq!(gpu.power_milliwatts, Some(250_500));
+        assert_eq!(gpu.pod, pod("ml", "train"));
+    }
+
+    #[test]
+    fn mig_instances_split_the_gpu_and_power_but_keep_their_own_memory() {
+        let hosts = readings(concat!(
+            "DCGM_FI_DEV_FB_USED{UUID=\"GPU-a\",GPU_I_ID=\"1\",namespace=\"ml\",pod=\"a\"} 100\n",
+            "DCGM_FI_DEV_POWER_USAGE{UUID=\"GPU-a\",GPU_I_ID=\"1\",namespace=\"ml\",pod=\"a\"} 200\n",
+            "DCGM_FI_DEV_FB_USED{UUID=\"GPU-a\",GPU_I_ID=\"2\",namespace=\"ml\",pod=\"b\"} 300\n",
+            "DCGM_FI_DEV_POWER_USAGE{UUID=\"GPU-a\",GPU_I_ID=\"2\",namespace=\"ml\",pod=\"b\"} 200\n",
+        ));
+
+        let gpus = &hosts[0].gpus;
+        assert_eq!(gpus.len(), 2);
+        assert!(gpus.iter().all(|g| g.share == 0.5 && g.power_milliwatts == Some(100_000)));
+        assert_eq!(gpus[0].memory_used_bytes, Some(100 * 1024 * 1024));
+        assert_eq!(gpus[1].memory_used_bytes, Some(300 * 1024 * 1024));
+    }
+
+    #[test]
+    fn pods_sharing_a_gpu_split_its_device_wide_values() {
+        let hosts = readings(concat!(
+            "DCGM_FI_DEV_FB_USED{UUID=\"GPU-a\",namespace=\"ml\",pod=\"a\"} 400\n",
+            "DCGM_FI_DEV_FB_USED{UUID=\"GPU-a\",exported_namespace=\"web\",exported_pod=\"b\",namespace=\"gpu\",pod=\"dcgm-exporter\"} 400\n",
+        ));
+
+        let gpus = &hosts[0].gpus;
+        assert_eq!(gpus.iter().map(|g| g.share).sum::<f64>(), 1.0);
+        assert!(gpus.iter().all(|g| g.memory_used_bytes == Some(200 * 1024 * 1024)));
+        assert_eq!(gpus[0].pod, pod("ml", "a"));
+        assert_eq!(gpus[1].pod, pod("web", "b"));
+    }
+
+    #[test]
+    fn samples_without_a_gpu_or_a_usable_value_are_dropped() {
+        let hosts = readings(concat!(
+            "DCGM_FI_DEV_GPU_UTIL{Hostname=\"node-1\"} 50\n",
+            "DCGM_FI_DEV_GPU_UTIL{gpu=\"0\",Hostname=\"node-1\"} NaN\n",
+            "DCGM_FI_DEV_POWER_USAGE{gpu=\"1\",Hostname=\"node-2\"} -1\n",
+            "DCGM_FI_DEV_GPU_UTIL{gpu=\"1\",Hostname=\"node-2\"} 10\n",
+        ));
+
+        assert_eq!(hosts.len(), 1);
+        assert_eq!(hosts[0].hostname.as_deref(), Some("node-2"));
+        assert_eq!(hosts[0].gpus[0].utilization_percent, Some(10.0));
+        assert_eq!(hosts[0].gpus[0].power_milliwatts, None);
+        assert_eq!(hosts[0].gpus[0].pod, None);
+    }
+}
@@ -31,7 +31,15 @@ pub fn map_container_summary_to_metrics(container: &ContainerSummary) -> MetricC
         fs_inodes_used: fs_inodes_used,
         fs_inodes: fs_inodes,
 
+        // Memory pressure
+        memory_available_bytes: container.memory.available_bytes,
+        memory_major_page_faults: container.memory.major_page_faults,
 
+        // Swap
+        swap_usage_bytes: container.swap.as_ref().and_then(|s| s.swap_usage_bytes),
+        swap_available_bytes: container.swap.as_ref().and_then(|s| s.swap_available_bytes),
+
+        ..Default::default()
     }
 }
 
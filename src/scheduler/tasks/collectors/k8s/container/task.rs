@@ -1,15 +1,23 @@
 use crate::core::persistence::info::k8s::container::info_container_collector_repository_trait::InfoContainerCollectorRepository;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_collector_repository_trait::MetricContainerMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
 use crate::scheduler::tasks::collectors::k8s::container::metric_container_minute_collector_repository::MetricContainerMinuteCollectorRepositoryImpl;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
 use anyhow::Result;
+use std::collections::HashSet;
 use crate::scheduler::tasks::collectors::k8s::container::info_container_minute_collector_mapper::map_container_summary_to_info;
 use crate::scheduler::tasks::collectors::k8s::container::info_container_minute_collector_repository::InfoContainerCollectorRepositoryImpl;
 use crate::scheduler::tasks::collectors::k8s::container::metric_container_minute_collector_mapper::map_container_summary_to_metrics;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::scheduler::tasks::collectors::cadvisor::mapper::merge_into_container;
+use crate::scheduler::tasks::collectors::cadvisor::models::CadvisorNodeStats;
 
 /// Collects container-level info and metrics from the node summary.
-pub async fn handle_container(summary: &Summary) -> Result<bool> {
+pub async fn handle_container(
+    summary: &Summary,
+    cadvisor: Option<&CadvisorNodeStats>,
+    seen: &mut HashSet<String>,
+) -> Result<bool> {
     let mut any_created = false;
 
     // Step 1: Return early if no pods
@@ -43,6 +51,7 @@ pub async fn handle_container(summary: &Summary) -> Result<bool> {
 
             // Compose a unique key for this container
             let container_key = format!("{}-{}", pod_uid, container.name);
+            seen.insert(container_key.clone());
 
             // ---- Info section ----
             let info_repo = InfoContainerCollectorRepositoryImpl::default();
@@ -55,9 +64,12 @@ pub async fn handle_container(summary: &Summary) -> Result<bool> {
 
             // ---- Metrics section ----
             let metric_repo = MetricContainerMinuteCollectorRepositoryImpl {
-                adapter: MetricContainerMinuteFsAdapter,
+                adapter: metric_backend()?.container(MetricTier::Minute),
             };
-            let metrics_dto = map_container_summary_to_metrics(container);
+            let mut metrics_dto = map_container_summary_to_metrics(container);
+            if let Some(stats) = cadvisor.and_then(|c| c.containers.get(&container_key)) {
+                merge_into_container(&mut metrics_dto, stats);
+            }
             metric_repo.append_row(&container_key, &metrics_dto)?;
         }
     }
@@ -1,6 +1,7 @@
 /* Entry point */
 mod task;
 pub use task::run;
+pub(crate) use task::{scrape_concurrency, scrape_timeout};
 
 /* Maps K8s API objects → internal models */
 /* Data structures */
@@ -8,3 +9,7 @@ pub mod summary_dto;
 pub mod node;
 mod pod;
 mod container;
+mod pvc;
+mod node_network;
+mod node_system;
+pub(crate) mod reconcile;
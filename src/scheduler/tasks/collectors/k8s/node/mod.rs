@@ -1,5 +1,5 @@
 pub mod task;
 
-mod info_node_minute_collector_repository;
+pub mod info_node_minute_collector_repository;
 mod metric_node_minute_collector_repository;
 
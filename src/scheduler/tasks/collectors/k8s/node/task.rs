@@ -1,13 +1,16 @@
 use crate::core::persistence::info::k8s::node::info_node_collector_repository_trait::InfoNodeCollectorRepository;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_collector_repository_trait::MetricNodeMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_fs_adapter::MetricNodeMinuteFsAdapter;
 use crate::scheduler::tasks::collectors::k8s::node::info_node_minute_collector_repository::InfoNodeCollectorRepositoryImpl;
 use crate::core::client::k8s::client_k8s_node_mapper::{map_node_to_node_info_entity, map_summary_to_metrics, map_summary_to_node_info};
 use crate::scheduler::tasks::collectors::k8s::node::metric_node_minute_collector_repository::MetricNodeMinuteCollectorRepositoryImpl;
 use crate::core::client::k8s::client_k8s_node_dto::Node;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::scheduler::tasks::collectors::dcgm::mapper::merge_into_node;
+use crate::scheduler::tasks::collectors::dcgm::models::DcgmNodeStats;
 
-pub async fn handle_node(summary: &Summary) -> Result<bool, anyhow::Error> {
+pub async fn handle_node(summary: &Summary, gpu: Option<&DcgmNodeStats>) -> Result<bool, anyhow::Error> {
     let node_name = &summary.node.node_name;
 
     // Step 1: Write info.rci if missing
@@ -16,9 +19,12 @@ pub async fn handle_node(summary: &Summary) -> Result<bool, anyhow::Error> {
     let created = info_repo.create_if_missing(node_name, &node_info)?;
 
     // Step 2: Append metrics
-    let metrics_dto = map_summary_to_metrics(summary);
+    let mut metrics_dto = map_summary_to_metrics(summary);
+    if let Some(stats) = gpu {
+        merge_into_node(&mut metrics_dto, &stats.node);
+    }
     let metric_repo = MetricNodeMinuteCollectorRepositoryImpl {
-        adapter: MetricNodeMinuteFsAdapter,
+        adapter: metric_backend()?.node(MetricTier::Minute),
     };
     metric_repo.append_row(node_name, &metrics_dto)?; // ✅ correct method
 
@@ -39,8 +45,7 @@ pub async fn update_node_info(
 
     let node_info = map_node_to_node_info_entity(&node)?;
 
-    repo.update(&node_info)
-        .expect("Failed to update node info in InfoNodeCollectorRepository");
+    repo.update(&node_info)?;
 
     Ok(())
 }
@@ -0,0 +1,14 @@
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use crate::scheduler::tasks::collectors::k8s::summary_dto::NetworkInterface;
+use chrono::{DateTime, Utc};
+
+/// Maps the counters of one node network interface into MetricNodeNetworkEntity.
+pub fn map_interface_to_metrics(interface: &NetworkInterface, time: DateTime<Utc>) -> MetricNodeNetworkEntity {
+    MetricNodeNetworkEntity {
+        time,
+        rx_bytes: interface.rx_bytes,
+        tx_bytes: interface.tx_bytes,
+        rx_errors: interface.rx_errors,
+        tx_errors: interface.tx_errors,
+    }
+}
@@ -0,0 +1,14 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use crate::core::persistence::metrics::k8s::node_network::minute::metric_node_network_minute_collector_repository_trait::MetricNodeNetworkMinuteCollectorRepository;
+use std::sync::Arc;
+
+pub struct MetricNodeNetworkMinuteCollectorRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeNetworkEntity>>,
+}
+
+impl MetricNodeNetworkMinuteCollectorRepository for MetricNodeNetworkMinuteCollectorRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeNetworkEntity> {
+        self.adapter.as_ref()
+    }
+}
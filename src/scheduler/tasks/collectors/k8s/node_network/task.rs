@@ -0,0 +1,46 @@
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::{
+    is_valid_interface_name, node_network_metric_key,
+};
+use crate::core::persistence::metrics::k8s::node_network::minute::metric_node_network_minute_collector_repository_trait::MetricNodeNetworkMinuteCollectorRepository;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::scheduler::tasks::collectors::k8s::node_network::metric_node_network_minute_collector_mapper::map_interface_to_metrics;
+use crate::scheduler::tasks::collectors::k8s::node_network::metric_node_network_minute_collector_repository::MetricNodeNetworkMinuteCollectorRepositoryImpl;
+use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tracing::debug;
+
+/// Collects the counters of every node network interface from the node summary.
+///
+/// The node row only keeps the sum over all interfaces; this stores each one
+/// under `<node>_<interface>` so traffic can be told apart per NIC.
+pub async fn handle_node_network(summary: &Summary) -> Result<()> {
+    let node_name = &summary.node.node_name;
+    let Some(network) = &summary.node.network else {
+        return Ok(());
+    };
+    let Some(interfaces) = &network.interfaces else {
+        return Ok(());
+    };
+
+    let time = DateTime::parse_from_rfc3339(&network.time)
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now());
+
+    let metric_repo = MetricNodeNetworkMinuteCollectorRepositoryImpl {
+        adapter: metric_backend()?.node_network(MetricTier::Minute),
+    };
+
+    for interface in interfaces {
+        if !is_valid_interface_name(&interface.name) {
+            debug!("Skipping interface '{}' of node '{}'", interface.name, node_name);
+            continue;
+        }
+
+        let key = node_network_metric_key(node_name, &interface.name);
+        metric_repo.append_row(&key, &map_interface_to_metrics(interface, time))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use crate::scheduler::tasks::collectors::k8s::summary_dto::SystemContainer;
+use chrono::{DateTime, Utc};
+
+/// Maps the usage of one node system container into MetricNodeSystemEntity.
+pub fn map_system_container_to_metrics(container: &SystemContainer, time: DateTime<Utc>) -> MetricNodeSystemEntity {
+    MetricNodeSystemEntity {
+        time,
+        cpu_usage_nano_cores: container.cpu.usage_nano_cores,
+        cpu_usage_core_nano_seconds: container.cpu.usage_core_nano_seconds,
+        memory_usage_bytes: container.memory.usage_bytes,
+        memory_working_set_bytes: container.memory.working_set_bytes,
+        memory_rss_bytes: container.memory.rss_bytes,
+    }
+}
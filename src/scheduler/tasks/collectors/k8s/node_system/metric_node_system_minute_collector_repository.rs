@@ -0,0 +1,14 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use crate::core::persistence::metrics::k8s::node_system::minute::metric_node_system_minute_collector_repository_trait::MetricNodeSystemMinuteCollectorRepository;
+use std::sync::Arc;
+
+pub struct MetricNodeSystemMinuteCollectorRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeSystemEntity>>,
+}
+
+impl MetricNodeSystemMinuteCollectorRepository for MetricNodeSystemMinuteCollectorRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeSystemEntity> {
+        self.adapter.as_ref()
+    }
+}
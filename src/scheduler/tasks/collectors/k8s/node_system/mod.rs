@@ -0,0 +1,3 @@
+pub mod task;
+mod metric_node_system_minute_collector_repository;
+mod metric_node_system_minute_collector_mapper;
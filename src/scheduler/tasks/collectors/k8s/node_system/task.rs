@@ -0,0 +1,44 @@
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::{
+    is_valid_system_container_name, node_system_metric_key,
+};
+use crate::core::persistence::metrics::k8s::node_system::minute::metric_node_system_minute_collector_repository_trait::MetricNodeSystemMinuteCollectorRepository;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::scheduler::tasks::collectors::k8s::node_system::metric_node_system_minute_collector_mapper::map_system_container_to_metrics;
+use crate::scheduler::tasks::collectors::k8s::node_system::metric_node_system_minute_collector_repository::MetricNodeSystemMinuteCollectorRepositoryImpl;
+use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tracing::debug;
+
+/// Collects the usage of every node system container from the node summary.
+///
+/// The kubelet reports its own cgroup, the container runtime's and the pods
+/// cgroup; this stores each one under `<node>_<container>` so the share of the
+/// node the platform itself consumes can be told apart from the workloads.
+pub async fn handle_node_system(summary: &Summary) -> Result<()> {
+    let node_name = &summary.node.node_name;
+    let Some(containers) = &summary.node.system_containers else {
+        return Ok(());
+    };
+
+    let metric_repo = MetricNodeSystemMinuteCollectorRepositoryImpl {
+        adapter: metric_backend()?.node_system(MetricTier::Minute),
+    };
+
+    for container in containers {
+        if !is_valid_system_container_name(&container.name) {
+            debug!("Skipping system container '{}' of node '{}'", container.name, node_name);
+            continue;
+        }
+
+        let time = DateTime::parse_from_rfc3339(&container.cpu.time)
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        let key = node_system_metric_key(node_name, &container.name);
+        metric_repo.append_row(&key, &map_system_container_to_metrics(container, time))?;
+    }
+
+    Ok(())
+}
@@ -64,6 +64,18 @@ pub fn map_pod_summary_to_metrics(pod: &PodSummary) -> MetricPodEntity {
         pv_inodes_used: pv_inodes_used,
         pv_inodes: pv_inodes,
 
+        // Memory pressure
+        memory_available_bytes: pod.memory.available_bytes,
+        memory_major_page_faults: pod.memory.major_page_faults,
+
+        // Swap
+        swap_usage_bytes: pod.swap.as_ref().and_then(|s| s.swap_usage_bytes),
+        swap_available_bytes: pod.swap.as_ref().and_then(|s| s.swap_available_bytes),
+
+        // Processes
+        process_count: pod.process_stats.as_ref().and_then(|p| p.process_count),
+
+        ..Default::default()
     }
 }
 
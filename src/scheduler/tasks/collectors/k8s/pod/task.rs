@@ -1,14 +1,25 @@
 use crate::core::persistence::info::k8s::pod::info_pod_collector_repository_trait::InfoPodCollectorRepository;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_collector_repository_trait::MetricPodMinuteCollectorRepository;
-use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_adapter::MetricPodMinuteFsAdapter;
 use crate::scheduler::tasks::collectors::k8s::pod::info_pod_minute_collector_mapper::map_pod_summary_to_info;
 use crate::scheduler::tasks::collectors::k8s::pod::info_pod_minute_collector_repository::InfoPodCollectorRepositoryImpl;
 use crate::scheduler::tasks::collectors::k8s::pod::metric_pod_minute_collector_mapper::map_pod_summary_to_metrics;
 use crate::scheduler::tasks::collectors::k8s::pod::metric_pod_minute_collector_repository::MetricPodMinuteCollectorRepositoryImpl;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
 use anyhow::Result;
+use std::collections::HashSet;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::scheduler::tasks::collectors::cadvisor::mapper::merge_into_pod;
+use crate::scheduler::tasks::collectors::cadvisor::models::CadvisorNodeStats;
+use crate::scheduler::tasks::collectors::dcgm::mapper::merge_into_pod as merge_gpu_into_pod;
+use crate::scheduler::tasks::collectors::dcgm::models::DcgmNodeStats;
 
-pub async fn handle_pod(summary: &Summary) -> Result<bool> {
+pub async fn handle_pod(
+    summary: &Summary,
+    cadvisor: Option<&CadvisorNodeStats>,
+    gpu: Option<&DcgmNodeStats>,
+    seen: &mut HashSet<String>,
+) -> Result<bool> {
     let mut any_created = false;
 
     // Step 1: If there are no pods, return early
@@ -26,6 +37,7 @@ pub async fn handle_pod(summary: &Summary) -> Result<bool> {
             tracing::debug!("⚠️ Skipping static pod (config hash) '{}'", pod_uid);
             continue;
         }
+        seen.insert(pod_uid.clone());
 
         // ---- Info section ----
         let info_repo = InfoPodCollectorRepositoryImpl::default();
@@ -37,9 +49,16 @@ pub async fn handle_pod(summary: &Summary) -> Result<bool> {
 
         // ---- Metrics section ----
         let metric_repo = MetricPodMinuteCollectorRepositoryImpl {
-            adapter: MetricPodMinuteFsAdapter,
+            adapter: metric_backend()?.pod(MetricTier::Minute),
         };
-        let metrics_dto = map_pod_summary_to_metrics(pod);
+        let mut metrics_dto = map_pod_summary_to_metrics(pod);
+        if let Some(stats) = cadvisor.and_then(|c| c.pods.get(pod_uid)) {
+            merge_into_pod(&mut metrics_dto, stats);
+        }
+        let pod_key = (pod.pod_ref.namespace.clone(), pod.pod_ref.name.clone());
+        if let Some(stats) = gpu.and_then(|g| g.pods.get(&pod_key)) {
+            merge_gpu_into_pod(&mut metrics_dto, stats);
+        }
         metric_repo.append_row(pod_uid, &metrics_dto)?;
     }
 
@@ -0,0 +1,24 @@
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::scheduler::tasks::collectors::k8s::summary_dto::VolumeStats;
+use chrono::{DateTime, Utc};
+
+/// Maps the stats of a PVC-backed pod volume into MetricPvcEntity.
+///
+/// Rows are stamped with `time`, the pod's CPU time like the pod rows, not the
+/// volume's own timestamp: the kubelet refreshes volume stats less often than
+/// the summary is scraped, so that timestamp repeats across ticks.
+pub fn map_volume_stats_to_metrics(volume: &VolumeStats, time: DateTime<Utc>) -> MetricPvcEntity {
+    MetricPvcEntity {
+        time,
+
+        used_bytes: volume.used_bytes,
+        capacity_bytes: volume.capacity_bytes,
+        available_bytes: volume.available_bytes,
+
+        inodes_used: volume.inodes_used,
+        inodes: volume.inodes,
+        inodes_free: volume.inodes_free,
+
+        ..Default::default()
+    }
+}
@@ -0,0 +1,14 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use crate::core::persistence::metrics::k8s::pvc::minute::metric_pvc_minute_collector_repository_trait::MetricPvcMinuteCollectorRepository;
+use std::sync::Arc;
+
+pub struct MetricPvcMinuteCollectorRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricPvcEntity>>,
+}
+
+impl MetricPvcMinuteCollectorRepository for MetricPvcMinuteCollectorRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPvcEntity> {
+        self.adapter.as_ref()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod task;
+mod metric_pvc_minute_collector_repository;
+mod metric_pvc_minute_collector_mapper;
@@ -0,0 +1,53 @@
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::pvc_metric_key;
+use crate::core::persistence::metrics::k8s::pvc::minute::metric_pvc_minute_collector_repository_trait::MetricPvcMinuteCollectorRepository;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::scheduler::tasks::collectors::k8s::pvc::metric_pvc_minute_collector_mapper::map_volume_stats_to_metrics;
+use crate::scheduler::tasks::collectors::k8s::pvc::metric_pvc_minute_collector_repository::MetricPvcMinuteCollectorRepositoryImpl;
+use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+/// Collects the volume stats of PVC-backed pod volumes from the node summary.
+///
+/// A claim mounted by several pods (or, for RWX volumes, on several nodes) is
+/// reported once per pod; only the first report of a cycle is stored, tracked
+/// in `seen`.
+pub async fn handle_pvc(summary: &Summary, seen: &mut HashSet<String>) -> Result<()> {
+    let pods = match &summary.pods {
+        Some(p) if !p.is_empty() => p,
+        _ => return Ok(()),
+    };
+
+    let metric_repo = MetricPvcMinuteCollectorRepositoryImpl {
+        adapter: metric_backend()?.pvc(MetricTier::Minute),
+    };
+
+    for pod in pods {
+        let Some(volumes) = &pod.volume else { continue };
+
+        let pod_time = DateTime::parse_from_rfc3339(&pod.cpu.time)
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        for volume in volumes {
+            let Some(pvc_ref) = &volume.pvc_ref else { continue };
+            // The claim lives in the pod's namespace when the kubelet leaves it out
+            let (Some(name), namespace) = (&pvc_ref.name, pvc_ref.namespace.as_ref().unwrap_or(&pod.pod_ref.namespace))
+            else {
+                continue;
+            };
+
+            let pvc_key = pvc_metric_key(namespace, name);
+            if !seen.insert(pvc_key.clone()) {
+                continue;
+            }
+
+            let metrics_dto = map_volume_stats_to_metrics(volume, pod_time);
+            metric_repo.append_row(&pvc_key, &metrics_dto)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,228 @@
+//! Tombstoning of nodes, pods and containers that left the cluster.
+//!
+//! Every collector cycle compares the objects seen against `info/k8s/*`. An
+//! object missing for [`DELETED_AFTER_SEC`] (and at least
+//! [`MIN_DELETED_AFTER_MISSES`] consecutive cycles) is marked `deleted`; one
+//! that shows up again is restored. Retention purges the metrics
+//! of deleted objects once every tier has expired.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use tracing::{debug, error, info};
+
+use crate::core::persistence::info::k8s::container::info_container_collector_repository_trait::InfoContainerCollectorRepository;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::info_source::is_k8s_source;
+use crate::core::persistence::info::k8s::node::info_node_collector_repository_trait::InfoNodeCollectorRepository;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_collector_repository_trait::InfoPodCollectorRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::scheduler::tasks::scrape_interval_sec;
+use crate::scheduler::tasks::collectors::k8s::container::info_container_minute_collector_repository::InfoContainerCollectorRepositoryImpl;
+use crate::scheduler::tasks::collectors::k8s::node::info_node_minute_collector_repository::InfoNodeCollectorRepositoryImpl;
+use crate::scheduler::tasks::collectors::k8s::pod::info_pod_minute_collector_repository::InfoPodCollectorRepositoryImpl;
+
+/// How long an object must be missing before it is marked deleted.
+/// Rides out a kubelet that skips a summary or a pod being rescheduled.
+pub const DELETED_AFTER_SEC: u64 = 180;
+
+/// Fewest consecutive missed cycles before an object is marked deleted, for
+/// scrape intervals longer than [`DELETED_AFTER_SEC`].
+pub const MIN_DELETED_AFTER_MISSES: u64 = 2;
+
+/// Consecutive missed cycles that add up to [`DELETED_AFTER_SEC`] at a scrape
+/// interval of `interval_sec`.
+pub fn deleted_after_misses(interval_sec: u32) -> u64 {
+    DELETED_AFTER_SEC
+        .div_ceil(u64::from(interval_sec.max(1)))
+        .max(MIN_DELETED_AFTER_MISSES)
+}
+
+/// Objects seen during one collector cycle.
+#[derive(Debug, Default)]
+pub struct LiveObjects {
+    /// Nodes listed by the API server.
+    pub nodes: HashSet<String>,
+    /// Nodes whose summary was collected. Pods and containers of other nodes
+    /// are left alone: a failed scrape says nothing about them.
+    pub scraped_nodes: HashSet<String>,
+    pub pods: HashSet<String>,
+    pub containers: HashSet<String>,
+    /// PVCs stored this cycle. Not reconciled (claims have no info entry);
+    /// keeps a claim mounted by several pods from being stored twice.
+    pub pvcs: HashSet<String>,
+}
+
+/// Tombstone fields shared by the info entities.
+pub(crate) trait Tombstone {
+    fn tombstone(&mut self) -> (&mut Option<bool>, &mut Option<u64>);
+}
+
+impl Tombstone for InfoNodeEntity {
+    fn tombstone(&mut self) -> (&mut Option<bool>, &mut Option<u64>) {
+        (&mut self.deleted, &mut self.last_check_deleted_count)
+    }
+}
+
+impl Tombstone for InfoPodEntity {
+    fn tombstone(&mut self) -> (&mut Option<bool>, &mut Option<u64>) {
+        (&mut self.deleted, &mut self.last_check_deleted_count)
+    }
+}
+
+impl Tombstone for InfoContainerEntity {
+    fn tombstone(&mut self) -> (&mut Option<bool>, &mut Option<u64>) {
+        (&mut self.deleted, &mut self.last_check_deleted_count)
+    }
+}
+
+#[derive(Debug, Default)]
+struct ReconcileCount {
+    deleted: usize,
+    restored: usize,
+}
+
+/// Applies one cycle's observation to a single entity.
+/// Returns whether it changed and has to be written back.
+fn observe<T: Tombstone>(entity: &mut T, seen: bool, deleted_after: u64, count: &mut ReconcileCount) -> bool {
+    let (deleted, misses) = entity.tombstone();
+    let was_deleted = deleted.unwrap_or(false);
+
+    if seen {
+        if !was_deleted && misses.unwrap_or(0) == 0 {
+            return false;
+        }
+        if was_deleted {
+            count.restored += 1;
+        }
+        *deleted = Some(false);
+        *misses = Some(0);
+        return true;
+    }
+
+    if was_deleted {
+        return false;
+    }
+    let missed = misses.unwrap_or(0) + 1;
+    *misses = Some(missed);
+    if missed >= deleted_after {
+        *deleted = Some(true);
+        count.deleted += 1;
+    }
+    true
+}
+
+pub(crate) fn reconcile_kind<T: Tombstone>(
+    kind: &str,
+    adapter: &dyn InfoDynamicFsAdapterTrait<T>,
+    live: &HashSet<String>,
+    in_scope: impl Fn(&T) -> bool,
+) -> Result<()> {
+    let mut count = ReconcileCount::default();
+    let deleted_after = deleted_after_misses(scrape_interval_sec());
+
+    for key in adapter.keys()? {
+        let mut entity = match adapter.read(&key) {
+            Ok(entity) => entity,
+            Err(e) => {
+                error!("⚠️ Failed to read {} info '{}': {}", kind, key, e);
+                continue;
+            }
+        };
+        if !in_scope(&entity) {
+            continue;
+        }
+        if observe(&mut entity, live.contains(&key), deleted_after, &mut count) {
+            if let Err(e) = adapter.update(&entity) {
+                error!("⚠️ Failed to update {} info '{}': {}", kind, key, e);
+            }
+        }
+    }
+
+    if count.deleted > 0 || count.restored > 0 {
+        info!(
+            "🪦 Reconciled {} info: {} marked deleted, {} restored",
+            kind, count.deleted, count.restored
+        );
+    } else {
+        debug!("Reconciled {} info: no changes", kind);
+    }
+    Ok(())
+}
+
+/// Marks info entries missing from `live` deleted and restores those seen again.
+pub fn reconcile_deleted(live: &LiveObjects) -> Result<()> {
+    // Objects of a node that left the cluster are reconciled as well.
+    let on_scraped_node = |node: Option<&str>| match node {
+        Some(n) => live.scraped_nodes.contains(n) || !live.nodes.contains(n),
+        None => true,
+    };
+
+    // Hosts and containers of exporters are reconciled by their own collector.
+    let node_repo = InfoNodeCollectorRepositoryImpl::default();
+    reconcile_kind("node", node_repo.fs_adapter(), &live.nodes, |n: &InfoNodeEntity| {
+        is_k8s_source(n.source.as_deref())
+    })?;
+
+    let pod_repo = InfoPodCollectorRepositoryImpl::default();
+    reconcile_kind("pod", pod_repo.fs_adapter(), &live.pods, |p: &InfoPodEntity| {
+        on_scraped_node(p.node_name.as_deref())
+    })?;
+
+    let container_repo = InfoContainerCollectorRepositoryImpl::default();
+    reconcile_kind(
+        "container",
+        container_repo.fs_adapter(),
+        &live.containers,
+        |c: &InfoContainerEntity| is_k8s_source(c.source.as_deref()) && on_scraped_node(c.node_name.as_deref()),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn misses_add_up_to_the_deletion_delay() {
+        assert_eq!(deleted_after_misses(60), 3);
+        assert_eq!(deleted_after_misses(10), 18);
+        assert_eq!(deleted_after_misses(300), MIN_DELETED_AFTER_MISSES);
+    }
+
+    #[test]
+    fn object_is_tombstoned_after_consecutive_misses_and_restored_when_seen() {
+        let mut pod = InfoPodEntity::default();
+        let mut count = ReconcileCount::default();
+
+        assert!(!observe(&mut pod, true, 3, &mut count));
+        assert!(observe(&mut pod, false, 3, &mut count));
+        assert!(observe(&mut pod, false, 3, &mut count));
+        assert_eq!((pod.deleted, pod.last_check_deleted_count), (None, Some(2)));
+
+        assert!(observe(&mut pod, false, 3, &mut count));
+        assert_eq!(pod.deleted, Some(true));
+        assert_eq!(count.deleted, 1);
+        // Further misses leave a tombstoned object alone.
+        assert!(!observe(&mut pod, false, 3, &mut count));
+
+        assert!(observe(&mut pod, true, 3, &mut count));
+        assert_eq!((pod.deleted, pod.last_check_deleted_count), (Some(false), Some(0)));
+        assert_eq!(count.restored, 1);
+    }
+
+    #[test]
+    fn a_miss_streak_resets_when_the_object_is_seen() {
+        let mut node = InfoNodeEntity::default();
+        let mut count = ReconcileCount::default();
+
+        observe(&mut node, false, 2, &mut count);
+        observe(&mut node, true, 2, &mut count);
+        observe(&mut node, false, 2, &mut count);
+        assert_eq!((node.deleted, node.last_check_deleted_count), (Some(false), Some(1)));
+        assert_eq!(count.deleted, 0);
+    }
+}
@@ -1,14 +1,57 @@
 use crate::core::client::k8s::util::{build_client, read_token};
 use crate::core::client::k8s::client_k8s_node::{fetch_node_summary, fetch_nodes};
+use crate::core::client::k8s::scrape_status::{record_scrape, retain_nodes, NodeScrapeStatus};
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
 use crate::scheduler::tasks::collectors::k8s::node::task::{handle_node, update_node_info};
 use crate::scheduler::tasks::collectors::k8s::pod::task::handle_pod;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
-use anyhow::Result;
-use tracing::{debug, error};
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use futures_util::{stream, StreamExt};
+use std::env;
+use std::time::{Duration, Instant};
+use tracing::{debug, error, warn};
 use crate::scheduler::tasks::collectors::k8s::container::task::handle_container;
+use crate::scheduler::tasks::collectors::k8s::pvc::task::handle_pvc;
+use crate::scheduler::tasks::collectors::k8s::node_network::task::handle_node_network;
+use crate::scheduler::tasks::collectors::k8s::node_system::task::handle_node_system;
+use crate::scheduler::tasks::collectors::k8s::reconcile::{reconcile_deleted, LiveObjects};
+use crate::scheduler::tasks::collectors::cadvisor::repository::take_node_stats;
+use crate::scheduler::tasks::collectors::dcgm::models::PodKey;
+use crate::scheduler::tasks::collectors::dcgm::repository::take_node_stats as take_gpu_stats;
+use std::collections::HashSet;
+
+/// Summaries fetched at the same time unless `RUSTCOST_SCRAPE_CONCURRENCY` is set.
+const DEFAULT_SCRAPE_CONCURRENCY: usize = 16;
+
+/// Collectors run one after another each tick (cAdvisor, DCGM, k8s, RustExporter).
+const COLLECTORS_PER_TICK: u32 = 4;
+
+pub(crate) fn scrape_concurrency() -> usize {
+    env::var("RUSTCOST_SCRAPE_CONCURRENCY")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or(DEFAULT_SCRAPE_CONCURRENCY)
+}
+
+/// Budget for one request of a collector: `RUSTCOST_SCRAPE_TIMEOUT_SEC`, or an
+/// equal share of `scrape_interval_sec` per collector, so a slow kubelet or
+/// exporter cannot push the collectors past their tick.
+pub(crate) fn scrape_timeout(settings: &InfoSettingEntity) -> Duration {
+    let secs = env::var("RUSTCOST_SCRAPE_TIMEOUT_SEC")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or_else(|| (settings.scrape_interval_sec() / COLLECTORS_PER_TICK).max(1) as u64);
+    Duration::from_secs(secs)
+}
 
 /// Collects node-level stats from the Kubelet `/stats/summary` endpoint.
-pub async fn run() -> Result<()> {
+///
+/// Summaries are fetched concurrently, each bounded by a timeout; a node that
+/// fails or times out is recorded and skipped without affecting the others.
+pub async fn run(settings: &InfoSettingEntity) -> Result<()> {
     debug!("Starting K8s node stats task...");
 
     // --- Build client & token ---
@@ -17,30 +60,76 @@ pub async fn run() -> Result<()> {
 
     // --- Step 1: Fetch all nodes ---
     let node_list = fetch_nodes(&token, &client).await?;
+    let mut live = LiveObjects::default();
+    for node in &node_list.items {
+        live.nodes.insert(node.metadata.name.clone());
+    }
+    retain_nodes(&live.nodes);
+
+    // --- Step 2: Fetch /proxy/stats/summary of every node, a few at a time ---
+    let timeout = scrape_timeout(settings);
+    let mut scrapes = stream::iter(node_list.items)
+        .map(|node| {
+            let (token, client) = (&token, &client);
+            async move {
+                let started = Instant::now();
+                let summary = match tokio::time::timeout(
+                    timeout,
+                    fetch_node_summary(token, client, &node.metadata.name),
+                )
+                .await
+                {
+                    Ok(result) => result,
+                    Err(_) => Err(anyhow!("timed out after {}s", timeout.as_secs())),
+                };
+                (node, summary, started.elapsed())
+            }
+        })
+        .buffer_unordered(scrape_concurrency());
 
-    // --- Step 2: For each node, call /proxy/stats/summary ---
-    for node in node_list.items {
+    // Results are stored one at a time as they arrive.
+    while let Some((node, summary, elapsed)) = scrapes.next().await {
         let node_name = node.metadata.name.clone();
 
-        match fetch_node_summary(&token, &client, &node_name).await {
-            Ok(summary) => {
-                match handle_summary(&summary).await {
-                    Ok(result) => {
+        let outcome = match summary {
+            Ok(summary) => match handle_summary(&summary, &mut live).await {
+                Ok(result) => {
+                    live.scraped_nodes.insert(node_name.clone());
 
-                        // if new node
-                        if let Some(_name) = result.node_name {
-                            update_node_info(node).await?;
+                    // if new node
+                    if result.node_name.is_some() {
+                        if let Err(e) = update_node_info(node).await {
+                            error!("❌ Failed to update node info for {}: {:?}", node_name, e);
                         }
-                        // new_pods.extend(result.updated_pods);
-                        // new_containers.extend(result.updated_containers);
                     }
-                    Err(e) => error!("❌ Failed to handle summary for {}: {:?}", node_name, e),
+                    Ok(())
                 }
-            }
+                Err(e) => {
+                    error!("❌ Failed to handle summary for {}: {:?}", node_name, e);
+                    Err(e)
+                }
+            },
             Err(e) => {
                 error!("❌ Failed to fetch summary for {}: {:?}", node_name, e);
+                Err(e)
             }
+        };
+
+        if elapsed > timeout / 2 {
+            warn!("🐢 Summary of {} took {} ms", node_name, elapsed.as_millis());
         }
+        record_scrape(NodeScrapeStatus {
+            node_name,
+            scraped_at: Utc::now(),
+            duration_ms: elapsed.as_millis() as u64,
+            success: outcome.is_ok(),
+            error: outcome.err().map(|e| e.to_string()),
+        });
+    }
+
+    // --- Step 3: Tombstone objects that are gone ---
+    if let Err(e) = reconcile_deleted(&live) {
+        error!("❌ Failed to reconcile deleted objects: {:?}", e);
     }
     Ok(())
 }
@@ -53,48 +142,31 @@ pub struct SummaryHandleResultDto {
 }
 
 
-/// Handle and persist one `/stats/summary` response
-pub async fn handle_summary(summary: &Summary) -> Result<SummaryHandleResultDto> {
+/// Handle and persist one `/stats/summary` response, node interfaces and system containers included;
+/// records the pods, containers and PVCs it lists in `live`. Pod and container rows are merged with
+/// the node's cAdvisor stats of this tick, node and pod rows with its DCGM GPU stats, when the
+/// collectors got them.
+pub async fn handle_summary(summary: &Summary, live: &mut LiveObjects) -> Result<SummaryHandleResultDto> {
     let mut result = SummaryHandleResultDto::default();
 
-    if handle_node(summary).await? {
+    let pods_on_node: HashSet<PodKey> = summary
+        .pods
+        .iter()
+        .flatten()
+        .map(|p| (p.pod_ref.namespace.clone(), p.pod_ref.name.clone()))
+        .collect();
+    let gpu = take_gpu_stats(&summary.node.node_name, &pods_on_node);
+
+    if handle_node(summary, gpu.as_ref()).await? {
         result.node_name = Some(summary.node.node_name.clone());
     }
+    handle_node_network(summary).await?;
+    handle_node_system(summary).await?;
 
-    handle_pod(summary).await?;
-    handle_container(summary).await?;
+    let cadvisor = take_node_stats(&summary.node.node_name);
+    handle_pod(summary, cadvisor.as_ref(), gpu.as_ref(), &mut live.pods).await?;
+    handle_container(summary, cadvisor.as_ref(), &mut live.containers).await?;
+    handle_pvc(summary, &mut live.pvcs).await?;
 
     Ok(result)
 }
-
-/* ---------------- Tests ---------------- */
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use tracing_subscriber::{fmt, EnvFilter};
-
-    #[test]
-    fn test_run_does_not_panic() {
-        // Initialize full tracing (only once)
-        let _ = fmt()
-            .with_env_filter(EnvFilter::new("debug")) // show debug/info/warn/error
-            .with_target(true)
-            .with_level(true)
-            .with_test_writer()
-            .try_init();
-
-        // Build a single-threaded Tokio runtime
-        let rt = tokio::runtime::Builder::new_current_thread()
-            .enable_all()
-            .build()
-            .expect("Failed to build tokio runtime");
-
-        // Run async code inside the same thread (so debugger can attach)
-        rt.block_on(async {
-            let result = run().await;
-            // Allow both Ok and Err but ensure no panic
-            assert!(result.is_ok() || result.is_err());
-        });
-    }
-}
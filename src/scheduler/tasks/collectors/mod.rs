@@ -1,3 +1,5 @@
 pub mod rustexporter;
 pub mod cadvisor;
+pub(crate) mod common;
+pub mod dcgm;
 pub mod k8s;
@@ -0,0 +1,115 @@
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::info_source::SOURCE_RUSTEXPORTER;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::scheduler::tasks::collectors::rustexporter::models::{ExporterContainer, ExporterHost, ExporterUsage};
+use chrono::{DateTime, Utc};
+
+/// Refreshes the node info of an exporter host.
+///
+/// Starts from the stored entry, so team/service/env set through the API and
+/// the tombstone state survive. Allocatable equals capacity: a plain host
+/// reserves nothing for a kubelet.
+pub fn map_host_to_node_info(host: &ExporterHost, existing: Option<InfoNodeEntity>) -> InfoNodeEntity {
+    let mut info = existing.unwrap_or_default();
+
+    info.node_name = Some(host.name.clone());
+    info.source = Some(SOURCE_RUSTEXPORTER.to_string());
+    info.last_updated_info_at = Some(Utc::now());
+    info.ready = Some(true);
+
+    info.hostname = host.hostname.clone().or_else(|| Some(host.name.clone()));
+    info.internal_ip = host.internal_ip.clone();
+    info.architecture = host.architecture.clone();
+    info.os_image = host.os_image.clone();
+    info.kernel_version = host.kernel_version.clone();
+    info.operating_system = host.operating_system.clone();
+    info.container_runtime = host.container_runtime.clone();
+
+    info.cpu_capacity_cores = host.cpu_capacity_cores;
+    info.memory_capacity_bytes = host.memory_capacity_bytes;
+    info.ephemeral_storage_capacity_bytes = host.ephemeral_storage_capacity_bytes;
+    info.cpu_allocatable_cores = host.cpu_capacity_cores;
+    info.memory_allocatable_bytes = host.memory_capacity_bytes;
+    info.ephemeral_storage_allocatable_bytes = host.ephemeral_storage_capacity_bytes;
+
+    info
+}
+
+pub fn map_usage_to_node_metrics(usage: &ExporterUsage, time: DateTime<Utc>) -> MetricNodeEntity {
+    MetricNodeEntity {
+        time,
+
+        // CPU
+        cpu_usage_nano_cores: usage.cpu_usage_nano_cores,
+        cpu_usage_core_nano_seconds: usage.cpu_usage_core_nano_seconds,
+
+        // Memory
+        memory_usage_bytes: usage.memory_usage_bytes,
+        memory_working_set_bytes: usage.memory_working_set_bytes,
+        memory_rss_bytes: usage.memory_rss_bytes,
+        memory_available_bytes: usage.memory_available_bytes,
+
+        // Network
+        network_physical_rx_bytes: usage.network_rx_bytes,
+        network_physical_tx_bytes: usage.network_tx_bytes,
+        network_physical_rx_errors: usage.network_rx_errors,
+        network_physical_tx_errors: usage.network_tx_errors,
+
+        // Filesystem
+        fs_used_bytes: usage.fs_used_bytes,
+        fs_capacity_bytes: usage.fs_capacity_bytes,
+
+        ..Default::default()
+    }
+}
+
+/// Refreshes the container info of an exporter container. It has no pod; the
+/// host stands in as its node.
+pub fn map_container_to_info(
+    container: &ExporterContainer,
+    host_name: &str,
+    existing: Option<InfoContainerEntity>,
+) -> InfoContainerEntity {
+    let mut info = existing.unwrap_or_default();
+
+    info.container_name = Some(container.name.clone());
+    info.node_name = Some(host_name.to_string());
+    info.source = Some(SOURCE_RUSTEXPORTER.to_string());
+    info.last_updated_info_at = Some(Utc::now());
+
+    info.container_id = container.id.clone();
+    info.image = container.image.clone();
+    info.start_time = container.started_at;
+    info.state = Some("Running".to_string());
+    info.ready = Some(true);
+
+    info.cpu_limit_millicores = container.cpu_limit_millicores;
+    info.memory_limit_bytes = container.memory_limit_bytes;
+
+    info
+}
+
+pub fn map_container_to_metrics(container: &ExporterContainer, time: DateTime<Utc>) -> MetricContainerEntity {
+    let usage = &container.usage;
+    MetricContainerEntity {
+        time,
+
+        // CPU
+        cpu_usage_nano_cores: usage.cpu_usage_nano_cores,
+        cpu_usage_core_nano_seconds: usage.cpu_usage_core_nano_seconds,
+
+        // Memory
+        memory_usage_bytes: usage.memory_usage_bytes,
+        memory_working_set_bytes: usage.memory_working_set_bytes,
+        memory_rss_bytes: usage.memory_rss_bytes,
+        memory_available_bytes: usage.memory_available_bytes,
+
+        // Filesystem
+        fs_used_bytes: usage.fs_used_bytes,
+        fs_capacity_bytes: usage.fs_capacity_bytes,
+
+        ..Default::default()
+    }
+}
@@ -1,2 +1,8 @@
+/* Entry point */
 mod task;
 pub use task::run;
+
+/* Exporter payload → node and container entities */
+pub mod models;
+mod mapper;
+mod repository;
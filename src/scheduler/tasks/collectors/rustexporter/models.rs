@@ -0,0 +1,118 @@
+//! Payload served by a RustExporter, fetched with `GET <url>` as JSON.
+//!
+//! ```json
+//! {
+//!   "timestamp": "2026-10-18T12:00:00Z",
+//!   "host": {
+//!     "name": "vm-billing-01",
+//!     "internal_ip": "10.0.4.17",
+//!     "os_image": "Ubuntu 24.04 LTS",
+//!     "container_runtime": "docker://27.1.1",
+//!     "cpu_capacity_cores": 8,
+//!     "memory_capacity_bytes": 34359738368
+//!   },
+//!   "node": {
+//!     "cpu_usage_nano_cores": 1250000000,
+//!     "cpu_usage_core_nano_seconds": 9182736450000,
+//!     "memory_usage_bytes": 12884901888,
+//!     "memory_working_set_bytes": 8589934592,
+//!     "fs_used_bytes": 53687091200,
+//!     "fs_capacity_bytes": 107374182400,
+//!     "network_rx_bytes": 73829103,
+//!     "network_tx_bytes": 10293847
+//!   },
+//!   "containers": [
+//!     {
+//!       "name": "billing-api",
+//!       "id": "docker://4f1c9a",
+//!       "image": "ghcr.io/acme/billing-api:1.4.2",
+//!       "cpu_limit_millicores": 2000,
+//!       "cpu_usage_nano_cores": 250000000,
+//!       "memory_working_set_bytes": 536870912
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Only `host.name` and each `containers[].name` are required; they become the
+//! storage keys and must be made of letters, digits, `-`, `_` and `.`. Metrics
+//! use the units of the kubelet summary: nano-cores, cumulative core
+//! nano-seconds and cumulative network counters, bytes. Containers report no
+//! network counters; they share the host's.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExporterPayload {
+    /// Time of the sample. Only checked against the scrape time, which the rows
+    /// are stamped with; see `MAX_CLOCK_SKEW_SEC`.
+    pub timestamp: Option<DateTime<Utc>>,
+    pub host: ExporterHost,
+    #[serde(default)]
+    pub node: ExporterUsage,
+    #[serde(default)]
+    pub containers: Vec<ExporterContainer>,
+}
+
+/// Identity and capacity of the host, stored as node info.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExporterHost {
+    pub name: String,
+    pub hostname: Option<String>,
+    pub internal_ip: Option<String>,
+    pub architecture: Option<String>,
+    pub os_image: Option<String>,
+    pub kernel_version: Option<String>,
+    pub operating_system: Option<String>,
+    pub container_runtime: Option<String>,
+    pub cpu_capacity_cores: Option<u32>,
+    pub memory_capacity_bytes: Option<u64>,
+    pub ephemeral_storage_capacity_bytes: Option<u64>,
+}
+
+/// Usage of the host or of one container.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ExporterUsage {
+    // CPU
+    pub cpu_usage_nano_cores: Option<u64>,
+    pub cpu_usage_core_nano_seconds: Option<u64>,
+
+    // Memory
+    pub memory_usage_bytes: Option<u64>,
+    pub memory_working_set_bytes: Option<u64>,
+    pub memory_rss_bytes: Option<u64>,
+    pub memory_available_bytes: Option<u64>,
+
+    // Filesystem
+    pub fs_used_bytes: Option<u64>,
+    pub fs_capacity_bytes: Option<u64>,
+
+    // Network (host only)
+    pub network_rx_bytes: Option<u64>,
+    pub network_tx_bytes: Option<u64>,
+    pub network_rx_errors: Option<u64>,
+    pub network_tx_errors: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExporterContainer {
+    pub name: String,
+    /// Runtime ID, e.g. `docker://…`.
+    pub id: Option<String>,
+    pub image: Option<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub cpu_limit_millicores: Option<u64>,
+    pub memory_limit_bytes: Option<u64>,
+    #[serde(flatten)]
+    pub usage: ExporterUsage,
+}
+
+/// Host names and container names become path components of the storage keys.
+pub fn is_valid_exporter_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 253
+        && !name.starts_with('.')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
@@ -0,0 +1,96 @@
+use crate::core::persistence::info::k8s::container::info_container_collector_repository_trait::InfoContainerCollectorRepository;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::container::info_container_fs_adapter::InfoContainerFsAdapter;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::node::info_node_collector_repository_trait::InfoNodeCollectorRepository;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::node::info_node_fs_adapter::InfoNodeFsAdapter;
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_collector_repository_trait::MetricContainerMinuteCollectorRepository;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_collector_repository_trait::MetricNodeMinuteCollectorRepository;
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use anyhow::Result;
+use std::sync::Arc;
+
+/// Node info of exporter hosts, stored next to the Kubernetes nodes.
+pub struct ExporterNodeInfoRepositoryImpl {
+    adapter: InfoNodeFsAdapter,
+}
+
+impl Default for ExporterNodeInfoRepositoryImpl {
+    fn default() -> Self {
+        Self {
+            adapter: InfoNodeFsAdapter,
+        }
+    }
+}
+
+impl InfoNodeCollectorRepository for ExporterNodeInfoRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoNodeEntity> {
+        &self.adapter
+    }
+
+    fn exists(&self, node_name: &str) -> Result<bool> {
+        self.adapter.exists(node_name)
+    }
+
+    fn create_if_missing(&self, node_name: &str, data: &InfoNodeEntity) -> Result<bool> {
+        if self.adapter.exists(node_name)? {
+            return Ok(false);
+        }
+        self.adapter.insert(data)?;
+        Ok(true)
+    }
+}
+
+/// Container info of exporter containers, keyed `<host>-<container_name>`.
+pub struct ExporterContainerInfoRepositoryImpl {
+    adapter: InfoContainerFsAdapter,
+}
+
+impl Default for ExporterContainerInfoRepositoryImpl {
+    fn default() -> Self {
+        Self {
+            adapter: InfoContainerFsAdapter,
+        }
+    }
+}
+
+impl InfoContainerCollectorRepository for ExporterContainerInfoRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoContainerEntity> {
+        &self.adapter
+    }
+
+    fn exists(&self, container_key: &str) -> Result<bool> {
+        self.adapter.exists(container_key)
+    }
+
+    fn create_if_missing(&self, container_key: &str, data: &InfoContainerEntity) -> Result<bool> {
+        if self.adapter.exists(container_key)? {
+            return Ok(false);
+        }
+        self.adapter.insert(data)?;
+        Ok(true)
+    }
+}
+
+pub struct ExporterNodeMetricRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeEntity>>,
+}
+
+impl MetricNodeMinuteCollectorRepository for ExporterNodeMetricRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
+        self.adapter.as_ref()
+    }
+}
+
+pub struct ExporterContainerMetricRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricContainerEntity>>,
+}
+
+impl MetricContainerMinuteCollectorRepository for ExporterContainerMetricRepositoryImpl {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
+        self.adapter.as_ref()
+    }
+}
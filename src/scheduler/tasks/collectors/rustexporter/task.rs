@@ -1,6 +1,187 @@
-use anyhow::Result;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::info::k8s::container::info_container_collector_repository_trait::InfoContainerCollectorRepository;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::info_source::{exporter_container_key, is_k8s_source, SOURCE_RUSTEXPORTER};
+use crate::core::persistence::info::k8s::node::info_node_collector_repository_trait::InfoNodeCollectorRepository;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_collector_repository_trait::MetricContainerMinuteCollectorRepository;
+use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_collector_repository_trait::MetricNodeMinuteCollectorRepository;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::scheduler::tasks::collectors::k8s::reconcile::reconcile_kind;
+use crate::scheduler::tasks::collectors::k8s::{scrape_concurrency, scrape_timeout};
+use crate::scheduler::tasks::collectors::rustexporter::mapper::{
+    map_container_to_info, map_container_to_metrics, map_host_to_node_info, map_usage_to_node_metrics,
+};
+use crate::scheduler::tasks::collectors::rustexporter::models::{is_valid_exporter_name, ExporterPayload};
+use crate::scheduler::tasks::collectors::rustexporter::repository::{
+    ExporterContainerInfoRepositoryImpl, ExporterContainerMetricRepositoryImpl, ExporterNodeInfoRepositoryImpl,
+    ExporterNodeMetricRepositoryImpl,
+};
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+use futures_util::{stream, StreamExt};
+use reqwest::Client;
+use std::collections::HashSet;
+use tracing::{debug, error, warn};
 
-pub async fn run() -> Result<()> {
-    // placeholder implementation
+/// Largest difference between a payload's timestamp and the scrape time that is
+/// accepted; beyond it the exporter's clock is off and the payload is dropped.
+const MAX_CLOCK_SKEW_SEC: i64 = 30;
+
+/// Hosts and containers seen during one collector cycle.
+#[derive(Debug, Default)]
+struct ExporterLiveObjects {
+    /// Hosts named by a payload, stored or not; a second exporter claiming one is refused.
+    claimed_hosts: HashSet<String>,
+    /// Hosts whose payload was stored completely.
+    stored_hosts: HashSet<String>,
+    containers: HashSet<String>,
+}
+
+/// Collects hosts and containers outside Kubernetes from the configured
+/// RustExporters (`container_exporter_urls`), see [`ExporterPayload`].
+///
+/// Hosts are stored as nodes and their containers as containers, tagged with
+/// the `rustexporter` source, so they show up in the same metric and cost
+/// views. Each URL is fetched and stored on its own: a failing or slow exporter
+/// is logged and skipped without affecting the others.
+pub async fn run(settings: &InfoSettingEntity) -> Result<()> {
+    if !settings.enable_container_exporter {
+        debug!("RustExporter collector disabled");
+        return Ok(());
+    }
+
+    let urls: Vec<String> = settings
+        .container_exporter_urls
+        .iter()
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty())
+        .collect();
+    if urls.is_empty() {
+        warn!("⚠️ RustExporter collector enabled without exporter URLs");
+        return Ok(());
+    }
+    debug!("Starting RustExporter task for {} exporter(s)...", urls.len());
+
+    let client = Client::new();
+    let timeout = scrape_timeout(settings);
+    let mut scrapes = stream::iter(urls)
+        .map(|url| {
+            let client = &client;
+            async move {
+                let payload = match tokio::time::timeout(timeout, fetch_payload(client, &url)).await {
+                    Ok(result) => result,
+                    Err(_) => Err(anyhow!("timed out after {}s", timeout.as_secs())),
+                };
+                (url, payload, Utc::now())
+            }
+        })
+        .buffer_unordered(scrape_concurrency());
+
+    let mut live = ExporterLiveObjects::default();
+    while let Some((url, payload, scraped_at)) = scrapes.next().await {
+        let outcome = match payload {
+            Ok(payload) => handle_payload(&payload, scraped_at, &mut live),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = outcome {
+            error!("❌ RustExporter '{}' failed: {:?}", url, e);
+        }
+    }
+
+    if let Err(e) = reconcile_exporter_containers(&live) {
+        error!("❌ Failed to reconcile exporter containers: {:?}", e);
+    }
+    Ok(())
+}
+
+async fn fetch_payload(client: &Client, url: &str) -> Result<ExporterPayload> {
+    let resp = client.get(url).send().await?.error_for_status()?;
+    Ok(resp.json().await?)
+}
+
+/// Stores the host and containers of one payload, stamped with the scrape time.
+///
+/// The exporter's clock is not trusted: a payload whose timestamp is more than
+/// [`MAX_CLOCK_SKEW_SEC`] away from `scraped_at` is rejected.
+fn handle_payload(payload: &ExporterPayload, scraped_at: DateTime<Utc>, live: &mut ExporterLiveObjects) -> Result<()> {
+    let host_name = payload.host.name.as_str();
+    if !is_valid_exporter_name(host_name) {
+        bail!("invalid host name '{}'", host_name);
+    }
+    if let Some(timestamp) = payload.timestamp {
+        let skew = (timestamp - scraped_at).num_seconds().abs();
+        if skew > MAX_CLOCK_SKEW_SEC {
+            bail!(
+                "host '{}' reported time {} which is {}s away from the scrape time {}",
+                host_name, timestamp, skew, scraped_at
+            );
+        }
+    }
+    if !live.claimed_hosts.insert(host_name.to_string()) {
+        bail!("host '{}' is already reported by another exporter", host_name);
+    }
+
+    let backend = metric_backend()?;
+
+    // ---- Host → node ----
+    let node_info_repo = ExporterNodeInfoRepositoryImpl::default();
+    let existing = match node_info_repo.exists(host_name)? {
+        true => Some(node_info_repo.fs_adapter().read(host_name)?),
+        false => None,
+    };
+    if existing.as_ref().is_some_and(|n| is_k8s_source(n.source.as_deref())) {
+        bail!("host '{}' has the name of a Kubernetes node", host_name);
+    }
+    node_info_repo.update(&map_host_to_node_info(&payload.host, existing))?;
+
+    let node_metric_repo = ExporterNodeMetricRepositoryImpl {
+        adapter: backend.node(MetricTier::Minute),
+    };
+    node_metric_repo.append_row(host_name, &map_usage_to_node_metrics(&payload.node, scraped_at))?;
+
+    // ---- Containers ----
+    let container_info_repo = ExporterContainerInfoRepositoryImpl::default();
+    let container_metric_repo = ExporterContainerMetricRepositoryImpl {
+        adapter: backend.container(MetricTier::Minute),
+    };
+    for container in &payload.containers {
+        if !is_valid_exporter_name(&container.name) {
+            warn!("⚠️ Skipping container '{}' of host '{}': invalid name", container.name, host_name);
+            continue;
+        }
+        let key = exporter_container_key(host_name, &container.name);
+        if !live.containers.insert(key.clone()) {
+            warn!("⚠️ Skipping duplicate container '{}' of host '{}'", container.name, host_name);
+            continue;
+        }
+
+        let existing = match container_info_repo.exists(&key)? {
+            true => Some(container_info_repo.fs_adapter().read(&key)?),
+            false => None,
+        };
+        container_info_repo.update(&map_container_to_info(container, host_name, existing))?;
+        container_metric_repo.append_row(&key, &map_container_to_metrics(container, scraped_at))?;
+    }
+
+    live.stored_hosts.insert(host_name.to_string());
+    debug!("Stored exporter host '{}' with {} container(s)", host_name, payload.containers.len());
     Ok(())
 }
+
+/// Tombstones exporter containers that are gone from hosts stored this cycle.
+///
+/// Hosts themselves are never tombstoned here: a failing URL says nothing
+/// about the host behind it, and the URL does not name the host.
+fn reconcile_exporter_containers(live: &ExporterLiveObjects) -> Result<()> {
+    let repo = ExporterContainerInfoRepositoryImpl::default();
+    reconcile_kind(
+        "exporter container",
+        repo.fs_adapter(),
+        &live.containers,
+        |c: &InfoContainerEntity| {
+            c.source.as_deref() == Some(SOURCE_RUSTEXPORTER)
+                && c.node_name.as_deref().is_some_and(|n| live.stored_hosts.contains(n))
+        },
+    )
+}
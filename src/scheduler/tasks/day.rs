@@ -1,13 +1,22 @@
 use anyhow::Result;
 use tracing::{debug, error};
 
+use crate::core::persistence::storage_lock::lock_for_write;
+
 pub async fn run() -> Result<()> {
-    debug!("Running day task (aggregation + retention)...");
+    debug!("Running day task (aggregation + compression + retention)...");
+
+    // Backup/restore wait for in-flight writes to finish.
+    let _guard = lock_for_write().await;
 
     if let Err(e) = super::processors::day::run().await {
         error!(?e, "Daily aggregator failed");
     }
 
+    if let Err(e) = super::processors::compression::run().await {
+        error!(?e, "Segment compression failed");
+    }
+
     if let Err(e) = super::processors::retention::run().await {
         error!(?e, "Retention cleanup failed");
     }
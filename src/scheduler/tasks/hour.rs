@@ -1,12 +1,21 @@
 use anyhow::Result;
 use tracing::{debug, error};
 
+use crate::core::persistence::storage_lock::lock_for_write;
+
 pub async fn run() -> Result<()> {
-    debug!("Running hour task (aggregation + summarization)...");
+    debug!("Running hour task (aggregation + summarization + quota)...");
+
+    // Backup/restore wait for in-flight writes to finish.
+    let _guard = lock_for_write().await;
 
     if let Err(e) = super::processors::hour::run().await {
         error!(?e, "hour aggregator failed");
     }
 
+    if let Err(e) = super::processors::quota::run().await {
+        error!(?e, "Storage quota enforcement failed");
+    }
+
     Ok(())
 }
@@ -1,24 +1,70 @@
 use anyhow::Result;
-use tracing::{debug, error};
+use std::time::Duration;
+use tracing::{debug, error, warn};
+
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::metrics::segment::index::set_index_enabled;
+use crate::core::persistence::metrics::segment::write_buffer::set_batch_size;
+use crate::core::persistence::storage_lock::lock_for_write;
 
 pub async fn run() -> Result<()> {
-    debug!("Running minutely task (collectors + summarizers)...");
+    debug!("Running minutely task (quota + collectors + summarizers)...");
+
+    // Backup/restore wait for in-flight writes to finish.
+    let _guard = lock_for_write().await;
 
     // Info check (safe and fast)
     let info = super::info::load_info_state().await?;
     debug!("Version: {}", info.version.git_version);
     debug!("Settings: {:?}", info.settings);
+    set_index_enabled(info.settings.enable_index_file);
+    set_batch_size(info.settings.metrics_batch_size);
 
+    // Make room before this tick's rows are written; the full walk runs hourly
+    if let Err(e) = super::processors::quota::check().await {
+        error!(?e, "Storage quota enforcement failed");
+    }
 
     // --- Collectors ---
-    if let Err(e) = super::collectors::k8s::run().await {
+    // Bounded by the tick: per-request timeouts cover slow endpoints, this covers everything else.
+    let deadline = Duration::from_secs(info.settings.scrape_interval_sec() as u64);
+    if tokio::time::timeout(deadline, run_collectors(&info.settings)).await.is_err() {
+        error!("Collectors did not finish within {}s; the rest of this tick is skipped", deadline.as_secs());
+    }
+
+    Ok(())
+}
+
+async fn run_collectors(settings: &InfoSettingEntity) {
+    // cAdvisor first: the k8s collector merges its stats into the pod and container rows.
+    if let Err(e) = super::collectors::cadvisor::run(settings).await {
+        error!(?e, "cAdvisor collector failed");
+    }
+
+    // DCGM before k8s as well: GPU stats go into the node and pod rows.
+    if let Err(e) = super::collectors::dcgm::run(settings).await {
+        error!(?e, "DCGM collector failed");
+    }
+
+    if let Err(e) = super::collectors::k8s::run(settings).await {
         error!(?e, "K8s collector failed");
     }
 
-    if let Err(e) = super::collectors::rustexporter::run().await {
+    if let Err(e) = super::collectors::rustexporter::run(settings).await {
         error!(?e, "RustExporter collector failed");
     }
-
-    Ok(())
 }
 
+/// Seconds between two collector runs, from `scrape_interval_sec`.
+///
+/// Read by the minute loop before every wait, so a changed setting applies from
+/// the next run. Falls back to the default when the settings cannot be read.
+pub fn scrape_interval_sec() -> u32 {
+    match super::info::settings::task::load_or_init_settings() {
+        Ok(settings) => settings.scrape_interval_sec(),
+        Err(e) => {
+            warn!(?e, "Failed to read scrape interval, using the default");
+            InfoSettingEntity::default().scrape_interval_sec()
+        }
+    }
+}
@@ -8,4 +8,5 @@ mod info;
 pub use day::run as day_task;
 pub use hour::run as hour_task;
 pub use minute::run as minute_task;
+pub use minute::scrape_interval_sec;
 
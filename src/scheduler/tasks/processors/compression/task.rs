@@ -0,0 +1,72 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tracing::{debug, error};
+
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_container_dir_path, metric_k8s_node_dir_path, metric_k8s_node_network_dir_path,
+    metric_k8s_node_system_dir_path, metric_k8s_pod_dir_path, metric_k8s_pvc_dir_path,
+};
+use crate::core::persistence::metrics::segment::compression::compress_closed_segments;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+use crate::scheduler::tasks::info::settings::task::load_or_init_settings;
+
+/// Compresses closed minute/hour/day segments for every node, pod and container.
+///
+/// Only runs when `compression_enabled` is set. Segments for the current
+/// day/month/year are still being appended to and are left as plain text.
+pub async fn run() -> Result<()> {
+    let settings = load_or_init_settings()?;
+    if !settings.compression_enabled {
+        debug!("Segment compression disabled; skipping");
+        return Ok(());
+    }
+
+    let now = Utc::now();
+    let mut total = 0;
+    for base_dir in [
+        metric_k8s_node_dir_path(),
+        metric_k8s_pod_dir_path(),
+        metric_k8s_container_dir_path(),
+        metric_k8s_pvc_dir_path(),
+        metric_k8s_node_network_dir_path(),
+        metric_k8s_node_system_dir_path(),
+    ] {
+        total += compress_kind(&base_dir, now)?;
+    }
+
+    debug!("✅ Compressed {} closed metric segments", total);
+    Ok(())
+}
+
+fn compress_kind(base_dir: &Path, now: DateTime<Utc>) -> Result<usize> {
+    if !base_dir.exists() {
+        return Ok(0);
+    }
+
+    let mut compressed = 0;
+    for key_dir in collect_key_dirs(base_dir)? {
+        for tier in MetricTier::ALL {
+            let dir = key_dir.join(tier.dir_name());
+            match compress_closed_segments(&dir, tier, now) {
+                Ok(n) => compressed += n,
+                Err(err) => error!("⚠️ Compression failed for {:?}: {}", dir, err),
+            }
+        }
+    }
+    Ok(compressed)
+}
+
+/// Collects all per-object directories under the given base directory.
+fn collect_key_dirs(base_dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(base_dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    Ok(dirs)
+}
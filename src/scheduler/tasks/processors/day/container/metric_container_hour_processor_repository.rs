@@ -2,15 +2,15 @@ use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAda
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_processor_repository_trait::MetricContainerDayProcessorRepository;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use chrono::{DateTime, Utc};
-use crate::core::persistence::metrics::k8s::container::day::metric_container_day_fs_adapter::MetricContainerDayFsAdapter;
+use std::sync::Arc;
 
 pub struct MetricContainerDayProcessorRepositoryImpl {
-    pub adapter: MetricContainerDayFsAdapter,
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricContainerEntity>>,
 }
 
 impl MetricContainerDayProcessorRepository for MetricContainerDayProcessorRepositoryImpl  {
     fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn append_row_aggregated(&self, container_uid: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> anyhow::Result<()> {
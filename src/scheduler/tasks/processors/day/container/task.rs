@@ -1,41 +1,38 @@
-use std::fs;
-use std::path::{PathBuf};
-
 use anyhow::{Context, Result};
 use chrono::{Duration, Timelike, Utc};
 
 use crate::core::persistence::metrics::k8s::container::day::{
-    metric_container_day_fs_adapter::MetricContainerDayFsAdapter,
     metric_container_day_processor_repository_trait::MetricContainerDayProcessorRepository,
 };
-use tracing::{debug, error};
-use crate::core::persistence::metrics::k8s::path::metric_k8s_container_dir_path;
+use tracing::{debug, error, info};
+use crate::core::persistence::metrics::segment::watermark::pending_windows;
 use crate::scheduler::tasks::processors::day::container::metric_container_hour_processor_repository::MetricContainerDayProcessorRepositoryImpl;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{MetricObjectKind, MetricStorageBackend};
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Day windows aggregated per object and run (one month); a longer backlog
+/// is worked off oldest first over the following runs.
+const MAX_CATCH_UP_WINDOWS: usize = 31;
 
 /// Aggregates all containers’ minute-level metrics into dayly metrics.
 ///
-/// This scans `data/metric/container/{container_key}/` and calls `append_row_aggregated()`
-/// for each container directory, generating an dayly summary.
+/// This walks every container with stored metrics and upserts every window since the
+/// object's watermark, so missed windows are caught up and reruns are safe.
 pub async fn process_container_hour_to_day() -> Result<()> {
-    let (start, end) = previous_day_window()?;
-    let base_dir = metric_k8s_container_dir_path();
-
-    if !base_dir.exists() {
-        debug!("No containers directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
-    let container_keys = collect_container_keys(&base_dir)?;
+    let (_, until) = previous_day_window()?;
+    let backend = metric_backend()?;
+    let container_keys = backend.keys(MetricObjectKind::Container)?;
     if container_keys.is_empty() {
-        debug!("No container metric directories found under {:?}", base_dir);
+        debug!("No container metrics found");
         return Ok(());
     }
 
     let repo = MetricContainerDayProcessorRepositoryImpl {
-        adapter: MetricContainerDayFsAdapter,
+        adapter: backend.container(MetricTier::Day),
     };
 
-    process_all_containers(&repo, &container_keys, start, end);
+    process_all_containers(&repo, backend.as_ref(), &container_keys, until);
     Ok(())
 }
 
@@ -43,7 +40,8 @@ pub async fn process_container_hour_to_day() -> Result<()> {
 fn previous_day_window() -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc>)> {
     let now = Utc::now();
     let end = now
-        .with_minute(0)
+        .with_hour(0)
+        .and_then(|d| d.with_minute(0))
         .and_then(|d| d.with_second(0))
         .and_then(|d| d.with_nanosecond(0))
         .context("failed to round current time to day")?;
@@ -51,36 +49,34 @@ fn previous_day_window() -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc>
     Ok((start, end))
 }
 
-/// Collects all container UIDs (directory names) under the given base directory.
-fn collect_container_keys(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut container_keys = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(container_key) = entry.file_name().to_str() {
-                container_keys.push(container_key.to_string());
-            }
-        }
-    }
-
-    Ok(container_keys)
-}
 
 /// Aggregates minute-level data into dayly data for all given containers.
 fn process_all_containers<R: MetricContainerDayProcessorRepository>(
     repo: &R,
+    backend: &dyn MetricStorageBackend,
     container_keys: &[String],
-    start: chrono::DateTime<Utc>,
-    end: chrono::DateTime<Utc>,
+    until: chrono::DateTime<Utc>,
 ) {
     for container_key in container_keys {
-        match repo.append_row_aggregated(container_key, start, end) {
-            Ok(_) => debug!(
-                "✅ Aggregated container '{}' minute metrics from {} → {}",
-                container_key, start, end
-            ),
+        let watermark = backend.watermark(MetricObjectKind::Container, MetricTier::Day, container_key);
+        let windows = pending_windows(watermark, until, Duration::days(1), MAX_CATCH_UP_WINDOWS);
+        let (Some(&(first, _)), Some(&(_, end))) = (windows.first(), windows.last()) else {
+            continue;
+        };
+
+        match repo.upsert_aggregated(container_key, &windows) {
+            Ok(rows) => {
+                debug!(
+                    "✅ Aggregated container '{}' hour metrics from {} → {} ({} windows, {} rows)",
+                    container_key, first, end, windows.len(), rows
+                );
+                if let Err(err) = backend.set_watermark(MetricObjectKind::Container, MetricTier::Day, container_key, end) {
+                    error!("⚠️ Failed to record watermark for container '{}': {}", container_key, err);
+                }
+                if end < until {
+                    info!("⏳ Container '{}' day aggregation caught up to {}; the rest follows next run", container_key, end);
+                }
+            }
             Err(err) => error!(
                 "⚠️ Failed to aggregate container '{}' metrics: {}",
                 container_key, err
@@ -3,4 +3,7 @@ pub use task::run;
 
 pub mod container;
 pub mod node;
-pub mod pod;
\ No newline at end of file
+pub mod pod;
+pub mod pvc;
+pub mod node_network;
+pub mod node_system;
\ No newline at end of file
@@ -1,41 +1,38 @@
-use std::fs;
-use std::path::{PathBuf};
-
 use anyhow::{Context, Result};
 use chrono::{Duration, Timelike, Utc};
 
 use crate::core::persistence::metrics::k8s::node::day::{
-    metric_node_day_fs_adapter::MetricNodeDayFsAdapter,
     metric_node_day_processor_repository_trait::MetricNodeDayProcessorRepository,
 };
-use tracing::{debug, error};
-use crate::core::persistence::metrics::k8s::path::metric_k8s_node_dir_path;
+use tracing::{debug, error, info};
+use crate::core::persistence::metrics::segment::watermark::pending_windows;
 use crate::scheduler::tasks::processors::day::node::metric_node_day_processor_repository::MetricNodeDayProcessorRepositoryImpl;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{MetricObjectKind, MetricStorageBackend};
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Day windows aggregated per object and run (one month); a longer backlog
+/// is worked off oldest first over the following runs.
+const MAX_CATCH_UP_WINDOWS: usize = 31;
 
 /// Aggregates all nodes’ minute-level metrics into dayly metrics.
 ///
-/// This scans `data/metric/node/{node_name}/` and calls `append_row_aggregated()`
-/// for each node directory, generating an dayly summary.
+/// This walks every node with stored metrics and upserts every window since the
+/// object's watermark, so missed windows are caught up and reruns are safe.
 pub async fn process_node_hour_to_day() -> Result<()> {
-    let (start, end) = previous_day_window()?;
-    let base_dir = metric_k8s_node_dir_path();
-
-    if !base_dir.exists() {
-        debug!("No nodes directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
-    let node_names = collect_node_names(&base_dir)?;
+    let (_, until) = previous_day_window()?;
+    let backend = metric_backend()?;
+    let node_names = backend.keys(MetricObjectKind::Node)?;
     if node_names.is_empty() {
-        debug!("No node metric directories found under {:?}", base_dir);
+        debug!("No node metrics found");
         return Ok(());
     }
 
     let repo = MetricNodeDayProcessorRepositoryImpl {
-        adapter: MetricNodeDayFsAdapter,
+        adapter: backend.node(MetricTier::Day),
     };
 
-    process_all_nodes(&repo, &node_names, start, end);
+    process_all_nodes(&repo, backend.as_ref(), &node_names, until);
     Ok(())
 }
 
@@ -43,7 +40,8 @@ pub async fn process_node_hour_to_day() -> Result<()> {
 fn previous_day_window() -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc>)> {
     let now = Utc::now();
     let end = now
-        .with_minute(0)
+        .with_hour(0)
+        .and_then(|d| d.with_minute(0))
         .and_then(|d| d.with_second(0))
         .and_then(|d| d.with_nanosecond(0))
         .context("failed to round current time to day")?;
@@ -51,36 +49,34 @@ fn previous_day_window() -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc>
     Ok((start, end))
 }
 
-/// Collects all node UIDs (directory names) under the given base directory.
-fn collect_node_names(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut node_names = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(node_name) = entry.file_name().to_str() {
-                node_names.push(node_name.to_string());
-            }
-        }
-    }
-
-    Ok(node_names)
-}
 
 /// Aggregates minute-level data into dayly data for all given nodes.
 fn process_all_nodes<R: MetricNodeDayProcessorRepository>(
     repo: &R,
+    backend: &dyn MetricStorageBackend,
     node_names: &[String],
-    start: chrono::DateTime<Utc>,
-    end: chrono::DateTime<Utc>,
+    until: chrono::DateTime<Utc>,
 ) {
     for node_name in node_names {
-        match repo.append_row_aggregated(node_name, start, end) {
-            Ok(_) => debug!(
-                "✅ Aggregated node '{}' minute metrics from {} → {}",
-                node_name, start, end
-            ),
+        let watermark = backend.watermark(MetricObjectKind::Node, MetricTier::Day, node_name);
+        let windows = pending_windows(watermark, until, Duration::days(1), MAX_CATCH_UP_WINDOWS);
+        let (Some(&(first, _)), Some(&(_, end))) = (windows.first(), windows.last()) else {
+            continue;
+        };
+
+        match repo.upsert_aggregated(node_name, &windows) {
+            Ok(rows) => {
+                debug!(
+                    "✅ Aggregated node '{}' hour metrics from {} → {} ({} windows, {} rows)",
+                    node_name, first, end, windows.len(), rows
+                );
+                if let Err(err) = backend.set_watermark(MetricObjectKind::Node, MetricTier::Day, node_name, end) {
+                    error!("⚠️ Failed to record watermark for node '{}': {}", node_name, err);
+                }
+                if end < until {
+                    info!("⏳ Node '{}' day aggregation caught up to {}; the rest follows next run", node_name, end);
+                }
+            }
             Err(err) => error!(
                 "⚠️ Failed to aggregate node '{}' metrics: {}",
                 node_name, err
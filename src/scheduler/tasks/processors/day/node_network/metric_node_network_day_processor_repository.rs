@@ -0,0 +1,19 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_network::day::metric_node_network_day_processor_repository_trait::MetricNodeNetworkDayProcessorRepository;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+pub struct MetricNodeNetworkDayProcessorRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeNetworkEntity>>,
+}
+
+impl MetricNodeNetworkDayProcessorRepository for MetricNodeNetworkDayProcessorRepositoryImpl  {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeNetworkEntity> {
+        self.adapter.as_ref()
+    }
+
+    fn append_row_aggregated(&self, interface_key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> anyhow::Result<()> {
+        self.adapter.append_row_aggregated(interface_key, start, end)
+    }
+}
@@ -0,0 +1,87 @@
+use anyhow::{Context, Result};
+use chrono::{Duration, Timelike, Utc};
+
+use crate::core::persistence::metrics::k8s::node_network::day::{
+    metric_node_network_day_processor_repository_trait::MetricNodeNetworkDayProcessorRepository,
+};
+use tracing::{debug, error, info};
+use crate::core::persistence::metrics::segment::watermark::pending_windows;
+use crate::scheduler::tasks::processors::day::node_network::metric_node_network_day_processor_repository::MetricNodeNetworkDayProcessorRepositoryImpl;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{MetricObjectKind, MetricStorageBackend};
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Day windows aggregated per object and run (one month); a longer backlog
+/// is worked off oldest first over the following runs.
+const MAX_CATCH_UP_WINDOWS: usize = 31;
+
+/// Aggregates all node network interfaces’ hour-level metrics into day metrics.
+///
+/// This walks every node network interface with stored metrics and upserts
+/// every window since the object's watermark, so missed windows are caught up
+/// and reruns are safe.
+pub async fn process_node_network_hour_to_day() -> Result<()> {
+    let (_, until) = previous_day_window()?;
+    let backend = metric_backend()?;
+    let interface_keys = backend.keys(MetricObjectKind::NodeNetwork)?;
+    if interface_keys.is_empty() {
+        debug!("No node network metrics found");
+        return Ok(());
+    }
+
+    let repo = MetricNodeNetworkDayProcessorRepositoryImpl {
+        adapter: backend.node_network(MetricTier::Day),
+    };
+
+    process_all_node_networks(&repo, backend.as_ref(), &interface_keys, until);
+    Ok(())
+}
+
+/// Returns the start and end of the previous full day.
+fn previous_day_window() -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc>)> {
+    let now = Utc::now();
+    let end = now
+        .with_hour(0)
+        .and_then(|d| d.with_minute(0))
+        .and_then(|d| d.with_second(0))
+        .and_then(|d| d.with_nanosecond(0))
+        .context("failed to round current time to day")?;
+    let start = end - Duration::days(1);
+    Ok((start, end))
+}
+
+
+/// Aggregates hour-level data into day data for all given node network interfaces.
+fn process_all_node_networks<R: MetricNodeNetworkDayProcessorRepository>(
+    repo: &R,
+    backend: &dyn MetricStorageBackend,
+    interface_keys: &[String],
+    until: chrono::DateTime<Utc>,
+) {
+    for interface_key in interface_keys {
+        let watermark = backend.watermark(MetricObjectKind::NodeNetwork, MetricTier::Day, interface_key);
+        let windows = pending_windows(watermark, until, Duration::days(1), MAX_CATCH_UP_WINDOWS);
+        let (Some(&(first, _)), Some(&(_, end))) = (windows.first(), windows.last()) else {
+            continue;
+        };
+
+        match repo.upsert_aggregated(interface_key, &windows) {
+            Ok(rows) => {
+                debug!(
+                    "✅ Aggregated node interface '{}' hour metrics from {} → {} ({} windows, {} rows)",
+                    interface_key, first, end, windows.len(), rows
+                );
+                if let Err(err) = backend.set_watermark(MetricObjectKind::NodeNetwork, MetricTier::Day, interface_key, end) {
+                    error!("⚠️ Failed to record watermark for node interface '{}': {}", interface_key, err);
+                }
+                if end < until {
+                    info!("⏳ Node interface '{}' day aggregation caught up to {}; the rest follows next run", interface_key, end);
+                }
+            }
+            Err(err) => error!(
+                "⚠️ Failed to aggregate node interface '{}' metrics: {}",
+                interface_key, err
+            ),
+        }
+    }
+}
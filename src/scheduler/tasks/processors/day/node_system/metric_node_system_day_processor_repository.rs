@@ -0,0 +1,19 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_system::day::metric_node_system_day_processor_repository_trait::MetricNodeSystemDayProcessorRepository;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+pub struct MetricNodeSystemDayProcessorRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeSystemEntity>>,
+}
+
+impl MetricNodeSystemDayProcessorRepository for MetricNodeSystemDayProcessorRepositoryImpl  {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeSystemEntity> {
+        self.adapter.as_ref()
+    }
+
+    fn append_row_aggregated(&self, system_key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> anyhow::Result<()> {
+        self.adapter.append_row_aggregated(system_key, start, end)
+    }
+}
@@ -1,41 +1,38 @@
-use std::fs;
-use std::path::{PathBuf};
-
 use anyhow::{Context, Result};
 use chrono::{Duration, Timelike, Utc};
 
 use crate::core::persistence::metrics::k8s::pod::day::{
-    metric_pod_day_fs_adapter::MetricPodDayFsAdapter,
     metric_pod_day_processor_repository_trait::MetricPodDayProcessorRepository,
 };
-use tracing::{debug, error};
-use crate::core::persistence::metrics::k8s::path::metric_k8s_pod_dir_path;
+use tracing::{debug, error, info};
+use crate::core::persistence::metrics::segment::watermark::pending_windows;
 use crate::scheduler::tasks::processors::day::pod::metric_pod_day_processor_repository::MetricPodDayProcessorRepositoryImpl;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{MetricObjectKind, MetricStorageBackend};
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Day windows aggregated per object and run (one month); a longer backlog
+/// is worked off oldest first over the following runs.
+const MAX_CATCH_UP_WINDOWS: usize = 31;
 
 /// Aggregates all pods’ minute-level metrics into dayly metrics.
 ///
-/// This scans `data/metric/pod/{pod_uid}/` and calls `append_row_aggregated()`
-/// for each pod directory, generating an dayly summary.
+/// This walks every pod with stored metrics and upserts every window since the
+/// object's watermark, so missed windows are caught up and reruns are safe.
 pub async fn process_pod_hour_to_day() -> Result<()> {
-    let (start, end) = previous_day_window()?;
-    let base_dir = metric_k8s_pod_dir_path();
-
-    if !base_dir.exists() {
-        debug!("No pods directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
-    let pod_uids = collect_pod_uids(&base_dir)?;
+    let (_, until) = previous_day_window()?;
+    let backend = metric_backend()?;
+    let pod_uids = backend.keys(MetricObjectKind::Pod)?;
     if pod_uids.is_empty() {
-        debug!("No pod metric directories found under {:?}", base_dir);
+        debug!("No pod metrics found");
         return Ok(());
     }
 
     let repo = MetricPodDayProcessorRepositoryImpl {
-        adapter: MetricPodDayFsAdapter,
+        adapter: backend.pod(MetricTier::Day),
     };
 
-    process_all_pods(&repo, &pod_uids, start, end);
+    process_all_pods(&repo, backend.as_ref(), &pod_uids, until);
     Ok(())
 }
 
@@ -43,7 +40,8 @@ pub async fn process_pod_hour_to_day() -> Result<()> {
 fn previous_day_window() -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc>)> {
     let now = Utc::now();
     let end = now
-        .with_minute(0)
+        .with_hour(0)
+        .and_then(|d| d.with_minute(0))
         .and_then(|d| d.with_second(0))
         .and_then(|d| d.with_nanosecond(0))
         .context("failed to round current time to day")?;
@@ -51,36 +49,34 @@ fn previous_day_window() -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc>
     Ok((start, end))
 }
 
-/// Collects all pod UIDs (directory names) under the given base directory.
-fn collect_pod_uids(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut pod_uids = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(pod_uid) = entry.file_name().to_str() {
-                pod_uids.push(pod_uid.to_string());
-            }
-        }
-    }
-
-    Ok(pod_uids)
-}
 
 /// Aggregates minute-level data into dayly data for all given pods.
 fn process_all_pods<R: MetricPodDayProcessorRepository>(
     repo: &R,
+    backend: &dyn MetricStorageBackend,
     pod_uids: &[String],
-    start: chrono::DateTime<Utc>,
-    end: chrono::DateTime<Utc>,
+    until: chrono::DateTime<Utc>,
 ) {
     for pod_uid in pod_uids {
-        match repo.append_row_aggregated(pod_uid, start, end) {
-            Ok(_) => debug!(
-                "✅ Aggregated pod '{}' minute metrics from {} → {}",
-                pod_uid, start, end
-            ),
+        let watermark = backend.watermark(MetricObjectKind::Pod, MetricTier::Day, pod_uid);
+        let windows = pending_windows(watermark, until, Duration::days(1), MAX_CATCH_UP_WINDOWS);
+        let (Some(&(first, _)), Some(&(_, end))) = (windows.first(), windows.last()) else {
+            continue;
+        };
+
+        match repo.upsert_aggregated(pod_uid, &windows) {
+            Ok(rows) => {
+                debug!(
+                    "✅ Aggregated pod '{}' hour metrics from {} → {} ({} windows, {} rows)",
+                    pod_uid, first, end, windows.len(), rows
+                );
+                if let Err(err) = backend.set_watermark(MetricObjectKind::Pod, MetricTier::Day, pod_uid, end) {
+                    error!("⚠️ Failed to record watermark for pod '{}': {}", pod_uid, err);
+                }
+                if end < until {
+                    info!("⏳ Pod '{}' day aggregation caught up to {}; the rest follows next run", pod_uid, end);
+                }
+            }
             Err(err) => error!(
                 "⚠️ Failed to aggregate pod '{}' metrics: {}",
                 pod_uid, err
@@ -88,4 +84,3 @@ fn process_all_pods<R: MetricPodDayProcessorRepository>(
         }
     }
 }
-
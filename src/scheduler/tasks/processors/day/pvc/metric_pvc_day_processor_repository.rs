@@ -0,0 +1,19 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::pvc::day::metric_pvc_day_processor_repository_trait::MetricPvcDayProcessorRepository;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+pub struct MetricPvcDayProcessorRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricPvcEntity>>,
+}
+
+impl MetricPvcDayProcessorRepository for MetricPvcDayProcessorRepositoryImpl  {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPvcEntity> {
+        self.adapter.as_ref()
+    }
+
+    fn append_row_aggregated(&self, pvc_key: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> anyhow::Result<()> {
+        self.adapter.append_row_aggregated(pvc_key, start, end)
+    }
+}
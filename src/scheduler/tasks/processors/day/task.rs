@@ -3,6 +3,9 @@ use tracing::{debug};
 use crate::scheduler::tasks::processors::day::pod::task::process_pod_hour_to_day;
 use crate::scheduler::tasks::processors::day::node::task::process_node_hour_to_day;
 use crate::scheduler::tasks::processors::day::container::task::process_container_hour_to_day;
+use crate::scheduler::tasks::processors::day::pvc::task::process_pvc_hour_to_day;
+use crate::scheduler::tasks::processors::day::node_network::task::process_node_network_hour_to_day;
+use crate::scheduler::tasks::processors::day::node_system::task::process_node_system_hour_to_day;
 
 pub async fn run() -> Result<()> {
     debug!("Running day aggregation task...");
@@ -13,6 +16,15 @@ pub async fn run() -> Result<()> {
     process_container_hour_to_day()
         .await
         .expect("Failed to process container hour-to-day aggregation");
+    process_pvc_hour_to_day()
+        .await
+        .expect("Failed to process pvc hour-to-day aggregation");
+    process_node_network_hour_to_day()
+        .await
+        .expect("Failed to process node network hour-to-day aggregation");
+    process_node_system_hour_to_day()
+        .await
+        .expect("Failed to process node system hour-to-day aggregation");
     process_node_hour_to_day()
         .await
         .expect("Failed to process node hour-to-day aggregation");
@@ -2,15 +2,15 @@ use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAda
 use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_processor_repository_trait::MetricContainerHourProcessorRepository;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use chrono::{DateTime, Utc};
-use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_fs_adapter::MetricContainerHourFsAdapter;
+use std::sync::Arc;
 
 pub struct MetricContainerHourProcessorRepositoryImpl {
-    pub adapter: MetricContainerHourFsAdapter,
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricContainerEntity>>,
 }
 
 impl MetricContainerHourProcessorRepository for MetricContainerHourProcessorRepositoryImpl  {
     fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn append_row_aggregated(&self, container_uid: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> anyhow::Result<()> {
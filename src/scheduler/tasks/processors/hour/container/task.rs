@@ -1,40 +1,38 @@
-use std::fs;
-use std::path::PathBuf;
-
 use anyhow::{Context, Result};
 use chrono::{Duration, Timelike, Utc};
 
 use crate::core::persistence::metrics::k8s::container::hour::{
-    metric_container_hour_fs_adapter::MetricContainerHourFsAdapter,
     metric_container_hour_processor_repository_trait::MetricContainerHourProcessorRepository,
 };
 use crate::scheduler::tasks::processors::hour::container::metric_container_hour_processor_repository::MetricContainerHourProcessorRepositoryImpl;
-use tracing::{debug, error};
-use crate::core::persistence::metrics::k8s::path::metric_k8s_container_dir_path;
+use tracing::{debug, error, info};
+use crate::core::persistence::metrics::segment::watermark::pending_windows;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{MetricObjectKind, MetricStorageBackend};
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Hour windows aggregated per object and run (one week); a longer backlog
+/// is worked off oldest first over the following runs.
+const MAX_CATCH_UP_WINDOWS: usize = 24 * 7;
 
 /// Aggregates all containers’ minute-level metrics into hour metrics.
 ///
-/// This scans `data/metric/container/{container_key}/` and calls `append_row_aggregated()`
-/// for each container directory, generating an hour summary.
+/// This walks every container with stored metrics and upserts every window since the
+/// object's watermark, so missed windows are caught up and reruns are safe.
 pub async fn process_container_minute_to_hour() -> Result<()> {
-    let (start, end) = previous_hour_window()?;
-    let base_dir = metric_k8s_container_dir_path();
-    if !base_dir.exists() {
-        debug!("No containers directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
-    let container_keys = collect_container_keys(&base_dir)?;
+    let (_, until) = previous_hour_window()?;
+    let backend = metric_backend()?;
+    let container_keys = backend.keys(MetricObjectKind::Container)?;
     if container_keys.is_empty() {
-        debug!("No container metric directories found under {:?}", base_dir);
+        debug!("No container metrics found");
         return Ok(());
     }
 
     let repo = MetricContainerHourProcessorRepositoryImpl {
-        adapter: MetricContainerHourFsAdapter,
+        adapter: backend.container(MetricTier::Hour),
     };
 
-    process_all_containers(&repo, &container_keys, start, end);
+    process_all_containers(&repo, backend.as_ref(), &container_keys, until);
     Ok(())
 }
 
@@ -50,36 +48,34 @@ fn previous_hour_window() -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc
     Ok((start, end))
 }
 
-/// Collects all container UIDs (directory names) under the given base directory.
-fn collect_container_keys(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut container_keys = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(container_key) = entry.file_name().to_str() {
-                container_keys.push(container_key.to_string());
-            }
-        }
-    }
-
-    Ok(container_keys)
-}
 
 /// Aggregates minute-level data into hour data for all given containers.
 fn process_all_containers<R: MetricContainerHourProcessorRepository>(
     repo: &R,
+    backend: &dyn MetricStorageBackend,
     container_keys: &[String],
-    start: chrono::DateTime<Utc>,
-    end: chrono::DateTime<Utc>,
+    until: chrono::DateTime<Utc>,
 ) {
     for container_key in container_keys {
-        match repo.append_row_aggregated(container_key, start, end) {
-            Ok(_) => debug!(
-                "✅ Aggregated container '{}' minute metrics from {} → {}",
-                container_key, start, end
-            ),
+        let watermark = backend.watermark(MetricObjectKind::Container, MetricTier::Hour, container_key);
+        let windows = pending_windows(watermark, until, Duration::hours(1), MAX_CATCH_UP_WINDOWS);
+        let (Some(&(first, _)), Some(&(_, end))) = (windows.first(), windows.last()) else {
+            continue;
+        };
+
+        match repo.upsert_aggregated(container_key, &windows) {
+            Ok(rows) => {
+                debug!(
+                    "✅ Aggregated container '{}' minute metrics from {} → {} ({} windows, {} rows)",
+                    container_key, first, end, windows.len(), rows
+                );
+                if let Err(err) = backend.set_watermark(MetricObjectKind::Container, MetricTier::Hour, container_key, end) {
+                    error!("⚠️ Failed to record watermark for container '{}': {}", container_key, err);
+                }
+                if end < until {
+                    info!("⏳ Container '{}' hour aggregation caught up to {}; the rest follows next run", container_key, end);
+                }
+            }
             Err(err) => error!(
                 "⚠️ Failed to aggregate container '{}' metrics: {}",
                 container_key, err
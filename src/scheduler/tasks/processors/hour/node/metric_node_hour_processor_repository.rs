@@ -2,15 +2,15 @@ use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAda
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_processor_repository_trait::MetricNodeHourProcessorRepository;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use chrono::{DateTime, Utc};
-use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_fs_adapter::MetricNodeHourFsAdapter;
+use std::sync::Arc;
 
 pub struct MetricNodeHourProcessorRepositoryImpl {
-    pub adapter: MetricNodeHourFsAdapter,
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeEntity>>,
 }
 
 impl MetricNodeHourProcessorRepository for MetricNodeHourProcessorRepositoryImpl  {
     fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn append_row_aggregated(&self, node_uid: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> anyhow::Result<()> {
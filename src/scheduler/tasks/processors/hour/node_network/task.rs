@@ -0,0 +1,86 @@
+use anyhow::{Context, Result};
+use chrono::{Duration, Timelike, Utc};
+
+use crate::core::persistence::metrics::k8s::node_network::hour::{
+    metric_node_network_hour_processor_repository_trait::MetricNodeNetworkHourProcessorRepository,
+};
+use crate::scheduler::tasks::processors::hour::node_network::metric_node_network_hour_processor_repository::MetricNodeNetworkHourProcessorRepositoryImpl;
+use tracing::{debug, error, info};
+use crate::core::persistence::metrics::segment::watermark::pending_windows;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{MetricObjectKind, MetricStorageBackend};
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Hour windows aggregated per object and run (one week); a longer backlog
+/// is worked off oldest first over the following runs.
+const MAX_CATCH_UP_WINDOWS: usize = 24 * 7;
+
+/// Aggregates all node network interfaces’ minute-level metrics into hour metrics.
+///
+/// This walks every node network interface with stored metrics and upserts
+/// every window since the object's watermark, so missed windows are caught up
+/// and reruns are safe.
+pub async fn process_node_network_minute_to_hour() -> Result<()> {
+    let (_, until) = previous_hour_window()?;
+    let backend = metric_backend()?;
+    let interface_keys = backend.keys(MetricObjectKind::NodeNetwork)?;
+    if interface_keys.is_empty() {
+        debug!("No node network metrics found");
+        return Ok(());
+    }
+
+    let repo = MetricNodeNetworkHourProcessorRepositoryImpl {
+        adapter: backend.node_network(MetricTier::Hour),
+    };
+
+    process_all_node_networks(&repo, backend.as_ref(), &interface_keys, until);
+    Ok(())
+}
+
+/// Returns the start and end of the previous full hour.
+fn previous_hour_window() -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc>)> {
+    let now = Utc::now();
+    let end = now
+        .with_minute(0)
+        .and_then(|d| d.with_second(0))
+        .and_then(|d| d.with_nanosecond(0))
+        .context("failed to round current time to hour")?;
+    let start = end - Duration::hours(1);
+    Ok((start, end))
+}
+
+
+/// Aggregates minute-level data into hour data for all given node network interfaces.
+fn process_all_node_networks<R: MetricNodeNetworkHourProcessorRepository>(
+    repo: &R,
+    backend: &dyn MetricStorageBackend,
+    interface_keys: &[String],
+    until: chrono::DateTime<Utc>,
+) {
+    for interface_key in interface_keys {
+        let watermark = backend.watermark(MetricObjectKind::NodeNetwork, MetricTier::Hour, interface_key);
+        let windows = pending_windows(watermark, until, Duration::hours(1), MAX_CATCH_UP_WINDOWS);
+        let (Some(&(first, _)), Some(&(_, end))) = (windows.first(), windows.last()) else {
+            continue;
+        };
+
+        match repo.upsert_aggregated(interface_key, &windows) {
+            Ok(rows) => {
+                debug!(
+                    "✅ Aggregated node interface '{}' minute metrics from {} → {} ({} windows, {} rows)",
+                    interface_key, first, end, windows.len(), rows
+                );
+                if let Err(err) = backend.set_watermark(MetricObjectKind::NodeNetwork, MetricTier::Hour, interface_key, end) {
+                    error!("⚠️ Failed to record watermark for node interface '{}': {}", interface_key, err);
+                }
+                if end < until {
+                    info!("⏳ Node interface '{}' hour aggregation caught up to {}; the rest follows next run", interface_key, end);
+                }
+            }
+            Err(err) => error!(
+                "⚠️ Failed to aggregate node interface '{}' metrics: {}",
+                interface_key, err
+            ),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod task;
+pub mod metric_node_system_hour_processor_repository;
\ No newline at end of file
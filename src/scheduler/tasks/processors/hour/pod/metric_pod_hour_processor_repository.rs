@@ -2,15 +2,15 @@ use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAda
 use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_processor_repository_trait::MetricPodHourProcessorRepository;
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use chrono::{DateTime, Utc};
-use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_fs_adapter::MetricPodHourFsAdapter;
+use std::sync::Arc;
 
 pub struct MetricPodHourProcessorRepositoryImpl {
-    pub adapter: MetricPodHourFsAdapter,
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricPodEntity>>,
 }
 
 impl MetricPodHourProcessorRepository for MetricPodHourProcessorRepositoryImpl  {
     fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn append_row_aggregated(&self, pod_uid: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> anyhow::Result<()> {
@@ -0,0 +1,85 @@
+use anyhow::{Context, Result};
+use chrono::{Duration, Timelike, Utc};
+
+use crate::core::persistence::metrics::k8s::pvc::hour::{
+    metric_pvc_hour_processor_repository_trait::MetricPvcHourProcessorRepository,
+};
+use crate::scheduler::tasks::processors::hour::pvc::metric_pvc_hour_processor_repository::MetricPvcHourProcessorRepositoryImpl;
+use tracing::{debug, error, info};
+use crate::core::persistence::metrics::segment::watermark::pending_windows;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{MetricObjectKind, MetricStorageBackend};
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Hour windows aggregated per object and run (one week); a longer backlog
+/// is worked off oldest first over the following runs.
+const MAX_CATCH_UP_WINDOWS: usize = 24 * 7;
+
+/// Aggregates all PVCs’ minute-level metrics into hour metrics.
+///
+/// This walks every PVC with stored metrics and upserts every window since the
+/// object's watermark, so missed windows are caught up and reruns are safe.
+pub async fn process_pvc_minute_to_hour() -> Result<()> {
+    let (_, until) = previous_hour_window()?;
+    let backend = metric_backend()?;
+    let pvc_keys = backend.keys(MetricObjectKind::Pvc)?;
+    if pvc_keys.is_empty() {
+        debug!("No pvc metrics found");
+        return Ok(());
+    }
+
+    let repo = MetricPvcHourProcessorRepositoryImpl {
+        adapter: backend.pvc(MetricTier::Hour),
+    };
+
+    process_all_pvcs(&repo, backend.as_ref(), &pvc_keys, until);
+    Ok(())
+}
+
+/// Returns the start and end of the previous full hour.
+fn previous_hour_window() -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc>)> {
+    let now = Utc::now();
+    let end = now
+        .with_minute(0)
+        .and_then(|d| d.with_second(0))
+        .and_then(|d| d.with_nanosecond(0))
+        .context("failed to round current time to hour")?;
+    let start = end - Duration::hours(1);
+    Ok((start, end))
+}
+
+
+/// Aggregates minute-level data into hour data for all given PVCs.
+fn process_all_pvcs<R: MetricPvcHourProcessorRepository>(
+    repo: &R,
+    backend: &dyn MetricStorageBackend,
+    pvc_keys: &[String],
+    until: chrono::DateTime<Utc>,
+) {
+    for pvc_key in pvc_keys {
+        let watermark = backend.watermark(MetricObjectKind::Pvc, MetricTier::Hour, pvc_key);
+        let windows = pending_windows(watermark, until, Duration::hours(1), MAX_CATCH_UP_WINDOWS);
+        let (Some(&(first, _)), Some(&(_, end))) = (windows.first(), windows.last()) else {
+            continue;
+        };
+
+        match repo.upsert_aggregated(pvc_key, &windows) {
+            Ok(rows) => {
+                debug!(
+                    "✅ Aggregated pvc '{}' minute metrics from {} → {} ({} windows, {} rows)",
+                    pvc_key, first, end, windows.len(), rows
+                );
+                if let Err(err) = backend.set_watermark(MetricObjectKind::Pvc, MetricTier::Hour, pvc_key, end) {
+                    error!("⚠️ Failed to record watermark for pvc '{}': {}", pvc_key, err);
+                }
+                if end < until {
+                    info!("⏳ Pvc '{}' hour aggregation caught up to {}; the rest follows next run", pvc_key, end);
+                }
+            }
+            Err(err) => error!(
+                "⚠️ Failed to aggregate pvc '{}' metrics: {}",
+                pvc_key, err
+            ),
+        }
+    }
+}
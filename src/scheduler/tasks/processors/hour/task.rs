@@ -3,6 +3,9 @@ use tracing::{debug};
 use crate::scheduler::tasks::processors::hour::pod::task::process_pod_minute_to_hour;
 use crate::scheduler::tasks::processors::hour::node::task::process_node_minute_to_hour;
 use crate::scheduler::tasks::processors::hour::container::task::process_container_minute_to_hour;
+use crate::scheduler::tasks::processors::hour::pvc::task::process_pvc_minute_to_hour;
+use crate::scheduler::tasks::processors::hour::node_network::task::process_node_network_minute_to_hour;
+use crate::scheduler::tasks::processors::hour::node_system::task::process_node_system_minute_to_hour;
 
 pub async fn run() -> Result<()> {
     debug!("Running hour aggregation task...");
@@ -13,6 +16,15 @@ pub async fn run() -> Result<()> {
     process_container_minute_to_hour()
         .await
         .expect("Failed to process container minute-to-hour aggregation");
+    process_pvc_minute_to_hour()
+        .await
+        .expect("Failed to process pvc minute-to-hour aggregation");
+    process_node_network_minute_to_hour()
+        .await
+        .expect("Failed to process node network minute-to-hour aggregation");
+    process_node_system_minute_to_hour()
+        .await
+        .expect("Failed to process node system minute-to-hour aggregation");
     process_node_minute_to_hour()
         .await
         .expect("Failed to process node minute-to-hour aggregation");
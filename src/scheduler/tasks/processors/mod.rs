@@ -1,3 +1,5 @@
 pub mod retention;
+pub mod compression;
+pub mod quota;
 pub mod hour;
-pub mod day;
\ No newline at end of file
+pub mod day;
@@ -0,0 +1,2 @@
+mod task;
+pub use task::{check, run};
@@ -0,0 +1,40 @@
+use anyhow::Result;
+use chrono::Utc;
+use tracing::debug;
+
+use crate::core::persistence::quota::storage_quota_manager::StorageQuotaManager;
+use crate::scheduler::tasks::info::settings::task::load_or_init_settings;
+
+/// Enforces `max_storage_gb`, evicting the oldest minute and then hour segments.
+/// Walks the whole data directory.
+pub async fn run() -> Result<()> {
+    let settings = load_or_init_settings()?;
+    let Some(manager) = StorageQuotaManager::new(settings.max_storage_gb) else {
+        debug!("Storage quota disabled; skipping");
+        return Ok(());
+    };
+
+    let report = manager.enforce(Utc::now())?;
+    debug!(
+        "✅ Storage quota check: {} / {} bytes, evicted {} segments ({} bytes)",
+        report.used_bytes_after, report.limit_bytes, report.evicted_segments, report.evicted_bytes
+    );
+    Ok(())
+}
+
+/// Per-collection quota check; walks the data directory only when the usage
+/// estimated from the last walk and the writes since exceeds the limit.
+pub async fn check() -> Result<()> {
+    let settings = load_or_init_settings()?;
+    let Some(manager) = StorageQuotaManager::new(settings.max_storage_gb) else {
+        return Ok(());
+    };
+
+    if let Some(report) = manager.check(Utc::now())? {
+        debug!(
+            "✅ Storage quota enforced: {} / {} bytes, evicted {} segments ({} bytes)",
+            report.used_bytes_after, report.limit_bytes, report.evicted_segments, report.evicted_bytes
+        );
+    }
+    Ok(())
+}
@@ -1,7 +1,10 @@
 mod task;
-pub use task::run;
+pub use task::{run, RetentionPlan};
 
 pub mod container;
 pub mod node;
 pub mod pod;
-
+pub mod pvc;
+pub mod node_network;
+pub mod node_system;
+pub mod purge;
@@ -1,19 +1,23 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use chrono::{DateTime, Utc};
-use crate::core::persistence::metrics::k8s::node::day::metric_node_day_fs_adapter::MetricNodeDayFsAdapter;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_retention_repository_traits::MetricNodeDayRetentionRepository;
+use std::sync::Arc;
 
 pub struct MetricNodeDayRetentionRepositoryImpl {
-    pub adapter: MetricNodeDayFsAdapter,
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeEntity>>,
 }
 
 impl MetricNodeDayRetentionRepository for MetricNodeDayRetentionRepositoryImpl  {
     fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity> {
-        &self.adapter
+        self.adapter.as_ref()
     }
 
     fn cleanup_old(&self, node_key: &str, before: DateTime<Utc>) -> anyhow::Result<()> {
         self.adapter.cleanup_old(node_key, before)
     }
+
+    fn archive_old(&self, node_key: &str, before: DateTime<Utc>) -> anyhow::Result<()> {
+        self.adapter.archive_old(node_key, before)
+    }
 }
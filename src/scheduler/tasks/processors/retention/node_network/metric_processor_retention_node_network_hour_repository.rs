@@ -0,0 +1,23 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::MetricNodeNetworkEntity;
+use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::k8s::node_network::hour::metric_node_network_hour_retention_repository_traits::MetricNodeNetworkHourRetentionRepository;
+use std::sync::Arc;
+
+pub struct MetricNodeNetworkHourRetentionRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeNetworkEntity>>,
+}
+
+impl MetricNodeNetworkHourRetentionRepository for MetricNodeNetworkHourRetentionRepositoryImpl  {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeNetworkEntity> {
+        self.adapter.as_ref()
+    }
+
+    fn cleanup_old(&self, interface_key: &str, before: DateTime<Utc>) -> anyhow::Result<()> {
+        self.adapter.cleanup_old(interface_key, before)
+    }
+
+    fn archive_old(&self, interface_key: &str, before: DateTime<Utc>) -> anyhow::Result<()> {
+        self.adapter.archive_old(interface_key, before)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod task;
+pub mod metric_processor_retention_node_network_hour_repository;
+pub mod metric_processor_retention_node_network_day_repository;
+pub mod metric_processor_retention_node_network_minute_repository;
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anyhow::{ Result};
+use tracing::{debug, error};
+
+use crate::core::persistence::metrics::k8s::node_network::day::metric_node_network_day_retention_repository_traits::MetricNodeNetworkDayRetentionRepository;
+use crate::core::persistence::metrics::k8s::node_network::hour::metric_node_network_hour_retention_repository_traits::MetricNodeNetworkHourRetentionRepository;
+use crate::core::persistence::metrics::k8s::node_network::minute::metric_node_network_minute_retention_repository_traits::MetricNodeNetworkMinuteRetentionRepository;
+use crate::scheduler::tasks::processors::retention::RetentionPlan;
+use crate::scheduler::tasks::processors::retention::node_network::metric_processor_retention_node_network_day_repository::MetricNodeNetworkDayRetentionRepositoryImpl;
+use crate::scheduler::tasks::processors::retention::node_network::metric_processor_retention_node_network_hour_repository::MetricNodeNetworkHourRetentionRepositoryImpl;
+use crate::scheduler::tasks::processors::retention::node_network::metric_processor_retention_node_network_minute_repository::MetricNodeNetworkMinuteRetentionRepositoryImpl;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricObjectKind;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Runs retention cleanup for all node network interfaces across minute/hour/day metrics.
+pub async fn run(plan: &RetentionPlan) -> Result<()> {
+    let interface_keys = metric_backend()?.keys(MetricObjectKind::NodeNetwork)?;
+    if interface_keys.is_empty() {
+        debug!("No node network metrics found");
+        return Ok(());
+    }
+
+    // Create adapters of the active storage backend
+    let day_adapter = metric_backend()?.node_network(MetricTier::Day);
+    let hour_adapter = metric_backend()?.node_network(MetricTier::Hour);
+    let minute_adapter = metric_backend()?.node_network(MetricTier::Minute);
+
+    // Create repositories
+    let day_repo = MetricNodeNetworkDayRetentionRepositoryImpl { adapter: day_adapter };
+    let hour_repo = MetricNodeNetworkHourRetentionRepositoryImpl { adapter: hour_adapter };
+    let minute_repo = MetricNodeNetworkMinuteRetentionRepositoryImpl { adapter: minute_adapter };
+
+    // Run cleanup for each node network interface
+    for interface_key in &interface_keys {
+        debug!("🧹 Running retention cleanup for node interface '{}'", interface_key);
+
+        if let Some(before) = plan.minute_before {
+            if let Err(err) = minute_repo.apply_retention(interface_key, before, plan.policy) {
+                error!("⚠️ Minute retention failed for {}: {}", interface_key, err);
+            }
+        }
+        if let Some(before) = plan.hour_before {
+            if let Err(err) = hour_repo.apply_retention(interface_key, before, plan.policy) {
+                error!("⚠️ Hour retention failed for {}: {}", interface_key, err);
+            }
+        }
+        if let Some(before) = plan.day_before {
+            if let Err(err) = day_repo.apply_retention(interface_key, before, plan.policy) {
+                error!("⚠️ Day retention failed for {}: {}", interface_key, err);
+            }
+        }
+    }
+
+    debug!("✅ Retention cleanup complete for all node network interfaces");
+    Ok(())
+}
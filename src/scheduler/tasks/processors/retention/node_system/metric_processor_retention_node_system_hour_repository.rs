@@ -0,0 +1,23 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::MetricNodeSystemEntity;
+use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::k8s::node_system::hour::metric_node_system_hour_retention_repository_traits::MetricNodeSystemHourRetentionRepository;
+use std::sync::Arc;
+
+pub struct MetricNodeSystemHourRetentionRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricNodeSystemEntity>>,
+}
+
+impl MetricNodeSystemHourRetentionRepository for MetricNodeSystemHourRetentionRepositoryImpl  {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeSystemEntity> {
+        self.adapter.as_ref()
+    }
+
+    fn cleanup_old(&self, system_key: &str, before: DateTime<Utc>) -> anyhow::Result<()> {
+        self.adapter.cleanup_old(system_key, before)
+    }
+
+    fn archive_old(&self, system_key: &str, before: DateTime<Utc>) -> anyhow::Result<()> {
+        self.adapter.archive_old(system_key, before)
+    }
+}
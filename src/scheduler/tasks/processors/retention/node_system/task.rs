@@ -0,0 +1,56 @@
+use anyhow::{ Result};
+use tracing::{debug, error};
+
+use crate::core::persistence::metrics::k8s::node_system::day::metric_node_system_day_retention_repository_traits::MetricNodeSystemDayRetentionRepository;
+use crate::core::persistence::metrics::k8s::node_system::hour::metric_node_system_hour_retention_repository_traits::MetricNodeSystemHourRetentionRepository;
+use crate::core::persistence::metrics::k8s::node_system::minute::metric_node_system_minute_retention_repository_traits::MetricNodeSystemMinuteRetentionRepository;
+use crate::scheduler::tasks::processors::retention::RetentionPlan;
+use crate::scheduler::tasks::processors::retention::node_system::metric_processor_retention_node_system_day_repository::MetricNodeSystemDayRetentionRepositoryImpl;
+use crate::scheduler::tasks::processors::retention::node_system::metric_processor_retention_node_system_hour_repository::MetricNodeSystemHourRetentionRepositoryImpl;
+use crate::scheduler::tasks::processors::retention::node_system::metric_processor_retention_node_system_minute_repository::MetricNodeSystemMinuteRetentionRepositoryImpl;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricObjectKind;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Runs retention cleanup for all node system containers across minute/hour/day metrics.
+pub async fn run(plan: &RetentionPlan) -> Result<()> {
+    let system_keys = metric_backend()?.keys(MetricObjectKind::NodeSystem)?;
+    if system_keys.is_empty() {
+        debug!("No node system metrics found");
+        return Ok(());
+    }
+
+    // Create adapters of the active storage backend
+    let day_adapter = metric_backend()?.node_system(MetricTier::Day);
+    let hour_adapter = metric_backend()?.node_system(MetricTier::Hour);
+    let minute_adapter = metric_backend()?.node_system(MetricTier::Minute);
+
+    // Create repositories
+    let day_repo = MetricNodeSystemDayRetentionRepositoryImpl { adapter: day_adapter };
+    let hour_repo = MetricNodeSystemHourRetentionRepositoryImpl { adapter: hour_adapter };
+    let minute_repo = MetricNodeSystemMinuteRetentionRepositoryImpl { adapter: minute_adapter };
+
+    // Run cleanup for each node system container
+    for system_key in &system_keys {
+        debug!("🧹 Running retention cleanup for node system container '{}'", system_key);
+
+        if let Some(before) = plan.minute_before {
+            if let Err(err) = minute_repo.apply_retention(system_key, before, plan.policy) {
+                error!("⚠️ Minute retention failed for {}: {}", system_key, err);
+            }
+        }
+        if let Some(before) = plan.hour_before {
+            if let Err(err) = hour_repo.apply_retention(system_key, before, plan.policy) {
+                error!("⚠️ Hour retention failed for {}: {}", system_key, err);
+            }
+        }
+        if let Some(before) = plan.day_before {
+            if let Err(err) = day_repo.apply_retention(system_key, before, plan.policy) {
+                error!("⚠️ Day retention failed for {}: {}", system_key, err);
+            }
+        }
+    }
+
+    debug!("✅ Retention cleanup complete for all node system containers");
+    Ok(())
+}
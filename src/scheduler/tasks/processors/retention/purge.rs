@@ -0,0 +1,138 @@
+use anyhow::Result;
+use tracing::{debug, error, info};
+
+use crate::core::persistence::info::k8s::container::info_container_fs_adapter::InfoContainerFsAdapter;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::node::info_node_fs_adapter::InfoNodeFsAdapter;
+use crate::core::persistence::info::k8s::pod::info_pod_fs_adapter::InfoPodFsAdapter;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::{MetricObjectKind, MetricStorageBackend};
+use crate::core::persistence::metrics::k8s::node_network::metric_node_network_entity::split_node_network_key;
+use crate::core::persistence::metrics::k8s::node_system::metric_node_system_entity::split_node_system_key;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Purges objects marked deleted by the collector once retention has expired
+/// every tier of their metrics.
+///
+/// Runs after the per-kind retention passes. The metric directory and the info
+/// entry are removed together, so processors no longer walk the object.
+pub async fn run() -> Result<()> {
+    let backend = metric_backend()?;
+
+    let nodes = purge_kind(backend.as_ref(), MetricObjectKind::Node, &InfoNodeFsAdapter, |n| n.deleted)?;
+    let pods = purge_kind(backend.as_ref(), MetricObjectKind::Pod, &InfoPodFsAdapter, |p| p.deleted)?;
+    let containers = purge_kind(
+        backend.as_ref(),
+        MetricObjectKind::Container,
+        &InfoContainerFsAdapter,
+        |c| c.deleted,
+    )?;
+    let interfaces = purge_node_series(
+        backend.as_ref(),
+        MetricObjectKind::NodeNetwork,
+        split_node_network_key,
+        &InfoNodeFsAdapter,
+    )?;
+    let system_containers = purge_node_series(
+        backend.as_ref(),
+        MetricObjectKind::NodeSystem,
+        split_node_system_key,
+        &InfoNodeFsAdapter,
+    )?;
+
+    if nodes + pods + containers + interfaces + system_containers > 0 {
+        info!(
+            "🪦 Purged deleted objects: {} nodes, {} pods, {} containers, {} node interfaces, {} node system containers",
+            nodes, pods, containers, interfaces, system_containers
+        );
+    } else {
+        debug!("No deleted objects to purge");
+    }
+    Ok(())
+}
+
+/// Purges the deleted objects of one kind that have no rows left in any tier.
+/// Returns the number of objects purged.
+fn purge_kind<T>(
+    backend: &dyn MetricStorageBackend,
+    kind: MetricObjectKind,
+    info: &dyn InfoDynamicFsAdapterTrait<T>,
+    deleted: impl Fn(&T) -> Option<bool>,
+) -> Result<usize> {
+    let mut purged = 0;
+
+    for key in info.keys()? {
+        let Ok(entity) = info.read(&key) else { continue };
+        if deleted(&entity) != Some(true) {
+            continue;
+        }
+
+        match has_rows(backend, kind, &key) {
+            Ok(false) => {}
+            Ok(true) => continue,
+            Err(e) => {
+                error!("⚠️ Failed to check stored rows of {} '{}': {}", kind.as_str(), key, e);
+                continue;
+            }
+        }
+
+        if let Err(e) = backend.remove_object(kind, &key) {
+            error!("⚠️ Failed to purge metrics of {} '{}': {}", kind.as_str(), key, e);
+            continue;
+        }
+        if let Err(e) = info.delete(&key) {
+            error!("⚠️ Failed to purge info of {} '{}': {}", kind.as_str(), key, e);
+            continue;
+        }
+        debug!("🪦 Purged deleted {} '{}'", kind.as_str(), key);
+        purged += 1;
+    }
+    Ok(purged)
+}
+
+/// Purges the per-node series (interfaces, system containers) of nodes that
+/// are gone, once they have no rows left. They have no info entry of their
+/// own; they follow their node.
+fn purge_node_series(
+    backend: &dyn MetricStorageBackend,
+    kind: MetricObjectKind,
+    split_key: fn(&str) -> Option<(&str, &str)>,
+    nodes: &InfoNodeFsAdapter,
+) -> Result<usize> {
+    let live_nodes = nodes.keys()?;
+    let mut purged = 0;
+
+    for key in backend.keys(kind)? {
+        let Some((node_name, _)) = split_key(&key) else { continue };
+        if live_nodes.iter().any(|n| n == node_name) {
+            continue;
+        }
+
+        match has_rows(backend, kind, &key) {
+            Ok(false) => {}
+            Ok(true) => continue,
+            Err(e) => {
+                error!("⚠️ Failed to check stored rows of {} '{}': {}", kind.as_str(), key, e);
+                continue;
+            }
+        }
+
+        if let Err(e) = backend.remove_object(kind, &key) {
+            error!("⚠️ Failed to purge metrics of {} '{}': {}", kind.as_str(), key, e);
+            continue;
+        }
+        debug!("🪦 Purged {} '{}' of removed node", kind.as_str(), key);
+        purged += 1;
+    }
+    Ok(purged)
+}
+
+/// Whether any tier still holds rows of the object.
+fn has_rows(backend: &dyn MetricStorageBackend, kind: MetricObjectKind, key: &str) -> Result<bool> {
+    for tier in MetricTier::ALL {
+        if backend.first_time(kind, tier, key)?.is_some() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
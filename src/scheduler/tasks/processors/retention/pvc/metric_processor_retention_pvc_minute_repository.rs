@@ -0,0 +1,23 @@
+use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::k8s::pvc::metric_pvc_entity::MetricPvcEntity;
+use chrono::{DateTime, Utc};
+use crate::core::persistence::metrics::k8s::pvc::minute::metric_pvc_minute_retention_repository_traits::MetricPvcMinuteRetentionRepository;
+use std::sync::Arc;
+
+pub struct MetricPvcMinuteRetentionRepositoryImpl {
+    pub adapter: Arc<dyn MetricFsAdapterBase<MetricPvcEntity>>,
+}
+
+impl MetricPvcMinuteRetentionRepository for MetricPvcMinuteRetentionRepositoryImpl  {
+    fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPvcEntity> {
+        self.adapter.as_ref()
+    }
+
+    fn cleanup_old(&self, pvc_key: &str, before: DateTime<Utc>) -> anyhow::Result<()> {
+        self.adapter.cleanup_old(pvc_key, before)
+    }
+
+    fn archive_old(&self, pvc_key: &str, before: DateTime<Utc>) -> anyhow::Result<()> {
+        self.adapter.archive_old(pvc_key, before)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod task;
+pub mod metric_processor_retention_pvc_hour_repository;
+pub mod metric_processor_retention_pvc_day_repository;
+pub mod metric_processor_retention_pvc_minute_repository;
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anyhow::{ Result};
+use tracing::{debug, error};
+
+use crate::core::persistence::metrics::k8s::pvc::day::metric_pvc_day_retention_repository_traits::MetricPvcDayRetentionRepository;
+use crate::core::persistence::metrics::k8s::pvc::hour::metric_pvc_hour_retention_repository_traits::MetricPvcHourRetentionRepository;
+use crate::core::persistence::metrics::k8s::pvc::minute::metric_pvc_minute_retention_repository_traits::MetricPvcMinuteRetentionRepository;
+use crate::scheduler::tasks::processors::retention::RetentionPlan;
+use crate::scheduler::tasks::processors::retention::pvc::metric_processor_retention_pvc_day_repository::MetricPvcDayRetentionRepositoryImpl;
+use crate::scheduler::tasks::processors::retention::pvc::metric_processor_retention_pvc_hour_repository::MetricPvcHourRetentionRepositoryImpl;
+use crate::scheduler::tasks::processors::retention::pvc::metric_processor_retention_pvc_minute_repository::MetricPvcMinuteRetentionRepositoryImpl;
+use crate::core::persistence::metrics::backend::metric_backend;
+use crate::core::persistence::metrics::backend::metric_storage_backend_trait::MetricObjectKind;
+use crate::core::persistence::metrics::segment::tier::MetricTier;
+
+/// Runs retention cleanup for all PVCs across minute/hour/day metrics.
+pub async fn run(plan: &RetentionPlan) -> Result<()> {
+    let pvc_keys = metric_backend()?.keys(MetricObjectKind::Pvc)?;
+    if pvc_keys.is_empty() {
+        debug!("No pvc metrics found");
+        return Ok(());
+    }
+
+    // Create adapters of the active storage backend
+    let day_adapter = metric_backend()?.pvc(MetricTier::Day);
+    let hour_adapter = metric_backend()?.pvc(MetricTier::Hour);
+    let minute_adapter = metric_backend()?.pvc(MetricTier::Minute);
+
+    // Create repositories
+    let day_repo = MetricPvcDayRetentionRepositoryImpl { adapter: day_adapter };
+    let hour_repo = MetricPvcHourRetentionRepositoryImpl { adapter: hour_adapter };
+    let minute_repo = MetricPvcMinuteRetentionRepositoryImpl { adapter: minute_adapter };
+
+    // Run cleanup for each PVC
+    for pvc_key in &pvc_keys {
+        debug!("🧹 Running retention cleanup for pvc '{}'", pvc_key);
+
+        if let Some(before) = plan.minute_before {
+            if let Err(err) = minute_repo.apply_retention(pvc_key, before, plan.policy) {
+                error!("⚠️ Minute retention failed for {}: {}", pvc_key, err);
+            }
+        }
+        if let Some(before) = plan.hour_before {
+            if let Err(err) = hour_repo.apply_retention(pvc_key, before, plan.policy) {
+                error!("⚠️ Hour retention failed for {}: {}", pvc_key, err);
+            }
+        }
+        if let Some(before) = plan.day_before {
+            if let Err(err) = day_repo.apply_retention(pvc_key, before, plan.policy) {
+                error!("⚠️ Day retention failed for {}: {}", pvc_key, err);
+            }
+        }
+    }
+
+    debug!("✅ Retention cleanup complete for all pvcs");
+    Ok(())
+}
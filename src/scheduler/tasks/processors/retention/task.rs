@@ -1,9 +1,57 @@
 use anyhow::Result;
+use chrono::{DateTime, Duration, Months, Utc};
+use tracing::debug;
+
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::metrics::metric_retention_policy::MetricRetentionPolicy;
+use crate::scheduler::tasks::info::settings::task::load_or_init_settings;
 use crate::scheduler::tasks::processors::retention;
 
+/// Retention cutoffs and policy resolved from the persisted settings for one run.
+#[derive(Debug, Clone, Copy)]
+pub struct RetentionPlan {
+    pub policy: MetricRetentionPolicy,
+    /// Cutoff per tier; `None` (a retention value of `0`) keeps the tier forever.
+    pub minute_before: Option<DateTime<Utc>>,
+    pub hour_before: Option<DateTime<Utc>>,
+    pub day_before: Option<DateTime<Utc>>,
+}
+
+impl RetentionPlan {
+    pub fn from_settings(settings: &InfoSettingEntity, now: DateTime<Utc>) -> Self {
+        let minute_before = match settings.minute_retention_days {
+            0 => None,
+            days => Some(now - Duration::days(days as i64)),
+        };
+        let hour_before = match settings.hour_retention_months {
+            0 => None,
+            months => now.checked_sub_months(Months::new(months)),
+        };
+        let day_before = match settings.day_retention_years {
+            0 => None,
+            years => now.checked_sub_months(Months::new(years.saturating_mul(12))),
+        };
+
+        Self {
+            policy: MetricRetentionPolicy::from_setting(&settings.retention_policy),
+            minute_before,
+            hour_before,
+            day_before,
+        }
+    }
+}
+
 pub async fn run() -> Result<()> {
-    retention::pod::task::run().await?;
-    retention::node::task::run().await?;
-    retention::container::task::run().await?;
+    let settings = load_or_init_settings()?;
+    let plan = RetentionPlan::from_settings(&settings, Utc::now());
+    debug!(?plan, "Resolved retention plan from settings");
+
+    retention::pod::task::run(&plan).await?;
+    retention::node::task::run(&plan).await?;
+    retention::container::task::run(&plan).await?;
+    retention::pvc::task::run(&plan).await?;
+    retention::node_network::task::run(&plan).await?;
+    retention::node_system::task::run(&plan).await?;
+    retention::purge::run().await?;
     Ok(())
-}
\ No newline at end of file
+}